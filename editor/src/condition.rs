@@ -38,6 +38,7 @@ impl ConditionEditor {
                         <option>"—"</option>
                         <option>"Day"</option>
                         <option>"Feast"</option>
+                        <option>"Within Octave"</option>
                         <option>"Season"</option>
                         <option>"Observed Season"</option>
                         <option>"Week"</option>
@@ -207,6 +208,9 @@ fn edit_condition(root_condition: &Behavior<Option<Condition>>) -> View {
                         Condition::Feast(feast) => {
 							select_enum!(root_condition, feast, Feast, Condition::Feast)
 						},
+                        Condition::WithinOctave(feast) => {
+							select_enum!(root_condition, feast, Feast, Condition::WithinOctave)
+						},
                         Condition::Season(season) => {
 							select_enum!(root_condition, season, Season, Condition::Season)
 						},
@@ -331,6 +335,7 @@ fn default_condition_from_type(condition_type: &str) -> Option<Condition> {
             Weekday::Sun,
         ))),
         "Feast" => Some(Condition::Feast(Feast::AllSaintsDay)),
+        "Within Octave" => Some(Condition::WithinOctave(Feast::ChristmasDay)),
         "Season" => Some(Condition::Season(Season::Advent)),
         "Observed Season" => Some(Condition::ObservedSeason(Season::Advent)),
         "Week" => Some(Condition::Week(LiturgicalWeek::Advent1)),
@@ -366,6 +371,7 @@ fn value_from_condition_type(condition: &Condition) -> &'static str {
     match condition {
         Condition::Day(_) => "Day",
         Condition::Feast(_) => "Feast",
+        Condition::WithinOctave(_) => "Within Octave",
         Condition::Season(_) => "Season",
         Condition::ObservedSeason(_) => "Observed Season",
         Condition::Week(_) => "Week",
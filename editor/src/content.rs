@@ -68,6 +68,7 @@ pub fn content_editing_view(
                     <option>"Litany"</option>
                     <option>"Liturgy"</option>
                     <option>"Parallel"</option>
+                    <option>"Preface of the Day"</option>
                     <option>"Preces"</option>
                     <option>"Psalm"</option>
                     <option>"Psalm Citation"</option>
@@ -97,6 +98,7 @@ fn swap_content(doc: &Behavior<Document>, new_type: &str) -> Content {
         "Collect of the Day" => Content::CollectOfTheDay {
             allow_multiple: false,
         },
+        "Preface of the Day" => Content::PrefaceOfTheDay,
         //"Document Link" => Content::DocumentLink(DocumentLink::from(curr)),
         //"Gloria Patri" => Content::GloriaPatri(GloriaPatri::from(curr)),
         "Heading" => Content::Heading(Heading::from(curr)),
@@ -125,6 +127,7 @@ fn content_type_as_str(content: &Content) -> &'static str {
         Content::Parallel(_) => "Parallel",
         Content::Choice(_) => "Choice",
         Content::CollectOfTheDay { .. } => "Collect of the Day",
+        Content::PrefaceOfTheDay => "Preface of the Day",
         Content::Empty => "Empty",
         Content::Error(_) => "Error",
         Content::Antiphon(_) => "Antiphon",
@@ -1,4 +1,4 @@
-use calendar::{Date, Feast, LiturgicalDay, LiturgicalDayId};
+use calendar::{Color, Date, Feast, LiturgicalDay, LiturgicalDayId};
 use lectionary::Reading;
 use liturgy::{Document, Psalm};
 use serde::{Deserialize, Serialize};
@@ -14,6 +14,8 @@ pub struct EucharisticLectionarySummary {
 pub struct EucharisticObservanceSummary {
     pub observance: LiturgicalDayId,
     pub localized_name: String,
+    pub color: Color,
+    pub color_alternatives: Vec<Color>,
     pub collects: Option<Document>,
     pub tracked_readings: TrackedReadings,
     pub epistle: Vec<String>,
@@ -63,6 +65,8 @@ pub struct PartialDailySummary {
 pub struct ObservanceSummary {
     pub observance: LiturgicalDayId,
     pub localized_name: String,
+    pub color: Color,
+    pub color_alternatives: Vec<Color>,
     pub bcp_black_letter_days: Vec<(Feast, String)>,
     pub lff_black_letter_days: Vec<(Feast, String)>,
     pub collects: Option<Document>,
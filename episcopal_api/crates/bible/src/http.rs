@@ -0,0 +1,185 @@
+use liturgy::{BiblicalReading, BiblicalReadingIntro, Version};
+use reference_parser::{BibleVerse, BibleVersePart, Book};
+use serde::{Deserialize, Serialize};
+
+/// The Bible API used by the Common Prayer Online website
+pub const DEFAULT_BIBLE_API_URL: &str = "https://us-central1-venite-2.cloudfunctions.net/bible";
+
+/// Loads readings from an HTTP endpoint that takes `citation` and `version` query parameters
+/// and responds with JSON in the form of an [ApiReading]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct HttpBibleProvider {
+    pub url: String,
+}
+
+impl HttpBibleProvider {
+    pub fn new(url: impl Into<String>) -> Self {
+        Self { url: url.into() }
+    }
+
+    /// The URL from which the text of the citation can be loaded
+    /// ```
+    /// # use bible::HttpBibleProvider;
+    /// # use liturgy::Version;
+    /// let provider = HttpBibleProvider::new("https://example.com/bible");
+    /// assert_eq!(
+    ///     provider.reading_url("John 1:1-14", Version::NRSV),
+    ///     "https://example.com/bible?citation=John%201%3A1-14&version=NRSV"
+    /// );
+    /// ```
+    pub fn reading_url(&self, citation: &str, version: Version) -> String {
+        format!(
+            "{}?citation={}&version={}",
+            self.url,
+            urlencoding::encode(citation),
+            version
+        )
+    }
+}
+
+impl Default for HttpBibleProvider {
+    fn default() -> Self {
+        Self::new(DEFAULT_BIBLE_API_URL)
+    }
+}
+
+/// A reading as returned by the Bible API
+#[derive(Deserialize, Clone, Default, Debug, PartialEq, Serialize)]
+pub struct ApiReading {
+    pub citation: String,
+    pub label: String,
+    pub version: Version,
+    pub value: Vec<serde_json::value::Value>,
+}
+
+#[derive(Debug)]
+enum ReadingContent {
+    Verse(BibleVerse, String),
+    ParagraphBreak,
+}
+
+impl ApiReading {
+    pub fn api_data_to_biblical_reading(
+        &self,
+        citation: &str,
+        intro: &Option<BiblicalReadingIntro>,
+    ) -> BiblicalReading {
+        self.api_data_to_biblical_reading_with(citation, intro, strip_entities)
+    }
+
+    /// Like [ApiReading::api_data_to_biblical_reading], but decodes HTML entities in the text
+    /// of each verse with the given function (e.g., the browser's own HTML parser), rather than
+    /// with the fixed list of replacements in [strip_entities].
+    /// ```
+    /// # use bible::ApiReading;
+    /// let data: ApiReading = serde_json::from_str(r#"{
+    ///     "citation": "John 1:1",
+    ///     "label": "John 1:1",
+    ///     "version": "NRSV",
+    ///     "value": [{"book": "John", "chapter": "1", "verse": "1", "text": "In the beginning&amp;&#146;"}]
+    /// }"#).unwrap();
+    /// let reading = data.api_data_to_biblical_reading_with("John 1:1", &None, |text| {
+    ///     text.replace("&amp;", "&")
+    /// });
+    /// assert_eq!(reading.text[0].1, "In the beginning&&#146;");
+    /// let reading = data.api_data_to_biblical_reading("John 1:1", &None);
+    /// assert_eq!(reading.text[0].1, "In the beginning&amp;’");
+    /// ```
+    pub fn api_data_to_biblical_reading_with(
+        &self,
+        citation: &str,
+        intro: &Option<BiblicalReadingIntro>,
+        decode_entities: impl Fn(String) -> String,
+    ) -> BiblicalReading {
+        let mut text = Vec::new();
+        let parts = self
+            .value
+            .iter()
+            .filter_map(|line| {
+                let book = line.get("book");
+                let chapter = line.get("chapter");
+                let verse = line.get("verse");
+                let text = line.get("text");
+                let ldf_type = line.get("type");
+                match (ldf_type, book, chapter, verse, text) {
+                    (Some(value), ..) => {
+                        if value == &serde_json::Value::String("heading".to_string()) {
+                            Some(ReadingContent::ParagraphBreak)
+                        } else {
+                            None
+                        }
+                    }
+                    (_, Some(book), Some(chapter), Some(verse), Some(text)) => {
+                        let text = text.as_str().unwrap_or_default().to_string();
+                        let book = Book::from(book.as_str().unwrap_or_default());
+                        let chapter = chapter
+                            .as_str()
+                            .unwrap_or_default()
+                            .parse()
+                            .unwrap_or_default();
+                        let verse = verse
+                            .as_str()
+                            .unwrap_or_default()
+                            .parse()
+                            .unwrap_or_default();
+
+                        Some(ReadingContent::Verse(
+                            BibleVerse {
+                                book,
+                                chapter,
+                                verse,
+                                verse_part: BibleVersePart::All,
+                            },
+                            decode_entities(text),
+                        ))
+                    }
+                    _ => None,
+                }
+            })
+            .collect::<Vec<_>>();
+        for (idx, piece) in parts.iter().enumerate() {
+            let peeked = parts.get(idx + 1);
+            let this_verse = match (piece, peeked) {
+                (ReadingContent::Verse(verse, text), None) => {
+                    Some((verse.to_owned(), text.to_owned()))
+                }
+                (ReadingContent::Verse(verse, text), Some(ReadingContent::Verse(..))) => {
+                    Some((verse.to_owned(), text.to_owned()))
+                }
+                (ReadingContent::Verse(verse, text), Some(ReadingContent::ParagraphBreak)) => {
+                    Some((verse.to_owned(), text.to_owned() + "\n\n"))
+                }
+                (ReadingContent::ParagraphBreak, _) => None,
+            };
+            if let Some(data) = this_verse {
+                text.push(data);
+            }
+        }
+
+        BiblicalReading {
+            citation: citation.to_string(),
+            intro: intro.clone(),
+            text,
+        }
+    }
+}
+
+/// Replaces the entities the Bible API uses for curly quotes and dashes, without a full HTML parser.
+pub fn strip_entities(text: String) -> String {
+    // HTML handles certain entities in a way that's not actually Unicode,
+    // so we need to fix them manually — we can't actually just use a normal entity replacement
+    // see https://stackoverflow.com/questions/7031633/146-is-getting-converted-as-u0092-by-nokogiri-in-ruby-on-rails
+    text.replace("&#141;", "‘")
+        .replace("&#142;", "’")
+        .replace("&#143;", "“")
+        .replace("&#144;", "”")
+        .replace("&#146;", "’")
+        .replace("&#147;", "“")
+        .replace("&#148;", "”")
+        .replace("&#149;", "‘")
+        .replace("&#150;", "’")
+        .replace("&#151;", "—")
+        .replace("&#152;", "”")
+        .replace("&#153;", "–")
+        .replace("&#154;", "—")
+}
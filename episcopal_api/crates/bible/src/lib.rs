@@ -3,6 +3,12 @@ use std::collections::HashSet;
 use liturgy::{BiblicalReading, Content, Document, Version};
 use reference_parser::{BibleReference, BibleVerse, BibleVersePart, Book};
 
+mod http;
+pub use http::*;
+mod provider;
+pub use provider::*;
+mod registry;
+pub use registry::*;
 pub mod rv09;
 pub use rv09::*;
 mod usx_book_codes;
@@ -28,33 +34,55 @@ pub trait OfflineBible {
 
     /// The main function you will call convert a Biblical citation into a liturgical document
     fn get_citation(citation: &str) -> Result<Document, UsxError> {
-        let reference = BibleReference::from(citation);
-
-        let books = reference
-            .ranges
-            .iter()
-            .flat_map(|range| {
-                let start = range.start.book;
-                let end = range.end.and_then(|end| end.book);
-                [start, end].into_iter()
-            })
-            .flatten()
-            .collect::<HashSet<_>>();
-
-        if books.len() > 1 {
-            return Err(UsxError::CrossBookCitation);
-        }
+        citation_to_document(citation, Self::version(), Self::load_book)
+    }
+}
 
-        let book = reference.ranges.get(0).and_then(|range| range.start.book);
-        match book {
-            None => Ok(Document::from(Content::Empty)),
-            Some(book) => {
-                let text = Self::load_book(book)?;
-                let xml = roxmltree::Document::parse(&text).map_err(UsxError::XmlParsing)?;
-                let document = UsxDocument(xml);
+/// Converts a Biblical citation into a liturgical document, using `load_book` to load
+/// the USX text of whichever book the citation refers to
+fn citation_to_document(
+    citation: &str,
+    version: Version,
+    load_book: impl Fn(Book) -> Result<String, UsxError>,
+) -> Result<Document, UsxError> {
+    match citation_to_reading(citation, load_book)? {
+        None => Ok(Document::from(Content::Empty)),
+        Some(reading) => Ok(Document::from(reading).version(version)),
+    }
+}
 
-                Ok(document.to_document(Self::version(), citation, &reference))
-            }
+/// Loads the text of a Biblical citation, using `load_book` to load the USX text of whichever
+/// book the citation refers to. Returns `None` if the citation doesn't refer to any book.
+fn citation_to_reading(
+    citation: &str,
+    load_book: impl Fn(Book) -> Result<String, UsxError>,
+) -> Result<Option<BiblicalReading>, UsxError> {
+    let reference = BibleReference::from(citation);
+
+    let books = reference
+        .ranges
+        .iter()
+        .flat_map(|range| {
+            let start = range.start.book;
+            let end = range.end.and_then(|end| end.book);
+            [start, end].into_iter()
+        })
+        .flatten()
+        .collect::<HashSet<_>>();
+
+    if books.len() > 1 {
+        return Err(UsxError::CrossBookCitation);
+    }
+
+    let book = reference.ranges.get(0).and_then(|range| range.start.book);
+    match book {
+        None => Ok(None),
+        Some(book) => {
+            let text = load_book(book)?;
+            let xml = roxmltree::Document::parse(&text).map_err(UsxError::XmlParsing)?;
+            let document = UsxDocument(xml);
+
+            Ok(Some(document.to_biblical_reading(citation, &reference)))
         }
     }
 }
@@ -68,6 +96,10 @@ impl<'a> UsxDocument<'a> {
         citation: &str,
         reference: &BibleReference,
     ) -> Document {
+        Document::from(self.to_biblical_reading(citation, reference)).version(version)
+    }
+
+    pub fn to_biblical_reading(&self, citation: &str, reference: &BibleReference) -> BiblicalReading {
         let mut text: Vec<(BibleVerse, String)> = Vec::new();
 
         for node in self
@@ -90,14 +122,11 @@ impl<'a> UsxDocument<'a> {
             text.push((verse, verse_text));
         }
 
-        // build return object
-        let content = BiblicalReading {
+        BiblicalReading {
             citation: citation.to_string(),
             text,
             intro: None,
-        };
-
-        Document::from(content).version(version)
+        }
     }
 }
 
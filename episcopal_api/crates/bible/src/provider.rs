@@ -0,0 +1,229 @@
+use std::{collections::HashMap, fs, path::PathBuf, sync::Mutex};
+
+use liturgy::{BiblicalReading, Version};
+use thiserror::Error;
+
+#[cfg(feature = "http")]
+use crate::HttpBibleProvider;
+use crate::{OfflineBibles, UsxError};
+
+#[derive(Error, Debug)]
+pub enum BibleProviderError {
+    #[error("{0} is not available from this provider")]
+    VersionNotAvailable(Version),
+    #[error("citation not found: {0}")]
+    CitationNotFound(String),
+    #[error("error loading USX text")]
+    Usx(#[from] UsxError),
+    #[error("error requesting reading: {0}")]
+    Http(String),
+    #[error("error parsing reading")]
+    Json(#[from] serde_json::Error),
+}
+
+/// A source for the text of Biblical readings, e.g., a local directory of USX files or an HTTP API
+pub trait BibleProvider: Send + Sync {
+    /// Whether this provider can load readings in the given version
+    fn has_version(&self, version: Version) -> bool;
+
+    /// Loads the text of a Biblical citation in the given version
+    fn get_reading(
+        &self,
+        version: Version,
+        citation: &str,
+    ) -> Result<BiblicalReading, BibleProviderError>;
+}
+
+impl BibleProvider for OfflineBibles {
+    fn has_version(&self, version: Version) -> bool {
+        self.contains(version)
+    }
+
+    fn get_reading(
+        &self,
+        version: Version,
+        citation: &str,
+    ) -> Result<BiblicalReading, BibleProviderError> {
+        let bible = self
+            .get(version)
+            .ok_or(BibleProviderError::VersionNotAvailable(version))?;
+        Ok(bible.get_reading(citation)?)
+    }
+}
+
+#[cfg(feature = "http")]
+impl BibleProvider for HttpBibleProvider {
+    fn has_version(&self, version: Version) -> bool {
+        version.is_bible_translation()
+    }
+
+    fn get_reading(
+        &self,
+        version: Version,
+        citation: &str,
+    ) -> Result<BiblicalReading, BibleProviderError> {
+        if !self.has_version(version) {
+            return Err(BibleProviderError::VersionNotAvailable(version));
+        }
+        let reading = ureq::get(&self.reading_url(citation, version))
+            .call()
+            .map_err(|e| BibleProviderError::Http(e.to_string()))?
+            .into_json::<crate::ApiReading>()
+            .map_err(|e| BibleProviderError::Http(e.to_string()))?;
+        Ok(reading.api_data_to_biblical_reading(citation, &None))
+    }
+}
+
+/// Wraps another [BibleProvider], caching each reading on disk as JSON so that it
+/// only needs to be loaded once for each `(Version, citation)` pair
+pub struct CachedBibleProvider<P: BibleProvider> {
+    provider: P,
+    dir: PathBuf,
+}
+
+impl<P: BibleProvider> CachedBibleProvider<P> {
+    pub fn new(provider: P, dir: impl Into<PathBuf>) -> Self {
+        Self {
+            provider,
+            dir: dir.into(),
+        }
+    }
+
+    fn path(&self, version: Version, citation: &str) -> PathBuf {
+        let version: &'static str = version.into();
+        self.dir
+            .join(version)
+            .join(format!("{}.json", urlencoding::encode(citation)))
+    }
+}
+
+impl<P: BibleProvider> BibleProvider for CachedBibleProvider<P> {
+    fn has_version(&self, version: Version) -> bool {
+        self.provider.has_version(version)
+    }
+
+    fn get_reading(
+        &self,
+        version: Version,
+        citation: &str,
+    ) -> Result<BiblicalReading, BibleProviderError> {
+        let path = self.path(version, citation);
+        let cached = fs::read_to_string(&path)
+            .ok()
+            .and_then(|json| serde_json::from_str(&json).ok());
+        match cached {
+            Some(reading) => Ok(reading),
+            None => {
+                let reading = self.provider.get_reading(version, citation)?;
+                // a reading that can't be cached can still be returned, so ignore errors writing to the cache
+                if let Some(parent) = path.parent() {
+                    let _ = fs::create_dir_all(parent);
+                }
+                let _ = fs::write(&path, serde_json::to_string(&reading)?);
+                Ok(reading)
+            }
+        }
+    }
+}
+
+/// A [BibleProvider] with a fixed set of readings, which keeps track of every reading requested
+/// ```
+/// # use bible::{BibleProvider, MockBibleProvider};
+/// # use liturgy::{BiblicalReading, Version};
+/// let provider = MockBibleProvider::default().with_reading(
+///     Version::NRSV,
+///     BiblicalReading {
+///         citation: "John 11:35".to_string(),
+///         text: vec![],
+///         intro: None,
+///     },
+/// );
+/// assert!(provider.get_reading(Version::NRSV, "John 11:35").is_ok());
+/// assert!(provider.get_reading(Version::NRSV, "John 11:36").is_err());
+/// assert_eq!(provider.requests().len(), 2);
+/// ```
+#[derive(Debug, Default)]
+pub struct MockBibleProvider {
+    readings: HashMap<(Version, String), BiblicalReading>,
+    requests: Mutex<Vec<(Version, String)>>,
+}
+
+impl MockBibleProvider {
+    #[must_use]
+    pub fn with_reading(mut self, version: Version, reading: BiblicalReading) -> Self {
+        self.readings
+            .insert((version, reading.citation.clone()), reading);
+        self
+    }
+
+    /// Every reading that has been requested from this provider, in order
+    pub fn requests(&self) -> Vec<(Version, String)> {
+        self.requests.lock().unwrap().clone()
+    }
+}
+
+impl BibleProvider for MockBibleProvider {
+    fn has_version(&self, version: Version) -> bool {
+        self.readings.keys().any(|(search, _)| *search == version)
+    }
+
+    fn get_reading(
+        &self,
+        version: Version,
+        citation: &str,
+    ) -> Result<BiblicalReading, BibleProviderError> {
+        self.requests
+            .lock()
+            .unwrap()
+            .push((version, citation.to_string()));
+        self.readings
+            .get(&(version, citation.to_string()))
+            .cloned()
+            .ok_or_else(|| BibleProviderError::CitationNotFound(citation.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reference_parser::{BibleVerse, BibleVersePart, Book};
+
+    #[test]
+    fn caches_readings_on_disk() {
+        let reading = BiblicalReading {
+            citation: "John 11:35".to_string(),
+            text: vec![(
+                BibleVerse {
+                    book: Book::John,
+                    chapter: 11,
+                    verse: 35,
+                    verse_part: BibleVersePart::All,
+                },
+                "Jesus began to weep.".to_string(),
+            )],
+            intro: None,
+        };
+        let dir = std::env::temp_dir().join(format!("bible-cache-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+
+        let provider = CachedBibleProvider::new(
+            MockBibleProvider::default().with_reading(Version::NRSV, reading.clone()),
+            &dir,
+        );
+        assert_eq!(
+            provider.get_reading(Version::NRSV, "John 11:35").unwrap(),
+            reading
+        );
+        assert_eq!(
+            provider.get_reading(Version::NRSV, "John 11:35").unwrap(),
+            reading
+        );
+        assert_eq!(
+            provider.provider.requests(),
+            vec![(Version::NRSV, "John 11:35".to_string())]
+        );
+        assert!(provider.get_reading(Version::NRSV, "John 11:36").is_err());
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+}
@@ -0,0 +1,129 @@
+use std::{
+    collections::HashMap,
+    fs,
+    path::{Path, PathBuf},
+};
+
+use lazy_static::lazy_static;
+use liturgy::{BiblicalReading, Document, Version};
+use reference_parser::Book;
+use strum::IntoEnumIterator;
+
+use crate::{citation_to_document, citation_to_reading, usx_book_code, UsxError, BIBLE_ROOT_DIR};
+
+/// A Bible translation stored as a directory of USX files, one per book, each named
+/// with the book's USX code (e.g., `GEN.usx`)
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UsxBible {
+    pub version: Version,
+    pub path: PathBuf,
+}
+
+impl UsxBible {
+    pub fn new(version: Version, path: impl Into<PathBuf>) -> Self {
+        Self {
+            version,
+            path: path.into(),
+        }
+    }
+
+    pub fn load_book(&self, book: Book) -> Result<String, UsxError> {
+        let path = self.path.join(format!("{}.usx", usx_book_code(book)));
+        fs::read_to_string(path).map_err(|_| UsxError::BookNotFound(book))
+    }
+
+    /// Converts a Biblical citation into a liturgical document
+    pub fn get_citation(&self, citation: &str) -> Result<Document, UsxError> {
+        citation_to_document(citation, self.version, |book| self.load_book(book))
+    }
+
+    /// Loads the text of a Biblical citation
+    pub fn get_reading(&self, citation: &str) -> Result<BiblicalReading, UsxError> {
+        let reading = citation_to_reading(citation, |book| self.load_book(book))?;
+        Ok(reading.unwrap_or_else(|| BiblicalReading {
+            citation: citation.to_string(),
+            text: Vec::new(),
+            intro: None,
+        }))
+    }
+}
+
+lazy_static! {
+    /// Every translation installed in the `bibles` directory under `BIBLE_DIR`
+    pub static ref OFFLINE_BIBLES: OfflineBibles =
+        OfflineBibles::installed_in(Path::new(&*BIBLE_ROOT_DIR).join("bibles"));
+}
+
+/// The Bible translations that are available offline, so that readings in those
+/// versions can be loaded synchronously
+#[derive(Clone, Debug, Default)]
+pub struct OfflineBibles {
+    bibles: HashMap<Version, UsxBible>,
+}
+
+impl OfflineBibles {
+    /// Finds every translation installed in `dir`. A translation is installed if `dir` has
+    /// a subdirectory named for its [Version] (e.g., `KJV`, `WEB`, or `LSG`) that contains its USX
+    /// files, either directly or in `release/USX_1` as in a Digital Bible Library bundle.
+    pub fn installed_in(dir: impl AsRef<Path>) -> Self {
+        let dir = dir.as_ref();
+        let mut bibles = Self::default();
+        for version in Version::iter() {
+            let name: &'static str = version.into();
+            let root = dir.join(name);
+            let bundle = root.join("release").join("USX_1");
+            if bundle.is_dir() {
+                bibles.insert(UsxBible::new(version, bundle));
+            } else if root.is_dir() {
+                bibles.insert(UsxBible::new(version, root));
+            }
+        }
+        bibles
+    }
+
+    pub fn insert(&mut self, bible: UsxBible) {
+        self.bibles.insert(bible.version, bible);
+    }
+
+    pub fn get(&self, version: Version) -> Option<&UsxBible> {
+        self.bibles.get(&version)
+    }
+
+    pub fn contains(&self, version: Version) -> bool {
+        self.bibles.contains_key(&version)
+    }
+
+    /// The versions that are installed, in the order in which [Version] is declared
+    pub fn versions(&self) -> Vec<Version> {
+        let mut versions = self.bibles.keys().copied().collect::<Vec<_>>();
+        versions.sort();
+        versions
+    }
+
+    /// Converts a Biblical citation into a liturgical document, if the version is installed
+    pub fn get_citation(
+        &self,
+        version: Version,
+        citation: &str,
+    ) -> Option<Result<Document, UsxError>> {
+        self.get(version).map(|bible| bible.get_citation(citation))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_installed_translations() {
+        let bibles = OfflineBibles::installed_in(concat!(env!("CARGO_MANIFEST_DIR"), "/bibles"));
+        assert_eq!(bibles.versions(), vec![Version::RV09]);
+        assert!(bibles.get_citation(Version::KJV, "John 1:1").is_none());
+
+        let reading = bibles
+            .get_citation(Version::RV09, "John 11:35")
+            .unwrap()
+            .unwrap();
+        assert_eq!(reading.version, Version::RV09);
+    }
+}
@@ -1,10 +1,8 @@
-use std::{fs::File, io::Read};
-
 use lazy_static::lazy_static;
 use liturgy::Version;
 use reference_parser::Book;
 
-use crate::{usx_book_code, OfflineBible, UsxError};
+use crate::{OfflineBible, UsxBible, UsxError};
 
 pub struct ReinaValera {}
 
@@ -15,16 +13,11 @@ lazy_static! {
 
 impl OfflineBible for ReinaValera {
     fn load_book(book: Book) -> Result<String, UsxError> {
-        let code = usx_book_code(book);
-        let mut file = File::open(&format!(
-            "{}/bibles/RV09/release/USX_1/{}.usx",
-            &*BIBLE_ROOT_DIR, code
-        ))
-        .map_err(|_| UsxError::BookNotFound(book))?;
-        let mut buf = String::new();
-        file.read_to_string(&mut buf)
-            .map_err(|_| UsxError::BookNotFound(book))?;
-        Ok(buf)
+        UsxBible::new(
+            Version::RV09,
+            format!("{}/bibles/RV09/release/USX_1", &*BIBLE_ROOT_DIR),
+        )
+        .load_book(book)
     }
 
     fn version() -> liturgy::Version {
@@ -2,26 +2,31 @@ use language::Language;
 use status::Status;
 
 use crate::{
-    feasts::KalendarEntry, holy_day::HolyDayId, liturgical_week::Cycle, Calendar, Feast,
-    LiturgicalWeek, Proper, Rank, Season, Time, Weekday,
+    feasts::KalendarEntry, holy_day::HolyDayId, liturgical_week::Cycle, Calendar, Color, Computus,
+    Feast, HolyDay, LiturgicalWeek, Proper, Rank, Sanctoral, Season, Time, Weekday,
 };
 
 /// [Calendar](Calendar) that calculates dates from the liturgical and sanctoral calendar
 /// of the 1979 Book of Common Prayer of The Episcopal Church.
 pub const BCP1979_CALENDAR: Calendar = Calendar {
+    computus: Computus::Gregorian,
     easter_cycle_begins: 7,
     christmas_cycle_begins: 4,
+    christmas_cycle_ends: None,
     has_propers: true,
     weeks: &BCP1979_WEEKS,
     holy_days: &BCP1979_FEASTS,
     holy_days_fallback: None,
     holy_day_ranks: &BCP1979_HOLY_DAY_RANKS,
     feast_seasons: &BCP1979_HOLY_DAY_SEASONS,
+    feast_colors: &BCP1979_FEAST_COLORS,
     week_seasons: &BCP1979_WEEK_SEASONS,
     feast_names: &BCP1979_FEAST_NAMES,
     week_names: &BCP1979_WEEK_NAMES,
     proper_names: &BCP1979_PROPER_NAMES,
     major_day_alternatives: &BCP1979_MAJOR_DAY_ALTERNATIVES,
+    octaves: &BCP1979_OCTAVES,
+    local_feasts: &[],
 };
 
 pub(crate) const BCP1979_MAJOR_DAY_ALTERNATIVES: [(Feast, &[Feast]); 4] = [
@@ -34,6 +39,63 @@ pub(crate) const BCP1979_MAJOR_DAY_ALTERNATIVES: [(Feast, &[Feast]); 4] = [
     (Feast::Pentecost, &[Feast::PentecostVigil]),
 ];
 
+/// Apostles, evangelists, and martyrs, whose feasts are kept in red
+pub(crate) const BCP1979_FEAST_COLORS: [(Feast, Color); 35] = [
+    (Feast::Stephen, Color::Red),
+    (Feast::HolyInnocents, Color::Red),
+    (Feast::HolyCross, Color::Red),
+    (Feast::Andrew, Color::Red),
+    (Feast::Barnabas, Color::Red),
+    (Feast::Bartholomew, Color::Red),
+    (Feast::James, Color::Red),
+    (Feast::JamesOfJerusalem, Color::Red),
+    (Feast::Luke, Color::Red),
+    (Feast::Mark, Color::Red),
+    (Feast::Matthew, Color::Red),
+    (Feast::Matthias, Color::Red),
+    (Feast::PeterAndPaul, Color::Red),
+    (Feast::PhilipAndJames, Color::Red),
+    (Feast::SimonAndJude, Color::Red),
+    (Feast::Thomas, Color::Red),
+    // martyrs
+    (Feast::AgnesAndCeciliaOfRome, Color::Red),
+    (Feast::Alban, Color::Red),
+    (Feast::Alphege, Color::Red),
+    (Feast::BernardMizeki, Color::Red),
+    (Feast::Boniface, Color::Red),
+    (Feast::CyprianOfCarthage, Color::Red),
+    (Feast::Fabian, Color::Red),
+    (Feast::IgnatiusOfAntioch, Color::Red),
+    (Feast::JamesHannington, Color::Red),
+    (Feast::JohnColeridgePatteson, Color::Red),
+    (Feast::Justin, Color::Red),
+    (Feast::LatimerRidleyCranmer, Color::Red),
+    (Feast::LaurenceOfRome, Color::Red),
+    (Feast::PerpetuaAndFelicity, Color::Red),
+    (Feast::PolycarpOfSmyrna, Color::Red),
+    (Feast::TheMartyrsOfJapan, Color::Red),
+    (Feast::TheMartyrsOfNewGuinea, Color::Red),
+    (Feast::TheMartyrsOfUganda, Color::Red),
+    (Feast::VincentOfSaragossa, Color::Red),
+];
+
+pub(crate) const BCP1979_OCTAVES: [HolyDay; 2] = [
+    HolyDay::new(
+        Feast::ChristmasDay,
+        Sanctoral::BCP1979,
+        Rank::PrincipalFeast,
+        Some(Season::Christmas),
+    )
+    .with_octave(HolyDayId::Date(1, 1)),
+    HolyDay::new(
+        Feast::EasterSunday,
+        Sanctoral::BCP1979,
+        Rank::PrincipalFeast,
+        Some(Season::Easter),
+    )
+    .with_octave(HolyDayId::SpecialDay(LiturgicalWeek::Easter2, Weekday::Sun)),
+];
+
 pub(crate) const BCP1979_WEEKS: [(Cycle, u8, LiturgicalWeek); 59] = [
     (Cycle::Advent, 0, LiturgicalWeek::LastPentecost),
     (Cycle::Advent, 1, LiturgicalWeek::Advent1),
@@ -1421,7 +1483,7 @@ pub const BCP1979_FEASTS: [KalendarEntry; 215] = [
     ),
 ];
 
-pub(crate) const BCP1979_FEAST_NAMES : [(Feast, Language, &str, Status); 199] = [
+pub(crate) const BCP1979_FEAST_NAMES : [(Feast, Language, &str, Status); 597] = [
     (Feast::AbsalomJones, Language::En, "Absalom Jones, Priest, 1818", Status::Authorized),
     (Feast::AgnesAndCeciliaOfRome, Language::En, "Agnes, Martyr at Rome, 304", Status::Authorized),
     (Feast::AidanOfLindisfarne, Language::En, "Aidan, Bishop of Lindisfarne, 651", Status::Authorized),
@@ -1620,10 +1682,408 @@ pub(crate) const BCP1979_FEAST_NAMES : [(Feast, Language, &str, Status); 199] =
     (Feast::January5, Language::En, "January 5", Status::Authorized),
     (Feast::EveOfEpiphany, Language::En, "Eve of Epiphany", Status::Authorized),
     (Feast::EmberDay, Language::En, "Ember Day", Status::Authorized),
-    (Feast::RogationDay, Language::En, "Rogation Day", Status::Authorized)
+    (Feast::RogationDay, Language::En, "Rogation Day", Status::Authorized),
+    (Feast::AllSaintsDay, Language::Es, "Día de Todos los Santos", Status::Authorized),
+    (Feast::AllSoulsDay, Language::Es, "Conmemoración de Todos los Fieles Difuntos", Status::Authorized),
+    (Feast::Annunciation, Language::Es, "La Anunciación de Nuestro Señor Jesucristo a la Bienaventurada Virgen María", Status::Authorized),
+    (Feast::ChristmasDay, Language::Es, "Día de Navidad", Status::Authorized),
+    (Feast::ChristmasDayII, Language::Es, "Navidad II", Status::Authorized),
+    (Feast::ChristmasDayIII, Language::Es, "Navidad III", Status::Authorized),
+    (Feast::ConfessionOfStPeter, Language::Es, "La Confesión de San Pedro Apóstol", Status::Authorized),
+    (Feast::ConversionOfStPaul, Language::Es, "La Conversión de San Pablo Apóstol", Status::Authorized),
+    (Feast::EasterSunday, Language::Es, "Día de Pascua", Status::Authorized),
+    (Feast::EasterVigil, Language::Es, "Vigilia Pascual", Status::Authorized),
+    (Feast::EasterDayEveningService, Language::Es, "Oficio Vespertino del Día de Pascua", Status::Authorized),
+    (Feast::Epiphany, Language::Es, "La Epifanía", Status::Authorized),
+    (Feast::EveOfAllSaints, Language::Es, "Víspera del Día de Todos los Santos", Status::Authorized),
+    (Feast::EveOfHolyCross, Language::Es, "Víspera del Día de la Santa Cruz", Status::Authorized),
+    (Feast::EveOfStJohnTheBaptist, Language::Es, "Víspera de la Natividad de San Juan Bautista", Status::Authorized),
+    (Feast::EveOfTheAnnunciation, Language::Es, "Víspera de la Anunciación de Nuestro Señor Jesucristo a la Bienaventurada Virgen María", Status::Authorized),
+    (Feast::EveOfThePresentation, Language::Es, "Víspera de la Presentación de Nuestro Señor Jesucristo en el Templo", Status::Authorized),
+    (Feast::EveOfTheTransfiguration, Language::Es, "Víspera de la Transfiguración de Nuestro Señor Jesucristo", Status::Authorized),
+    (Feast::EveOfTheVisitation, Language::Es, "Víspera de la Visitación de la Bienaventurada Virgen María", Status::Authorized),
+    (Feast::FridayAfterAscension, Language::Es, "Viernes después del Día de la Ascensión", Status::Authorized),
+    (Feast::FridayInEasterWeek, Language::Es, "Viernes de la Semana de Pascua", Status::Authorized),
+    (Feast::GoodFriday, Language::Es, "Viernes Santo", Status::Authorized),
+    (Feast::FridayAfterAshWednesday, Language::Es, "Viernes después del Miércoles de Ceniza", Status::Authorized),
+    (Feast::HolyCross, Language::Es, "Día de la Santa Cruz", Status::Authorized),
+    (Feast::HolyInnocents, Language::Es, "Los Santos Inocentes", Status::Authorized),
+    (Feast::HolyName, Language::Es, "El Santo Nombre de Nuestro Señor Jesucristo", Status::Authorized),
+    (Feast::IndependenceDay, Language::Es, "Día de la Independencia", Status::Authorized),
+    (Feast::MondayInEasterWeek, Language::Es, "Lunes de la Semana de Pascua", Status::Authorized),
+    (Feast::MondayInHolyWeek, Language::Es, "Lunes de Semana Santa", Status::Authorized),
+    (Feast::NativityOfStJohnTheBaptist, Language::Es, "La Natividad de San Juan Bautista", Status::Authorized),
+    (Feast::SaturdayAfterAscension, Language::Es, "Sábado después del Día de la Ascensión", Status::Authorized),
+    (Feast::EveOfPentecost, Language::Es, "Víspera de Pentecostés", Status::Authorized),
+    (Feast::PentecostVigil, Language::Es, "Vigilia de Pentecostés", Status::Authorized),
+    (Feast::SaturdayInEasterWeek, Language::Es, "Sábado de la Semana de Pascua", Status::Authorized),
+    (Feast::HolySaturday, Language::Es, "Sábado Santo", Status::Authorized),
+    (Feast::SaturdayAfterAshWednesday, Language::Es, "Sábado después del Miércoles de Ceniza", Status::Authorized),
+    (Feast::PeterAndPaul, Language::Es, "San Pedro y San Pablo, Apóstoles", Status::Authorized),
+    (Feast::PhilipAndJames, Language::Es, "San Felipe y Santiago, Apóstoles", Status::Authorized),
+    (Feast::SimonAndJude, Language::Es, "San Simón y San Judas, Apóstoles", Status::Authorized),
+    (Feast::Andrew, Language::Es, "San Andrés Apóstol", Status::Authorized),
+    (Feast::Barnabas, Language::Es, "San Bernabé Apóstol", Status::Authorized),
+    (Feast::Bartholomew, Language::Es, "San Bartolomé Apóstol", Status::Authorized),
+    (Feast::James, Language::Es, "Santiago Apóstol", Status::Authorized),
+    (Feast::JamesOfJerusalem, Language::Es, "Santiago de Jerusalén, Hermano de Nuestro Señor Jesucristo, y Mártir", Status::Authorized),
+    (Feast::John, Language::Es, "Fiesta de San Juan", Status::Authorized),
+    (Feast::Joseph, Language::Es, "San José", Status::Authorized),
+    (Feast::Luke, Language::Es, "San Lucas Evangelista", Status::Authorized),
+    (Feast::Mark, Language::Es, "San Marcos Evangelista", Status::Authorized),
+    (Feast::MaryMagdalene, Language::Es, "Santa María Magdalena", Status::Authorized),
+    (Feast::Mary, Language::Es, "Santa María la Virgen, Madre de Nuestro Señor Jesucristo", Status::Authorized),
+    (Feast::Matthew, Language::Es, "San Mateo, Apóstol y Evangelista", Status::Authorized),
+    (Feast::Matthias, Language::Es, "San Matías Apóstol", Status::Authorized),
+    (Feast::Michael, Language::Es, "San Miguel y Todos los Ángeles", Status::Authorized),
+    (Feast::Stephen, Language::Es, "Fiesta de San Esteban", Status::Authorized),
+    (Feast::Thomas, Language::Es, "Santo Tomás Apóstol", Status::Authorized),
+    (Feast::PalmSunday, Language::Es, "Domingo de Ramos", Status::Authorized),
+    (Feast::Pentecost, Language::Es, "Pentecostés", Status::Authorized),
+    (Feast::EveOfTrinitySunday, Language::Es, "Víspera del Domingo de la Trinidad", Status::Authorized),
+    (Feast::TrinitySunday, Language::Es, "Domingo de la Trinidad", Status::Authorized),
+    (Feast::ThanksgivingDay, Language::Es, "Día de Acción de Gracias", Status::Authorized),
+    (Feast::ThePresentation, Language::Es, "La Presentación de Nuestro Señor Jesucristo en el Templo (La Candelaria)", Status::Authorized),
+    (Feast::TheTransfiguration, Language::Es, "La Transfiguración de Nuestro Señor Jesucristo", Status::Authorized),
+    (Feast::TheVisitation, Language::Es, "La Visitación de la Bienaventurada Virgen María", Status::Authorized),
+    (Feast::AscensionDay, Language::Es, "Día de la Ascensión", Status::Authorized),
+    (Feast::ThursdayInEasterWeek, Language::Es, "Jueves de la Semana de Pascua", Status::Authorized),
+    (Feast::MaundyThursday, Language::Es, "Jueves Santo", Status::Authorized),
+    (Feast::ThursdayAfterAshWednesday, Language::Es, "Jueves después del Miércoles de Ceniza", Status::Authorized),
+    (Feast::TuesdayInEasterWeek, Language::Es, "Martes de la Semana de Pascua", Status::Authorized),
+    (Feast::TuesdayInHolyWeek, Language::Es, "Martes de Semana Santa", Status::Authorized),
+    (Feast::EveOfTheAscension, Language::Es, "Víspera de la Ascensión", Status::Authorized),
+    (Feast::WednesdayInEasterWeek, Language::Es, "Miércoles de la Semana de Pascua", Status::Authorized),
+    (Feast::WednesdayInHolyWeek, Language::Es, "Miércoles de Semana Santa", Status::Authorized),
+    (Feast::AshWednesday, Language::Es, "Miércoles de Ceniza", Status::Authorized),
+    (Feast::December24, Language::Es, "24 de diciembre", Status::Authorized),
+    (Feast::ChristmasEve, Language::Es, "Nochebuena", Status::Authorized),
+    (Feast::December29, Language::Es, "29 de diciembre", Status::Authorized),
+    (Feast::December30, Language::Es, "30 de diciembre", Status::Authorized),
+    (Feast::December31, Language::Es, "31 de diciembre", Status::Authorized),
+    (Feast::EveOfHolyName, Language::Es, "Víspera del Santo Nombre", Status::Authorized),
+    (Feast::January2, Language::Es, "2 de enero", Status::Authorized),
+    (Feast::January3, Language::Es, "3 de enero", Status::Authorized),
+    (Feast::January4, Language::Es, "4 de enero", Status::Authorized),
+    (Feast::January5, Language::Es, "5 de enero", Status::Authorized),
+    (Feast::EveOfEpiphany, Language::Es, "Víspera de la Epifanía", Status::Authorized),
+    (Feast::EmberDay, Language::Es, "Día de Témporas", Status::Authorized),
+    (Feast::RogationDay, Language::Es, "Día de Rogativas", Status::Authorized),
+    (Feast::AbsalomJones, Language::Es, "Absalom Jones, Presbítero, 1818", Status::Authorized),
+    (Feast::AgnesAndCeciliaOfRome, Language::Es, "Inés, Mártir en Roma, 304", Status::Authorized),
+    (Feast::AidanOfLindisfarne, Language::Es, "Aidán, Obispo de Lindisfarne, 651", Status::Authorized),
+    (Feast::Alban, Language::Es, "Albano, Primer Mártir de Britania, c. 304", Status::Authorized),
+    (Feast::AlcuinOfYork, Language::Es, "Alcuino, Diácono, y Abad de Tours, 804", Status::Authorized),
+    (Feast::Alfred, Language::Es, "Alfredo el Grande, Rey de los Sajones Occidentales, 889", Status::Authorized),
+    (Feast::Alphege, Language::Es, "Alfego, Arzobispo de Canterbury, y Mártir, 1012", Status::Authorized),
+    (Feast::AmbroseOfMilan, Language::Es, "Ambrosio, Obispo de Milán, 397", Status::Authorized),
+    (Feast::AnselmOfCanterbury, Language::Es, "Anselmo, Arzobispo de Canterbury, 1109", Status::Authorized),
+    (Feast::Anskar, Language::Es, "Oscar, Arzobispo de Hamburgo, Misionero en Dinamarca y Suecia, 865", Status::Authorized),
+    (Feast::AntonyOfEgypt, Language::Es, "Antonio, Abad en Egipto, 356", Status::Authorized),
+    (Feast::AthanasiusOfAlexandria, Language::Es, "Atanasio, Obispo de Alejandría, 373", Status::Authorized),
+    (Feast::AugustineOfHippo, Language::Es, "Agustín, Obispo de Hipona, 430", Status::Authorized),
+    (Feast::AugustineOfCanterbury, Language::Es, "Agustín, Primer Arzobispo de Canterbury, 605", Status::Authorized),
+    (Feast::BasilOfCaesarea, Language::Es, "Basilio el Grande, Obispo de Cesarea, 379", Status::Authorized),
+    (Feast::Bede, Language::Es, "Beda el Venerable, Presbítero, y Monje de Jarrow, 735", Status::Authorized),
+    (Feast::BenedictOfNursia, Language::Es, "Benito de Nursia, Abad de Montecasino, c. 540", Status::Authorized),
+    (Feast::BernardOfClairvaux, Language::Es, "Bernardo, Abad de Claraval, 1153", Status::Authorized),
+    (Feast::BernardMizeki, Language::Es, "Bernardo Mizeki, Catequista y Mártir en Rodesia, 1896", Status::Authorized),
+    (Feast::Boniface, Language::Es, "Bonifacio, Arzobispo de Maguncia, Misionero en Alemania, y Mártir, 754", Status::Authorized),
+    (Feast::CatherineOfSiena, Language::Es, "Catalina de Siena, 1380", Status::Authorized),
+    (Feast::ChadOfLichfield, Language::Es, "Chad, Obispo de Lichfield, 672", Status::Authorized),
+    (Feast::ChanningMooreWilliams, Language::Es, "Channing Moore Williams, Obispo Misionero en China y Japón, 1910", Status::Authorized),
+    (Feast::CharlesHenryBrent, Language::Es, "Charles Henry Brent, Obispo de Filipinas, y del Oeste de Nueva York, 1929", Status::Authorized),
+    (Feast::CharlesSimeon, Language::Es, "Charles Simeon, Presbítero, 1836", Status::Authorized),
+    (Feast::ClareOfAssisi, Language::Es, "Clara, Abadesa en Asís, 1253", Status::Authorized),
+    (Feast::ClementOfRome, Language::Es, "Clemente, Obispo de Roma, c. 100", Status::Authorized),
+    (Feast::ClementOfAlexandria, Language::Es, "Clemente de Alejandría, Presbítero, c. 210", Status::Authorized),
+    (Feast::ColumbaOfIona, Language::Es, "Columba, Abad de Iona, 597", Status::Authorized),
+    (Feast::SamuelSeabury, Language::Es, "Consagración de Samuel Seabury, Primer Obispo Norteamericano, 1784", Status::Authorized),
+    (Feast::Cuthbert, Language::Es, "Cutberto, Obispo de Lindisfarne, 687", Status::Authorized),
+    (Feast::CyprianOfCarthage, Language::Es, "Cipriano, Obispo y Mártir de Cartago, 258", Status::Authorized),
+    (Feast::CyrilOfJerusalem, Language::Es, "Cirilo, Obispo de Jerusalén, 386", Status::Authorized),
+    (Feast::CyrilAndMethodius, Language::Es, "Cirilo, Monje, y Metodio, Obispo, Misioneros entre los Eslavos, 869, 885", Status::Authorized),
+    (Feast::JulianOfNorwich, Language::Es, "Dama Juliana de Norwich, c. 1417", Status::Authorized),
+    (Feast::DavidOfWales, Language::Es, "David, Obispo de Menevia, Gales, c. 544", Status::Authorized),
+    (Feast::Dominic, Language::Es, "Domingo, Presbítero y Fraile, 1221", Status::Authorized),
+    (Feast::Dunstan, Language::Es, "Dunstano, Arzobispo de Canterbury, 988", Status::Authorized),
+    (Feast::EdwardBouveriePusey, Language::Es, "Edward Bouverie Pusey, Presbítero, 1882", Status::Authorized),
+    (Feast::ElizabethOfHungary, Language::Es, "Isabel, Princesa de Hungría, 1231", Status::Authorized),
+    (Feast::EphremOfNisibis, Language::Es, "Efrén de Edesa, Siria, Diácono, 373", Status::Authorized),
+    (Feast::Fabian, Language::Es, "Fabián, Obispo y Mártir de Roma, 250", Status::Authorized),
+    (Feast::FrancisOfAssisi, Language::Es, "Francisco de Asís, Fraile, 1226", Status::Authorized),
+    (Feast::FrederickDenisonMaurice, Language::Es, "Frederick Denison Maurice, Presbítero, 1872", Status::Authorized),
+    (Feast::GeorgeAugustusSelwyn, Language::Es, "George Augustus Selwyn, Obispo de Nueva Zelanda, y de Lichfield, 1878", Status::Authorized),
+    (Feast::GeorgeHerbert, Language::Es, "George Herbert, Presbítero, 1633", Status::Authorized),
+    (Feast::GregoryOfNazianzus, Language::Es, "Gregorio Nacianceno, Obispo de Constantinopla, 389", Status::Authorized),
+    (Feast::GregoryTheGreat, Language::Es, "Gregorio Magno, Obispo de Roma, 604", Status::Authorized),
+    (Feast::GregoryTheIlluminator, Language::Es, "Gregorio el Iluminador, Obispo y Misionero de Armenia, c. 332", Status::Authorized),
+    (Feast::HenryMartyn, Language::Es, "Henry Martyn, Presbítero, y Misionero en la India y Persia, 1812", Status::Authorized),
+    (Feast::HilaryOfPoitiers, Language::Es, "Hilario, Obispo de Poitiers, 367", Status::Authorized),
+    (Feast::HildaOfWhitby, Language::Es, "Hilda, Abadesa de Whitby, 680", Status::Authorized),
+    (Feast::HughOfLincoln, Language::Es, "Hugo, Obispo de Lincoln, 1200", Status::Authorized),
+    (Feast::LatimerRidleyCranmer, Language::Es, "Hugh Latimer y Nicholas Ridley, Obispos, 1555, y Thomas Cranmer, Arzobispo de Canterbury, 1556", Status::Authorized),
+    (Feast::IgnatiusOfAntioch, Language::Es, "Ignacio, Obispo de Antioquía, y Mártir, c. 115", Status::Authorized),
+    (Feast::IrenaeusOfLyons, Language::Es, "Ireneo, Obispo de Lyon, c. 202", Status::Authorized),
+    (Feast::JacksonKemper, Language::Es, "Jackson Kemper, Primer Obispo Misionero en los Estados Unidos, 1870", Status::Authorized),
+    (Feast::JamesDeKoven, Language::Es, "James De Koven, Presbítero, 1879", Status::Authorized),
+    (Feast::JamesHannington, Language::Es, "James Hannington, Obispo de África Ecuatorial Oriental, y sus Compañeros, Mártires, 1885", Status::Authorized),
+    (Feast::JamesLloydBreck, Language::Es, "James Lloyd Breck, Presbítero, 1876", Status::Authorized),
+    (Feast::JeremyTaylor, Language::Es, "Jeremy Taylor, Obispo de Down, Connor y Dromore, 1667", Status::Authorized),
+    (Feast::Jerome, Language::Es, "Jerónimo, Presbítero, y Monje de Belén, 420", Status::Authorized),
+    (Feast::JohnAndCharlesWesley, Language::Es, "Juan y Carlos Wesley, Presbíteros, 1791, 1788", Status::Authorized),
+    (Feast::JohnChrysostom, Language::Es, "Juan Crisóstomo, Obispo de Constantinopla, 407", Status::Authorized),
+    (Feast::JohnColeridgePatteson, Language::Es, "John Coleridge Patteson, Obispo de Melanesia, y sus Compañeros, Mártires, 1871", Status::Authorized),
+    (Feast::JohnDonne, Language::Es, "John Donne, Presbítero, 1631", Status::Authorized),
+    (Feast::JohnHenryHobart, Language::Es, "John Henry Hobart, Obispo de Nueva York, 1830", Status::Authorized),
+    (Feast::JohnKeble, Language::Es, "John Keble, Presbítero, 1866", Status::Authorized),
+    (Feast::JohnMasonNeale, Language::Es, "John Mason Neale, Presbítero, 1866", Status::Authorized),
+    (Feast::JohnOfDamascus, Language::Es, "Juan Damasceno, Presbítero, c. 760", Status::Authorized),
+    (Feast::JosephButler, Language::Es, "Joseph Butler, Obispo de Durham, 1752", Status::Authorized),
+    (Feast::JosephOfArimathea, Language::Es, "José de Arimatea", Status::Authorized),
+    (Feast::Justin, Language::Es, "Justino, Mártir en Roma, c. 167", Status::Authorized),
+    (Feast::LancelotAndrewes, Language::Es, "Lancelot Andrewes, Obispo de Winchester", Status::Authorized),
+    (Feast::LaurenceOfRome, Language::Es, "Lorenzo, Diácono y Mártir en Roma, 258", Status::Authorized),
+    (Feast::LeoOfRome, Language::Es, "León Magno, Obispo de Roma, 461", Status::Authorized),
+    (Feast::Louis, Language::Es, "Luis, Rey de Francia, 1270", Status::Authorized),
+    (Feast::MargaretOfScotland, Language::Es, "Margarita, Reina de Escocia, 1093", Status::Authorized),
+    (Feast::MartinOfTours, Language::Es, "Martín, Obispo de Tours, 397", Status::Authorized),
+    (Feast::Monica, Language::Es, "Mónica, Madre de Agustín de Hipona, 387", Status::Authorized),
+    (Feast::NicholasOfMyra, Language::Es, "Nicolás, Obispo de Mira, c. 342", Status::Authorized),
+    (Feast::NicholasFerrar, Language::Es, "Nicholas Ferrar, Diácono, 1637", Status::Authorized),
+    (Feast::Ninian, Language::Es, "Niniano, Obispo en Galloway, c. 430", Status::Authorized),
+    (Feast::PatrickOfIreland, Language::Es, "Patricio, Obispo y Misionero de Irlanda, 461", Status::Authorized),
+    (Feast::PerpetuaAndFelicity, Language::Es, "Perpetua y sus Compañeras, Mártires en Cartago, 202", Status::Authorized),
+    (Feast::PhillipsBrooks, Language::Es, "Phillips Brooks, Obispo de Massachusetts, 1893", Status::Authorized),
+    (Feast::PolycarpOfSmyrna, Language::Es, "Policarpo, Obispo y Mártir de Esmirna, 156", Status::Authorized),
+    (Feast::RemigiusOfRheims, Language::Es, "Remigio, Obispo de Reims, c. 530", Status::Authorized),
+    (Feast::RichardOfChichester, Language::Es, "Ricardo, Obispo de Chichester, 1253", Status::Authorized),
+    (Feast::RichardHooker, Language::Es, "Richard Hooker, Presbítero, 1600", Status::Authorized),
+    (Feast::RobertGrosseteste, Language::Es, "Robert Grosseteste, Obispo de Lincoln, 1253", Status::Authorized),
+    (Feast::SamuelIsaacJosephScherechewsky, Language::Es, "Samuel Isaac Joseph Schereschewsky, Obispo de Shanghái, 1906", Status::Authorized),
+    (Feast::SergiusOfRadonezh, Language::Es, "Sergio, Abad de la Santísima Trinidad, Moscú, 1932", Status::Authorized),
+    (Feast::TheMartyrsOfJapan, Language::Es, "Los Mártires de Japón, 1597", Status::Authorized),
+    (Feast::TheMartyrsOfNewGuinea, Language::Es, "Los Mártires de Nueva Guinea, 1942", Status::Authorized),
+    (Feast::TheMartyrsOfUganda, Language::Es, "Los Mártires de Uganda, 1886", Status::Authorized),
+    (Feast::TheodoreOfTarsus, Language::Es, "Teodoro de Tarso, Arzobispo de Canterbury, 690", Status::Authorized),
+    (Feast::ThomasAKempis, Language::Es, "Tomás de Kempis, Presbítero, 1471", Status::Authorized),
+    (Feast::ThomasAquinas, Language::Es, "Tomás de Aquino, Presbítero y Fraile, 1274", Status::Authorized),
+    (Feast::ThomasBray, Language::Es, "Thomas Bray, Presbítero y Misionero, 1730", Status::Authorized),
+    (Feast::ThomasKen, Language::Es, "Thomas Ken, Obispo de Bath y Wells, 1711", Status::Authorized),
+    (Feast::TitusAndTimothy, Language::Es, "Timoteo y Tito, Compañeros de San Pablo", Status::Authorized),
+    (Feast::VincentOfSaragossa, Language::Es, "Vicente, Diácono de Zaragoza, y Mártir, 304", Status::Authorized),
+    (Feast::WilliamAugustusMuhlenberg, Language::Es, "William Augustus Muhlenberg, Presbítero, 1877", Status::Authorized),
+    (Feast::WilliamLaud, Language::Es, "William Laud, Arzobispo de Canterbury, 1645", Status::Authorized),
+    (Feast::WilliamLaw, Language::Es, "William Law, Presbítero, 1761", Status::Authorized),
+    (Feast::WilliamPorcherDubose, Language::Es, "William Porcher DuBose, Presbítero, 1918", Status::Authorized),
+    (Feast::WilliamReedHuntington, Language::Es, "William Reed Huntington, Presbítero, 1909", Status::Authorized),
+    (Feast::WilliamTyndale, Language::Es, "William Tyndale, Presbítero, 1536", Status::Authorized),
+    (Feast::WilliamWhite, Language::Es, "William White, Obispo de Pensilvania, 1836", Status::Authorized),
+    (Feast::WilliamWilberforce, Language::Es, "William Wilberforce, 1833", Status::Authorized),
+    (Feast::Willibrord, Language::Es, "Wilibrordo, Arzobispo de Utrecht, Misionero en Frisia, 739", Status::Authorized),
+    (Feast::WulfstanOfWorcester, Language::Es, "Wulfstano, Obispo de Worcester, 1095", Status::Authorized),
+    (Feast::AllSaintsDay, Language::Fr, "La Toussaint", Status::Authorized),
+    (Feast::AllSoulsDay, Language::Fr, "Commémoration de tous les fidèles défunts", Status::Authorized),
+    (Feast::Annunciation, Language::Fr, "L’Annonciation de notre Seigneur Jésus-Christ à la bienheureuse Vierge Marie", Status::Authorized),
+    (Feast::ChristmasDay, Language::Fr, "Le Jour de Noël", Status::Authorized),
+    (Feast::ChristmasDayII, Language::Fr, "Noël II", Status::Authorized),
+    (Feast::ChristmasDayIII, Language::Fr, "Noël III", Status::Authorized),
+    (Feast::ConfessionOfStPeter, Language::Fr, "La Confession de saint Pierre, apôtre", Status::Authorized),
+    (Feast::ConversionOfStPaul, Language::Fr, "La Conversion de saint Paul, apôtre", Status::Authorized),
+    (Feast::EasterSunday, Language::Fr, "Le Jour de Pâques", Status::Authorized),
+    (Feast::EasterVigil, Language::Fr, "La Vigile pascale", Status::Authorized),
+    (Feast::EasterDayEveningService, Language::Fr, "Office du soir du Jour de Pâques", Status::Authorized),
+    (Feast::Epiphany, Language::Fr, "L’Épiphanie", Status::Authorized),
+    (Feast::EveOfAllSaints, Language::Fr, "Veille de la Toussaint", Status::Authorized),
+    (Feast::EveOfHolyCross, Language::Fr, "Veille de la fête de la Sainte-Croix", Status::Authorized),
+    (Feast::EveOfStJohnTheBaptist, Language::Fr, "Veille de la Nativité de saint Jean-Baptiste", Status::Authorized),
+    (Feast::EveOfTheAnnunciation, Language::Fr, "Veille de l’Annonciation de notre Seigneur Jésus-Christ à la bienheureuse Vierge Marie", Status::Authorized),
+    (Feast::EveOfThePresentation, Language::Fr, "Veille de la Présentation de notre Seigneur Jésus-Christ au Temple", Status::Authorized),
+    (Feast::EveOfTheTransfiguration, Language::Fr, "Veille de la Transfiguration de notre Seigneur Jésus-Christ", Status::Authorized),
+    (Feast::EveOfTheVisitation, Language::Fr, "Veille de la Visitation de la bienheureuse Vierge Marie", Status::Authorized),
+    (Feast::FridayAfterAscension, Language::Fr, "Vendredi après l’Ascension", Status::Authorized),
+    (Feast::FridayInEasterWeek, Language::Fr, "Vendredi de la Semaine de Pâques", Status::Authorized),
+    (Feast::GoodFriday, Language::Fr, "Vendredi saint", Status::Authorized),
+    (Feast::FridayAfterAshWednesday, Language::Fr, "Vendredi après le mercredi des Cendres", Status::Authorized),
+    (Feast::HolyCross, Language::Fr, "Fête de la Sainte-Croix", Status::Authorized),
+    (Feast::HolyInnocents, Language::Fr, "Les Saints Innocents", Status::Authorized),
+    (Feast::HolyName, Language::Fr, "Le Saint Nom de notre Seigneur Jésus-Christ", Status::Authorized),
+    (Feast::IndependenceDay, Language::Fr, "Jour de l’Indépendance", Status::Authorized),
+    (Feast::MondayInEasterWeek, Language::Fr, "Lundi de la Semaine de Pâques", Status::Authorized),
+    (Feast::MondayInHolyWeek, Language::Fr, "Lundi saint", Status::Authorized),
+    (Feast::NativityOfStJohnTheBaptist, Language::Fr, "La Nativité de saint Jean-Baptiste", Status::Authorized),
+    (Feast::SaturdayAfterAscension, Language::Fr, "Samedi après l’Ascension", Status::Authorized),
+    (Feast::EveOfPentecost, Language::Fr, "Veille de la Pentecôte", Status::Authorized),
+    (Feast::PentecostVigil, Language::Fr, "Vigile de la Pentecôte", Status::Authorized),
+    (Feast::SaturdayInEasterWeek, Language::Fr, "Samedi de la Semaine de Pâques", Status::Authorized),
+    (Feast::HolySaturday, Language::Fr, "Samedi saint", Status::Authorized),
+    (Feast::SaturdayAfterAshWednesday, Language::Fr, "Samedi après le mercredi des Cendres", Status::Authorized),
+    (Feast::PeterAndPaul, Language::Fr, "Saint Pierre et saint Paul, apôtres", Status::Authorized),
+    (Feast::PhilipAndJames, Language::Fr, "Saint Philippe et saint Jacques, apôtres", Status::Authorized),
+    (Feast::SimonAndJude, Language::Fr, "Saint Simon et saint Jude, apôtres", Status::Authorized),
+    (Feast::Andrew, Language::Fr, "Saint André, apôtre", Status::Authorized),
+    (Feast::Barnabas, Language::Fr, "Saint Barnabé, apôtre", Status::Authorized),
+    (Feast::Bartholomew, Language::Fr, "Saint Barthélemy, apôtre", Status::Authorized),
+    (Feast::James, Language::Fr, "Saint Jacques, apôtre", Status::Authorized),
+    (Feast::JamesOfJerusalem, Language::Fr, "Saint Jacques de Jérusalem, frère de notre Seigneur Jésus-Christ, et martyr", Status::Authorized),
+    (Feast::John, Language::Fr, "Fête de saint Jean", Status::Authorized),
+    (Feast::Joseph, Language::Fr, "Saint Joseph", Status::Authorized),
+    (Feast::Luke, Language::Fr, "Saint Luc, évangéliste", Status::Authorized),
+    (Feast::Mark, Language::Fr, "Saint Marc, évangéliste", Status::Authorized),
+    (Feast::MaryMagdalene, Language::Fr, "Sainte Marie-Madeleine", Status::Authorized),
+    (Feast::Mary, Language::Fr, "Sainte Marie la Vierge, mère de notre Seigneur Jésus-Christ", Status::Authorized),
+    (Feast::Matthew, Language::Fr, "Saint Matthieu, apôtre et évangéliste", Status::Authorized),
+    (Feast::Matthias, Language::Fr, "Saint Matthias, apôtre", Status::Authorized),
+    (Feast::Michael, Language::Fr, "Saint Michel et tous les anges", Status::Authorized),
+    (Feast::Stephen, Language::Fr, "Fête de saint Étienne", Status::Authorized),
+    (Feast::Thomas, Language::Fr, "Saint Thomas, apôtre", Status::Authorized),
+    (Feast::PalmSunday, Language::Fr, "Dimanche des Rameaux", Status::Authorized),
+    (Feast::Pentecost, Language::Fr, "La Pentecôte", Status::Authorized),
+    (Feast::EveOfTrinitySunday, Language::Fr, "Veille du dimanche de la Trinité", Status::Authorized),
+    (Feast::TrinitySunday, Language::Fr, "Dimanche de la Trinité", Status::Authorized),
+    (Feast::ThanksgivingDay, Language::Fr, "Jour d’action de grâce", Status::Authorized),
+    (Feast::ThePresentation, Language::Fr, "La Présentation de notre Seigneur Jésus-Christ au Temple (la Chandeleur)", Status::Authorized),
+    (Feast::TheTransfiguration, Language::Fr, "La Transfiguration de notre Seigneur Jésus-Christ", Status::Authorized),
+    (Feast::TheVisitation, Language::Fr, "La Visitation de la bienheureuse Vierge Marie", Status::Authorized),
+    (Feast::AscensionDay, Language::Fr, "L’Ascension", Status::Authorized),
+    (Feast::ThursdayInEasterWeek, Language::Fr, "Jeudi de la Semaine de Pâques", Status::Authorized),
+    (Feast::MaundyThursday, Language::Fr, "Jeudi saint", Status::Authorized),
+    (Feast::ThursdayAfterAshWednesday, Language::Fr, "Jeudi après le mercredi des Cendres", Status::Authorized),
+    (Feast::TuesdayInEasterWeek, Language::Fr, "Mardi de la Semaine de Pâques", Status::Authorized),
+    (Feast::TuesdayInHolyWeek, Language::Fr, "Mardi saint", Status::Authorized),
+    (Feast::EveOfTheAscension, Language::Fr, "Veille de l’Ascension", Status::Authorized),
+    (Feast::WednesdayInEasterWeek, Language::Fr, "Mercredi de la Semaine de Pâques", Status::Authorized),
+    (Feast::WednesdayInHolyWeek, Language::Fr, "Mercredi saint", Status::Authorized),
+    (Feast::AshWednesday, Language::Fr, "Mercredi des Cendres", Status::Authorized),
+    (Feast::December24, Language::Fr, "24 décembre", Status::Authorized),
+    (Feast::ChristmasEve, Language::Fr, "La Veille de Noël", Status::Authorized),
+    (Feast::December29, Language::Fr, "29 décembre", Status::Authorized),
+    (Feast::December30, Language::Fr, "30 décembre", Status::Authorized),
+    (Feast::December31, Language::Fr, "31 décembre", Status::Authorized),
+    (Feast::EveOfHolyName, Language::Fr, "Veille du Saint Nom", Status::Authorized),
+    (Feast::January2, Language::Fr, "2 janvier", Status::Authorized),
+    (Feast::January3, Language::Fr, "3 janvier", Status::Authorized),
+    (Feast::January4, Language::Fr, "4 janvier", Status::Authorized),
+    (Feast::January5, Language::Fr, "5 janvier", Status::Authorized),
+    (Feast::EveOfEpiphany, Language::Fr, "Veille de l’Épiphanie", Status::Authorized),
+    (Feast::EmberDay, Language::Fr, "Quatre-Temps", Status::Authorized),
+    (Feast::RogationDay, Language::Fr, "Rogations", Status::Authorized),
+    (Feast::AbsalomJones, Language::Fr, "Absalom Jones, prêtre, 1818", Status::Authorized),
+    (Feast::AgnesAndCeciliaOfRome, Language::Fr, "Agnès, martyre à Rome, 304", Status::Authorized),
+    (Feast::AidanOfLindisfarne, Language::Fr, "Aidan, évêque de Lindisfarne, 651", Status::Authorized),
+    (Feast::Alban, Language::Fr, "Alban, premier martyr de Bretagne, v. 304", Status::Authorized),
+    (Feast::AlcuinOfYork, Language::Fr, "Alcuin, diacre, et abbé de Tours, 804", Status::Authorized),
+    (Feast::Alfred, Language::Fr, "Alfred le Grand, roi des Saxons de l’Ouest, 889", Status::Authorized),
+    (Feast::Alphege, Language::Fr, "Alphège, archevêque de Cantorbéry, et martyr, 1012", Status::Authorized),
+    (Feast::AmbroseOfMilan, Language::Fr, "Ambroise, évêque de Milan, 397", Status::Authorized),
+    (Feast::AnselmOfCanterbury, Language::Fr, "Anselme, archevêque de Cantorbéry, 1109", Status::Authorized),
+    (Feast::Anskar, Language::Fr, "Anschaire, archevêque de Hambourg, missionnaire au Danemark et en Suède, 865", Status::Authorized),
+    (Feast::AntonyOfEgypt, Language::Fr, "Antoine, abbé en Égypte, 356", Status::Authorized),
+    (Feast::AthanasiusOfAlexandria, Language::Fr, "Athanase, évêque d’Alexandrie, 373", Status::Authorized),
+    (Feast::AugustineOfHippo, Language::Fr, "Augustin, évêque d’Hippone, 430", Status::Authorized),
+    (Feast::AugustineOfCanterbury, Language::Fr, "Augustin, premier archevêque de Cantorbéry, 605", Status::Authorized),
+    (Feast::BasilOfCaesarea, Language::Fr, "Basile le Grand, évêque de Césarée, 379", Status::Authorized),
+    (Feast::Bede, Language::Fr, "Bède le Vénérable, prêtre, et moine de Jarrow, 735", Status::Authorized),
+    (Feast::BenedictOfNursia, Language::Fr, "Benoît de Nursie, abbé du Mont-Cassin, v. 540", Status::Authorized),
+    (Feast::BernardOfClairvaux, Language::Fr, "Bernard, abbé de Clairvaux, 1153", Status::Authorized),
+    (Feast::BernardMizeki, Language::Fr, "Bernard Mizeki, catéchiste et martyr en Rhodésie, 1896", Status::Authorized),
+    (Feast::Boniface, Language::Fr, "Boniface, archevêque de Mayence, missionnaire en Allemagne, et martyr, 754", Status::Authorized),
+    (Feast::CatherineOfSiena, Language::Fr, "Catherine de Sienne, 1380", Status::Authorized),
+    (Feast::ChadOfLichfield, Language::Fr, "Chad, évêque de Lichfield, 672", Status::Authorized),
+    (Feast::ChanningMooreWilliams, Language::Fr, "Channing Moore Williams, évêque missionnaire en Chine et au Japon, 1910", Status::Authorized),
+    (Feast::CharlesHenryBrent, Language::Fr, "Charles Henry Brent, évêque des Philippines, et de l’Ouest de New York, 1929", Status::Authorized),
+    (Feast::CharlesSimeon, Language::Fr, "Charles Simeon, prêtre, 1836", Status::Authorized),
+    (Feast::ClareOfAssisi, Language::Fr, "Claire, abbesse à Assise, 1253", Status::Authorized),
+    (Feast::ClementOfRome, Language::Fr, "Clément, évêque de Rome, v. 100", Status::Authorized),
+    (Feast::ClementOfAlexandria, Language::Fr, "Clément d’Alexandrie, prêtre, v. 210", Status::Authorized),
+    (Feast::ColumbaOfIona, Language::Fr, "Colomba, abbé d’Iona, 597", Status::Authorized),
+    (Feast::SamuelSeabury, Language::Fr, "Consécration de Samuel Seabury, premier évêque américain, 1784", Status::Authorized),
+    (Feast::Cuthbert, Language::Fr, "Cuthbert, évêque de Lindisfarne, 687", Status::Authorized),
+    (Feast::CyprianOfCarthage, Language::Fr, "Cyprien, évêque et martyr de Carthage, 258", Status::Authorized),
+    (Feast::CyrilOfJerusalem, Language::Fr, "Cyrille, évêque de Jérusalem, 386", Status::Authorized),
+    (Feast::CyrilAndMethodius, Language::Fr, "Cyrille, moine, et Méthode, évêque, missionnaires auprès des Slaves, 869, 885", Status::Authorized),
+    (Feast::JulianOfNorwich, Language::Fr, "Dame Julienne de Norwich, v. 1417", Status::Authorized),
+    (Feast::DavidOfWales, Language::Fr, "David, évêque de Ménévie, pays de Galles, v. 544", Status::Authorized),
+    (Feast::Dominic, Language::Fr, "Dominique, prêtre et frère, 1221", Status::Authorized),
+    (Feast::Dunstan, Language::Fr, "Dunstan, archevêque de Cantorbéry, 988", Status::Authorized),
+    (Feast::EdwardBouveriePusey, Language::Fr, "Edward Bouverie Pusey, prêtre, 1882", Status::Authorized),
+    (Feast::ElizabethOfHungary, Language::Fr, "Élisabeth, princesse de Hongrie, 1231", Status::Authorized),
+    (Feast::EphremOfNisibis, Language::Fr, "Éphrem d’Édesse, Syrie, diacre, 373", Status::Authorized),
+    (Feast::Fabian, Language::Fr, "Fabien, évêque et martyr de Rome, 250", Status::Authorized),
+    (Feast::FrancisOfAssisi, Language::Fr, "François d’Assise, frère, 1226", Status::Authorized),
+    (Feast::FrederickDenisonMaurice, Language::Fr, "Frederick Denison Maurice, prêtre, 1872", Status::Authorized),
+    (Feast::GeorgeAugustusSelwyn, Language::Fr, "George Augustus Selwyn, évêque de Nouvelle-Zélande, et de Lichfield, 1878", Status::Authorized),
+    (Feast::GeorgeHerbert, Language::Fr, "George Herbert, prêtre, 1633", Status::Authorized),
+    (Feast::GregoryOfNazianzus, Language::Fr, "Grégoire de Nazianze, évêque de Constantinople, 389", Status::Authorized),
+    (Feast::GregoryTheGreat, Language::Fr, "Grégoire le Grand, évêque de Rome, 604", Status::Authorized),
+    (Feast::GregoryTheIlluminator, Language::Fr, "Grégoire l’Illuminateur, évêque et missionnaire d’Arménie, v. 332", Status::Authorized),
+    (Feast::HenryMartyn, Language::Fr, "Henry Martyn, prêtre, et missionnaire en Inde et en Perse, 1812", Status::Authorized),
+    (Feast::HilaryOfPoitiers, Language::Fr, "Hilaire, évêque de Poitiers, 367", Status::Authorized),
+    (Feast::HildaOfWhitby, Language::Fr, "Hilda, abbesse de Whitby, 680", Status::Authorized),
+    (Feast::HughOfLincoln, Language::Fr, "Hugues, évêque de Lincoln, 1200", Status::Authorized),
+    (Feast::LatimerRidleyCranmer, Language::Fr, "Hugh Latimer et Nicholas Ridley, évêques, 1555, et Thomas Cranmer, archevêque de Cantorbéry, 1556", Status::Authorized),
+    (Feast::IgnatiusOfAntioch, Language::Fr, "Ignace, évêque d’Antioche, et martyr, v. 115", Status::Authorized),
+    (Feast::IrenaeusOfLyons, Language::Fr, "Irénée, évêque de Lyon, v. 202", Status::Authorized),
+    (Feast::JacksonKemper, Language::Fr, "Jackson Kemper, premier évêque missionnaire aux États-Unis, 1870", Status::Authorized),
+    (Feast::JamesDeKoven, Language::Fr, "James De Koven, prêtre, 1879", Status::Authorized),
+    (Feast::JamesHannington, Language::Fr, "James Hannington, évêque de l’Afrique équatoriale orientale, et ses compagnons, martyrs, 1885", Status::Authorized),
+    (Feast::JamesLloydBreck, Language::Fr, "James Lloyd Breck, prêtre, 1876", Status::Authorized),
+    (Feast::JeremyTaylor, Language::Fr, "Jeremy Taylor, évêque de Down, Connor et Dromore, 1667", Status::Authorized),
+    (Feast::Jerome, Language::Fr, "Jérôme, prêtre, et moine de Bethléem, 420", Status::Authorized),
+    (Feast::JohnAndCharlesWesley, Language::Fr, "John et Charles Wesley, prêtres, 1791, 1788", Status::Authorized),
+    (Feast::JohnChrysostom, Language::Fr, "Jean Chrysostome, évêque de Constantinople, 407", Status::Authorized),
+    (Feast::JohnColeridgePatteson, Language::Fr, "John Coleridge Patteson, évêque de Mélanésie, et ses compagnons, martyrs, 1871", Status::Authorized),
+    (Feast::JohnDonne, Language::Fr, "John Donne, prêtre, 1631", Status::Authorized),
+    (Feast::JohnHenryHobart, Language::Fr, "John Henry Hobart, évêque de New York, 1830", Status::Authorized),
+    (Feast::JohnKeble, Language::Fr, "John Keble, prêtre, 1866", Status::Authorized),
+    (Feast::JohnMasonNeale, Language::Fr, "John Mason Neale, prêtre, 1866", Status::Authorized),
+    (Feast::JohnOfDamascus, Language::Fr, "Jean Damascène, prêtre, v. 760", Status::Authorized),
+    (Feast::JosephButler, Language::Fr, "Joseph Butler, évêque de Durham, 1752", Status::Authorized),
+    (Feast::JosephOfArimathea, Language::Fr, "Joseph d’Arimathie", Status::Authorized),
+    (Feast::Justin, Language::Fr, "Justin, martyr à Rome, v. 167", Status::Authorized),
+    (Feast::LancelotAndrewes, Language::Fr, "Lancelot Andrewes, évêque de Winchester", Status::Authorized),
+    (Feast::LaurenceOfRome, Language::Fr, "Laurent, diacre et martyr à Rome, 258", Status::Authorized),
+    (Feast::LeoOfRome, Language::Fr, "Léon le Grand, évêque de Rome, 461", Status::Authorized),
+    (Feast::Louis, Language::Fr, "Louis, roi de France, 1270", Status::Authorized),
+    (Feast::MargaretOfScotland, Language::Fr, "Marguerite, reine d’Écosse, 1093", Status::Authorized),
+    (Feast::MartinOfTours, Language::Fr, "Martin, évêque de Tours, 397", Status::Authorized),
+    (Feast::Monica, Language::Fr, "Monique, mère d’Augustin d’Hippone, 387", Status::Authorized),
+    (Feast::NicholasOfMyra, Language::Fr, "Nicolas, évêque de Myre, v. 342", Status::Authorized),
+    (Feast::NicholasFerrar, Language::Fr, "Nicholas Ferrar, diacre, 1637", Status::Authorized),
+    (Feast::Ninian, Language::Fr, "Ninian, évêque en Galloway, v. 430", Status::Authorized),
+    (Feast::PatrickOfIreland, Language::Fr, "Patrick, évêque et missionnaire d’Irlande, 461", Status::Authorized),
+    (Feast::PerpetuaAndFelicity, Language::Fr, "Perpétue et ses compagnes, martyres à Carthage, 202", Status::Authorized),
+    (Feast::PhillipsBrooks, Language::Fr, "Phillips Brooks, évêque du Massachusetts, 1893", Status::Authorized),
+    (Feast::PolycarpOfSmyrna, Language::Fr, "Polycarpe, évêque et martyr de Smyrne, 156", Status::Authorized),
+    (Feast::RemigiusOfRheims, Language::Fr, "Remi, évêque de Reims, v. 530", Status::Authorized),
+    (Feast::RichardOfChichester, Language::Fr, "Richard, évêque de Chichester, 1253", Status::Authorized),
+    (Feast::RichardHooker, Language::Fr, "Richard Hooker, prêtre, 1600", Status::Authorized),
+    (Feast::RobertGrosseteste, Language::Fr, "Robert Grosseteste, évêque de Lincoln, 1253", Status::Authorized),
+    (Feast::SamuelIsaacJosephScherechewsky, Language::Fr, "Samuel Isaac Joseph Schereschewsky, évêque de Shanghai, 1906", Status::Authorized),
+    (Feast::SergiusOfRadonezh, Language::Fr, "Serge, abbé de la Sainte-Trinité, Moscou, 1932", Status::Authorized),
+    (Feast::TheMartyrsOfJapan, Language::Fr, "Les Martyrs du Japon, 1597", Status::Authorized),
+    (Feast::TheMartyrsOfNewGuinea, Language::Fr, "Les Martyrs de Nouvelle-Guinée, 1942", Status::Authorized),
+    (Feast::TheMartyrsOfUganda, Language::Fr, "Les Martyrs de l’Ouganda, 1886", Status::Authorized),
+    (Feast::TheodoreOfTarsus, Language::Fr, "Théodore de Tarse, archevêque de Cantorbéry, 690", Status::Authorized),
+    (Feast::ThomasAKempis, Language::Fr, "Thomas a Kempis, prêtre, 1471", Status::Authorized),
+    (Feast::ThomasAquinas, Language::Fr, "Thomas d’Aquin, prêtre et frère, 1274", Status::Authorized),
+    (Feast::ThomasBray, Language::Fr, "Thomas Bray, prêtre et missionnaire, 1730", Status::Authorized),
+    (Feast::ThomasKen, Language::Fr, "Thomas Ken, évêque de Bath et Wells, 1711", Status::Authorized),
+    (Feast::TitusAndTimothy, Language::Fr, "Timothée et Tite, compagnons de saint Paul", Status::Authorized),
+    (Feast::VincentOfSaragossa, Language::Fr, "Vincent, diacre de Saragosse, et martyr, 304", Status::Authorized),
+    (Feast::WilliamAugustusMuhlenberg, Language::Fr, "William Augustus Muhlenberg, prêtre, 1877", Status::Authorized),
+    (Feast::WilliamLaud, Language::Fr, "William Laud, archevêque de Cantorbéry, 1645", Status::Authorized),
+    (Feast::WilliamLaw, Language::Fr, "William Law, prêtre, 1761", Status::Authorized),
+    (Feast::WilliamPorcherDubose, Language::Fr, "William Porcher DuBose, prêtre, 1918", Status::Authorized),
+    (Feast::WilliamReedHuntington, Language::Fr, "William Reed Huntington, prêtre, 1909", Status::Authorized),
+    (Feast::WilliamTyndale, Language::Fr, "William Tyndale, prêtre, 1536", Status::Authorized),
+    (Feast::WilliamWhite, Language::Fr, "William White, évêque de Pennsylvanie, 1836", Status::Authorized),
+    (Feast::WilliamWilberforce, Language::Fr, "William Wilberforce, 1833", Status::Authorized),
+    (Feast::Willibrord, Language::Fr, "Willibrord, archevêque d’Utrecht, missionnaire en Frise, 739", Status::Authorized),
+    (Feast::WulfstanOfWorcester, Language::Fr, "Wulfstan, évêque de Worcester, 1095", Status::Authorized)
 ];
 
-pub(crate) const BCP1979_WEEK_NAMES: [(LiturgicalWeek, Language, &str); 59] = [
+pub(crate) const BCP1979_WEEK_NAMES: [(LiturgicalWeek, Language, &str); 177] = [
     (
         LiturgicalWeek::Pentecost10,
         Language::En,
@@ -1899,9 +2359,567 @@ pub(crate) const BCP1979_WEEK_NAMES: [(LiturgicalWeek, Language, &str); 59] = [
         Language::En,
         "Trinity Sunday",
     ),
+    (
+        LiturgicalWeek::Advent1,
+        Language::Es,
+        "El Primer Domingo de Adviento",
+    ),
+    (
+        LiturgicalWeek::Advent2,
+        Language::Es,
+        "El Segundo Domingo de Adviento",
+    ),
+    (
+        LiturgicalWeek::Advent3,
+        Language::Es,
+        "El Tercer Domingo de Adviento",
+    ),
+    (
+        LiturgicalWeek::Advent4,
+        Language::Es,
+        "El Cuarto Domingo de Adviento",
+    ),
+    (LiturgicalWeek::Christmas, Language::Es, "Navidad"),
+    (
+        LiturgicalWeek::Christmas1,
+        Language::Es,
+        "El Primer Domingo después de Navidad",
+    ),
+    (
+        LiturgicalWeek::Christmas2,
+        Language::Es,
+        "El Segundo Domingo después de Navidad",
+    ),
+    (LiturgicalWeek::Epiphany, Language::Es, "La Epifanía"),
+    (
+        LiturgicalWeek::Epiphany1,
+        Language::Es,
+        "El Primer Domingo después de la Epifanía",
+    ),
+    (
+        LiturgicalWeek::Epiphany2,
+        Language::Es,
+        "El Segundo Domingo después de la Epifanía",
+    ),
+    (
+        LiturgicalWeek::Epiphany3,
+        Language::Es,
+        "El Tercer Domingo después de la Epifanía",
+    ),
+    (
+        LiturgicalWeek::Epiphany4,
+        Language::Es,
+        "El Cuarto Domingo después de la Epifanía",
+    ),
+    (
+        LiturgicalWeek::Epiphany5,
+        Language::Es,
+        "El Quinto Domingo después de la Epifanía",
+    ),
+    (
+        LiturgicalWeek::Epiphany6,
+        Language::Es,
+        "El Sexto Domingo después de la Epifanía",
+    ),
+    (
+        LiturgicalWeek::Epiphany7,
+        Language::Es,
+        "El Séptimo Domingo después de la Epifanía",
+    ),
+    (
+        LiturgicalWeek::Epiphany8,
+        Language::Es,
+        "El Octavo Domingo después de la Epifanía",
+    ),
+    (
+        LiturgicalWeek::LastEpiphany,
+        Language::Es,
+        "El Último Domingo después de la Epifanía",
+    ),
+    (
+        LiturgicalWeek::Lent1,
+        Language::Es,
+        "El Primer Domingo en Cuaresma",
+    ),
+    (
+        LiturgicalWeek::Lent2,
+        Language::Es,
+        "El Segundo Domingo en Cuaresma",
+    ),
+    (
+        LiturgicalWeek::Lent3,
+        Language::Es,
+        "El Tercer Domingo en Cuaresma",
+    ),
+    (
+        LiturgicalWeek::Lent4,
+        Language::Es,
+        "El Cuarto Domingo en Cuaresma",
+    ),
+    (
+        LiturgicalWeek::Lent5,
+        Language::Es,
+        "El Quinto Domingo en Cuaresma",
+    ),
+    (
+        LiturgicalWeek::HolyWeek,
+        Language::Es,
+        "El Domingo de Ramos",
+    ),
+    (LiturgicalWeek::Easter, Language::Es, "El Día de Pascua"),
+    (
+        LiturgicalWeek::Easter2,
+        Language::Es,
+        "El Segundo Domingo de Pascua",
+    ),
+    (
+        LiturgicalWeek::Easter3,
+        Language::Es,
+        "El Tercer Domingo de Pascua",
+    ),
+    (
+        LiturgicalWeek::Easter4,
+        Language::Es,
+        "El Cuarto Domingo de Pascua",
+    ),
+    (
+        LiturgicalWeek::Easter5,
+        Language::Es,
+        "El Quinto Domingo de Pascua",
+    ),
+    (
+        LiturgicalWeek::Easter6,
+        Language::Es,
+        "El Sexto Domingo de Pascua",
+    ),
+    (
+        LiturgicalWeek::Easter7,
+        Language::Es,
+        "El Séptimo Domingo de Pascua",
+    ),
+    (LiturgicalWeek::Pentecost, Language::Es, "Pentecostés"),
+    (
+        LiturgicalWeek::TrinitySunday,
+        Language::Es,
+        "El Domingo de la Trinidad",
+    ),
+    (
+        LiturgicalWeek::Pentecost2,
+        Language::Es,
+        "El Segundo Domingo después de Pentecostés",
+    ),
+    (
+        LiturgicalWeek::Pentecost3,
+        Language::Es,
+        "El Tercer Domingo después de Pentecostés",
+    ),
+    (
+        LiturgicalWeek::Pentecost4,
+        Language::Es,
+        "El Cuarto Domingo después de Pentecostés",
+    ),
+    (
+        LiturgicalWeek::Pentecost5,
+        Language::Es,
+        "El Quinto Domingo después de Pentecostés",
+    ),
+    (
+        LiturgicalWeek::Pentecost6,
+        Language::Es,
+        "El Sexto Domingo después de Pentecostés",
+    ),
+    (
+        LiturgicalWeek::Pentecost7,
+        Language::Es,
+        "El Séptimo Domingo después de Pentecostés",
+    ),
+    (
+        LiturgicalWeek::Pentecost8,
+        Language::Es,
+        "El Octavo Domingo después de Pentecostés",
+    ),
+    (
+        LiturgicalWeek::Pentecost9,
+        Language::Es,
+        "El Noveno Domingo después de Pentecostés",
+    ),
+    (
+        LiturgicalWeek::Pentecost10,
+        Language::Es,
+        "El Décimo Domingo después de Pentecostés",
+    ),
+    (
+        LiturgicalWeek::Pentecost11,
+        Language::Es,
+        "El Undécimo Domingo después de Pentecostés",
+    ),
+    (
+        LiturgicalWeek::Pentecost12,
+        Language::Es,
+        "El Duodécimo Domingo después de Pentecostés",
+    ),
+    (
+        LiturgicalWeek::Pentecost13,
+        Language::Es,
+        "El Decimotercer Domingo después de Pentecostés",
+    ),
+    (
+        LiturgicalWeek::Pentecost14,
+        Language::Es,
+        "El Decimocuarto Domingo después de Pentecostés",
+    ),
+    (
+        LiturgicalWeek::Pentecost15,
+        Language::Es,
+        "El Decimoquinto Domingo después de Pentecostés",
+    ),
+    (
+        LiturgicalWeek::Pentecost16,
+        Language::Es,
+        "El Decimosexto Domingo después de Pentecostés",
+    ),
+    (
+        LiturgicalWeek::Pentecost17,
+        Language::Es,
+        "El Decimoséptimo Domingo después de Pentecostés",
+    ),
+    (
+        LiturgicalWeek::Pentecost18,
+        Language::Es,
+        "El Decimoctavo Domingo después de Pentecostés",
+    ),
+    (
+        LiturgicalWeek::Pentecost19,
+        Language::Es,
+        "El Decimonoveno Domingo después de Pentecostés",
+    ),
+    (
+        LiturgicalWeek::Pentecost20,
+        Language::Es,
+        "El Vigésimo Domingo después de Pentecostés",
+    ),
+    (
+        LiturgicalWeek::Pentecost21,
+        Language::Es,
+        "El Vigésimo Primer Domingo después de Pentecostés",
+    ),
+    (
+        LiturgicalWeek::Pentecost22,
+        Language::Es,
+        "El Vigésimo Segundo Domingo después de Pentecostés",
+    ),
+    (
+        LiturgicalWeek::Pentecost23,
+        Language::Es,
+        "El Vigésimo Tercer Domingo después de Pentecostés",
+    ),
+    (
+        LiturgicalWeek::Pentecost24,
+        Language::Es,
+        "El Vigésimo Cuarto Domingo después de Pentecostés",
+    ),
+    (
+        LiturgicalWeek::Pentecost25,
+        Language::Es,
+        "El Vigésimo Quinto Domingo después de Pentecostés",
+    ),
+    (
+        LiturgicalWeek::Pentecost26,
+        Language::Es,
+        "El Vigésimo Sexto Domingo después de Pentecostés",
+    ),
+    (
+        LiturgicalWeek::Pentecost27,
+        Language::Es,
+        "El Vigésimo Séptimo Domingo después de Pentecostés",
+    ),
+    (
+        LiturgicalWeek::LastPentecost,
+        Language::Es,
+        "El Último Domingo después de Pentecostés",
+    ),
+    (
+        LiturgicalWeek::Advent1,
+        Language::Fr,
+        "Le Premier Dimanche de l’Avent",
+    ),
+    (
+        LiturgicalWeek::Advent2,
+        Language::Fr,
+        "Le Deuxième Dimanche de l’Avent",
+    ),
+    (
+        LiturgicalWeek::Advent3,
+        Language::Fr,
+        "Le Troisième Dimanche de l’Avent",
+    ),
+    (
+        LiturgicalWeek::Advent4,
+        Language::Fr,
+        "Le Quatrième Dimanche de l’Avent",
+    ),
+    (LiturgicalWeek::Christmas, Language::Fr, "Noël"),
+    (
+        LiturgicalWeek::Christmas1,
+        Language::Fr,
+        "Le Premier Dimanche après Noël",
+    ),
+    (
+        LiturgicalWeek::Christmas2,
+        Language::Fr,
+        "Le Deuxième Dimanche après Noël",
+    ),
+    (LiturgicalWeek::Epiphany, Language::Fr, "L’Épiphanie"),
+    (
+        LiturgicalWeek::Epiphany1,
+        Language::Fr,
+        "Le Premier Dimanche après l’Épiphanie",
+    ),
+    (
+        LiturgicalWeek::Epiphany2,
+        Language::Fr,
+        "Le Deuxième Dimanche après l’Épiphanie",
+    ),
+    (
+        LiturgicalWeek::Epiphany3,
+        Language::Fr,
+        "Le Troisième Dimanche après l’Épiphanie",
+    ),
+    (
+        LiturgicalWeek::Epiphany4,
+        Language::Fr,
+        "Le Quatrième Dimanche après l’Épiphanie",
+    ),
+    (
+        LiturgicalWeek::Epiphany5,
+        Language::Fr,
+        "Le Cinquième Dimanche après l’Épiphanie",
+    ),
+    (
+        LiturgicalWeek::Epiphany6,
+        Language::Fr,
+        "Le Sixième Dimanche après l’Épiphanie",
+    ),
+    (
+        LiturgicalWeek::Epiphany7,
+        Language::Fr,
+        "Le Septième Dimanche après l’Épiphanie",
+    ),
+    (
+        LiturgicalWeek::Epiphany8,
+        Language::Fr,
+        "Le Huitième Dimanche après l’Épiphanie",
+    ),
+    (
+        LiturgicalWeek::LastEpiphany,
+        Language::Fr,
+        "Le Dernier Dimanche après l’Épiphanie",
+    ),
+    (
+        LiturgicalWeek::Lent1,
+        Language::Fr,
+        "Le Premier Dimanche du Carême",
+    ),
+    (
+        LiturgicalWeek::Lent2,
+        Language::Fr,
+        "Le Deuxième Dimanche du Carême",
+    ),
+    (
+        LiturgicalWeek::Lent3,
+        Language::Fr,
+        "Le Troisième Dimanche du Carême",
+    ),
+    (
+        LiturgicalWeek::Lent4,
+        Language::Fr,
+        "Le Quatrième Dimanche du Carême",
+    ),
+    (
+        LiturgicalWeek::Lent5,
+        Language::Fr,
+        "Le Cinquième Dimanche du Carême",
+    ),
+    (
+        LiturgicalWeek::HolyWeek,
+        Language::Fr,
+        "Le Dimanche des Rameaux",
+    ),
+    (LiturgicalWeek::Easter, Language::Fr, "Le Jour de Pâques"),
+    (
+        LiturgicalWeek::Easter2,
+        Language::Fr,
+        "Le Deuxième Dimanche de Pâques",
+    ),
+    (
+        LiturgicalWeek::Easter3,
+        Language::Fr,
+        "Le Troisième Dimanche de Pâques",
+    ),
+    (
+        LiturgicalWeek::Easter4,
+        Language::Fr,
+        "Le Quatrième Dimanche de Pâques",
+    ),
+    (
+        LiturgicalWeek::Easter5,
+        Language::Fr,
+        "Le Cinquième Dimanche de Pâques",
+    ),
+    (
+        LiturgicalWeek::Easter6,
+        Language::Fr,
+        "Le Sixième Dimanche de Pâques",
+    ),
+    (
+        LiturgicalWeek::Easter7,
+        Language::Fr,
+        "Le Septième Dimanche de Pâques",
+    ),
+    (LiturgicalWeek::Pentecost, Language::Fr, "La Pentecôte"),
+    (
+        LiturgicalWeek::TrinitySunday,
+        Language::Fr,
+        "Le Dimanche de la Trinité",
+    ),
+    (
+        LiturgicalWeek::Pentecost2,
+        Language::Fr,
+        "Le Deuxième Dimanche après la Pentecôte",
+    ),
+    (
+        LiturgicalWeek::Pentecost3,
+        Language::Fr,
+        "Le Troisième Dimanche après la Pentecôte",
+    ),
+    (
+        LiturgicalWeek::Pentecost4,
+        Language::Fr,
+        "Le Quatrième Dimanche après la Pentecôte",
+    ),
+    (
+        LiturgicalWeek::Pentecost5,
+        Language::Fr,
+        "Le Cinquième Dimanche après la Pentecôte",
+    ),
+    (
+        LiturgicalWeek::Pentecost6,
+        Language::Fr,
+        "Le Sixième Dimanche après la Pentecôte",
+    ),
+    (
+        LiturgicalWeek::Pentecost7,
+        Language::Fr,
+        "Le Septième Dimanche après la Pentecôte",
+    ),
+    (
+        LiturgicalWeek::Pentecost8,
+        Language::Fr,
+        "Le Huitième Dimanche après la Pentecôte",
+    ),
+    (
+        LiturgicalWeek::Pentecost9,
+        Language::Fr,
+        "Le Neuvième Dimanche après la Pentecôte",
+    ),
+    (
+        LiturgicalWeek::Pentecost10,
+        Language::Fr,
+        "Le Dixième Dimanche après la Pentecôte",
+    ),
+    (
+        LiturgicalWeek::Pentecost11,
+        Language::Fr,
+        "Le Onzième Dimanche après la Pentecôte",
+    ),
+    (
+        LiturgicalWeek::Pentecost12,
+        Language::Fr,
+        "Le Douzième Dimanche après la Pentecôte",
+    ),
+    (
+        LiturgicalWeek::Pentecost13,
+        Language::Fr,
+        "Le Treizième Dimanche après la Pentecôte",
+    ),
+    (
+        LiturgicalWeek::Pentecost14,
+        Language::Fr,
+        "Le Quatorzième Dimanche après la Pentecôte",
+    ),
+    (
+        LiturgicalWeek::Pentecost15,
+        Language::Fr,
+        "Le Quinzième Dimanche après la Pentecôte",
+    ),
+    (
+        LiturgicalWeek::Pentecost16,
+        Language::Fr,
+        "Le Seizième Dimanche après la Pentecôte",
+    ),
+    (
+        LiturgicalWeek::Pentecost17,
+        Language::Fr,
+        "Le Dix-septième Dimanche après la Pentecôte",
+    ),
+    (
+        LiturgicalWeek::Pentecost18,
+        Language::Fr,
+        "Le Dix-huitième Dimanche après la Pentecôte",
+    ),
+    (
+        LiturgicalWeek::Pentecost19,
+        Language::Fr,
+        "Le Dix-neuvième Dimanche après la Pentecôte",
+    ),
+    (
+        LiturgicalWeek::Pentecost20,
+        Language::Fr,
+        "Le Vingtième Dimanche après la Pentecôte",
+    ),
+    (
+        LiturgicalWeek::Pentecost21,
+        Language::Fr,
+        "Le Vingt et unième Dimanche après la Pentecôte",
+    ),
+    (
+        LiturgicalWeek::Pentecost22,
+        Language::Fr,
+        "Le Vingt-deuxième Dimanche après la Pentecôte",
+    ),
+    (
+        LiturgicalWeek::Pentecost23,
+        Language::Fr,
+        "Le Vingt-troisième Dimanche après la Pentecôte",
+    ),
+    (
+        LiturgicalWeek::Pentecost24,
+        Language::Fr,
+        "Le Vingt-quatrième Dimanche après la Pentecôte",
+    ),
+    (
+        LiturgicalWeek::Pentecost25,
+        Language::Fr,
+        "Le Vingt-cinquième Dimanche après la Pentecôte",
+    ),
+    (
+        LiturgicalWeek::Pentecost26,
+        Language::Fr,
+        "Le Vingt-sixième Dimanche après la Pentecôte",
+    ),
+    (
+        LiturgicalWeek::Pentecost27,
+        Language::Fr,
+        "Le Vingt-septième Dimanche après la Pentecôte",
+    ),
+    (
+        LiturgicalWeek::LastPentecost,
+        Language::Fr,
+        "Le Dernier Dimanche après la Pentecôte",
+    ),
 ];
 
-pub(crate) const BCP1979_PROPER_NAMES: [(Proper, Language, &str); 29] = [
+pub(crate) const BCP1979_PROPER_NAMES: [(Proper, Language, &str); 87] = [
     (Proper::Proper1, Language::En, "Proper 1"),
     (Proper::Proper2, Language::En, "Proper 2"),
     (Proper::Proper3, Language::En, "Proper 3"),
@@ -1931,6 +2949,64 @@ pub(crate) const BCP1979_PROPER_NAMES: [(Proper, Language, &str); 29] = [
     (Proper::Proper27, Language::En, "Proper 27"),
     (Proper::Proper28, Language::En, "Proper 28"),
     (Proper::Proper29, Language::En, "Proper 29"),
+    (Proper::Proper1, Language::Es, "Propio 1"),
+    (Proper::Proper2, Language::Es, "Propio 2"),
+    (Proper::Proper3, Language::Es, "Propio 3"),
+    (Proper::Proper4, Language::Es, "Propio 4"),
+    (Proper::Proper5, Language::Es, "Propio 5"),
+    (Proper::Proper6, Language::Es, "Propio 6"),
+    (Proper::Proper7, Language::Es, "Propio 7"),
+    (Proper::Proper8, Language::Es, "Propio 8"),
+    (Proper::Proper9, Language::Es, "Propio 9"),
+    (Proper::Proper10, Language::Es, "Propio 10"),
+    (Proper::Proper11, Language::Es, "Propio 11"),
+    (Proper::Proper12, Language::Es, "Propio 12"),
+    (Proper::Proper13, Language::Es, "Propio 13"),
+    (Proper::Proper14, Language::Es, "Propio 14"),
+    (Proper::Proper15, Language::Es, "Propio 15"),
+    (Proper::Proper16, Language::Es, "Propio 16"),
+    (Proper::Proper17, Language::Es, "Propio 17"),
+    (Proper::Proper18, Language::Es, "Propio 18"),
+    (Proper::Proper19, Language::Es, "Propio 19"),
+    (Proper::Proper20, Language::Es, "Propio 20"),
+    (Proper::Proper21, Language::Es, "Propio 21"),
+    (Proper::Proper22, Language::Es, "Propio 22"),
+    (Proper::Proper23, Language::Es, "Propio 23"),
+    (Proper::Proper24, Language::Es, "Propio 24"),
+    (Proper::Proper25, Language::Es, "Propio 25"),
+    (Proper::Proper26, Language::Es, "Propio 26"),
+    (Proper::Proper27, Language::Es, "Propio 27"),
+    (Proper::Proper28, Language::Es, "Propio 28"),
+    (Proper::Proper29, Language::Es, "Propio 29"),
+    (Proper::Proper1, Language::Fr, "Propre 1"),
+    (Proper::Proper2, Language::Fr, "Propre 2"),
+    (Proper::Proper3, Language::Fr, "Propre 3"),
+    (Proper::Proper4, Language::Fr, "Propre 4"),
+    (Proper::Proper5, Language::Fr, "Propre 5"),
+    (Proper::Proper6, Language::Fr, "Propre 6"),
+    (Proper::Proper7, Language::Fr, "Propre 7"),
+    (Proper::Proper8, Language::Fr, "Propre 8"),
+    (Proper::Proper9, Language::Fr, "Propre 9"),
+    (Proper::Proper10, Language::Fr, "Propre 10"),
+    (Proper::Proper11, Language::Fr, "Propre 11"),
+    (Proper::Proper12, Language::Fr, "Propre 12"),
+    (Proper::Proper13, Language::Fr, "Propre 13"),
+    (Proper::Proper14, Language::Fr, "Propre 14"),
+    (Proper::Proper15, Language::Fr, "Propre 15"),
+    (Proper::Proper16, Language::Fr, "Propre 16"),
+    (Proper::Proper17, Language::Fr, "Propre 17"),
+    (Proper::Proper18, Language::Fr, "Propre 18"),
+    (Proper::Proper19, Language::Fr, "Propre 19"),
+    (Proper::Proper20, Language::Fr, "Propre 20"),
+    (Proper::Proper21, Language::Fr, "Propre 21"),
+    (Proper::Proper22, Language::Fr, "Propre 22"),
+    (Proper::Proper23, Language::Fr, "Propre 23"),
+    (Proper::Proper24, Language::Fr, "Propre 24"),
+    (Proper::Proper25, Language::Fr, "Propre 25"),
+    (Proper::Proper26, Language::Fr, "Propre 26"),
+    (Proper::Proper27, Language::Fr, "Propre 27"),
+    (Proper::Proper28, Language::Fr, "Propre 28"),
+    (Proper::Proper29, Language::Fr, "Propre 29"),
 ];
 
 #[cfg(test)]
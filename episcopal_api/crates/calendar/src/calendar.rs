@@ -5,20 +5,26 @@ use language::Language;
 use status::Status;
 
 use crate::{
-    easter_in_year, feasts::KalendarEntry, holy_day::HolyDayId, liturgical_day::LiturgicalDayId,
-    liturgical_week::Cycle, propers::calculate_proper, DailyOfficeYear, Date, Feast, LiturgicalDay,
-    LiturgicalWeek, Proper, RCLYear, Rank, Season, Time, Weekday,
+    feasts::KalendarEntry, holy_day::HolyDayId, liturgical_day::LiturgicalDayId,
+    liturgical_week::Cycle, propers::calculate_proper, Color, Computus, DailyOfficeYear, Date, Feast, HolyDay, LiturgicalDay,
+    LiturgicalWeek, LocalFeast, Proper, RCLYear, Rank, Season, Time, Weekday,
 };
 
 /// The settings for a particular calendar. Different calendars vary slightly
 /// in the way their liturgical cycles are set up relative to Christmas and Easter.
 /// Based on this structure, we can generate a [LiturgicalWeek](LiturgicalWeek)
 /// and [LiturgicalDay](LiturgicalDay) from any date.
-pub struct Calendar {
+pub struct Calendar<'a> {
+    /// How the date of Easter is calculated in the calendar
+    pub computus: Computus,
     /// How many weeks before Easter the Easter cycle begins in the calendar
     pub easter_cycle_begins: u8,
     /// How many weeks before Christmas the Christmas cycle begins in the calendar
     pub christmas_cycle_begins: u8,
+    /// In calendars in which the season after the Epiphany always runs until a fixed date
+    /// (e.g., until the Presentation on February 2 in Common Worship), the month and day
+    /// of that date. Any week that begins before this date remains in the Christmas cycle.
+    pub christmas_cycle_ends: Option<(u8, u8)>,
     /// Whether to use `Proper 1`, `Proper 2`, etc. for weeks after Pentecost
     pub has_propers: bool,
     /// Maps nth week of cycle onto the liturgical week identifier
@@ -29,11 +35,14 @@ pub struct Calendar {
     pub holy_days: &'static [KalendarEntry],
     /// A calendar that can be used to look up information for feasts that are not found in this calendar
     /// (used for e.g., days that appear in the BCP calendar but don't have separate LFF entries)
-    pub holy_days_fallback: Option<&'static Calendar>,
+    pub holy_days_fallback: Option<&'static Calendar<'static>>,
     /// Ranks of holy days in this calendar
     pub holy_day_ranks: &'static [(Feast, Rank)],
     /// Associations between [Feast]s and [Season]s
     pub feast_seasons: &'static [(Feast, Season)],
+    /// The liturgical color of holy days that have a color of their own, rather than the color
+    /// of their season (e.g., red for apostles and martyrs)
+    pub feast_colors: &'static [(Feast, Color)],
     /// Associations between [LiturgicalWeek]s and [Season]s
     pub week_seasons: &'static [(LiturgicalWeek, Season)],
     /// Name for each [Feast](crate::Feast), by language
@@ -44,9 +53,14 @@ pub struct Calendar {
     pub proper_names: &'static [(Proper, Language, &'static str)],
     /// Alternative services for certain major days
     pub major_day_alternatives: &'static [(Feast, &'static [Feast])],
+    /// Holy days that are celebrated through an [Octave](crate::Octave), each giving the day
+    /// on which its octave ends
+    pub octaves: &'static [HolyDay],
+    /// Feasts observed locally, in addition to the holy days of the calendar (see [Calendar::with_local_feasts])
+    pub local_feasts: &'a [LocalFeast],
 }
 
-impl Calendar {
+impl Calendar<'_> {
     /// The [LiturgicalDay](LiturgicalDay) that is observed on a given date,
     /// including any feasts or special observances and any transferred feasts.
     /// ```
@@ -62,10 +76,19 @@ impl Calendar {
     /// assert_eq!(thursday_easter_6.observed, LiturgicalDayId::Feast(Feast::AscensionDay));
     /// ```
     pub fn liturgical_day(&self, date: Date, evening: bool) -> LiturgicalDay {
-        let mut original = self.liturgical_day_without_transferred_feasts(date, evening);
+        let original = self.liturgical_day_without_transferred_feasts(date, evening);
+        let transferred = self.transferred_feast(&original);
+        self.with_transferred_feast(original, transferred)
+    }
 
+    /// Completes a [LiturgicalDay] generated by [Calendar::liturgical_day_without_transferred_feasts]
+    /// by observing the feast (if any) that has been transferred to it.
+    pub(crate) fn with_transferred_feast(
+        &self,
+        mut original: LiturgicalDay,
+        transferred: Option<Feast>,
+    ) -> LiturgicalDay {
         // modify if feasts should be transferred
-        let transferred = self.transferred_feast(&original);
         if let Some(transferred) = transferred {
             let alternate = std::mem::replace(
                 &mut original.observed,
@@ -127,7 +150,13 @@ impl Calendar {
         let proper = self.proper(date, week);
         let holy_days = self.holy_days(date, week, evening, false);
         let fallback_holy_days = self.holy_days_fallback.map(|fallback| {
-            Calendar::filter_holy_days(date, week, evening, false, fallback.holy_days)
+            Calendar::filter_holy_days(
+                date,
+                week,
+                evening,
+                false,
+                fallback.holy_days.iter().copied(),
+            )
         });
         let holy_days = if let Some(fallback) = fallback_holy_days {
             holy_days.chain(fallback).unique().collect::<Vec<_>>()
@@ -141,6 +170,7 @@ impl Calendar {
             .find(|(s_feast, _)| observed == LiturgicalDayId::Feast(*s_feast))
             .map(|(_, alternatives)| alternatives.to_vec())
             .unwrap_or_default();
+        let octave = self.octave(date);
 
         LiturgicalDay {
             date,
@@ -154,6 +184,7 @@ impl Calendar {
             observed,
             alternate,
             alternative_services,
+            octave,
         }
     }
 
@@ -169,18 +200,8 @@ impl Calendar {
     /// ```
     pub fn season(&self, day: &LiturgicalDay) -> Season {
         match day.observed {
-            LiturgicalDayId::Feast(feast) => self
-                .feast_seasons
-                .iter()
-                .find(|(search, _)| *search == feast)
-                .map(|(_, season)| *season)
-                .unwrap_or(Season::Saints),
-            LiturgicalDayId::TransferredFeast(feast) => self
-                .feast_seasons
-                .iter()
-                .find(|(search, _)| *search == feast)
-                .map(|(_, season)| *season)
-                .unwrap_or(Season::Saints),
+            LiturgicalDayId::Feast(feast) => self.feast_season(feast),
+            LiturgicalDayId::TransferredFeast(feast) => self.feast_season(feast),
             _ => self
                 .week_seasons
                 .iter()
@@ -236,53 +257,76 @@ impl Calendar {
         }
     }
 
-    /// The name of a [Feast](crate::Feast) in a given [Language](language::Language)
+    /// The name of a [Feast](crate::Feast) in a given [Language](language::Language), or in the first of its
+    /// [fallbacks](language::Language::fallback) in which the feast has a name
+    /// ```
+    /// # use calendar::{BCP1979_CALENDAR, LFF2018_CALENDAR, Feast};
+    /// # use language::Language;
+    /// assert_eq!(BCP1979_CALENDAR.feast_name(Feast::AshWednesday, Language::Es), Some("Miércoles de Ceniza".to_string()));
+    /// assert_eq!(LFF2018_CALENDAR.feast_name(Feast::Andrew, Language::Fr), Some("Saint André, apôtre".to_string()));
+    /// assert_eq!(BCP1979_CALENDAR.feast_name(Feast::Bede, Language::Fr), Some("Bède le Vénérable, prêtre, et moine de Jarrow, 735".to_string()));
+    /// // commemorations that have not been translated fall back to English
+    /// assert_eq!(LFF2018_CALENDAR.feast_name(Feast::AelredOfRievaulx, Language::Fr), Some("Aelred of Rievaulx, Monastic and Theologian, 1167".to_string()));
+    /// ```
     pub fn feast_name(&self, feast: Feast, language: Language) -> Option<String> {
-        if let Some(fallback) = self.holy_days_fallback {
-            self.feast_names
-                .iter()
-                .chain(fallback.feast_names.iter())
-                .find(|(s_feast, s_language, _, _)| *s_feast == feast && *s_language == language)
-                .map(|(_, _, name, status)| {
-                    if status == &Status::TrialUse {
-                        format!("[{name}]")
-                    } else {
-                        name.to_string()
-                    }
-                })
-        } else {
-            self.feast_names
-                .iter()
-                .find(|(s_feast, s_language, _, _)| *s_feast == feast && *s_language == language)
-                .map(|(_, _, name, status)| {
-                    if status == &Status::TrialUse {
-                        format!("[{name}]")
-                    } else {
-                        name.to_string()
-                    }
-                })
+        if let Some(local_feast) = self.local_feast(&feast) {
+            return language
+                .with_fallbacks()
+                .find_map(|language| local_feast.names.get(&language))
+                .cloned();
         }
+
+        let fallback_names = self
+            .holy_days_fallback
+            .map(|fallback| fallback.feast_names)
+            .unwrap_or_default();
+        language
+            .with_fallbacks()
+            .find_map(|language| {
+                self.feast_names.iter().chain(fallback_names.iter()).find(
+                    |(s_feast, s_language, _, _)| *s_feast == feast && *s_language == language,
+                )
+            })
+            .map(|(_, _, name, status)| {
+                if status == &Status::TrialUse {
+                    format!("[{name}]")
+                } else {
+                    name.to_string()
+                }
+            })
     }
 
-    /// The name of a [LiturgicalWeek](crate::LiturgicalWeek) in a given [Language](language::Language)
+    /// The name of a [LiturgicalWeek](crate::LiturgicalWeek) in a given [Language](language::Language),
+    /// or in the first of its [fallbacks](language::Language::fallback) in which the week has a name
+    /// ```
+    /// # use calendar::{BCP1979_CALENDAR, LiturgicalWeek};
+    /// # use language::Language;
+    /// assert_eq!(BCP1979_CALENDAR.week_name(LiturgicalWeek::Advent1, Language::Es), Some("El Primer Domingo de Adviento"));
+    /// assert_eq!(BCP1979_CALENDAR.week_name(LiturgicalWeek::Lent3, Language::Fr), Some("Le Troisième Dimanche du Carême"));
+    /// ```
     pub fn week_name(&self, week: LiturgicalWeek, language: Language) -> Option<&str> {
-        self.week_names
-            .iter()
-            .find(|(s_week, s_language, _)| *s_week == week && *s_language == language)
-            .map(|(_, _, name)| *name)
+        language.with_fallbacks().find_map(|language| {
+            self.week_names
+                .iter()
+                .find(|(s_week, s_language, _)| *s_week == week && *s_language == language)
+                .map(|(_, _, name)| *name)
+        })
     }
 
-    /// The name of a [Proper](crate::Proper) in a given [Language](language::Language)
+    /// The name of a [Proper](crate::Proper) in a given [Language](language::Language),
+    /// or in the first of its [fallbacks](language::Language::fallback) in which the proper has a name
     pub fn proper_name(&self, proper: Proper, language: Language) -> Option<&str> {
-        self.proper_names
-            .iter()
-            .find(|(s_proper, s_language, _)| *s_proper == proper && *s_language == language)
-            .map(|(_, _, name)| *name)
+        language.with_fallbacks().find_map(|language| {
+            self.proper_names
+                .iter()
+                .find(|(s_proper, s_language, _)| *s_proper == proper && *s_language == language)
+                .map(|(_, _, name)| *name)
+        })
     }
 
     /// The [LiturgicalWeek](LiturgicalWeek) within which a given date falls,
     /// ignoring any feasts or special observances.
-    fn liturgical_week(&self, date: Date) -> LiturgicalWeek {
+    pub(crate) fn liturgical_week(&self, date: Date) -> LiturgicalWeek {
         let index = self.liturgical_week_index(date);
         self.weeks
             .iter()
@@ -303,7 +347,9 @@ impl Calendar {
 
     /// The rank of the given feast day in this calendar
     pub fn feast_day_rank(&self, feast: &Feast) -> Rank {
-        if let Some(fallback) = self.holy_days_fallback {
+        if let Some(local_feast) = self.local_feast(feast) {
+            local_feast.rank
+        } else if let Some(fallback) = self.holy_days_fallback {
             self.holy_day_ranks
                 .iter()
                 .chain(fallback.holy_day_ranks.iter())
@@ -322,10 +368,9 @@ impl Calendar {
     /// Whether the given feast is the "Eve of ___"
     pub fn feast_is_eve(&self, feast: &Feast) -> bool {
         let in_own_calendar = self
-            .holy_days
-            .iter()
+            .kalendar_entries()
             .find(|(_, search_feast, _, _)| search_feast == feast)
-            .map(|(_, _, time, _)| matches!(*time, Time::EveningOnly(_)));
+            .map(|(_, _, time, _)| matches!(time, Time::EveningOnly(_)));
         let in_fallback = self
             .holy_days_fallback
             .and_then(|fallback| {
@@ -341,11 +386,10 @@ impl Calendar {
     /// If the feast is the Eve of ___, returns Some(___); otherwise, None
     pub fn feast_eve_following_day(&self, feast: &Feast) -> Option<Feast> {
         let in_own_calendar = self
-            .holy_days
-            .iter()
+            .kalendar_entries()
             .find(|(_, search_feast, _, _)| search_feast == feast)
             .and_then(|(_, _, time, _)| match time {
-                Time::EveningOnly(next_day) => next_day.as_ref(),
+                Time::EveningOnly(next_day) => next_day,
                 _ => None,
             });
         let in_fallback = self.holy_days_fallback.and_then(|fallback| {
@@ -359,7 +403,7 @@ impl Calendar {
                 })
         });
         match (in_own_calendar, in_fallback) {
-            (Some(f), _) => Some(*f),
+            (Some(f), _) => Some(f),
             (None, Some(f)) => Some(*f),
             (None, None) => None,
         }
@@ -371,89 +415,89 @@ impl Calendar {
         week: LiturgicalWeek,
         evening: bool,
         ignore_evening: bool,
-    ) -> impl Iterator<Item = Feast> {
-        Calendar::filter_holy_days(date, week, evening, ignore_evening, self.holy_days)
+    ) -> impl Iterator<Item = Feast> + '_ {
+        Calendar::filter_holy_days(date, week, evening, ignore_evening, self.kalendar_entries())
     }
 
-    fn filter_holy_days(
+    pub(crate) fn filter_holy_days<'b>(
         date: Date,
         week: LiturgicalWeek,
         evening: bool,
         ignore_evening: bool,
-        holy_days: &[KalendarEntry],
-    ) -> impl Iterator<Item = Feast> + '_ {
+        holy_days: impl Iterator<Item = KalendarEntry> + 'b,
+    ) -> impl Iterator<Item = Feast> + 'b {
         let today_month = date.month();
         let today_day = date.day();
         let today_weekday = date.weekday();
         let today_year = date.year();
-        holy_days
-            .iter()
-            .filter_map(move |(id, feast, f_time, f_stops_at_sunday)| {
-                let has_stopped = if let Some(stopping_week) = f_stops_at_sunday {
-                    week >= *stopping_week
-                } else {
-                    false
-                };
-                let time_ok = (!matches!(*f_time, Time::EveningOnly(_))
-                    || (!ignore_evening && matches!(*f_time, Time::EveningOnly(_)) && evening))
-                    && (*f_time != Time::MorningOnly || !evening);
-                match id {
-                    HolyDayId::Date(f_month, f_day) => {
-                        if *f_month == today_month && *f_day == today_day && time_ok && !has_stopped
-                        {
-                            Some(*feast)
-                        } else {
-                            None
-                        }
+        holy_days.filter_map(move |entry| {
+            let (id, feast, f_time, f_stops_at_sunday) = &entry;
+            let has_stopped = if let Some(stopping_week) = f_stops_at_sunday {
+                week >= *stopping_week
+            } else {
+                false
+            };
+            let time_ok = (!matches!(*f_time, Time::EveningOnly(_))
+                || (!ignore_evening && matches!(*f_time, Time::EveningOnly(_)) && evening))
+                && (*f_time != Time::MorningOnly || !evening);
+            match id {
+                HolyDayId::Date(f_month, f_day) => {
+                    if *f_month == today_month && *f_day == today_day && time_ok && !has_stopped
+                    {
+                        Some(*feast)
+                    } else {
+                        None
                     }
-                    HolyDayId::SpecialDay(f_week, f_weekday) => {
-                        if *f_week == week && *f_weekday == today_weekday && time_ok && !has_stopped
-                        {
-                            Some(*feast)
-                        } else {
-                            None
-                        }
+                }
+                HolyDayId::SpecialDay(f_week, f_weekday) => {
+                    if *f_week == week && *f_weekday == today_weekday && time_ok && !has_stopped
+                    {
+                        Some(*feast)
+                    } else {
+                        None
                     }
-                    HolyDayId::DayOfMonth { month, week, day } => {
-                        // divide date by 7 and round up => nth instance of a day of week
-                        if *month == today_month
-                            && *day == today_weekday
-                            && date.nth_instance_in_month() == *week
-                            && time_ok
-                            && !has_stopped
-                        {
-                            Some(*feast)
-                        } else {
-                            None
-                        }
+                }
+                HolyDayId::DayOfMonth { month, week, day } => {
+                    // divide date by 7 and round up => nth instance of a day of week
+                    if *month == today_month
+                        && *day == today_weekday
+                        && date.nth_instance_in_month() == *week
+                        && time_ok
+                        && !has_stopped
+                    {
+                        Some(*feast)
+                    } else {
+                        None
                     }
-                    HolyDayId::WeekdayAfterDate {
-                        month,
-                        day,
-                        starting_weekday,
-                        weekday,
-                    } => {
-                        let starting_date = Date::from_ymd(today_year, *month, *day);
-                        let starting_date = if let Some(starting_weekday) = starting_weekday {
-                            if starting_date.weekday() == *starting_weekday {
-                                starting_date.add_days(7)
-                            } else {
-                                starting_date
-                            }
-                        } else {
-                            starting_date
-                        };
-
-                        let distance_in_days = (date - starting_date).num_days();
-
-                        if (0..7).contains(&distance_in_days) && weekday == &date.weekday() {
-                            Some(*feast)
-                        } else {
-                            None
-                        }
+                }
+                HolyDayId::WeekdayAfterDate {
+                    month,
+                    day,
+                    starting_weekday,
+                    weekday,
+                } => {
+                    let after_date = Date::from_ymd(today_year, *month, *day);
+                    // the first `starting_weekday` after (not on) the date begins the week
+                    // e.g., the Fall Ember Days are the Wednesday after 9/14 and the Friday and Saturday that follow it
+                    let starting_date = if let Some(starting_weekday) = starting_weekday {
+                        let days_until = (7 + starting_weekday.num_days_from_sunday()
+                            - after_date.weekday().num_days_from_sunday())
+                            % 7;
+                        after_date.add_days(if days_until == 0 { 7 } else { days_until })
+                    } else {
+                        after_date
+                    };
+
+                    let distance_in_days = (date - starting_date).num_days();
+
+                    if (0..7).contains(&distance_in_days) && weekday == &date.weekday() {
+                        Some(*feast)
+                    } else {
+                        None
                     }
                 }
-            })
+            }
+        })
     }
 
     fn observed_day(
@@ -548,14 +592,18 @@ impl Calendar {
 
     fn liturgical_week_index(&self, date: Date) -> LiturgicalWeekIndex {
         let year = date.year();
-        let easter = easter_in_year(year.into());
+        let easter = self.computus.easter_in_year(year.into());
         let christmas_eve = Date::from_ymd(year, 12, 24);
         let last_epiphany = easter.subtract_weeks(self.easter_cycle_begins);
         let fourth_advent = christmas_eve.sunday_before();
         let last_pentecost = fourth_advent
             .sunday_before()
             .subtract_weeks(self.christmas_cycle_begins);
-        if date >= last_pentecost || date < last_epiphany {
+        let christmas_cycle_continues = self
+            .christmas_cycle_ends
+            .map(|(month, day)| date.sunday_before() < Date::from_ymd(year, month, day))
+            .unwrap_or(false);
+        if date >= last_pentecost || date < last_epiphany || christmas_cycle_continues {
             self.christmas_cycle_week(date)
         } else {
             self.easter_cycle_week(date, easter)
@@ -579,7 +627,7 @@ impl Calendar {
         if date <= christmas_eve {
             let advent_4 = christmas_eve.sunday_before();
             let weeks_from_advent_4 = date.sunday_before() - advent_4;
-            let week = weeks_from_advent_4.num_weeks() + 4;
+            let week = weeks_from_advent_4.num_weeks() + self.christmas_cycle_begins as i64;
             LiturgicalWeekIndex {
                 cycle: Cycle::Advent,
                 week: week.try_into().unwrap(),
@@ -630,7 +678,7 @@ struct LiturgicalWeekIndex {
 
 #[cfg(test)]
 mod tests {
-    use crate::BCP1979_CALENDAR;
+    use crate::{BCP1979_CALENDAR, COMMON_WORSHIP_CALENDAR};
 
     use super::*;
 
@@ -723,4 +771,24 @@ mod tests {
         let day = BCP1979_CALENDAR.liturgical_day(date, false);
         assert!(day.holy_days.contains(&Feast::EmberDay));
     }
+
+    #[test]
+    fn ember_days_follow_ember_wednesday() {
+        // 9/14/2023 is a Thursday: Fall Ember Days are 9/20, 9/22, and 9/23
+        // 9/14/2024 is a Saturday: Fall Ember Days are 9/18, 9/20, and 9/21
+        // 12/13/2025 is a Saturday: Winter Ember Days are 12/17, 12/19, and 12/20
+        for (year, expected) in [
+            (2023, [(9, 20), (9, 22), (9, 23)]),
+            (2024, [(9, 18), (9, 20), (9, 21)]),
+            (2025, [(12, 17), (12, 19), (12, 20)]),
+        ] {
+            for calendar in [BCP1979_CALENDAR, COMMON_WORSHIP_CALENDAR] {
+                let ember_days = calendar.dates_of(Feast::EmberDay, year..=year);
+                assert_eq!(ember_days.len(), 12);
+                for (month, day) in expected {
+                    assert!(ember_days.contains(&Date::from_ymd(year, month, day)));
+                }
+            }
+        }
+    }
 }
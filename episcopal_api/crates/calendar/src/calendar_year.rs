@@ -0,0 +1,280 @@
+use std::{collections::HashMap, ops::RangeInclusive};
+
+use itertools::Itertools;
+
+use crate::{Calendar, Date, LiturgicalDay, LiturgicalDayId, Rank};
+
+/// An iterator over the [LiturgicalDay]s observed on each date in a range, as generated by [Calendar::liturgical_days].
+pub struct LiturgicalDays<'a> {
+    calendar: &'a Calendar<'a>,
+    next: Date,
+    end: Date,
+    evening: bool,
+    yesterday: Option<LiturgicalDay>,
+}
+
+impl<'a> Iterator for LiturgicalDays<'a> {
+    type Item = LiturgicalDay;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.next > self.end {
+            return None;
+        }
+        let date = self.next;
+        self.next = date.add_days(1);
+
+        // transfers depend on the day before, which we've already generated unless this is the first day
+        let calendar = self.calendar;
+        let yesterday = self.yesterday.take().unwrap_or_else(|| {
+            calendar.liturgical_day_without_transferred_feasts(date.subtract_days(1), false)
+        });
+        let morning = calendar.liturgical_day_without_transferred_feasts(date, false);
+        let day = if self.evening {
+            calendar.liturgical_day_without_transferred_feasts(date, true)
+        } else {
+            morning.clone()
+        };
+        let transferred = calendar.transferred_feast_after(&day, &yesterday);
+        self.yesterday = Some(morning);
+        Some(calendar.with_transferred_feast(day, transferred))
+    }
+}
+
+/// A precomputed table of the [LiturgicalDay] observed on each date in a calendar year,
+/// for use when many lookups will be made in the same year.
+#[derive(Clone, Debug)]
+pub struct CalendarYear {
+    year: u16,
+    days: Vec<LiturgicalDay>,
+    /// Everything observed or commemorated on each date: the day's observance, along with any
+    /// days of optional observance and Ember or Rogation Days
+    observances: Vec<(Date, LiturgicalDayId)>,
+}
+
+impl CalendarYear {
+    /// The year the table covers.
+    pub fn year(&self) -> u16 {
+        self.year
+    }
+
+    /// The (morning) [LiturgicalDay] for a date, if it falls within this year.
+    /// ```
+    /// # use calendar::{BCP1979_CALENDAR, Date, Feast, LiturgicalDayId};
+    /// let table = BCP1979_CALENDAR.calendar_year(2022);
+    /// let day = table.day(Date::from_ymd(2022, 12, 25)).unwrap();
+    /// assert_eq!(day.observed, LiturgicalDayId::Feast(Feast::ChristmasDay));
+    /// assert_eq!(day, &BCP1979_CALENDAR.liturgical_day(Date::from_ymd(2022, 12, 25), false));
+    /// assert!(table.day(Date::from_ymd(2023, 1, 1)).is_none());
+    /// ```
+    pub fn day(&self, date: Date) -> Option<&LiturgicalDay> {
+        if date.year() == self.year {
+            self.days.get(date.day_in_year() as usize)
+        } else {
+            None
+        }
+    }
+
+    /// Every (morning) [LiturgicalDay] in the year, in order.
+    pub fn days(&self) -> &[LiturgicalDay] {
+        &self.days
+    }
+
+    /// All the dates in the year on which the given observance falls; see [Calendar::dates_of].
+    /// ```
+    /// # use calendar::{BCP1979_CALENDAR, Date, Feast};
+    /// let table = BCP1979_CALENDAR.calendar_year(2025);
+    /// assert_eq!(table.dates_of(Feast::EmberDay).count(), 12);
+    /// assert_eq!(table.dates_of(Feast::RogationDay).collect::<Vec<_>>(), vec![
+    ///     Date::from_ymd(2025, 5, 26),
+    ///     Date::from_ymd(2025, 5, 27),
+    ///     Date::from_ymd(2025, 5, 28)
+    /// ]);
+    /// ```
+    pub fn dates_of(&self, id: impl Into<LiturgicalDayId>) -> impl Iterator<Item = Date> + '_ {
+        let id = id.into();
+        self.observances
+            .iter()
+            .filter(move |(_, observance)| is_observance_of(*observance, id))
+            .map(|(date, _)| *date)
+            .dedup()
+    }
+}
+
+/// The [CalendarYear] tables for a [Calendar], each generated the first time it is needed and
+/// then kept, so that any number of lookups can be made without generating the same days again.
+/// ```
+/// # use calendar::{BCP1979_CALENDAR, Date, Feast, LiturgicalDayId};
+/// let mut years = BCP1979_CALENDAR.calendar_years();
+/// let day = years.liturgical_day(Date::from_ymd(2022, 12, 25));
+/// assert_eq!(day.observed, LiturgicalDayId::Feast(Feast::ChristmasDay));
+/// // 2022 has already been generated, and 2023 is generated once for both lookups
+/// assert_eq!(years.dates_of(Feast::EmberDay, 2022..=2023).len(), 24);
+/// assert_eq!(years.dates_of(Feast::RogationDay, 2022..=2023).len(), 6);
+/// ```
+pub struct CalendarYears<'a> {
+    calendar: &'a Calendar<'a>,
+    years: HashMap<u16, CalendarYear>,
+}
+
+impl CalendarYears<'_> {
+    /// The table for the given year, generating it if it has not been generated yet.
+    pub fn year(&mut self, year: u16) -> &CalendarYear {
+        let calendar = self.calendar;
+        self.years
+            .entry(year)
+            .or_insert_with(|| calendar.calendar_year(year))
+    }
+
+    /// The (morning) [LiturgicalDay] for a date, as given by [Calendar::liturgical_day].
+    pub fn liturgical_day(&mut self, date: Date) -> &LiturgicalDay {
+        self.year(date.year())
+            .day(date)
+            .expect("a CalendarYear includes every date in its year")
+    }
+
+    /// All the dates in the given years on which an observance falls; see [Calendar::dates_of].
+    pub fn dates_of(
+        &mut self,
+        id: impl Into<LiturgicalDayId>,
+        years: RangeInclusive<u16>,
+    ) -> Vec<Date> {
+        let id = id.into();
+        years
+            .flat_map(|year| self.year(year).dates_of(id).collect::<Vec<_>>())
+            .collect()
+    }
+}
+
+impl Calendar<'_> {
+    /// The [LiturgicalDay]s observed on each date in a range, including any transferred feasts.
+    ///
+    /// This gives the same results as calling [Calendar::liturgical_day] for each date,
+    /// but only generates each day once, rather than again to check for transfers from the day before.
+    /// ```
+    /// # use calendar::{BCP1979_CALENDAR, Date, Feast, LiturgicalDayId};
+    /// let start = Date::from_ymd(2021, 12, 24);
+    /// let end = Date::from_ymd(2021, 12, 31);
+    /// let days = BCP1979_CALENDAR.liturgical_days(start..=end, false).collect::<Vec<_>>();
+    /// assert_eq!(days.len(), 8);
+    /// assert_eq!(days[3].observed, LiturgicalDayId::TransferredFeast(Feast::Stephen));
+    /// for day in days {
+    ///     assert_eq!(day, BCP1979_CALENDAR.liturgical_day(day.date, false));
+    /// }
+    /// ```
+    pub fn liturgical_days(
+        &self,
+        dates: RangeInclusive<Date>,
+        evening: bool,
+    ) -> LiturgicalDays<'_> {
+        let (start, end) = dates.into_inner();
+        LiturgicalDays {
+            calendar: self,
+            next: start,
+            end,
+            evening,
+            yesterday: None,
+        }
+    }
+
+    /// A set of [CalendarYear] tables for this calendar, generated as they are needed.
+    pub fn calendar_years(&self) -> CalendarYears<'_> {
+        CalendarYears {
+            calendar: self,
+            years: HashMap::new(),
+        }
+    }
+
+    /// Generates a [CalendarYear] table of every [LiturgicalDay] in the given year.
+    pub fn calendar_year(&self, year: u16) -> CalendarYear {
+        let days = self
+            .liturgical_days(
+                Date::from_ymd(year, 1, 1)..=Date::from_ymd(year, 12, 31),
+                false,
+            )
+            .collect::<Vec<_>>();
+        let observances = days
+            .iter()
+            .flat_map(|day| {
+                let date = day.date;
+                let commemorations = day
+                    .holy_days
+                    .iter()
+                    .filter(|feast| self.feast_day_rank(feast) < Rank::PrecedenceOverWeekday)
+                    .map(move |feast| (date, LiturgicalDayId::Feast(*feast)));
+                std::iter::once((date, day.observed)).chain(commemorations)
+            })
+            .collect();
+        CalendarYear {
+            year,
+            days,
+            observances,
+        }
+    }
+
+    /// All the dates in the given years on which a [Feast] or other [LiturgicalDayId] is observed.
+    ///
+    /// A [Feast] is found on the date to which it is transferred, if it is transferred,
+    /// and days of optional observance and Ember or Rogation Days are found even though they
+    /// do not displace the propers of the day. Alternate observances (like a holy day falling
+    /// on a Sunday after Pentecost, which may be used in place of the Sunday propers) are not included.
+    ///
+    /// Each call generates every day in the given years; to make several lookups,
+    /// hold on to the tables from [Calendar::calendar_years] instead.
+    /// ```
+    /// # use calendar::{BCP1979_CALENDAR, Date, Feast, LiturgicalDayId, Proper, Weekday};
+    /// // St. Luke falls on a Sunday in 2026, and so is transferred to Monday
+    /// assert_eq!(BCP1979_CALENDAR.dates_of(Feast::Luke, 2026..=2027), vec![
+    ///     Date::from_ymd(2026, 10, 19),
+    ///     Date::from_ymd(2027, 10, 18)
+    /// ]);
+    ///
+    /// let proper_12 = LiturgicalDayId::ProperAndDay(Proper::Proper12, Weekday::Sun);
+    /// assert_eq!(BCP1979_CALENDAR.dates_of(proper_12, 2022..=2023), vec![
+    ///     Date::from_ymd(2022, 7, 24),
+    ///     Date::from_ymd(2023, 7, 30)
+    /// ]);
+    /// ```
+    pub fn dates_of(
+        &self,
+        id: impl Into<LiturgicalDayId>,
+        years: RangeInclusive<u16>,
+    ) -> Vec<Date> {
+        self.calendar_years().dates_of(id, years)
+    }
+}
+
+// a feast matches wherever it is observed, including on the day to which it's been transferred
+fn is_observance_of(observance: LiturgicalDayId, id: LiturgicalDayId) -> bool {
+    match (id, observance) {
+        (LiturgicalDayId::Feast(feast), LiturgicalDayId::TransferredFeast(transferred)) => {
+            feast == transferred
+        }
+        _ => observance == id,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Date, BCP1979_CALENDAR, LFF2018_CALENDAR};
+
+    #[test]
+    fn range_matches_individual_days() {
+        for calendar in [BCP1979_CALENDAR, LFF2018_CALENDAR] {
+            for evening in [false, true] {
+                let start = Date::from_ymd(2020, 1, 1);
+                let end = Date::from_ymd(2024, 12, 31);
+                for day in calendar.liturgical_days(start..=end, evening) {
+                    assert_eq!(day, calendar.liturgical_day(day.date, evening));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn table_covers_whole_year() {
+        let table = BCP1979_CALENDAR.calendar_year(2024);
+        assert_eq!(table.days().len(), 366);
+        let feb_29 = Date::from_ymd(2024, 2, 29);
+        assert_eq!(table.day(feb_29).map(|day| day.date), Some(feb_29));
+    }
+}
@@ -0,0 +1,1428 @@
+use language::Language;
+use status::Status;
+
+use crate::{
+    bcp1979::{
+        BCP1979_HOLY_DAY_SEASONS, BCP1979_MAJOR_DAY_ALTERNATIVES, BCP1979_OCTAVES,
+        BCP1979_PROPER_NAMES,
+    },
+    feasts::KalendarEntry,
+    holy_day::HolyDayId,
+    liturgical_week::Cycle,
+    Calendar, Color, Computus, Feast, LiturgicalWeek, Rank, Season, Time, Weekday,
+};
+
+/// [Calendar](Calendar) that calculates dates from the liturgical calendar of *Common Worship*
+/// (Church of England), which is also followed with minor variations elsewhere in the Communion.
+///
+/// Unlike the 1979 BCP, the season of Epiphany runs until the Presentation (February 2),
+/// and is followed by the Sundays before Lent; the Sundays after Trinity are followed by
+/// the Kingdom season, made up of the Sundays before Advent and Christ the King.
+/// ```
+/// # use crate::calendar::{COMMON_WORSHIP_CALENDAR, Date, LiturgicalWeek, Season};
+/// # use language::Language;
+/// let day = COMMON_WORSHIP_CALENDAR.liturgical_day(Date::from_ymd(2022, 2, 6), false);
+/// assert_eq!(day.week, LiturgicalWeek::FourthBeforeLent);
+/// assert_eq!(COMMON_WORSHIP_CALENDAR.season(&day), Season::OrdinaryTime);
+///
+/// let day = COMMON_WORSHIP_CALENDAR.liturgical_day(Date::from_ymd(2022, 11, 13), false);
+/// assert_eq!(day.week, LiturgicalWeek::SecondBeforeAdvent);
+/// assert_eq!(COMMON_WORSHIP_CALENDAR.season(&day), Season::Kingdom);
+/// assert_eq!(COMMON_WORSHIP_CALENDAR.week_name(day.week, Language::En), Some("The Second Sunday before Advent"));
+/// ```
+pub const COMMON_WORSHIP_CALENDAR: Calendar = Calendar {
+    computus: Computus::Gregorian,
+    easter_cycle_begins: 11,
+    christmas_cycle_begins: 7,
+    christmas_cycle_ends: Some((2, 2)),
+    has_propers: true,
+    weeks: &COMMON_WORSHIP_WEEKS,
+    holy_days: &COMMON_WORSHIP_FEASTS,
+    holy_days_fallback: None,
+    holy_day_ranks: &COMMON_WORSHIP_HOLY_DAY_RANKS,
+    feast_seasons: &BCP1979_HOLY_DAY_SEASONS,
+    feast_colors: &COMMON_WORSHIP_FEAST_COLORS,
+    week_seasons: &COMMON_WORSHIP_WEEK_SEASONS,
+    feast_names: &COMMON_WORSHIP_FEAST_NAMES,
+    week_names: &COMMON_WORSHIP_WEEK_NAMES,
+    proper_names: &BCP1979_PROPER_NAMES,
+    major_day_alternatives: &BCP1979_MAJOR_DAY_ALTERNATIVES,
+    octaves: &BCP1979_OCTAVES,
+    local_feasts: &[],
+};
+
+/// Apostles, evangelists, and martyrs, whose feasts are kept in red
+pub(crate) const COMMON_WORSHIP_FEAST_COLORS: [(Feast, Color); 16] = [
+    (Feast::Stephen, Color::Red),
+    (Feast::HolyInnocents, Color::Red),
+    (Feast::HolyCross, Color::Red),
+    (Feast::Andrew, Color::Red),
+    (Feast::Barnabas, Color::Red),
+    (Feast::Bartholomew, Color::Red),
+    (Feast::James, Color::Red),
+    (Feast::Luke, Color::Red),
+    (Feast::Mark, Color::Red),
+    (Feast::Matthew, Color::Red),
+    (Feast::Matthias, Color::Red),
+    (Feast::PeterAndPaul, Color::Red),
+    (Feast::PhilipAndJames, Color::Red),
+    (Feast::SimonAndJude, Color::Red),
+    (Feast::Thomas, Color::Red),
+    (Feast::George, Color::Red),
+];
+
+// The Christmas cycle begins with the Fourth Sunday before Advent, and the Easter cycle
+// with the Fifth Sunday before Lent (unless it falls before the Presentation)
+pub(crate) const COMMON_WORSHIP_WEEKS: [(Cycle, u8, LiturgicalWeek); 62] = [
+    (Cycle::Advent, 0, LiturgicalWeek::FourthBeforeAdvent),
+    (Cycle::Advent, 1, LiturgicalWeek::ThirdBeforeAdvent),
+    (Cycle::Advent, 2, LiturgicalWeek::SecondBeforeAdvent),
+    (Cycle::Advent, 3, LiturgicalWeek::LastPentecost),
+    (Cycle::Advent, 4, LiturgicalWeek::Advent1),
+    (Cycle::Advent, 5, LiturgicalWeek::Advent2),
+    (Cycle::Advent, 6, LiturgicalWeek::Advent3),
+    (Cycle::Advent, 7, LiturgicalWeek::Advent4),
+    (Cycle::Christmas, 0, LiturgicalWeek::Christmas),
+    (Cycle::Christmas, 1, LiturgicalWeek::Christmas1),
+    (Cycle::Christmas, 2, LiturgicalWeek::Christmas2),
+    (Cycle::Epiphany, 0, LiturgicalWeek::Epiphany),
+    (Cycle::Epiphany, 1, LiturgicalWeek::Epiphany1),
+    (Cycle::Epiphany, 2, LiturgicalWeek::Epiphany2),
+    (Cycle::Epiphany, 3, LiturgicalWeek::Epiphany3),
+    (Cycle::Epiphany, 4, LiturgicalWeek::Epiphany4),
+    (Cycle::Easter, 0, LiturgicalWeek::FifthBeforeLent),
+    (Cycle::Easter, 1, LiturgicalWeek::FourthBeforeLent),
+    (Cycle::Easter, 2, LiturgicalWeek::ThirdBeforeLent),
+    (Cycle::Easter, 3, LiturgicalWeek::SecondBeforeLent),
+    (Cycle::Easter, 4, LiturgicalWeek::LastEpiphany),
+    (Cycle::Easter, 5, LiturgicalWeek::Lent1),
+    (Cycle::Easter, 6, LiturgicalWeek::Lent2),
+    (Cycle::Easter, 7, LiturgicalWeek::Lent3),
+    (Cycle::Easter, 8, LiturgicalWeek::Lent4),
+    (Cycle::Easter, 9, LiturgicalWeek::Lent5),
+    (Cycle::Easter, 10, LiturgicalWeek::HolyWeek),
+    (Cycle::Easter, 11, LiturgicalWeek::Easter),
+    (Cycle::Easter, 12, LiturgicalWeek::Easter2),
+    (Cycle::Easter, 13, LiturgicalWeek::Easter3),
+    (Cycle::Easter, 14, LiturgicalWeek::Easter4),
+    (Cycle::Easter, 15, LiturgicalWeek::Easter5),
+    (Cycle::Easter, 16, LiturgicalWeek::Easter6),
+    (Cycle::Easter, 17, LiturgicalWeek::Easter7),
+    (Cycle::Easter, 18, LiturgicalWeek::Pentecost),
+    (Cycle::Easter, 19, LiturgicalWeek::TrinitySunday),
+    (Cycle::Easter, 20, LiturgicalWeek::Pentecost2),
+    (Cycle::Easter, 21, LiturgicalWeek::Pentecost3),
+    (Cycle::Easter, 22, LiturgicalWeek::Pentecost4),
+    (Cycle::Easter, 23, LiturgicalWeek::Pentecost5),
+    (Cycle::Easter, 24, LiturgicalWeek::Pentecost6),
+    (Cycle::Easter, 25, LiturgicalWeek::Pentecost7),
+    (Cycle::Easter, 26, LiturgicalWeek::Pentecost8),
+    (Cycle::Easter, 27, LiturgicalWeek::Pentecost9),
+    (Cycle::Easter, 28, LiturgicalWeek::Pentecost10),
+    (Cycle::Easter, 29, LiturgicalWeek::Pentecost11),
+    (Cycle::Easter, 30, LiturgicalWeek::Pentecost12),
+    (Cycle::Easter, 31, LiturgicalWeek::Pentecost13),
+    (Cycle::Easter, 32, LiturgicalWeek::Pentecost14),
+    (Cycle::Easter, 33, LiturgicalWeek::Pentecost15),
+    (Cycle::Easter, 34, LiturgicalWeek::Pentecost16),
+    (Cycle::Easter, 35, LiturgicalWeek::Pentecost17),
+    (Cycle::Easter, 36, LiturgicalWeek::Pentecost18),
+    (Cycle::Easter, 37, LiturgicalWeek::Pentecost19),
+    (Cycle::Easter, 38, LiturgicalWeek::Pentecost20),
+    (Cycle::Easter, 39, LiturgicalWeek::Pentecost21),
+    (Cycle::Easter, 40, LiturgicalWeek::Pentecost22),
+    (Cycle::Easter, 41, LiturgicalWeek::Pentecost23),
+    (Cycle::Easter, 42, LiturgicalWeek::Pentecost24),
+    (Cycle::Easter, 43, LiturgicalWeek::Pentecost25),
+    (Cycle::Easter, 44, LiturgicalWeek::Pentecost26),
+    (Cycle::Easter, 45, LiturgicalWeek::Pentecost27),
+];
+
+pub(crate) const COMMON_WORSHIP_WEEK_SEASONS: [(LiturgicalWeek, Season); 31] = [
+    (LiturgicalWeek::Advent1, Season::Advent),
+    (LiturgicalWeek::Advent2, Season::Advent),
+    (LiturgicalWeek::Advent3, Season::Advent),
+    (LiturgicalWeek::Advent4, Season::Advent),
+    (LiturgicalWeek::Christmas, Season::Christmas),
+    (LiturgicalWeek::Christmas1, Season::Christmas),
+    (LiturgicalWeek::Christmas2, Season::Christmas),
+    (LiturgicalWeek::Epiphany, Season::Epiphany),
+    (LiturgicalWeek::Epiphany1, Season::Epiphany),
+    (LiturgicalWeek::Epiphany2, Season::Epiphany),
+    (LiturgicalWeek::Epiphany3, Season::Epiphany),
+    (LiturgicalWeek::Epiphany4, Season::Epiphany),
+    (LiturgicalWeek::Lent1, Season::Lent),
+    (LiturgicalWeek::Lent2, Season::Lent),
+    (LiturgicalWeek::Lent3, Season::Lent),
+    (LiturgicalWeek::Lent4, Season::Lent),
+    (LiturgicalWeek::Lent5, Season::Lent),
+    (LiturgicalWeek::HolyWeek, Season::HolyWeek),
+    (LiturgicalWeek::Easter, Season::Easter),
+    (LiturgicalWeek::Easter2, Season::Easter),
+    (LiturgicalWeek::Easter3, Season::Easter),
+    (LiturgicalWeek::Easter4, Season::Easter),
+    (LiturgicalWeek::Easter5, Season::Easter),
+    (LiturgicalWeek::Easter6, Season::Easter),
+    (LiturgicalWeek::Easter7, Season::Ascension),
+    (LiturgicalWeek::Pentecost, Season::Pentecost),
+    (LiturgicalWeek::TrinitySunday, Season::Trinity),
+    (LiturgicalWeek::FourthBeforeAdvent, Season::Kingdom),
+    (LiturgicalWeek::ThirdBeforeAdvent, Season::Kingdom),
+    (LiturgicalWeek::SecondBeforeAdvent, Season::Kingdom),
+    (LiturgicalWeek::LastPentecost, Season::Kingdom),
+    // Algorithm defaults to Season::OrdinaryTime, so can ignore the Sundays before Lent and after Trinity
+];
+
+pub(crate) const COMMON_WORSHIP_HOLY_DAY_RANKS: [(Feast, Rank); 65] = [
+    (Feast::ChristmasEve, Rank::PrincipalFeast),
+    (Feast::ChristmasDay, Rank::PrincipalFeast),
+    (Feast::EveOfEpiphany, Rank::PrincipalFeast),
+    (Feast::Epiphany, Rank::PrincipalFeast),
+    (Feast::EveOfThePresentation, Rank::PrincipalFeast),
+    (Feast::ThePresentation, Rank::PrincipalFeast),
+    (Feast::PalmSunday, Rank::PrincipalFeast),
+    (Feast::EasterSunday, Rank::PrincipalFeast),
+    (Feast::EasterVigil, Rank::PrincipalFeast),
+    (Feast::EveOfTheAscension, Rank::PrincipalFeast),
+    (Feast::AscensionDay, Rank::PrincipalFeast),
+    (Feast::EveOfPentecost, Rank::PrincipalFeast),
+    (Feast::Pentecost, Rank::PrincipalFeast),
+    (Feast::PentecostVigil, Rank::PrincipalFeast),
+    (Feast::TrinitySunday, Rank::PrincipalFeast),
+    (Feast::EveOfAllSaints, Rank::PrincipalFeast),
+    (Feast::AllSaintsDay, Rank::PrincipalFeast),
+    (Feast::AshWednesday, Rank::PrecedenceOverHolyDay),
+    (Feast::MaundyThursday, Rank::PrecedenceOverHolyDay),
+    (Feast::GoodFriday, Rank::PrecedenceOverHolyDay),
+    (Feast::MondayInHolyWeek, Rank::PrecedenceOverHolyDay),
+    (Feast::TuesdayInHolyWeek, Rank::PrecedenceOverHolyDay),
+    (Feast::WednesdayInHolyWeek, Rank::PrecedenceOverHolyDay),
+    (Feast::HolySaturday, Rank::PrecedenceOverHolyDay),
+    (Feast::MondayInEasterWeek, Rank::PrecedenceOverHolyDay),
+    (Feast::TuesdayInEasterWeek, Rank::PrecedenceOverHolyDay),
+    (Feast::WednesdayInEasterWeek, Rank::PrecedenceOverHolyDay),
+    (Feast::ThursdayInEasterWeek, Rank::PrecedenceOverHolyDay),
+    (Feast::FridayInEasterWeek, Rank::PrecedenceOverHolyDay),
+    (Feast::SaturdayInEasterWeek, Rank::PrecedenceOverHolyDay),
+    (
+        Feast::ThursdayAfterAshWednesday,
+        Rank::PrecedenceOverWeekday,
+    ),
+    (Feast::FridayAfterAshWednesday, Rank::PrecedenceOverWeekday),
+    (
+        Feast::SaturdayAfterAshWednesday,
+        Rank::PrecedenceOverWeekday,
+    ),
+    (Feast::EveOfTrinitySunday, Rank::HolyDay),
+    (Feast::HolyName, Rank::HolyDay),
+    (Feast::ConversionOfStPaul, Rank::HolyDay),
+    (Feast::Joseph, Rank::HolyDay),
+    (Feast::Annunciation, Rank::HolyDay),
+    (Feast::George, Rank::HolyDay),
+    (Feast::Mark, Rank::HolyDay),
+    (Feast::PhilipAndJames, Rank::HolyDay),
+    (Feast::Matthias, Rank::HolyDay),
+    (Feast::TheVisitation, Rank::HolyDay),
+    (Feast::Barnabas, Rank::HolyDay),
+    (Feast::NativityOfStJohnTheBaptist, Rank::HolyDay),
+    (Feast::PeterAndPaul, Rank::HolyDay),
+    (Feast::Thomas, Rank::HolyDay),
+    (Feast::MaryMagdalene, Rank::HolyDay),
+    (Feast::James, Rank::HolyDay),
+    (Feast::TheTransfiguration, Rank::HolyDay),
+    (Feast::Mary, Rank::HolyDay),
+    (Feast::Bartholomew, Rank::HolyDay),
+    (Feast::HolyCross, Rank::HolyDay),
+    (Feast::Matthew, Rank::HolyDay),
+    (Feast::Michael, Rank::HolyDay),
+    (Feast::Luke, Rank::HolyDay),
+    (Feast::SimonAndJude, Rank::HolyDay),
+    (Feast::Andrew, Rank::HolyDay),
+    (Feast::Stephen, Rank::HolyDay),
+    (Feast::John, Rank::HolyDay),
+    (Feast::HolyInnocents, Rank::HolyDay),
+    (Feast::ThanksgivingForHolyCommunion, Rank::HolyDay),
+    (Feast::AllSoulsDay, Rank::OptionalObservance),
+    (Feast::EmberDay, Rank::EmberDay),
+    (Feast::RogationDay, Rank::EmberDay),
+];
+
+// Principal Feasts, Principal Holy Days, and Festivals
+pub const COMMON_WORSHIP_FEASTS: [KalendarEntry; 76] = [
+    // Rogation Days
+    (
+        HolyDayId::SpecialDay(LiturgicalWeek::Easter6, Weekday::Mon),
+        Feast::RogationDay,
+        Time::AllDay,
+        None,
+    ),
+    (
+        HolyDayId::SpecialDay(LiturgicalWeek::Easter6, Weekday::Tue),
+        Feast::RogationDay,
+        Time::AllDay,
+        None,
+    ),
+    (
+        HolyDayId::SpecialDay(LiturgicalWeek::Easter6, Weekday::Wed),
+        Feast::RogationDay,
+        Time::AllDay,
+        None,
+    ),
+    // Ember Days
+    (
+        HolyDayId::SpecialDay(LiturgicalWeek::Lent1, Weekday::Wed),
+        Feast::EmberDay,
+        Time::AllDay,
+        None,
+    ),
+    (
+        HolyDayId::SpecialDay(LiturgicalWeek::Lent1, Weekday::Fri),
+        Feast::EmberDay,
+        Time::AllDay,
+        None,
+    ),
+    (
+        HolyDayId::SpecialDay(LiturgicalWeek::Lent1, Weekday::Sat),
+        Feast::EmberDay,
+        Time::AllDay,
+        None,
+    ),
+    (
+        HolyDayId::SpecialDay(LiturgicalWeek::Pentecost, Weekday::Wed),
+        Feast::EmberDay,
+        Time::AllDay,
+        None,
+    ),
+    (
+        HolyDayId::SpecialDay(LiturgicalWeek::Pentecost, Weekday::Fri),
+        Feast::EmberDay,
+        Time::AllDay,
+        None,
+    ),
+    (
+        HolyDayId::SpecialDay(LiturgicalWeek::Pentecost, Weekday::Sat),
+        Feast::EmberDay,
+        Time::AllDay,
+        None,
+    ),
+    (
+        HolyDayId::WeekdayAfterDate {
+            month: 9,
+            day: 14,
+            starting_weekday: Some(Weekday::Wed),
+            weekday: Weekday::Wed,
+        },
+        Feast::EmberDay,
+        Time::AllDay,
+        None,
+    ),
+    (
+        HolyDayId::WeekdayAfterDate {
+            month: 9,
+            day: 14,
+            starting_weekday: Some(Weekday::Wed),
+            weekday: Weekday::Fri,
+        },
+        Feast::EmberDay,
+        Time::AllDay,
+        None,
+    ),
+    (
+        HolyDayId::WeekdayAfterDate {
+            month: 9,
+            day: 14,
+            starting_weekday: Some(Weekday::Wed),
+            weekday: Weekday::Sat,
+        },
+        Feast::EmberDay,
+        Time::AllDay,
+        None,
+    ),
+    (
+        HolyDayId::WeekdayAfterDate {
+            month: 12,
+            day: 13,
+            starting_weekday: Some(Weekday::Wed),
+            weekday: Weekday::Wed,
+        },
+        Feast::EmberDay,
+        Time::AllDay,
+        None,
+    ),
+    (
+        HolyDayId::WeekdayAfterDate {
+            month: 12,
+            day: 13,
+            starting_weekday: Some(Weekday::Wed),
+            weekday: Weekday::Fri,
+        },
+        Feast::EmberDay,
+        Time::AllDay,
+        None,
+    ),
+    (
+        HolyDayId::WeekdayAfterDate {
+            month: 12,
+            day: 13,
+            starting_weekday: Some(Weekday::Wed),
+            weekday: Weekday::Sat,
+        },
+        Feast::EmberDay,
+        Time::AllDay,
+        None,
+    ),
+    // Special days
+    (
+        HolyDayId::SpecialDay(LiturgicalWeek::LastEpiphany, Weekday::Wed),
+        Feast::AshWednesday,
+        Time::AllDay,
+        None,
+    ),
+    (
+        HolyDayId::SpecialDay(LiturgicalWeek::LastEpiphany, Weekday::Thu),
+        Feast::ThursdayAfterAshWednesday,
+        Time::AllDay,
+        None,
+    ),
+    (
+        HolyDayId::SpecialDay(LiturgicalWeek::LastEpiphany, Weekday::Fri),
+        Feast::FridayAfterAshWednesday,
+        Time::AllDay,
+        None,
+    ),
+    (
+        HolyDayId::SpecialDay(LiturgicalWeek::LastEpiphany, Weekday::Sat),
+        Feast::SaturdayAfterAshWednesday,
+        Time::AllDay,
+        None,
+    ),
+    (
+        HolyDayId::SpecialDay(LiturgicalWeek::HolyWeek, Weekday::Sun),
+        Feast::PalmSunday,
+        Time::AllDay,
+        None,
+    ),
+    (
+        HolyDayId::SpecialDay(LiturgicalWeek::HolyWeek, Weekday::Mon),
+        Feast::MondayInHolyWeek,
+        Time::AllDay,
+        None,
+    ),
+    (
+        HolyDayId::SpecialDay(LiturgicalWeek::HolyWeek, Weekday::Tue),
+        Feast::TuesdayInHolyWeek,
+        Time::AllDay,
+        None,
+    ),
+    (
+        HolyDayId::SpecialDay(LiturgicalWeek::HolyWeek, Weekday::Wed),
+        Feast::WednesdayInHolyWeek,
+        Time::AllDay,
+        None,
+    ),
+    (
+        HolyDayId::SpecialDay(LiturgicalWeek::HolyWeek, Weekday::Thu),
+        Feast::MaundyThursday,
+        Time::AllDay,
+        None,
+    ),
+    (
+        HolyDayId::SpecialDay(LiturgicalWeek::HolyWeek, Weekday::Fri),
+        Feast::GoodFriday,
+        Time::AllDay,
+        None,
+    ),
+    (
+        HolyDayId::SpecialDay(LiturgicalWeek::HolyWeek, Weekday::Sat),
+        Feast::HolySaturday,
+        Time::AllDay,
+        None,
+    ),
+    (
+        HolyDayId::SpecialDay(LiturgicalWeek::Easter, Weekday::Sun),
+        Feast::EasterSunday,
+        Time::AllDay,
+        None,
+    ),
+    (
+        HolyDayId::SpecialDay(LiturgicalWeek::Easter, Weekday::Mon),
+        Feast::MondayInEasterWeek,
+        Time::AllDay,
+        None,
+    ),
+    (
+        HolyDayId::SpecialDay(LiturgicalWeek::Easter, Weekday::Tue),
+        Feast::TuesdayInEasterWeek,
+        Time::AllDay,
+        None,
+    ),
+    (
+        HolyDayId::SpecialDay(LiturgicalWeek::Easter, Weekday::Wed),
+        Feast::WednesdayInEasterWeek,
+        Time::AllDay,
+        None,
+    ),
+    (
+        HolyDayId::SpecialDay(LiturgicalWeek::Easter, Weekday::Thu),
+        Feast::ThursdayInEasterWeek,
+        Time::AllDay,
+        None,
+    ),
+    (
+        HolyDayId::SpecialDay(LiturgicalWeek::Easter, Weekday::Fri),
+        Feast::FridayInEasterWeek,
+        Time::AllDay,
+        None,
+    ),
+    (
+        HolyDayId::SpecialDay(LiturgicalWeek::Easter, Weekday::Sat),
+        Feast::SaturdayInEasterWeek,
+        Time::AllDay,
+        None,
+    ),
+    (
+        HolyDayId::SpecialDay(LiturgicalWeek::Easter6, Weekday::Wed),
+        Feast::EveOfTheAscension,
+        Time::EveningOnly(Some(Feast::AscensionDay)),
+        None,
+    ),
+    (
+        HolyDayId::SpecialDay(LiturgicalWeek::Easter6, Weekday::Thu),
+        Feast::AscensionDay,
+        Time::AllDay,
+        None,
+    ),
+    (
+        HolyDayId::SpecialDay(LiturgicalWeek::Easter7, Weekday::Sat),
+        Feast::EveOfPentecost,
+        Time::EveningOnly(Some(Feast::Pentecost)),
+        None,
+    ),
+    (
+        HolyDayId::SpecialDay(LiturgicalWeek::Pentecost, Weekday::Sun),
+        Feast::Pentecost,
+        Time::AllDay,
+        None,
+    ),
+    (
+        HolyDayId::SpecialDay(LiturgicalWeek::Pentecost, Weekday::Sat),
+        Feast::EveOfTrinitySunday,
+        Time::EveningOnly(Some(Feast::TrinitySunday)),
+        None,
+    ),
+    (
+        HolyDayId::SpecialDay(LiturgicalWeek::TrinitySunday, Weekday::Sun),
+        Feast::TrinitySunday,
+        Time::AllDay,
+        None,
+    ),
+    (
+        HolyDayId::SpecialDay(LiturgicalWeek::TrinitySunday, Weekday::Thu),
+        Feast::ThanksgivingForHolyCommunion,
+        Time::AllDay,
+        None,
+    ),
+    // MM/DD feast days
+    (HolyDayId::Date(1, 1), Feast::HolyName, Time::AllDay, None),
+    (
+        HolyDayId::Date(1, 5),
+        Feast::EveOfEpiphany,
+        Time::EveningOnly(Some(Feast::Epiphany)),
+        None,
+    ),
+    (HolyDayId::Date(1, 6), Feast::Epiphany, Time::AllDay, None),
+    (
+        HolyDayId::Date(1, 25),
+        Feast::ConversionOfStPaul,
+        Time::AllDay,
+        None,
+    ),
+    (
+        HolyDayId::Date(2, 1),
+        Feast::EveOfThePresentation,
+        Time::EveningOnly(Some(Feast::ThePresentation)),
+        None,
+    ),
+    (
+        HolyDayId::Date(2, 2),
+        Feast::ThePresentation,
+        Time::AllDay,
+        None,
+    ),
+    (HolyDayId::Date(3, 19), Feast::Joseph, Time::AllDay, None),
+    (
+        HolyDayId::Date(3, 25),
+        Feast::Annunciation,
+        Time::AllDay,
+        None,
+    ),
+    (HolyDayId::Date(4, 23), Feast::George, Time::AllDay, None),
+    (HolyDayId::Date(4, 25), Feast::Mark, Time::AllDay, None),
+    (
+        HolyDayId::Date(5, 1),
+        Feast::PhilipAndJames,
+        Time::AllDay,
+        None,
+    ),
+    (HolyDayId::Date(5, 14), Feast::Matthias, Time::AllDay, None),
+    (
+        HolyDayId::Date(5, 31),
+        Feast::TheVisitation,
+        Time::AllDay,
+        None,
+    ),
+    (HolyDayId::Date(6, 11), Feast::Barnabas, Time::AllDay, None),
+    (
+        HolyDayId::Date(6, 24),
+        Feast::NativityOfStJohnTheBaptist,
+        Time::AllDay,
+        None,
+    ),
+    (
+        HolyDayId::Date(6, 29),
+        Feast::PeterAndPaul,
+        Time::AllDay,
+        None,
+    ),
+    (HolyDayId::Date(7, 3), Feast::Thomas, Time::AllDay, None),
+    (
+        HolyDayId::Date(7, 22),
+        Feast::MaryMagdalene,
+        Time::AllDay,
+        None,
+    ),
+    (HolyDayId::Date(7, 25), Feast::James, Time::AllDay, None),
+    (
+        HolyDayId::Date(8, 6),
+        Feast::TheTransfiguration,
+        Time::AllDay,
+        None,
+    ),
+    (HolyDayId::Date(8, 15), Feast::Mary, Time::AllDay, None),
+    (
+        HolyDayId::Date(8, 24),
+        Feast::Bartholomew,
+        Time::AllDay,
+        None,
+    ),
+    (HolyDayId::Date(9, 14), Feast::HolyCross, Time::AllDay, None),
+    (HolyDayId::Date(9, 21), Feast::Matthew, Time::AllDay, None),
+    (HolyDayId::Date(9, 29), Feast::Michael, Time::AllDay, None),
+    (HolyDayId::Date(10, 18), Feast::Luke, Time::AllDay, None),
+    (
+        HolyDayId::Date(10, 28),
+        Feast::SimonAndJude,
+        Time::AllDay,
+        None,
+    ),
+    (
+        HolyDayId::Date(10, 31),
+        Feast::EveOfAllSaints,
+        Time::EveningOnly(Some(Feast::AllSaintsDay)),
+        None,
+    ),
+    (
+        HolyDayId::Date(11, 1),
+        Feast::AllSaintsDay,
+        Time::AllDay,
+        None,
+    ),
+    (
+        HolyDayId::Date(11, 2),
+        Feast::AllSoulsDay,
+        Time::AllDay,
+        None,
+    ),
+    (HolyDayId::Date(11, 30), Feast::Andrew, Time::AllDay, None),
+    (
+        HolyDayId::Date(12, 24),
+        Feast::ChristmasEve,
+        Time::EveningOnly(Some(Feast::ChristmasDay)),
+        None,
+    ),
+    (
+        HolyDayId::Date(12, 25),
+        Feast::ChristmasDay,
+        Time::AllDay,
+        None,
+    ),
+    (HolyDayId::Date(12, 26), Feast::Stephen, Time::AllDay, None),
+    (HolyDayId::Date(12, 27), Feast::John, Time::AllDay, None),
+    (
+        HolyDayId::Date(12, 28),
+        Feast::HolyInnocents,
+        Time::AllDay,
+        None,
+    ),
+];
+
+pub(crate) const COMMON_WORSHIP_FEAST_NAMES: [(Feast, Language, &str, Status); 68] = [
+    (
+        Feast::ChristmasEve,
+        Language::En,
+        "Christmas Eve",
+        Status::Authorized,
+    ),
+    (
+        Feast::ChristmasDay,
+        Language::En,
+        "Christmas Day",
+        Status::Authorized,
+    ),
+    (
+        Feast::ChristmasDayII,
+        Language::En,
+        "Christmas Day (Set II)",
+        Status::Authorized,
+    ),
+    (
+        Feast::ChristmasDayIII,
+        Language::En,
+        "Christmas Day (Set III)",
+        Status::Authorized,
+    ),
+    (
+        Feast::Stephen,
+        Language::En,
+        "Stephen, Deacon, First Martyr",
+        Status::Authorized,
+    ),
+    (
+        Feast::John,
+        Language::En,
+        "John, Apostle and Evangelist",
+        Status::Authorized,
+    ),
+    (
+        Feast::HolyInnocents,
+        Language::En,
+        "The Holy Innocents",
+        Status::Authorized,
+    ),
+    (
+        Feast::HolyName,
+        Language::En,
+        "The Naming and Circumcision of Jesus",
+        Status::Authorized,
+    ),
+    (
+        Feast::EveOfEpiphany,
+        Language::En,
+        "Eve of the Epiphany",
+        Status::Authorized,
+    ),
+    (
+        Feast::Epiphany,
+        Language::En,
+        "The Epiphany",
+        Status::Authorized,
+    ),
+    (
+        Feast::ConversionOfStPaul,
+        Language::En,
+        "The Conversion of Paul",
+        Status::Authorized,
+    ),
+    (
+        Feast::EveOfThePresentation,
+        Language::En,
+        "Eve of the Presentation",
+        Status::Authorized,
+    ),
+    (
+        Feast::ThePresentation,
+        Language::En,
+        "The Presentation of Christ in the Temple (Candlemas)",
+        Status::Authorized,
+    ),
+    (
+        Feast::Joseph,
+        Language::En,
+        "Joseph of Nazareth",
+        Status::Authorized,
+    ),
+    (
+        Feast::Annunciation,
+        Language::En,
+        "The Annunciation of Our Lord to the Blessed Virgin Mary",
+        Status::Authorized,
+    ),
+    (
+        Feast::George,
+        Language::En,
+        "George, Martyr, Patron of England, c. 304",
+        Status::Authorized,
+    ),
+    (
+        Feast::Mark,
+        Language::En,
+        "Mark the Evangelist",
+        Status::Authorized,
+    ),
+    (
+        Feast::PhilipAndJames,
+        Language::En,
+        "Philip and James, Apostles",
+        Status::Authorized,
+    ),
+    (
+        Feast::Matthias,
+        Language::En,
+        "Matthias the Apostle",
+        Status::Authorized,
+    ),
+    (
+        Feast::TheVisitation,
+        Language::En,
+        "The Visit of the Blessed Virgin Mary to Elizabeth",
+        Status::Authorized,
+    ),
+    (
+        Feast::Barnabas,
+        Language::En,
+        "Barnabas the Apostle",
+        Status::Authorized,
+    ),
+    (
+        Feast::NativityOfStJohnTheBaptist,
+        Language::En,
+        "The Birth of John the Baptist",
+        Status::Authorized,
+    ),
+    (
+        Feast::PeterAndPaul,
+        Language::En,
+        "Peter and Paul, Apostles",
+        Status::Authorized,
+    ),
+    (
+        Feast::Thomas,
+        Language::En,
+        "Thomas the Apostle",
+        Status::Authorized,
+    ),
+    (
+        Feast::MaryMagdalene,
+        Language::En,
+        "Mary Magdalene",
+        Status::Authorized,
+    ),
+    (
+        Feast::James,
+        Language::En,
+        "James the Apostle",
+        Status::Authorized,
+    ),
+    (
+        Feast::TheTransfiguration,
+        Language::En,
+        "The Transfiguration of Our Lord",
+        Status::Authorized,
+    ),
+    (
+        Feast::Mary,
+        Language::En,
+        "The Blessed Virgin Mary",
+        Status::Authorized,
+    ),
+    (
+        Feast::Bartholomew,
+        Language::En,
+        "Bartholomew the Apostle",
+        Status::Authorized,
+    ),
+    (
+        Feast::HolyCross,
+        Language::En,
+        "Holy Cross Day",
+        Status::Authorized,
+    ),
+    (
+        Feast::Matthew,
+        Language::En,
+        "Matthew, Apostle and Evangelist",
+        Status::Authorized,
+    ),
+    (
+        Feast::Michael,
+        Language::En,
+        "Michael and All Angels",
+        Status::Authorized,
+    ),
+    (
+        Feast::Luke,
+        Language::En,
+        "Luke the Evangelist",
+        Status::Authorized,
+    ),
+    (
+        Feast::SimonAndJude,
+        Language::En,
+        "Simon and Jude, Apostles",
+        Status::Authorized,
+    ),
+    (
+        Feast::EveOfAllSaints,
+        Language::En,
+        "Eve of All Saints",
+        Status::Authorized,
+    ),
+    (
+        Feast::AllSaintsDay,
+        Language::En,
+        "All Saints’ Day",
+        Status::Authorized,
+    ),
+    (
+        Feast::AllSoulsDay,
+        Language::En,
+        "Commemoration of the Faithful Departed (All Souls’ Day)",
+        Status::Authorized,
+    ),
+    (
+        Feast::Andrew,
+        Language::En,
+        "Andrew the Apostle",
+        Status::Authorized,
+    ),
+    (
+        Feast::AshWednesday,
+        Language::En,
+        "Ash Wednesday",
+        Status::Authorized,
+    ),
+    (
+        Feast::ThursdayAfterAshWednesday,
+        Language::En,
+        "Thursday after Ash Wednesday",
+        Status::Authorized,
+    ),
+    (
+        Feast::FridayAfterAshWednesday,
+        Language::En,
+        "Friday after Ash Wednesday",
+        Status::Authorized,
+    ),
+    (
+        Feast::SaturdayAfterAshWednesday,
+        Language::En,
+        "Saturday after Ash Wednesday",
+        Status::Authorized,
+    ),
+    (
+        Feast::PalmSunday,
+        Language::En,
+        "Palm Sunday",
+        Status::Authorized,
+    ),
+    (
+        Feast::MondayInHolyWeek,
+        Language::En,
+        "Monday of Holy Week",
+        Status::Authorized,
+    ),
+    (
+        Feast::TuesdayInHolyWeek,
+        Language::En,
+        "Tuesday of Holy Week",
+        Status::Authorized,
+    ),
+    (
+        Feast::WednesdayInHolyWeek,
+        Language::En,
+        "Wednesday of Holy Week",
+        Status::Authorized,
+    ),
+    (
+        Feast::MaundyThursday,
+        Language::En,
+        "Maundy Thursday",
+        Status::Authorized,
+    ),
+    (
+        Feast::GoodFriday,
+        Language::En,
+        "Good Friday",
+        Status::Authorized,
+    ),
+    (
+        Feast::HolySaturday,
+        Language::En,
+        "Easter Eve",
+        Status::Authorized,
+    ),
+    (
+        Feast::EasterVigil,
+        Language::En,
+        "Easter Vigil",
+        Status::Authorized,
+    ),
+    (
+        Feast::EasterSunday,
+        Language::En,
+        "Easter Day",
+        Status::Authorized,
+    ),
+    (
+        Feast::EasterDayEveningService,
+        Language::En,
+        "Easter Day Evening Service",
+        Status::Authorized,
+    ),
+    (
+        Feast::MondayInEasterWeek,
+        Language::En,
+        "Monday of Easter Week",
+        Status::Authorized,
+    ),
+    (
+        Feast::TuesdayInEasterWeek,
+        Language::En,
+        "Tuesday of Easter Week",
+        Status::Authorized,
+    ),
+    (
+        Feast::WednesdayInEasterWeek,
+        Language::En,
+        "Wednesday of Easter Week",
+        Status::Authorized,
+    ),
+    (
+        Feast::ThursdayInEasterWeek,
+        Language::En,
+        "Thursday of Easter Week",
+        Status::Authorized,
+    ),
+    (
+        Feast::FridayInEasterWeek,
+        Language::En,
+        "Friday of Easter Week",
+        Status::Authorized,
+    ),
+    (
+        Feast::SaturdayInEasterWeek,
+        Language::En,
+        "Saturday of Easter Week",
+        Status::Authorized,
+    ),
+    (
+        Feast::EveOfTheAscension,
+        Language::En,
+        "Eve of Ascension Day",
+        Status::Authorized,
+    ),
+    (
+        Feast::AscensionDay,
+        Language::En,
+        "Ascension Day",
+        Status::Authorized,
+    ),
+    (
+        Feast::EveOfPentecost,
+        Language::En,
+        "Eve of Pentecost",
+        Status::Authorized,
+    ),
+    (
+        Feast::Pentecost,
+        Language::En,
+        "Day of Pentecost (Whit Sunday)",
+        Status::Authorized,
+    ),
+    (
+        Feast::PentecostVigil,
+        Language::En,
+        "Pentecost Vigil",
+        Status::Authorized,
+    ),
+    (
+        Feast::EveOfTrinitySunday,
+        Language::En,
+        "Eve of Trinity Sunday",
+        Status::Authorized,
+    ),
+    (
+        Feast::TrinitySunday,
+        Language::En,
+        "Trinity Sunday",
+        Status::Authorized,
+    ),
+    (
+        Feast::ThanksgivingForHolyCommunion,
+        Language::En,
+        "Day of Thanksgiving for the Institution of Holy Communion (Corpus Christi)",
+        Status::Authorized,
+    ),
+    (
+        Feast::EmberDay,
+        Language::En,
+        "Ember Day",
+        Status::Authorized,
+    ),
+    (
+        Feast::RogationDay,
+        Language::En,
+        "Rogation Day",
+        Status::Authorized,
+    ),
+];
+
+pub(crate) const COMMON_WORSHIP_WEEK_NAMES: [(LiturgicalWeek, Language, &str); 62] = [
+    (
+        LiturgicalWeek::Advent1,
+        Language::En,
+        "The First Sunday of Advent",
+    ),
+    (
+        LiturgicalWeek::Advent2,
+        Language::En,
+        "The Second Sunday of Advent",
+    ),
+    (
+        LiturgicalWeek::Advent3,
+        Language::En,
+        "The Third Sunday of Advent",
+    ),
+    (
+        LiturgicalWeek::Advent4,
+        Language::En,
+        "The Fourth Sunday of Advent",
+    ),
+    (LiturgicalWeek::Christmas, Language::En, "Christmas"),
+    (
+        LiturgicalWeek::Christmas1,
+        Language::En,
+        "The First Sunday of Christmas",
+    ),
+    (
+        LiturgicalWeek::Christmas2,
+        Language::En,
+        "The Second Sunday of Christmas",
+    ),
+    (LiturgicalWeek::Epiphany, Language::En, "The Epiphany"),
+    (
+        LiturgicalWeek::Epiphany1,
+        Language::En,
+        "The Baptism of Christ",
+    ),
+    (
+        LiturgicalWeek::Epiphany2,
+        Language::En,
+        "The Second Sunday of Epiphany",
+    ),
+    (
+        LiturgicalWeek::Epiphany3,
+        Language::En,
+        "The Third Sunday of Epiphany",
+    ),
+    (
+        LiturgicalWeek::Epiphany4,
+        Language::En,
+        "The Fourth Sunday of Epiphany",
+    ),
+    (
+        LiturgicalWeek::FifthBeforeLent,
+        Language::En,
+        "The Fifth Sunday before Lent",
+    ),
+    (
+        LiturgicalWeek::FourthBeforeLent,
+        Language::En,
+        "The Fourth Sunday before Lent",
+    ),
+    (
+        LiturgicalWeek::ThirdBeforeLent,
+        Language::En,
+        "The Third Sunday before Lent",
+    ),
+    (
+        LiturgicalWeek::SecondBeforeLent,
+        Language::En,
+        "The Second Sunday before Lent",
+    ),
+    (
+        LiturgicalWeek::LastEpiphany,
+        Language::En,
+        "The Sunday next before Lent",
+    ),
+    (
+        LiturgicalWeek::Lent1,
+        Language::En,
+        "The First Sunday of Lent",
+    ),
+    (
+        LiturgicalWeek::Lent2,
+        Language::En,
+        "The Second Sunday of Lent",
+    ),
+    (
+        LiturgicalWeek::Lent3,
+        Language::En,
+        "The Third Sunday of Lent",
+    ),
+    (
+        LiturgicalWeek::Lent4,
+        Language::En,
+        "The Fourth Sunday of Lent",
+    ),
+    (
+        LiturgicalWeek::Lent5,
+        Language::En,
+        "The Fifth Sunday of Lent",
+    ),
+    (LiturgicalWeek::HolyWeek, Language::En, "Palm Sunday"),
+    (LiturgicalWeek::Easter, Language::En, "Easter Day"),
+    (
+        LiturgicalWeek::Easter2,
+        Language::En,
+        "The Second Sunday of Easter",
+    ),
+    (
+        LiturgicalWeek::Easter3,
+        Language::En,
+        "The Third Sunday of Easter",
+    ),
+    (
+        LiturgicalWeek::Easter4,
+        Language::En,
+        "The Fourth Sunday of Easter",
+    ),
+    (
+        LiturgicalWeek::Easter5,
+        Language::En,
+        "The Fifth Sunday of Easter",
+    ),
+    (
+        LiturgicalWeek::Easter6,
+        Language::En,
+        "The Sixth Sunday of Easter",
+    ),
+    (
+        LiturgicalWeek::Easter7,
+        Language::En,
+        "The Seventh Sunday of Easter",
+    ),
+    (LiturgicalWeek::Pentecost, Language::En, "Day of Pentecost"),
+    (
+        LiturgicalWeek::TrinitySunday,
+        Language::En,
+        "Trinity Sunday",
+    ),
+    (
+        LiturgicalWeek::Pentecost2,
+        Language::En,
+        "The First Sunday after Trinity",
+    ),
+    (
+        LiturgicalWeek::Pentecost3,
+        Language::En,
+        "The Second Sunday after Trinity",
+    ),
+    (
+        LiturgicalWeek::Pentecost4,
+        Language::En,
+        "The Third Sunday after Trinity",
+    ),
+    (
+        LiturgicalWeek::Pentecost5,
+        Language::En,
+        "The Fourth Sunday after Trinity",
+    ),
+    (
+        LiturgicalWeek::Pentecost6,
+        Language::En,
+        "The Fifth Sunday after Trinity",
+    ),
+    (
+        LiturgicalWeek::Pentecost7,
+        Language::En,
+        "The Sixth Sunday after Trinity",
+    ),
+    (
+        LiturgicalWeek::Pentecost8,
+        Language::En,
+        "The Seventh Sunday after Trinity",
+    ),
+    (
+        LiturgicalWeek::Pentecost9,
+        Language::En,
+        "The Eighth Sunday after Trinity",
+    ),
+    (
+        LiturgicalWeek::Pentecost10,
+        Language::En,
+        "The Ninth Sunday after Trinity",
+    ),
+    (
+        LiturgicalWeek::Pentecost11,
+        Language::En,
+        "The Tenth Sunday after Trinity",
+    ),
+    (
+        LiturgicalWeek::Pentecost12,
+        Language::En,
+        "The Eleventh Sunday after Trinity",
+    ),
+    (
+        LiturgicalWeek::Pentecost13,
+        Language::En,
+        "The Twelfth Sunday after Trinity",
+    ),
+    (
+        LiturgicalWeek::Pentecost14,
+        Language::En,
+        "The Thirteenth Sunday after Trinity",
+    ),
+    (
+        LiturgicalWeek::Pentecost15,
+        Language::En,
+        "The Fourteenth Sunday after Trinity",
+    ),
+    (
+        LiturgicalWeek::Pentecost16,
+        Language::En,
+        "The Fifteenth Sunday after Trinity",
+    ),
+    (
+        LiturgicalWeek::Pentecost17,
+        Language::En,
+        "The Sixteenth Sunday after Trinity",
+    ),
+    (
+        LiturgicalWeek::Pentecost18,
+        Language::En,
+        "The Seventeenth Sunday after Trinity",
+    ),
+    (
+        LiturgicalWeek::Pentecost19,
+        Language::En,
+        "The Eighteenth Sunday after Trinity",
+    ),
+    (
+        LiturgicalWeek::Pentecost20,
+        Language::En,
+        "The Nineteenth Sunday after Trinity",
+    ),
+    (
+        LiturgicalWeek::Pentecost21,
+        Language::En,
+        "The Twentieth Sunday after Trinity",
+    ),
+    (
+        LiturgicalWeek::Pentecost22,
+        Language::En,
+        "The Twenty-First Sunday after Trinity",
+    ),
+    (
+        LiturgicalWeek::Pentecost23,
+        Language::En,
+        "The Twenty-Second Sunday after Trinity",
+    ),
+    (
+        LiturgicalWeek::Pentecost24,
+        Language::En,
+        "The Twenty-Third Sunday after Trinity",
+    ),
+    (
+        LiturgicalWeek::Pentecost25,
+        Language::En,
+        "The Twenty-Fourth Sunday after Trinity",
+    ),
+    (
+        LiturgicalWeek::Pentecost26,
+        Language::En,
+        "The Twenty-Fifth Sunday after Trinity",
+    ),
+    (
+        LiturgicalWeek::Pentecost27,
+        Language::En,
+        "The Twenty-Sixth Sunday after Trinity",
+    ),
+    (
+        LiturgicalWeek::FourthBeforeAdvent,
+        Language::En,
+        "The Fourth Sunday before Advent",
+    ),
+    (
+        LiturgicalWeek::ThirdBeforeAdvent,
+        Language::En,
+        "The Third Sunday before Advent",
+    ),
+    (
+        LiturgicalWeek::SecondBeforeAdvent,
+        Language::En,
+        "The Second Sunday before Advent",
+    ),
+    (
+        LiturgicalWeek::LastPentecost,
+        Language::En,
+        "Christ the King",
+    ),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Date, LiturgicalDayId, Proper};
+
+    #[test]
+    fn epiphany_runs_until_presentation() {
+        // Easter 2019 is late (April 21), so there are five Sundays before Lent
+        let day = COMMON_WORSHIP_CALENDAR.liturgical_day(Date::from_ymd(2019, 1, 27), false);
+        assert_eq!(day.week, LiturgicalWeek::Epiphany3);
+        let day = COMMON_WORSHIP_CALENDAR.liturgical_day(Date::from_ymd(2019, 2, 3), false);
+        assert_eq!(day.week, LiturgicalWeek::FifthBeforeLent);
+
+        // Easter 2008 is early (March 23), so Epiphany runs straight into the Sunday next before Lent
+        let day = COMMON_WORSHIP_CALENDAR.liturgical_day(Date::from_ymd(2008, 1, 27), false);
+        assert_eq!(day.week, LiturgicalWeek::Epiphany3);
+        let day = COMMON_WORSHIP_CALENDAR.liturgical_day(Date::from_ymd(2008, 2, 2), false);
+        assert_eq!(day.observed, LiturgicalDayId::Feast(Feast::ThePresentation));
+        let day = COMMON_WORSHIP_CALENDAR.liturgical_day(Date::from_ymd(2008, 2, 3), false);
+        assert_eq!(day.week, LiturgicalWeek::LastEpiphany);
+        let day = COMMON_WORSHIP_CALENDAR.liturgical_day(Date::from_ymd(2008, 2, 6), false);
+        assert_eq!(day.observed, LiturgicalDayId::Feast(Feast::AshWednesday));
+    }
+
+    #[test]
+    fn presentation_on_sunday() {
+        let day = COMMON_WORSHIP_CALENDAR.liturgical_day(Date::from_ymd(2020, 2, 2), false);
+        assert_eq!(day.week, LiturgicalWeek::FourthBeforeLent);
+        assert_eq!(day.observed, LiturgicalDayId::Feast(Feast::ThePresentation));
+    }
+
+    #[test]
+    fn sundays_after_trinity_and_before_advent() {
+        let day = COMMON_WORSHIP_CALENDAR.liturgical_day(Date::from_ymd(2022, 6, 19), false);
+        assert_eq!(day.week, LiturgicalWeek::Pentecost2);
+        assert_eq!(
+            COMMON_WORSHIP_CALENDAR.week_name(day.week, Language::En),
+            Some("The First Sunday after Trinity")
+        );
+
+        let day = COMMON_WORSHIP_CALENDAR.liturgical_day(Date::from_ymd(2022, 11, 6), false);
+        assert_eq!(day.week, LiturgicalWeek::ThirdBeforeAdvent);
+        assert_eq!(day.proper, Some(Proper::Proper27));
+        let day = COMMON_WORSHIP_CALENDAR.liturgical_day(Date::from_ymd(2022, 11, 20), false);
+        assert_eq!(day.week, LiturgicalWeek::LastPentecost);
+        assert_eq!(
+            COMMON_WORSHIP_CALENDAR.week_name(day.week, Language::En),
+            Some("Christ the King")
+        );
+        let day = COMMON_WORSHIP_CALENDAR.liturgical_day(Date::from_ymd(2022, 11, 27), false);
+        assert_eq!(day.week, LiturgicalWeek::Advent1);
+    }
+
+    #[test]
+    fn festivals_on_common_worship_dates() {
+        let day = COMMON_WORSHIP_CALENDAR.liturgical_day(Date::from_ymd(2022, 5, 14), false);
+        assert_eq!(day.observed, LiturgicalDayId::Feast(Feast::Matthias));
+        let day = COMMON_WORSHIP_CALENDAR.liturgical_day(Date::from_ymd(2021, 7, 3), false);
+        assert_eq!(day.observed, LiturgicalDayId::Feast(Feast::Thomas));
+        let day = COMMON_WORSHIP_CALENDAR.liturgical_day(Date::from_ymd(2022, 6, 16), false);
+        assert_eq!(
+            day.observed,
+            LiturgicalDayId::Feast(Feast::ThanksgivingForHolyCommunion)
+        );
+    }
+}
@@ -0,0 +1,131 @@
+use std::convert::TryInto;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{Calendar, Date};
+
+/// The method by which a [Calendar] calculates the date of Easter, from which the rest of the
+/// Easter cycle (and therefore most of the liturgical year) follows.
+///
+/// Only the date of Easter is affected: holy days appointed for fixed dates are still found on those
+/// dates in the Gregorian calendar. (Fixed dates in the Julian calendar can be converted with [Date::from_julian_ymd].)
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Computus {
+    /// The Gregorian reckoning, used by the Western churches since the reform of 1582
+    #[default]
+    Gregorian,
+    /// The Julian reckoning, used by the Orthodox churches
+    Julian,
+    /// The Julian reckoning of Easter for years before 1583, and the Gregorian reckoning thereafter.
+    /// As with the other reckonings, this affects only the date of Easter: holy days on fixed dates
+    /// are still found on their Gregorian dates, even before 1583.
+    Historical,
+}
+
+impl Computus {
+    /// The date of Easter in the given year, as a (Gregorian) [Date].
+    /// ```
+    /// # use calendar::{Computus, Date};
+    /// assert_eq!(Computus::Gregorian.easter_in_year(2024), Date::from_ymd(2024, 3, 31));
+    /// assert_eq!(Computus::Julian.easter_in_year(2024), Date::from_ymd(2024, 5, 5));
+    /// // the two reckonings sometimes coincide
+    /// assert_eq!(Computus::Julian.easter_in_year(2025), Date::from_ymd(2025, 4, 20));
+    /// // Easter 1500 was 19 April in the Julian calendar
+    /// assert_eq!(Computus::Historical.easter_in_year(1500), Date::from_julian_ymd(1500, 4, 19));
+    /// assert_eq!(Computus::Historical.easter_in_year(2024), Date::from_ymd(2024, 3, 31));
+    /// ```
+    pub fn easter_in_year(&self, year: u32) -> Date {
+        match self {
+            Computus::Gregorian => gregorian_easter(year),
+            Computus::Julian => julian_easter(year),
+            Computus::Historical if year < 1583 => julian_easter(year),
+            Computus::Historical => gregorian_easter(year),
+        }
+    }
+}
+
+impl Calendar<'_> {
+    /// A copy of this calendar that calculates the date of Easter using a different [Computus].
+    /// ```
+    /// # use calendar::{BCP1979_CALENDAR, Computus, Date, LiturgicalWeek, Weekday};
+    /// let orthodox_easter = BCP1979_CALENDAR.with_computus(Computus::Julian);
+    /// let day = orthodox_easter.liturgical_day(Date::from_ymd(2024, 5, 5), false);
+    /// assert_eq!((day.week, day.weekday), (LiturgicalWeek::Easter, Weekday::Sun));
+    /// let day = orthodox_easter.liturgical_day(Date::from_ymd(2024, 6, 23), false);
+    /// assert_eq!(day.week, LiturgicalWeek::Pentecost);
+    /// ```
+    #[must_use]
+    pub fn with_computus(self, computus: Computus) -> Self {
+        Self { computus, ..self }
+    }
+}
+
+#[allow(clippy::many_single_char_names)]
+fn gregorian_easter(year: u32) -> Date {
+    // Computus: Meeus/Jones/Butcher algorithm
+    let a = year % 19;
+    let b = year / 100;
+    let c = year % 100;
+    let d = b / 4;
+    let e = b % 4;
+    let f = (b + 8) / 25;
+    let g = (b - f + 1) / 3;
+    let h = (19 * a + b - d - g + 15) % 30;
+    let i = c / 4;
+    let k = c % 4;
+    let l = (32 + 2 * e + 2 * i - h - k) % 7;
+    let m = (a + 11 * h + 22 * l) / 451;
+    let month = (h + l - 7 * m + 114) / 31;
+    let day = ((h + l - 7 * m + 114) % 31) + 1;
+    Date::from_ymd(
+        year.try_into().unwrap(),
+        month.try_into().unwrap(),
+        day.try_into().unwrap(),
+    )
+}
+
+#[allow(clippy::many_single_char_names)]
+fn julian_easter(year: u32) -> Date {
+    // Computus: Meeus's Julian algorithm, which gives a date in the Julian calendar
+    let a = year % 4;
+    let b = year % 7;
+    let c = year % 19;
+    let d = (19 * c + 15) % 30;
+    let e = (2 * a + 4 * b + 34 - d) % 7;
+    let month = (d + e + 114) / 31;
+    let day = ((d + e + 114) % 31) + 1;
+    Date::from_julian_ymd(
+        year.try_into().unwrap(),
+        month.try_into().unwrap(),
+        day.try_into().unwrap(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Feast, LiturgicalWeek, Weekday, BCP1979_CALENDAR};
+
+    #[test]
+    fn easter_is_always_a_sunday() {
+        for year in 1000..3000 {
+            for computus in [Computus::Gregorian, Computus::Julian] {
+                assert_eq!(computus.easter_in_year(year).weekday(), Weekday::Sun);
+            }
+        }
+    }
+
+    #[test]
+    fn liturgical_days_before_the_gregorian_reform() {
+        let calendar = BCP1979_CALENDAR.with_computus(Computus::Historical);
+        let easter = calendar.liturgical_day(Date::from_julian_ymd(1500, 4, 19), false);
+        assert_eq!(easter.week, LiturgicalWeek::Easter);
+        assert_eq!(easter.weekday, Weekday::Sun);
+        let ash_wednesday = calendar.liturgical_day(Date::from_julian_ymd(1500, 3, 4), false);
+        assert_eq!(ash_wednesday.week, LiturgicalWeek::LastEpiphany);
+        assert_eq!(ash_wednesday.weekday, Weekday::Wed);
+        // fixed holy days keep their Gregorian dates
+        let christmas = calendar.liturgical_day(Date::from_ymd(1500, 12, 25), false);
+        assert_eq!(christmas.observed, Feast::ChristmasDay.into());
+    }
+}
@@ -13,6 +13,11 @@ pub enum DateError {
     Parse,
 }
 
+/// Difference between a chrono day number (counted from 1 January 1 CE) and a Julian Day Number
+const JULIAN_DAY_NUMBER_OFFSET: i64 = 1_721_425;
+
+/// A calendar date. Dates are reckoned according to the (proleptic) Gregorian calendar,
+/// but can be converted to and from the Julian calendar.
 #[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Date {
     pub(crate) naive_date: chrono::NaiveDate,
@@ -56,6 +61,77 @@ impl Date {
         Self { naive_date }
     }
 
+    /// Creates Date from a year, month, and day in the Julian calendar.
+    ///
+    /// Panics if the month and day do not make a valid date in the Julian calendar;
+    /// see [Date::from_julian_ymd_opt].
+    /// ```
+    /// # use calendar::Date;
+    /// // the day after Julian 4 October 1582 was Gregorian 15 October 1582
+    /// assert_eq!(Date::from_julian_ymd(1582, 10, 5), Date::from_ymd(1582, 10, 15));
+    /// // Christmas in the Julian calendar falls on January 7
+    /// assert_eq!(Date::from_julian_ymd(2022, 12, 25), Date::from_ymd(2023, 1, 7));
+    /// ```
+    pub fn from_julian_ymd(year: u16, month: u8, day: u8) -> Date {
+        Date::from_julian_ymd_opt(year, month, day).expect("invalid or out-of-range Julian date")
+    }
+
+    /// Creates Date from a year, month, and day in the Julian calendar,
+    /// or returns `None` if they do not make a valid date in that calendar.
+    /// ```
+    /// # use calendar::Date;
+    /// // every fourth year is a leap year in the Julian calendar, including 1900
+    /// assert_eq!(Date::from_julian_ymd_opt(1900, 2, 29), Some(Date::from_ymd(1900, 3, 13)));
+    /// assert_eq!(Date::from_julian_ymd_opt(1901, 2, 29), None);
+    /// assert_eq!(Date::from_julian_ymd_opt(2022, 13, 1), None);
+    /// assert_eq!(Date::from_julian_ymd_opt(2022, 4, 31), None);
+    /// ```
+    pub fn from_julian_ymd_opt(year: u16, month: u8, day: u8) -> Option<Date> {
+        if !(1..=12).contains(&month) || !(1..=31).contains(&day) {
+            return None;
+        }
+
+        let a = (14 - i64::from(month)) / 12;
+        let y = i64::from(year) + 4800 - a;
+        let m = i64::from(month) + 12 * a - 3;
+        let julian_day_number = i64::from(day) + (153 * m + 2) / 5 + 365 * y + y / 4 - 32083;
+        let naive_date = NaiveDate::from_num_days_from_ce_opt(
+            (julian_day_number - JULIAN_DAY_NUMBER_OFFSET)
+                .try_into()
+                .ok()?,
+        )?;
+        let date = Self { naive_date };
+        // a day past the end of the month (like 30 February) rolls over into the next month
+        if date.to_julian_ymd() == (year, month, day) {
+            Some(date)
+        } else {
+            None
+        }
+    }
+
+    /// The year, month, and day of this date in the Julian calendar.
+    /// ```
+    /// # use calendar::Date;
+    /// assert_eq!(Date::from_ymd(1582, 10, 15).to_julian_ymd(), (1582, 10, 5));
+    /// assert_eq!(Date::from_ymd(2024, 3, 13).to_julian_ymd(), (2024, 2, 29));
+    /// ```
+    pub fn to_julian_ymd(&self) -> (u16, u8, u8) {
+        let julian_day_number =
+            i64::from(self.naive_date.num_days_from_ce()) + JULIAN_DAY_NUMBER_OFFSET;
+        let c = julian_day_number + 32082;
+        let d = (4 * c + 3) / 1461;
+        let e = c - 1461 * d / 4;
+        let m = (5 * e + 2) / 153;
+        let day = e - (153 * m + 2) / 5 + 1;
+        let month = m + 3 - 12 * (m / 10);
+        let year = d - 4800 + m / 10;
+        (
+            year.try_into().unwrap(),
+            month.try_into().unwrap(),
+            day.try_into().unwrap(),
+        )
+    }
+
     /// Creates Date from a year, month, and day.
     pub fn parse_from_str(s: &str, fmt: &str) -> Result<Date, DateError> {
         let naive_date = chrono::NaiveDate::parse_from_str(s, fmt).map_err(|_| DateError::Parse)?;
@@ -142,32 +218,21 @@ impl Date {
         naive_date.into()
     }
 
+    /// The date written out in the given [Language](language::Language).
+    /// ```
+    /// # use calendar::Date;
+    /// # use language::Language;
+    /// let date = Date::from_ymd(2021, 12, 25);
+    /// assert_eq!(date.to_localized_name(Language::En), "December 25, 2021");
+    /// assert_eq!(date.to_localized_name(Language::Es), "25 de diciembre de 2021");
+    /// assert_eq!(date.to_localized_name(Language::Fr), "25 décembre 2021");
+    /// ```
     pub fn to_localized_name(&self, language: Language) -> String {
-        format!(
-            "{}, {}",
-            self.to_localized_name_without_year(language),
-            self.year()
-        )
+        language.format_date(Some(self.year()), self.month(), self.day())
     }
 
-    pub fn to_localized_name_without_year(&self, _language: Language) -> String {
-        // TODO i18n
-        let month = match self.month() {
-            1 => "January",
-            2 => "February",
-            3 => "March",
-            4 => "April",
-            5 => "May",
-            6 => "June",
-            7 => "July",
-            8 => "August",
-            9 => "September",
-            10 => "October",
-            11 => "November",
-            12 => "December",
-            _ => "",
-        };
-        format!("{} {}", month, self.day())
+    pub fn to_localized_name_without_year(&self, language: Language) -> String {
+        language.format_date(None, self.month(), self.day())
     }
 
     pub fn to_padded_string(&self) -> String {
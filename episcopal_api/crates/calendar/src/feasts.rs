@@ -1,7 +1,8 @@
 use std::str::FromStr;
 
 use serde::{Deserialize, Serialize};
-use strum_macros::{AsRefStr, Display, EnumIter, EnumString, IntoStaticStr};
+use strum::IntoEnumIterator;
+use strum_macros::{AsRefStr, EnumIter, IntoStaticStr};
 
 use crate::holy_day::HolyDayId;
 use crate::lff2018::LFF_BIOS;
@@ -33,8 +34,6 @@ pub fn bio_for_feast(feast: Feast) -> Option<&'static str> {
     Debug,
     Serialize,
     Deserialize,
-    EnumString,
-    Display,
     AsRefStr,
     IntoStaticStr,
     EnumIter,
@@ -375,6 +374,48 @@ pub enum Feast {
     January8,
     January9,
     EveOfEpiphany1,
+    // Common Worship
+    George,
+    ThanksgivingForHolyCommunion,
+    // Local observances
+    /// A feast defined locally (e.g., a parish’s patronal feast), identified by a number
+    /// unique within its [LocalFeast](crate::LocalFeast) definitions
+    Local(u16),
+}
+
+// `Display` and `FromStr` are written out by hand, rather than derived, so that a local feast
+// keeps its number when converted to a string and back (e.g., `Local(3)`)
+impl std::fmt::Display for Feast {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Feast::Local(id) => write!(f, "Local({})", id),
+            _ => f.write_str(self.as_ref()),
+        }
+    }
+}
+
+impl FromStr for Feast {
+    type Err = strum::ParseError;
+
+    /// ```
+    /// # use std::str::FromStr;
+    /// # use calendar::Feast;
+    /// assert_eq!(Feast::from_str("ChristmasDay"), Ok(Feast::ChristmasDay));
+    /// assert_eq!(Feast::from_str("Local(3)"), Ok(Feast::Local(3)));
+    /// assert_eq!(Feast::from_str(&Feast::Local(3).to_string()), Ok(Feast::Local(3)));
+    /// assert!(Feast::from_str("Local").is_err());
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Some(id) = s.strip_prefix("Local(").and_then(|s| s.strip_suffix(')')) {
+            id.parse()
+                .map(Feast::Local)
+                .map_err(|_| strum::ParseError::VariantNotFound)
+        } else {
+            Feast::iter()
+                .find(|feast| !matches!(feast, Feast::Local(_)) && feast.as_ref() == s)
+                .ok_or(strum::ParseError::VariantNotFound)
+        }
+    }
 }
 
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug, Serialize, Deserialize)]
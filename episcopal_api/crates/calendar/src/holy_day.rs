@@ -8,9 +8,58 @@ pub struct HolyDay {
     sanctoral: Sanctoral,
     rank: Rank,
     season: Option<Season>,
+    /// The day on which the feast's octave (or “week of” the feast) ends, if it is kept with one
     octave: Option<HolyDayId>,
 }
 
+impl HolyDay {
+    pub const fn new(
+        identifier: Feast,
+        sanctoral: Sanctoral,
+        rank: Rank,
+        season: Option<Season>,
+    ) -> Self {
+        Self {
+            identifier,
+            sanctoral,
+            rank,
+            season,
+            octave: None,
+        }
+    }
+
+    /// Keeps the feast with an octave that ends on the given day, which is
+    /// taken to fall in the year after the feast if it comes before the feast in its own year
+    /// (e.g., the octave of Christmas ends on January 1)
+    #[must_use]
+    pub const fn with_octave(self, octave_day: HolyDayId) -> Self {
+        Self {
+            octave: Some(octave_day),
+            ..self
+        }
+    }
+
+    pub fn feast(&self) -> Feast {
+        self.identifier
+    }
+
+    pub fn sanctoral(&self) -> Sanctoral {
+        self.sanctoral
+    }
+
+    pub fn rank(&self) -> Rank {
+        self.rank
+    }
+
+    pub fn season(&self) -> Option<Season> {
+        self.season
+    }
+
+    pub fn octave(&self) -> Option<HolyDayId> {
+        self.octave
+    }
+}
+
 #[derive(Copy, Clone, Debug, Serialize, Deserialize, PartialEq, Eq, Hash)]
 pub enum HolyDayId {
     /// Holy days defined by a month/day pair (e.g., Christmas is 12/25, St. Luke's Day is 10/18)
@@ -0,0 +1,236 @@
+use language::Language;
+
+use crate::{Calendar, Date, LiturgicalDay, LiturgicalDayId, Weekday};
+
+/// Domain used to generate globally-unique identifiers for each event
+const UID_DOMAIN: &str = "commonprayeronline.org";
+
+/// iCalendar content lines should not be longer than 75 octets (RFC 5545 § 3.1)
+const MAX_LINE_LENGTH: usize = 75;
+
+impl Calendar<'_> {
+    /// Generates an iCalendar (RFC 5545) document for every date from `start` to `end` (inclusive),
+    /// with one all-day `VEVENT` per observance: the principal feast or Sunday of each day,
+    /// as well as any lesser feasts that may also be observed on that day.
+    ///
+    /// Each event includes the name of the feast, its [Rank](crate::Rank), [Season](crate::Season),
+    /// and liturgical [Color](crate::Color), and whether the feast has been transferred from another date.
+    /// ```
+    /// # use crate::calendar::{BCP1979_CALENDAR, LFF2018_CALENDAR, Date};
+    /// # use language::Language;
+    /// let ics = BCP1979_CALENDAR.to_ical(Date::from_ymd(2020, 12, 24), Date::from_ymd(2020, 12, 26), Language::En);
+    /// assert!(ics.starts_with("BEGIN:VCALENDAR\r\n"));
+    /// assert!(ics.ends_with("END:VCALENDAR\r\n"));
+    /// assert!(ics.contains("DTSTART;VALUE=DATE:20201225\r\n"));
+    /// assert!(ics.contains("SUMMARY:Christmas Day\r\n"));
+    /// assert!(ics.contains("X-LITURGICAL-COLOR:White\r\n"));
+    ///
+    /// // St. Mark (4/25) falls on the Fourth Sunday of Easter in 2021 and is transferred to Monday
+    /// let ics = BCP1979_CALENDAR.to_ical(Date::from_ymd(2021, 4, 25), Date::from_ymd(2021, 4, 26), Language::En);
+    /// assert!(ics.contains("SUMMARY:The Fourth Sunday of Easter\r\n"));
+    /// assert!(ics.contains("DTSTART;VALUE=DATE:20210426\r\n"));
+    /// assert!(ics.contains("X-LITURGICAL-TRANSFERRED:TRUE\r\n"));
+    ///
+    /// // the LFF2018 calendar includes lesser feasts
+    /// let ics = LFF2018_CALENDAR.to_ical(Date::from_ymd(2021, 1, 19), Date::from_ymd(2021, 1, 19), Language::En);
+    /// assert!(ics.contains("SUMMARY:Wulfstan of Worcester\\, Bishop\\, 1095\r\n"));
+    /// ```
+    pub fn to_ical(&self, start: Date, end: Date, language: Language) -> String {
+        let mut lines = vec![
+            "BEGIN:VCALENDAR".to_string(),
+            "VERSION:2.0".to_string(),
+            format!("PRODID:-//{}//Liturgical Calendar//EN", UID_DOMAIN),
+            "CALSCALE:GREGORIAN".to_string(),
+            "METHOD:PUBLISH".to_string(),
+        ];
+
+        let mut date = start;
+        while date <= end {
+            let day = self.liturgical_day(date, false);
+            for event in self.ical_events(&day, language) {
+                lines.extend(event);
+            }
+            date = date.add_days(1);
+        }
+
+        lines.push("END:VCALENDAR".to_string());
+
+        lines
+            .into_iter()
+            .map(|line| fold_line(&line))
+            .map(|line| line + "\r\n")
+            .collect()
+    }
+
+    /// Generates the `VEVENT`s for a single day, as a list of events made up of (unfolded) content lines
+    fn ical_events(&self, day: &LiturgicalDay, language: Language) -> Vec<Vec<String>> {
+        let mut events = Vec::new();
+
+        // principal observance: any feast, or the Sunday
+        let principal = match day.observed {
+            LiturgicalDayId::Feast(feast) => Some((
+                self.feast_name(feast, language),
+                format!("{:?}", feast),
+                false,
+            )),
+            LiturgicalDayId::TransferredFeast(feast) => Some((
+                self.feast_name(feast, language),
+                format!("{:?}", feast),
+                true,
+            )),
+            _ if day.weekday == Weekday::Sun => Some((
+                self.week_name(day.week, language).map(String::from),
+                format!("{:?}", day.week),
+                false,
+            )),
+            _ => None,
+        };
+        if let Some((Some(name), id, transferred)) = principal {
+            events.push(self.ical_event(day, day, &name, &id, transferred));
+        }
+
+        // lesser feasts that are also observed on this day
+        for feast in &day.holy_days {
+            if let Some(name) = self.feast_name(*feast, language) {
+                let feast_day = LiturgicalDay {
+                    observed: LiturgicalDayId::Feast(*feast),
+                    ..day.clone()
+                };
+                events.push(self.ical_event(
+                    day,
+                    &feast_day,
+                    &name,
+                    &format!("{:?}", feast),
+                    false,
+                ));
+            }
+        }
+
+        events
+    }
+
+    fn ical_event(
+        &self,
+        day: &LiturgicalDay,
+        observance: &LiturgicalDay,
+        name: &str,
+        id: &str,
+        transferred: bool,
+    ) -> Vec<String> {
+        let start = day.date.as_chrono().format("%Y%m%d").to_string();
+        let end = day.date.add_days(1).as_chrono().format("%Y%m%d").to_string();
+        let rank = self.rank(observance);
+        let season = self.season(observance);
+        let color = self.color(observance);
+
+        let mut description = format!("Rank: {}\nSeason: {}\nColor: {}", rank, season, color);
+        if transferred {
+            description.push_str("\nTransferred from its usual date");
+        }
+
+        vec![
+            "BEGIN:VEVENT".to_string(),
+            format!("UID:{}-{}@{}", start, id, UID_DOMAIN),
+            // use the date itself as the timestamp, so that the calendar is stable across requests
+            format!("DTSTAMP:{}T000000Z", start),
+            format!("DTSTART;VALUE=DATE:{}", start),
+            format!("DTEND;VALUE=DATE:{}", end),
+            format!("SUMMARY:{}", escape_text(name)),
+            format!("DESCRIPTION:{}", escape_text(&description)),
+            format!("CATEGORIES:{}", escape_text(season.as_ref())),
+            "TRANSP:TRANSPARENT".to_string(),
+            format!("X-LITURGICAL-RANK:{}", rank),
+            format!("X-LITURGICAL-SEASON:{}", season),
+            format!("X-LITURGICAL-COLOR:{}", color),
+            format!(
+                "X-LITURGICAL-TRANSFERRED:{}",
+                if transferred { "TRUE" } else { "FALSE" }
+            ),
+            "END:VEVENT".to_string(),
+        ]
+    }
+}
+
+/// Escapes TEXT values as described in RFC 5545 § 3.3.11
+fn escape_text(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace(';', "\\;")
+        .replace(',', "\\,")
+        .replace('\n', "\\n")
+}
+
+/// Folds a content line into multiple lines of no more than 75 octets,
+/// without splitting any multi-byte characters (RFC 5545 § 3.1)
+fn fold_line(line: &str) -> String {
+    let mut folded = String::with_capacity(line.len());
+    let mut line_length = 0;
+    for c in line.chars() {
+        if line_length + c.len_utf8() > MAX_LINE_LENGTH {
+            folded.push_str("\r\n ");
+            // continuation lines begin with a single space
+            line_length = 1;
+        }
+        folded.push(c);
+        line_length += c.len_utf8();
+    }
+    folded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::BCP1979_CALENDAR;
+
+    #[test]
+    fn folds_long_lines() {
+        let line = "x".repeat(160);
+        let folded = fold_line(&line);
+        assert!(folded.split("\r\n").all(|line| line.len() <= MAX_LINE_LENGTH));
+        assert_eq!(folded.replace("\r\n ", ""), line);
+
+        let line = "é".repeat(80);
+        let folded = fold_line(&line);
+        assert!(folded.split("\r\n").all(|line| line.len() <= MAX_LINE_LENGTH));
+        assert_eq!(folded.replace("\r\n ", ""), line);
+    }
+
+    #[test]
+    fn one_event_per_observance() {
+        // All Saints' Day, two black-letter days, three ferial weekdays, and All Saints' Sunday
+        let ics = BCP1979_CALENDAR.to_ical(
+            Date::from_ymd(2021, 11, 1),
+            Date::from_ymd(2021, 11, 7),
+            Language::En,
+        );
+        assert_eq!(ics.matches("BEGIN:VEVENT").count(), 4);
+        assert_eq!(ics.matches("END:VEVENT").count(), 4);
+        assert_eq!(ics.matches("SUMMARY:All Saints’ Day\r\n").count(), 2);
+        assert!(ics.contains("SUMMARY:Richard Hooker\\, Priest\\, 1600\r\n"));
+        assert_eq!(ics.matches("X-LITURGICAL-TRANSFERRED:TRUE").count(), 1);
+    }
+
+    #[test]
+    fn liturgical_colors() {
+        let ics = BCP1979_CALENDAR.to_ical(
+            Date::from_ymd(2021, 2, 17),
+            Date::from_ymd(2021, 2, 17),
+            Language::En,
+        );
+        assert!(ics.contains("SUMMARY:Ash Wednesday\r\n"));
+        assert!(ics.contains("X-LITURGICAL-COLOR:Purple\r\n"));
+
+        let ics = BCP1979_CALENDAR.to_ical(
+            Date::from_ymd(2021, 5, 23),
+            Date::from_ymd(2021, 5, 23),
+            Language::En,
+        );
+        assert!(ics.contains("X-LITURGICAL-COLOR:Red\r\n"));
+
+        let ics = BCP1979_CALENDAR.to_ical(
+            Date::from_ymd(2021, 7, 11),
+            Date::from_ymd(2021, 7, 11),
+            Language::En,
+        );
+        assert!(ics.contains("X-LITURGICAL-COLOR:Green\r\n"));
+    }
+}
@@ -3,32 +3,60 @@ use language::Language;
 use crate::{
     bcp1979::{
         BCP1979_HOLY_DAY_RANKS, BCP1979_HOLY_DAY_SEASONS, BCP1979_MAJOR_DAY_ALTERNATIVES,
-        BCP1979_PROPER_NAMES, BCP1979_WEEKS, BCP1979_WEEK_NAMES, BCP1979_WEEK_SEASONS,
+        BCP1979_OCTAVES, BCP1979_PROPER_NAMES, BCP1979_WEEKS, BCP1979_WEEK_NAMES,
+        BCP1979_WEEK_SEASONS,
     },
     feasts::KalendarEntry,
     holy_day::HolyDayId,
-    Calendar, Feast, Time, BCP1979_CALENDAR,
+    Calendar, Color, Computus, Feast, Time, BCP1979_CALENDAR,
 };
 use status::Status;
 
 /// [Calendar](Calendar) that calculates dates from the liturgical and sanctoral calendar
 /// of Lesser Feasts & Fasts (2018).
 pub const LFF2018_CALENDAR: Calendar = Calendar {
+    computus: Computus::Gregorian,
     easter_cycle_begins: 7,
     christmas_cycle_begins: 4,
+    christmas_cycle_ends: None,
     has_propers: true,
     weeks: &BCP1979_WEEKS,
     holy_days: &LFF2018_FEASTS,
     holy_days_fallback: Some(&BCP1979_CALENDAR),
     holy_day_ranks: &BCP1979_HOLY_DAY_RANKS,
     feast_seasons: &BCP1979_HOLY_DAY_SEASONS,
+    feast_colors: &LFF2018_FEAST_COLORS,
     week_seasons: &BCP1979_WEEK_SEASONS,
     feast_names: &LFF2018_FEAST_NAMES,
     week_names: &BCP1979_WEEK_NAMES,
     proper_names: &BCP1979_PROPER_NAMES,
     major_day_alternatives: &BCP1979_MAJOR_DAY_ALTERNATIVES,
+    octaves: &BCP1979_OCTAVES,
+    local_feasts: &[],
 };
 
+/// Martyrs commemorated in Lesser Feasts & Fasts but not in the 1979 BCP, whose feasts are kept in red;
+/// the colors of other feasts are found in [BCP1979_CALENDAR]
+pub(crate) const LFF2018_FEAST_COLORS: [(Feast, Color); 17] = [
+    (Feast::AgathaOfSicily, Color::Red),
+    (Feast::AgnesAgathaLucy, Color::Red),
+    (Feast::BlandinaAndHerCompanions, Color::Red),
+    (Feast::CatherineBarbaraMargaret, Color::Red),
+    (Feast::CharlesDeFoucauld, Color::Red),
+    (Feast::JananiLuwum, Color::Red),
+    (Feast::JonathanMyrickDaniels, Color::Red),
+    (Feast::LucyOfSyracuse, Color::Red),
+    (Feast::MancheMasemola, Color::Red),
+    (Feast::MariaSkobtsova, Color::Red),
+    (Feast::MartinLutherKing, Color::Red),
+    (Feast::MartyrsOfTheReformationEra, Color::Red),
+    (Feast::MosesTheBlack, Color::Red),
+    (Feast::OscarRomero, Color::Red),
+    (Feast::TheMartyrsOfMemphis, Color::Red),
+    (Feast::TheclaOfIconium, Color::Red),
+    (Feast::ThomasBecket, Color::Red),
+];
+
 // Array of all observances listed in Lesser Feasts & Fasts (2018)
 pub const LFF2018_FEASTS: [KalendarEntry; 269] = [
     (HolyDayId::Date(1, 1), Feast::HolyName, Time::AllDay, None),
@@ -1,16 +1,20 @@
 #![feature(test)]
 
-use std::convert::TryInto;
-
 mod bcp1979;
 mod calendar;
+mod calendar_year;
+pub mod common_worship;
+mod computus;
 mod date;
 pub mod feasts;
 mod holy_day;
+mod ical;
 pub mod lff2018;
 mod liturgical_color;
 mod liturgical_day;
 mod liturgical_week;
+mod local;
+mod octave;
 pub mod propers;
 mod rank;
 mod sanctoral;
@@ -20,7 +24,10 @@ mod various_occasions;
 mod weekday;
 mod year;
 pub use self::calendar::Calendar;
+pub use calendar_year::{CalendarYear, CalendarYears, LiturgicalDays};
 pub use bcp1979::BCP1979_CALENDAR;
+pub use common_worship::COMMON_WORSHIP_CALENDAR;
+pub use computus::Computus;
 pub use date::*;
 pub use feasts::*;
 pub use holy_day::{HolyDay, HolyDayId};
@@ -28,6 +35,8 @@ pub use lff2018::LFF2018_CALENDAR;
 pub use liturgical_color::Color;
 pub use liturgical_day::{LiturgicalDay, LiturgicalDayId};
 pub use liturgical_week::{Cycle, LiturgicalWeek};
+pub use local::{CalendarWithLocalFeasts, LocalFeast};
+pub use octave::Octave;
 pub use propers::Proper;
 pub use rank::Rank;
 pub use sanctoral::Sanctoral;
@@ -53,28 +62,8 @@ pub use year::{DailyOfficeYear, RCLYear, Year, YearType};
 /// assert_eq!(easter_in_year(2027).month(), 3);
 /// assert_eq!(easter_in_year(2027).day(), 28);
 /// ```
-#[allow(clippy::many_single_char_names)]
 pub fn easter_in_year(year: u32) -> Date {
-    // Computus: Meeus/Jones/Butcher algorithm
-    let a = year % 19;
-    let b = year / 100;
-    let c = year % 100;
-    let d = b / 4;
-    let e = b % 4;
-    let f = (b + 8) / 25;
-    let g = (b - f + 1) / 3;
-    let h = (19 * a + b - d - g + 15) % 30;
-    let i = c / 4;
-    let k = c % 4;
-    let l = (32 + 2 * e + 2 * i - h - k) % 7;
-    let m = (a + 11 * h + 22 * l) / 451;
-    let month = (h + l - 7 * m + 114) / 31;
-    let day = ((h + l - 7 * m + 114) % 31) + 1;
-    Date::from_ymd(
-        year.try_into().unwrap(),
-        month.try_into().unwrap(),
-        day.try_into().unwrap(),
-    )
+    Computus::Gregorian.easter_in_year(year)
 }
 
 // Crate-wide benchmark
@@ -98,19 +87,22 @@ mod tests {
 }
 
 // Convert string slugs to calendars
-impl From<&str> for Calendar {
+impl From<&str> for Calendar<'static> {
     fn from(s: &str) -> Self {
         match s {
             "lff2018" => LFF2018_CALENDAR,
             "LFF2018" => LFF2018_CALENDAR,
             "lff" => LFF2018_CALENDAR,
             "LFF" => LFF2018_CALENDAR,
+            "cw" => COMMON_WORSHIP_CALENDAR,
+            "CW" => COMMON_WORSHIP_CALENDAR,
+            "CommonWorship" => COMMON_WORSHIP_CALENDAR,
             _ => BCP1979_CALENDAR,
         }
     }
 }
 
-impl Default for Calendar {
+impl Default for Calendar<'static> {
     fn default() -> Self {
         BCP1979_CALENDAR
     }
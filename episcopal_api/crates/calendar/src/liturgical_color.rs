@@ -1,6 +1,11 @@
 use serde::{Deserialize, Serialize};
+use strum_macros::{AsRefStr, Display, EnumIter, EnumString, IntoStaticStr};
 
-#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug, Serialize, Deserialize)]
+use crate::{
+    Calendar, Feast, LiturgicalDay, LiturgicalDayId, LiturgicalWeek, Rank, Season, Weekday,
+};
+
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug, Serialize, Deserialize, AsRefStr, Display, EnumIter, EnumString, IntoStaticStr)]
 pub enum Color {
     Purple,
     Blue,
@@ -8,4 +13,227 @@ pub enum Color {
     White,
     Green,
     Red,
+    /// Used on the Third Sunday of Advent and the Fourth Sunday in Lent
+    Rose,
+    Black,
+    /// Unbleached linen, the “Lenten array” of the Sarum tradition
+    LentenArray,
+}
+
+impl Calendar<'_> {
+    /// The customary liturgical color for a day, based on its [Season], [Rank], and the kind of feast observed
+    /// (e.g., red for martyrs and Pentecost, white for feasts of our Lord).
+    /// ```
+    /// # use calendar::{BCP1979_CALENDAR, Color, Date};
+    /// let color = |y, m, d| BCP1979_CALENDAR.color(&BCP1979_CALENDAR.liturgical_day(Date::from_ymd(y, m, d), false));
+    /// assert_eq!(color(2021, 11, 28), Color::Blue); // Advent 1
+    /// assert_eq!(color(2021, 12, 25), Color::White); // Christmas
+    /// assert_eq!(color(2022, 1, 18), Color::White); // Confession of St. Peter
+    /// assert_eq!(color(2022, 1, 23), Color::Green); // Epiphany 3
+    /// assert_eq!(color(2022, 3, 2), Color::Purple); // Ash Wednesday
+    /// assert_eq!(color(2022, 4, 15), Color::Red); // Good Friday
+    /// assert_eq!(color(2022, 6, 5), Color::Red); // Pentecost
+    /// assert_eq!(color(2022, 6, 12), Color::White); // Trinity Sunday
+    /// assert_eq!(color(2022, 7, 25), Color::Red); // St. James
+    /// assert_eq!(color(2022, 7, 26), Color::Green); // ferial weekday after Pentecost
+    /// ```
+    pub fn color(&self, day: &LiturgicalDay) -> Color {
+        self.colors(day).0
+    }
+
+    /// Other colors that are customarily used for a day, in addition to its primary [Calendar::color]
+    /// (e.g., purple as well as blue in Advent, or the Sarum Lenten array in Lent).
+    /// ```
+    /// # use calendar::{BCP1979_CALENDAR, Color, Date};
+    /// let alternatives = |y, m, d| BCP1979_CALENDAR.color_alternatives(&BCP1979_CALENDAR.liturgical_day(Date::from_ymd(y, m, d), false));
+    /// assert_eq!(alternatives(2021, 11, 28), vec![Color::Purple]); // Advent 1
+    /// assert_eq!(alternatives(2021, 12, 12), vec![Color::Purple, Color::Rose]); // Advent 3
+    /// assert_eq!(alternatives(2022, 3, 27), vec![Color::LentenArray, Color::Rose]); // Lent 4
+    /// assert_eq!(alternatives(2022, 7, 26), vec![]); // ferial weekday after Pentecost
+    /// ```
+    pub fn color_alternatives(&self, day: &LiturgicalDay) -> Vec<Color> {
+        self.colors(day).1
+    }
+
+    fn colors(&self, day: &LiturgicalDay) -> (Color, Vec<Color>) {
+        match day.observed {
+            LiturgicalDayId::Feast(feast) | LiturgicalDayId::TransferredFeast(feast) => {
+                // lesser feasts during Lent and Holy Week are kept without changing the color of the season
+                if self.feast_day_rank(&feast) <= Rank::OptionalObservance
+                    && matches!(self.base_season(day), Season::Lent | Season::HolyWeek)
+                {
+                    self.season_colors(day, self.base_season(day))
+                } else {
+                    self.feast_colors(day, feast)
+                }
+            }
+            _ => self.season_colors(day, self.season(day)),
+        }
+    }
+
+    fn feast_colors(&self, day: &LiturgicalDay, feast: Feast) -> (Color, Vec<Color>) {
+        // the eve of a feast takes the color of the feast itself
+        let feast = self.feast_eve_following_day(&feast).unwrap_or(feast);
+        match feast {
+            Feast::Pentecost | Feast::PentecostVigil => (Color::Red, vec![]),
+            Feast::PalmSunday
+            | Feast::MondayInHolyWeek
+            | Feast::TuesdayInHolyWeek
+            | Feast::WednesdayInHolyWeek => (Color::Red, vec![Color::Purple]),
+            Feast::MaundyThursday => (Color::White, vec![Color::Red]),
+            Feast::GoodFriday => (Color::Red, vec![Color::Black]),
+            Feast::HolySaturday => (Color::Purple, vec![Color::Black]),
+            Feast::AllSoulsDay => (Color::White, vec![Color::Purple, Color::Black]),
+            Feast::Annunciation | Feast::TheVisitation | Feast::Mary => {
+                (Color::White, vec![Color::Blue])
+            }
+            Feast::EmberDay | Feast::RogationDay => (Color::Purple, vec![]),
+            // e.g., red for apostles and martyrs
+            _ => match self.feast_color(feast) {
+                Some(color) => (color, vec![]),
+                None => match self.feast_season(feast) {
+                    season @ (Season::Lent | Season::HolyWeek) => self.season_colors(day, season),
+                    Season::Easter
+                    | Season::Ascension
+                    | Season::Christmas
+                    | Season::Incarnation => (Color::White, vec![Color::Gold]),
+                    _ => (Color::White, vec![]),
+                },
+            },
+        }
+    }
+
+    /// The color of a feast, if it has one of its own rather than the color of its season,
+    /// as given by a [LocalFeast](crate::LocalFeast) or by the calendar (or the calendar it falls back on)
+    fn feast_color(&self, feast: Feast) -> Option<Color> {
+        self.local_feast(&feast)
+            .and_then(|local_feast| local_feast.color)
+            .or_else(|| {
+                self.feast_colors
+                    .iter()
+                    .chain(
+                        self.holy_days_fallback
+                            .into_iter()
+                            .flat_map(|fallback| fallback.feast_colors.iter()),
+                    )
+                    .find(|(search, _)| *search == feast)
+                    .map(|(_, color)| *color)
+            })
+    }
+
+    pub(crate) fn feast_season(&self, feast: Feast) -> Season {
+        self.local_feast(&feast)
+            .and_then(|local_feast| local_feast.season)
+            .or_else(|| {
+                self.feast_seasons
+                    .iter()
+                    .find(|(search, _)| *search == feast)
+                    .map(|(_, season)| *season)
+            })
+            .unwrap_or(Season::Saints)
+    }
+
+    fn season_colors(&self, day: &LiturgicalDay, season: Season) -> (Color, Vec<Color>) {
+        let is_sunday = day.weekday == Weekday::Sun;
+        match season {
+            Season::Advent if is_sunday && day.week == LiturgicalWeek::Advent3 => {
+                (Color::Blue, vec![Color::Purple, Color::Rose])
+            }
+            Season::Advent => (Color::Blue, vec![Color::Purple]),
+            Season::Lent if is_sunday && day.week == LiturgicalWeek::Lent4 => {
+                (Color::Purple, vec![Color::LentenArray, Color::Rose])
+            }
+            Season::Lent => (Color::Purple, vec![Color::LentenArray]),
+            Season::HolyWeek => (Color::Red, vec![Color::Purple]),
+            Season::Ember | Season::Rogation => (Color::Purple, vec![]),
+            Season::Christmas | Season::Easter | Season::Ascension => {
+                (Color::White, vec![Color::Gold])
+            }
+            // the Baptism of our Lord and the Transfiguration
+            Season::Epiphany
+                if day.week == LiturgicalWeek::Epiphany
+                    || (is_sunday
+                        && matches!(
+                            day.week,
+                            LiturgicalWeek::Epiphany1 | LiturgicalWeek::LastEpiphany
+                        )) =>
+            {
+                (Color::White, vec![])
+            }
+            Season::Epiphany => (Color::Green, vec![]),
+            Season::Pentecost if day.week == LiturgicalWeek::Pentecost && is_sunday => {
+                (Color::Red, vec![])
+            }
+            Season::Trinity if is_sunday && day.week == LiturgicalWeek::TrinitySunday => {
+                (Color::White, vec![])
+            }
+            Season::Pentecost | Season::Trinity | Season::OrdinaryTime => (Color::Green, vec![]),
+            Season::Kingdom => (Color::Red, vec![Color::Green]),
+            _ => (Color::White, vec![]),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::*;
+    use crate::{Date, HolyDayId, LocalFeast, Time, LFF2018_CALENDAR};
+
+    fn observing(date: Date, feast: Feast) -> LiturgicalDay {
+        LiturgicalDay {
+            observed: LiturgicalDayId::Feast(feast),
+            ..LFF2018_CALENDAR.liturgical_day(date, false)
+        }
+    }
+
+    #[test]
+    fn martyrs_are_red() {
+        let day = observing(Date::from_ymd(2022, 1, 21), Feast::AgnesAndCeciliaOfRome);
+        assert_eq!(LFF2018_CALENDAR.color(&day), Color::Red);
+    }
+
+    #[test]
+    fn lesser_feasts_in_lent_keep_the_color_of_the_season() {
+        let day = observing(Date::from_ymd(2022, 3, 7), Feast::PerpetuaAndFelicity);
+        assert_eq!(LFF2018_CALENDAR.color(&day), Color::Purple);
+        assert_eq!(
+            LFF2018_CALENDAR.color_alternatives(&day),
+            vec![Color::LentenArray]
+        );
+    }
+
+    #[test]
+    fn martyrs_only_in_lff_fall_back_on_bcp_colors() {
+        // St. Alban is in the BCP calendar, and Janani Luwum only in LFF
+        let day = observing(Date::from_ymd(2022, 6, 22), Feast::Alban);
+        assert_eq!(LFF2018_CALENDAR.color(&day), Color::Red);
+        let day = observing(Date::from_ymd(2022, 2, 17), Feast::JananiLuwum);
+        assert_eq!(LFF2018_CALENDAR.color(&day), Color::Red);
+    }
+
+    #[test]
+    fn local_feasts_can_set_their_color() {
+        let feasts = [LocalFeast {
+            id: 1,
+            date: HolyDayId::Date(7, 15),
+            time: Time::AllDay,
+            until: None,
+            rank: Rank::HolyDay,
+            season: None,
+            color: Some(Color::Red),
+            names: HashMap::new(),
+        }];
+        let calendar = LFF2018_CALENDAR.with_local_feasts(&feasts);
+        let day = calendar.liturgical_day(Date::from_ymd(2020, 7, 15), false);
+        assert_eq!(day.observed, LiturgicalDayId::Feast(Feast::Local(1)));
+        assert_eq!(calendar.color(&day), Color::Red);
+    }
+
+    #[test]
+    fn other_lesser_feasts_are_white() {
+        let day = observing(Date::from_ymd(2022, 5, 25), Feast::Bede);
+        assert_eq!(LFF2018_CALENDAR.color(&day), Color::White);
+    }
 }
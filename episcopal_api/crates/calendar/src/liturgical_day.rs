@@ -1,8 +1,9 @@
 use crate::{
     year::{DailyOfficeYear, RCLYear},
-    Date, Feast, LiturgicalWeek, Proper, VariousOccasions, Weekday,
+    Date, Feast, LiturgicalWeek, Octave, Proper, VariousOccasions, Weekday,
 };
 use serde::{Deserialize, Serialize};
+use std::str::FromStr;
 
 #[derive(Clone, PartialEq, Eq, Hash, Debug, Serialize, Deserialize)]
 pub struct LiturgicalDay {
@@ -17,6 +18,9 @@ pub struct LiturgicalDay {
     pub observed: LiturgicalDayId,
     pub alternate: Option<LiturgicalDayId>,
     pub alternative_services: Vec<Feast>,
+    /// The octave (or “week of”) of a major feast within which this day falls, if any
+    #[serde(default)]
+    pub octave: Option<Octave>,
 }
 
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug, Serialize, Deserialize)]
@@ -28,3 +32,72 @@ pub enum LiturgicalDayId {
     DayOfMonth(u8),
     VariousOccasions(VariousOccasions),
 }
+
+impl std::fmt::Display for LiturgicalDayId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LiturgicalDayId::Feast(feast) => write!(f, "Feast({})", feast),
+            LiturgicalDayId::WeekAndDay(week, weekday) => {
+                write!(f, "WeekAndDay({}, {})", week, weekday)
+            }
+            LiturgicalDayId::ProperAndDay(proper, weekday) => {
+                write!(f, "ProperAndDay({}, {})", proper, weekday)
+            }
+            LiturgicalDayId::TransferredFeast(feast) => write!(f, "TransferredFeast({})", feast),
+            LiturgicalDayId::DayOfMonth(day) => write!(f, "DayOfMonth({})", day),
+            LiturgicalDayId::VariousOccasions(occasion) => {
+                write!(f, "VariousOccasions({})", occasion)
+            }
+        }
+    }
+}
+
+impl FromStr for LiturgicalDayId {
+    type Err = strum::ParseError;
+
+    /// Parses the form written by [Display](std::fmt::Display), like `WeekAndDay(Advent1, Sun)`
+    /// ```
+    /// # use std::str::FromStr;
+    /// # use calendar::{Feast, LiturgicalDayId, LiturgicalWeek, Weekday};
+    /// assert_eq!(
+    ///     LiturgicalDayId::from_str("WeekAndDay(Advent1, Sun)"),
+    ///     Ok(LiturgicalDayId::WeekAndDay(LiturgicalWeek::Advent1, Weekday::Sun))
+    /// );
+    /// let local = LiturgicalDayId::Feast(Feast::Local(3));
+    /// assert_eq!(LiturgicalDayId::from_str(&local.to_string()), Ok(local));
+    /// assert!(LiturgicalDayId::from_str("WeekAndDay(Advent1)").is_err());
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let (variant, args) = s
+            .split_once('(')
+            .and_then(|(variant, rest)| Some((variant, rest.strip_suffix(')')?)))
+            .ok_or(strum::ParseError::VariantNotFound)?;
+        let args = args.split(',').map(str::trim).collect::<Vec<_>>();
+        match (variant, args.as_slice()) {
+            ("Feast", [feast]) => feast.parse().map(LiturgicalDayId::Feast),
+            ("TransferredFeast", [feast]) => feast.parse().map(LiturgicalDayId::TransferredFeast),
+            ("WeekAndDay", [week, weekday]) => {
+                Ok(LiturgicalDayId::WeekAndDay(week.parse()?, weekday.parse()?))
+            }
+            ("ProperAndDay", [proper, weekday]) => Ok(LiturgicalDayId::ProperAndDay(
+                proper.parse()?,
+                weekday.parse()?,
+            )),
+            ("DayOfMonth", [day]) => day
+                .parse()
+                .map(LiturgicalDayId::DayOfMonth)
+                .map_err(|_| strum::ParseError::VariantNotFound),
+            ("VariousOccasions", [occasion]) => {
+                occasion.parse().map(LiturgicalDayId::VariousOccasions)
+            }
+            _ => Err(strum::ParseError::VariantNotFound),
+        }
+    }
+}
+
+impl From<Feast> for LiturgicalDayId {
+    fn from(feast: Feast) -> Self {
+        LiturgicalDayId::Feast(feast)
+    }
+}
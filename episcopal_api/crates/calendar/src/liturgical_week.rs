@@ -66,6 +66,11 @@ pub enum LiturgicalWeek {
     Epiphany6,
     Epiphany7,
     Epiphany8,
+    // Sundays before Lent (Common Worship)
+    FifthBeforeLent,
+    FourthBeforeLent,
+    ThirdBeforeLent,
+    SecondBeforeLent,
     LastEpiphany,
     Lent1,
     Lent2,
@@ -108,6 +113,10 @@ pub enum LiturgicalWeek {
     Pentecost25,
     Pentecost26,
     Pentecost27,
+    // Sundays of the Kingdom season (Common Worship)
+    FourthBeforeAdvent,
+    ThirdBeforeAdvent,
+    SecondBeforeAdvent,
     LastPentecost,
     None,
 }
@@ -0,0 +1,225 @@
+use std::collections::HashMap;
+
+use language::Language;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    feasts::KalendarEntry, Calendar, Color, Feast, HolyDayId, LiturgicalWeek, Rank, Season, Time,
+};
+
+/// A feast that is not included in any of the built-in calendars, like a parish’s
+/// patronal feast or the commemoration of a diocesan saint, which can be layered onto
+/// an existing [Calendar] using [Calendar::with_local_feasts] or [CalendarWithLocalFeasts].
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LocalFeast {
+    /// Uniquely identifies this feast as [Feast::Local]
+    pub id: u16,
+    /// When the feast is observed
+    pub date: HolyDayId,
+    /// Whether the feast is observed all day, or only in the morning or evening
+    #[serde(default = "all_day")]
+    pub time: Time,
+    /// The feast is not observed once this week has begun (see [KalendarEntry])
+    #[serde(default)]
+    pub until: Option<LiturgicalWeek>,
+    /// Determines whether the feast displaces, or is displaced by, other observances
+    pub rank: Rank,
+    #[serde(default)]
+    pub season: Option<Season>,
+    /// The liturgical color of the feast, if it is not that of the season (e.g., red for a martyr)
+    #[serde(default)]
+    pub color: Option<Color>,
+    /// The name of the feast, by language
+    pub names: HashMap<Language, String>,
+}
+
+fn all_day() -> Time {
+    Time::AllDay
+}
+
+impl LocalFeast {
+    /// The [Feast] by which this local feast is identified
+    pub fn feast(&self) -> Feast {
+        Feast::Local(self.id)
+    }
+
+    fn kalendar_entry(&self) -> KalendarEntry {
+        (self.date, self.feast(), self.time, self.until)
+    }
+}
+
+impl Calendar<'_> {
+    /// Creates a new calendar that includes all of the holy days in this calendar, along with the
+    /// given [LocalFeast]s (in place of any local feasts this calendar already has). Local feasts
+    /// follow the same rules of precedence and transfer as any other feast of the same [Rank].
+    ///
+    /// The new calendar borrows the local feasts; see [CalendarWithLocalFeasts] for a calendar
+    /// that owns them.
+    /// ```
+    /// # use std::collections::HashMap;
+    /// # use crate::calendar::{BCP1979_CALENDAR, Date, Feast, HolyDayId, LiturgicalDayId, LocalFeast, Rank, Time};
+    /// # use language::Language;
+    /// let patronal_feast = LocalFeast {
+    ///     id: 1,
+    ///     date: HolyDayId::Date(7, 15),
+    ///     time: Time::AllDay,
+    ///     until: None,
+    ///     rank: Rank::HolyDay,
+    ///     season: None,
+    ///     color: None,
+    ///     names: HashMap::from([(Language::En, "Saint Swithun".to_string())]),
+    /// };
+    /// let feasts = [patronal_feast];
+    /// let calendar = BCP1979_CALENDAR.with_local_feasts(&feasts);
+    /// assert_eq!(calendar.feast_name(Feast::Local(1), Language::En), Some("Saint Swithun".to_string()));
+    /// assert_eq!(calendar.feast_day_rank(&Feast::Local(1)), Rank::HolyDay);
+    ///
+    /// // 7/15/2020 is a Wednesday
+    /// let day = calendar.liturgical_day(Date::from_ymd(2020, 7, 15), false);
+    /// assert_eq!(day.observed, LiturgicalDayId::Feast(Feast::Local(1)));
+    ///
+    /// // 7/15/2018 is a Sunday, so the feast is transferred to Monday
+    /// let day = calendar.liturgical_day(Date::from_ymd(2018, 7, 15), false);
+    /// assert_ne!(day.observed, LiturgicalDayId::Feast(Feast::Local(1)));
+    /// let day = calendar.liturgical_day(Date::from_ymd(2018, 7, 16), false);
+    /// assert_eq!(day.observed, LiturgicalDayId::TransferredFeast(Feast::Local(1)));
+    /// ```
+    pub fn with_local_feasts<'b>(&self, feasts: &'b [LocalFeast]) -> Calendar<'b> {
+        Calendar {
+            computus: self.computus,
+            easter_cycle_begins: self.easter_cycle_begins,
+            christmas_cycle_begins: self.christmas_cycle_begins,
+            christmas_cycle_ends: self.christmas_cycle_ends,
+            has_propers: self.has_propers,
+            weeks: self.weeks,
+            holy_days: self.holy_days,
+            holy_days_fallback: self.holy_days_fallback,
+            holy_day_ranks: self.holy_day_ranks,
+            feast_seasons: self.feast_seasons,
+            feast_colors: self.feast_colors,
+            week_seasons: self.week_seasons,
+            feast_names: self.feast_names,
+            week_names: self.week_names,
+            proper_names: self.proper_names,
+            major_day_alternatives: self.major_day_alternatives,
+            octaves: self.octaves,
+            local_feasts: feasts,
+        }
+    }
+
+    /// The definition of the given feast, if it is one of this calendar’s local feasts
+    pub(crate) fn local_feast(&self, feast: &Feast) -> Option<&LocalFeast> {
+        self.local_feasts
+            .iter()
+            .find(|local_feast| local_feast.feast() == *feast)
+    }
+
+    /// Every holy day in this calendar (but not its fallback); local feasts are listed first,
+    /// so that they take priority over the built-in calendar
+    pub(crate) fn kalendar_entries(&self) -> impl Iterator<Item = KalendarEntry> + '_ {
+        self.local_feasts
+            .iter()
+            .map(LocalFeast::kalendar_entry)
+            .chain(self.holy_days.iter().copied())
+    }
+}
+
+/// One of the built-in calendars, along with a set of [LocalFeast]s that it owns.
+/// ```
+/// # use std::collections::HashMap;
+/// # use crate::calendar::{CalendarWithLocalFeasts, Date, Feast, HolyDayId, LiturgicalDayId, LocalFeast, Rank, Time, LFF2018_CALENDAR};
+/// # use language::Language;
+/// let local = CalendarWithLocalFeasts::new(
+///     LFF2018_CALENDAR,
+///     vec![LocalFeast {
+///         id: 1,
+///         date: HolyDayId::Date(7, 15),
+///         time: Time::AllDay,
+///         until: None,
+///         rank: Rank::HolyDay,
+///         season: None,
+///         color: None,
+///         names: HashMap::from([(Language::En, "Saint Swithun".to_string())]),
+///     }],
+/// );
+/// let calendar = local.as_calendar();
+/// let day = calendar.liturgical_day(Date::from_ymd(2020, 7, 15), false);
+/// assert_eq!(day.observed, LiturgicalDayId::Feast(Feast::Local(1)));
+/// ```
+pub struct CalendarWithLocalFeasts {
+    base: Calendar<'static>,
+    feasts: Vec<LocalFeast>,
+}
+
+impl CalendarWithLocalFeasts {
+    pub fn new(base: Calendar<'static>, feasts: Vec<LocalFeast>) -> Self {
+        Self { base, feasts }
+    }
+
+    /// The base calendar, including the local feasts
+    pub fn as_calendar(&self) -> Calendar<'_> {
+        self.base.with_local_feasts(&self.feasts)
+    }
+
+    pub fn feasts(&self) -> &[LocalFeast] {
+        &self.feasts
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Date, LiturgicalDayId, Weekday, BCP1979_CALENDAR, LFF2018_CALENDAR};
+
+    fn diocesan_saint(rank: Rank) -> LocalFeast {
+        LocalFeast {
+            id: 2,
+            date: HolyDayId::Date(10, 13),
+            time: Time::AllDay,
+            until: None,
+            rank,
+            season: None,
+            color: None,
+            names: HashMap::from([(Language::En, "Saint Edward the Confessor".to_string())]),
+        }
+    }
+
+    #[test]
+    fn lesser_local_feast_is_commemorated() {
+        // 10/13/2021 is a Wednesday
+        let feasts = [diocesan_saint(Rank::OptionalObservance)];
+        let calendar = BCP1979_CALENDAR.with_local_feasts(&feasts);
+        let day = calendar.liturgical_day(Date::from_ymd(2021, 10, 13), false);
+        assert_ne!(day.observed, LiturgicalDayId::Feast(Feast::Local(2)));
+        assert_eq!(day.holy_days, vec![Feast::Local(2)]);
+
+        // 10/13/2019 is a Sunday, which takes precedence and is not transferred
+        let day = calendar.liturgical_day(Date::from_ymd(2019, 10, 13), false);
+        assert_eq!(day.weekday, Weekday::Sun);
+        assert!(!day.holy_days.contains(&Feast::Local(2)));
+        let day = calendar.liturgical_day(Date::from_ymd(2019, 10, 14), false);
+        assert_ne!(
+            day.observed,
+            LiturgicalDayId::TransferredFeast(Feast::Local(2))
+        );
+    }
+
+    #[test]
+    fn local_feasts_layer_onto_lff() {
+        let local =
+            CalendarWithLocalFeasts::new(LFF2018_CALENDAR, vec![diocesan_saint(Rank::HolyDay)]);
+        let calendar = local.as_calendar();
+        let day = calendar.liturgical_day(Date::from_ymd(2021, 10, 13), false);
+        assert_eq!(day.observed, LiturgicalDayId::Feast(Feast::Local(2)));
+        // feasts from the base calendar, and its fallback, are unaffected
+        assert_eq!(
+            calendar.feast_name(Feast::ChristmasDay, Language::En),
+            LFF2018_CALENDAR.feast_name(Feast::ChristmasDay, Language::En)
+        );
+        let christmas = calendar.liturgical_day(Date::from_ymd(2020, 12, 25), false);
+        assert_eq!(
+            christmas.observed,
+            LiturgicalDayId::Feast(Feast::ChristmasDay)
+        );
+    }
+}
@@ -0,0 +1,198 @@
+use std::{cell::RefCell, collections::HashMap, convert::TryInto};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{Calendar, Computus, Cycle, Date, Feast, HolyDay, HolyDayId};
+
+/// The period following a major feast during which it continues to be celebrated: an octave
+/// (the feast and the seven days after it), or a shorter or longer “week of” the feast.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Octave {
+    /// The feast being celebrated
+    pub feast: Feast,
+    /// The date on which the feast itself falls
+    pub feast_date: Date,
+    /// The last day of the period (for a true octave, the eighth day counting the feast itself)
+    pub octave_day: Date,
+}
+
+impl Octave {
+    /// Which day of the octave a date is, counting the feast itself as the first day.
+    /// ```
+    /// # use calendar::{BCP1979_CALENDAR, Date};
+    /// let octave = BCP1979_CALENDAR.octave(Date::from_ymd(2022, 12, 28)).unwrap();
+    /// assert_eq!(octave.day_of_octave(Date::from_ymd(2022, 12, 28)), Some(4));
+    /// assert_eq!(octave.day_of_octave(Date::from_ymd(2023, 1, 1)), Some(8));
+    /// assert_eq!(octave.day_of_octave(Date::from_ymd(2023, 1, 2)), None);
+    /// ```
+    pub fn day_of_octave(&self, date: Date) -> Option<u8> {
+        if date < self.feast_date || date > self.octave_day {
+            None
+        } else {
+            ((date - self.feast_date).num_days() + 1).try_into().ok()
+        }
+    }
+}
+
+type OctaveCache = HashMap<(HolyDay, Computus, usize, u8, u16), Option<Octave>>;
+
+thread_local! {
+    /// Octaves already calculated, keyed by the holy day, the parts of a [Calendar] that determine
+    /// the dates of the Easter cycle (its computus, and the address of its static table of weeks
+    /// along with the index of the week in which the cycle begins), and the year of the feast
+    static OCTAVES: RefCell<OctaveCache> =
+        RefCell::new(HashMap::new());
+}
+
+impl Calendar<'_> {
+    /// The [Octave] within which a date falls, if any, according to the holy days
+    /// that this calendar keeps with octaves.
+    /// ```
+    /// # use calendar::{BCP1979_CALENDAR, Date, Feast};
+    /// let octave = BCP1979_CALENDAR.octave(Date::from_ymd(2022, 12, 28)).unwrap();
+    /// assert_eq!(octave.feast, Feast::ChristmasDay);
+    /// assert_eq!(octave.feast_date, Date::from_ymd(2022, 12, 25));
+    /// assert_eq!(octave.octave_day, Date::from_ymd(2023, 1, 1));
+    /// assert_eq!(BCP1979_CALENDAR.octave(Date::from_ymd(2023, 1, 2)), None);
+    /// ```
+    pub fn octave(&self, date: Date) -> Option<Octave> {
+        self.octaves
+            .iter()
+            .filter_map(|holy_day| {
+                // the feast on or before this date, whether this year or (e.g., for Christmas) last year
+                [date.year(), date.year() - 1]
+                    .iter()
+                    .filter_map(|year| self.octave_in_year(holy_day, *year))
+                    .find(|octave| octave.feast_date <= date)
+            })
+            .filter(|octave| date <= octave.octave_day)
+            // if two periods overlap, the more recent feast takes precedence
+            .max_by_key(|octave| octave.feast_date)
+    }
+
+    /// A copy of this calendar that keeps octaves for a different set of holy days,
+    /// each of which gives the day on which its octave ends.
+    /// ```
+    /// # use calendar::{BCP1979_CALENDAR, Date, Feast, HolyDay, HolyDayId, LiturgicalWeek, Rank, Sanctoral, Season, Weekday};
+    /// const OCTAVES: [HolyDay; 3] = [
+    ///     HolyDay::new(Feast::ChristmasDay, Sanctoral::BCP1979, Rank::PrincipalFeast, Some(Season::Christmas))
+    ///         .with_octave(HolyDayId::Date(1, 1)),
+    ///     HolyDay::new(Feast::EasterSunday, Sanctoral::BCP1979, Rank::PrincipalFeast, Some(Season::Easter))
+    ///         .with_octave(HolyDayId::SpecialDay(LiturgicalWeek::Easter2, Weekday::Sun)),
+    ///     HolyDay::new(Feast::AllSaintsDay, Sanctoral::BCP1979, Rank::PrincipalFeast, None)
+    ///         .with_octave(HolyDayId::Date(11, 8)),
+    /// ];
+    /// let calendar = BCP1979_CALENDAR.with_octaves(&OCTAVES);
+    /// let day = calendar.liturgical_day(Date::from_ymd(2022, 11, 4), false);
+    /// assert_eq!(day.octave.map(|octave| octave.feast), Some(Feast::AllSaintsDay));
+    /// let day = BCP1979_CALENDAR.liturgical_day(Date::from_ymd(2022, 11, 4), false);
+    /// assert_eq!(day.octave, None);
+    /// ```
+    #[must_use]
+    pub fn with_octaves(self, octaves: &'static [HolyDay]) -> Self {
+        Self { octaves, ..self }
+    }
+
+    /// The octave of a holy day that falls in the given year, calculated once
+    /// for each holy day and year
+    fn octave_in_year(&self, holy_day: &HolyDay, year: u16) -> Option<Octave> {
+        let key = (
+            *holy_day,
+            self.computus,
+            self.weeks.as_ptr() as usize,
+            self.easter_cycle_begins,
+            year,
+        );
+        OCTAVES.with(|octaves| {
+            *octaves
+                .borrow_mut()
+                .entry(key)
+                .or_insert_with(|| self.calculate_octave_in_year(holy_day, year))
+        })
+    }
+
+    fn calculate_octave_in_year(&self, holy_day: &HolyDay, year: u16) -> Option<Octave> {
+        let feast = holy_day.feast();
+        // the date of the feast is given by the holy days of its own sanctoral cycle
+        let calendar = holy_day.sanctoral().calendar();
+        let (id, _, _, _) = calendar
+            .holy_days
+            .iter()
+            .chain(
+                calendar
+                    .holy_days_fallback
+                    .into_iter()
+                    .flat_map(|fallback| fallback.holy_days.iter()),
+            )
+            .find(|(_, s_feast, _, _)| *s_feast == feast)?;
+        let feast_date = self.holy_day_date_in_year(*id, year)?;
+        let octave_day = holy_day.octave()?;
+        let octave_day = self
+            .holy_day_date_in_year(octave_day, year)
+            .filter(|octave_day| *octave_day >= feast_date)
+            .or_else(|| self.holy_day_date_in_year(octave_day, year + 1))?;
+        Some(Octave {
+            feast,
+            feast_date,
+            octave_day,
+        })
+    }
+
+    /// The date of a holy day in the given year, if it falls on a fixed date (like Christmas)
+    /// or on a fixed day of the Easter cycle (like Easter itself)
+    fn holy_day_date_in_year(&self, id: HolyDayId, year: u16) -> Option<Date> {
+        match id {
+            HolyDayId::Date(month, day) => Some(Date::from_ymd(year, month, day)),
+            HolyDayId::SpecialDay(week, weekday) => {
+                let (_, week_idx, _) = self
+                    .weeks
+                    .iter()
+                    .find(|(cycle, _, s_week)| *cycle == Cycle::Easter && *s_week == week)?;
+                let easter = self.computus.easter_in_year(year.into());
+                let sunday =
+                    easter.add_weeks(i64::from(*week_idx) - i64::from(self.easter_cycle_begins));
+                Some(sunday.add_days(weekday.num_days_from_sunday()))
+            }
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Date, Feast, LFF2018_CALENDAR};
+
+    #[test]
+    fn easter_octave_runs_through_second_sunday() {
+        // Easter 2022 was April 17
+        for day in 17..=24 {
+            let octave = LFF2018_CALENDAR.octave(Date::from_ymd(2022, 4, day));
+            assert_eq!(octave.map(|octave| octave.feast), Some(Feast::EasterSunday));
+            assert_eq!(
+                octave.map(|octave| octave.octave_day),
+                Some(Date::from_ymd(2022, 4, 24))
+            );
+        }
+        assert_eq!(LFF2018_CALENDAR.octave(Date::from_ymd(2022, 4, 16)), None);
+        assert_eq!(LFF2018_CALENDAR.octave(Date::from_ymd(2022, 4, 25)), None);
+    }
+
+    #[test]
+    fn christmas_octave_runs_into_new_year() {
+        let octave = LFF2018_CALENDAR.octave(Date::from_ymd(2023, 1, 1)).unwrap();
+        assert_eq!(octave.feast, Feast::ChristmasDay);
+        assert_eq!(octave.feast_date, Date::from_ymd(2022, 12, 25));
+        assert_eq!(LFF2018_CALENDAR.octave(Date::from_ymd(2022, 12, 24)), None);
+    }
+
+    #[test]
+    fn octave_does_not_change_observance() {
+        // the Holy Innocents are still observed within the octave of Christmas
+        let day = LFF2018_CALENDAR.liturgical_day(Date::from_ymd(2022, 12, 28), false);
+        assert_eq!(day.observed, Feast::HolyInnocents.into());
+        assert_eq!(
+            day.octave.map(|octave| octave.feast),
+            Some(Feast::ChristmasDay)
+        );
+    }
+}
@@ -1,7 +1,21 @@
 use serde::{Deserialize, Serialize};
 
+use crate::{Calendar, BCP1979_CALENDAR, COMMON_WORSHIP_CALENDAR, LFF2018_CALENDAR};
+
 #[derive(Copy, Clone, Debug, Serialize, Deserialize, PartialEq, Eq, Hash)]
 pub enum Sanctoral {
     BCP1979,
     LFF2018,
+    CommonWorship,
+}
+
+impl Sanctoral {
+    /// The built-in [Calendar] whose holy days make up this sanctoral cycle
+    pub(crate) fn calendar(&self) -> &'static Calendar<'static> {
+        match self {
+            Sanctoral::BCP1979 => &BCP1979_CALENDAR,
+            Sanctoral::LFF2018 => &LFF2018_CALENDAR,
+            Sanctoral::CommonWorship => &COMMON_WORSHIP_CALENDAR,
+        }
+    }
 }
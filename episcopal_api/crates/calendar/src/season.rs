@@ -13,6 +13,8 @@ pub enum Season {
     Pentecost,
     Trinity,
     OrdinaryTime,
+    /// The Sundays before Advent, from All Saints’ Day to Christ the King (Common Worship)
+    Kingdom,
     Saints,
     Ember,
     National,
@@ -38,6 +40,7 @@ impl Season {
                 | Season::Pentecost
                 | Season::Trinity
                 | Season::OrdinaryTime
+                | Season::Kingdom
         )
     }
 }
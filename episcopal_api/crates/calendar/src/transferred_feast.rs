@@ -5,7 +5,7 @@ use crate::{
     Rank, Time, Weekday,
 };
 
-impl Calendar {
+impl Calendar<'_> {
     /// Checks whether any feasts that would have occurred on a Sunday, during Holy Week,
     /// or during the week of Easter should be transferred forward to the next open day,
     /// and therefore override the propers a particular liturgical day:
@@ -23,9 +23,19 @@ impl Calendar {
     ///
     /// ```
     pub fn transferred_feast(&self, day: &LiturgicalDay) -> Option<Feast> {
-        let date = day.date;
         let yesterday =
-            self.liturgical_day_without_transferred_feasts(date.subtract_days(1), false);
+            self.liturgical_day_without_transferred_feasts(day.date.subtract_days(1), false);
+        self.transferred_feast_after(day, &yesterday)
+    }
+
+    /// Like [Calendar::transferred_feast], but reuses an already-generated (morning) [LiturgicalDay]
+    /// for the day before, as given by [Calendar::liturgical_day_without_transferred_feasts].
+    pub(crate) fn transferred_feast_after(
+        &self,
+        day: &LiturgicalDay,
+        yesterday: &LiturgicalDay,
+    ) -> Option<Feast> {
+        let date = day.date;
 
         // All Saints’ Sunday should be marked as a transferred All Saints’, not simply an observed All Saints’
         if date.month() == 11
@@ -75,18 +85,17 @@ impl Calendar {
                     let subtracted_date = date.subtract_days(delta);
                     let month = subtracted_date.month();
                     let day = subtracted_date.day();
-                    self.holy_days
-                        .iter()
+                    self.kalendar_entries()
                         .filter_map(move |(id, feast, time, stops_at_sunday)| {
                             if let HolyDayId::Date(s_month, s_day) = id {
-                                if *time == Time::AllDay
-                                    && month == *s_month
-                                    && day == *s_day
-                                    && self.feast_day_rank(feast) == Rank::HolyDay
+                                if time == Time::AllDay
+                                    && month == s_month
+                                    && day == s_day
+                                    && self.feast_day_rank(&feast) == Rank::HolyDay
                                     && (stops_at_sunday.is_none()
                                         || stops_at_sunday.unwrap() < week)
                                 {
-                                    Some((Date::from_ymd(date.year(), month, day), *feast))
+                                    Some((Date::from_ymd(date.year(), month, day), feast))
                                 } else {
                                     None
                                 }
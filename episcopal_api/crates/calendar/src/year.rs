@@ -1,4 +1,6 @@
 use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+use strum_macros::{Display, EnumString};
 
 use crate::{Date, LiturgicalWeek};
 
@@ -19,9 +21,48 @@ pub enum Year {
     Any,
 }
 
+impl std::fmt::Display for Year {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Year::DailyOffice(year) => write!(f, "DailyOffice({})", year),
+            Year::Rcl(year) => write!(f, "Rcl({})", year),
+            Year::Any => f.write_str("Any"),
+        }
+    }
+}
+
+impl FromStr for Year {
+    type Err = strum::ParseError;
+
+    /// Parses the form written by [Display](std::fmt::Display), like `Rcl(A)` or `Any`
+    /// ```
+    /// # use std::str::FromStr;
+    /// # use calendar::{DailyOfficeYear, RCLYear, Year};
+    /// assert_eq!(Year::from_str("Rcl(A)"), Ok(Year::Rcl(RCLYear::A)));
+    /// assert_eq!(Year::from_str(&Year::DailyOffice(DailyOfficeYear::Two).to_string()), Ok(Year::DailyOffice(DailyOfficeYear::Two)));
+    /// assert_eq!(Year::from_str("Any"), Ok(Year::Any));
+    /// assert!(Year::from_str("Rcl(D)").is_err());
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        if s == "Any" {
+            Ok(Year::Any)
+        } else if let Some(year) = s.strip_prefix("Rcl(").and_then(|s| s.strip_suffix(')')) {
+            year.parse().map(Year::Rcl)
+        } else if let Some(year) = s
+            .strip_prefix("DailyOffice(")
+            .and_then(|s| s.strip_suffix(')'))
+        {
+            year.parse().map(Year::DailyOffice)
+        } else {
+            Err(strum::ParseError::VariantNotFound)
+        }
+    }
+}
+
 /// The year in the 1979 Book of Common Prayer Daily Office lectionary, following the calculation
 /// of [BCP p. 934](https://www.episcopalchurch.org/wp-content/uploads/sites/2/2019/11/bcp_compressed.pdf#page=934)
-#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug, Deserialize, Serialize)]
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug, Deserialize, Serialize, Display, EnumString)]
 pub enum DailyOfficeYear {
     One,
     Two,
@@ -54,7 +95,7 @@ impl DailyOfficeYear {
 
 /// The year in the Revised Common Lectionary, following the calculation
 /// of [BCP p. 888](https://www.episcopalchurch.org/wp-content/uploads/sites/2/2019/11/bcp_compressed.pdf#page=888)
-#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug, Deserialize, Serialize)]
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug, Deserialize, Serialize, Display, EnumString)]
 pub enum RCLYear {
     A,
     B,
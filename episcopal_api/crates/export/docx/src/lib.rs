@@ -109,6 +109,9 @@ fn add_content(docx: Docx, doc: &Document) -> Docx {
         Content::CollectOfTheDay { allow_multiple: _ } => {
             docx.add_paragraph(paragraph_with_text("The Collect of the Day").style(HEADING_3))
         }
+        Content::PrefaceOfTheDay => {
+            docx.add_paragraph(paragraph_with_text("The Proper Preface").style(HEADING_3))
+        }
         Content::DocumentLink { .. } => docx,
         Content::Empty => docx,
         Content::Error(content) => content.add_to_docx(docx),
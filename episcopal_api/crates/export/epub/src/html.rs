@@ -0,0 +1,761 @@
+use liturgy::*;
+
+/// Builds the XHTML body of a chapter, keeping track of the headings that should appear
+/// in the book's table of contents
+#[derive(Clone, Debug, Default)]
+pub(crate) struct Html {
+    pub body: String,
+    pub headings: Vec<(String, String)>,
+}
+
+impl Html {
+    pub fn push(&mut self, element: String) {
+        self.body.push_str(&element);
+        self.body.push('\n');
+    }
+
+    /// Adds a heading with an anchor, which will be listed in the table of contents
+    pub fn push_nav_heading(&mut self, tag: &str, label: &str) {
+        let id = format!("h{}", self.headings.len() + 1);
+        self.push(format!(
+            r#"<{} id="{}">{}</{}>"#,
+            tag,
+            id,
+            escape(label),
+            tag
+        ));
+        self.headings.push((id, label.to_string()));
+    }
+
+    pub fn add_content(&mut self, doc: &Document) {
+        if doc.display == Show::Hidden {
+            return;
+        }
+
+        if let Some(label) = &doc.label {
+            self.push(element("h3", "", label));
+        }
+        if let Some(subtitle) = &doc.subtitle {
+            self.push(element("p", "subtitle", subtitle));
+        }
+
+        match &doc.content {
+            Content::Liturgy(liturgy) => {
+                for child in liturgy.body.iter() {
+                    self.add_content(child);
+                }
+            }
+            Content::Series(series) => {
+                for child in series.iter() {
+                    self.add_content(child);
+                }
+            }
+            Content::Parallel(parallel) => {
+                for child in parallel.iter() {
+                    let version = child
+                        .version_label
+                        .clone()
+                        .unwrap_or_else(|| child.version.to_string());
+                    self.push(r#"<div class="parallel">"#.to_string());
+                    self.push(element("h4", "version", &version));
+                    self.add_content(child);
+                    self.push("</div>".to_string());
+                }
+            }
+            Content::Choice(choice) => {
+                if let Some(selected) = choice.options.get(choice.selected) {
+                    self.add_content(selected);
+                }
+            }
+            Content::CollectOfTheDay { allow_multiple: _ } => {
+                self.push(element("h3", "", "The Collect of the Day"))
+            }
+            Content::PrefaceOfTheDay => self.push(element("h3", "", "The Proper Preface")),
+            Content::DocumentLink { .. } => {}
+            Content::Empty => {}
+            Content::Error(error) => self.push(element("p", "error", &error.to_string())),
+            Content::Antiphon(antiphon) => {
+                self.push(element("p", "antiphon", &antiphon.to_string()))
+            }
+            Content::BiblicalCitation(citation) => {
+                self.push(element("p", "citation", &citation.to_string()))
+            }
+            Content::BiblicalReading(reading) => self.add_biblical_reading(reading),
+            Content::Canticle(canticle) => self.add_canticle(canticle),
+            Content::CanticleTableEntry(_) => {}
+            Content::GloriaPatri(gloria_patri) => self.add_gloria_patri(gloria_patri),
+            Content::Heading(heading) => self.add_heading(heading),
+            Content::HymnLink(link) => {
+                if let HymnLink::Hymn(hymnal, number) = link {
+                    self.push(element("p", "hymn", &format!("{} {}", hymnal, number)));
+                }
+            }
+            Content::Invitatory(invitatory) => self.add_invitatory(invitatory),
+            Content::LectionaryReading(_) => {}
+            Content::Litany(litany) => {
+                for line in litany.iter() {
+                    self.push(format!(
+                        r#"<p class="litany">{}<br/><strong class="response">{}</strong></p>"#,
+                        lines(line),
+                        lines(&litany.response)
+                    ));
+                }
+            }
+            Content::Preces(preces) => {
+                let rows = preces
+                    .iter()
+                    .enumerate()
+                    .map(|(idx, (label, text))| {
+                        let text = if idx % 2 == 1 {
+                            format!(r#"<strong class="response">{}</strong>"#, lines(text))
+                        } else {
+                            lines(text)
+                        };
+                        format!(
+                            r#"<tr><td class="label"><em>{}</em></td><td>{}</td></tr>"#,
+                            escape(label),
+                            text
+                        )
+                    })
+                    .collect::<String>();
+                self.push(format!(r#"<table class="preces">{}</table>"#, rows));
+            }
+            Content::Psalm(psalm) => self.add_psalm(psalm),
+            Content::PsalmCitation(citation) => {
+                self.push(element("p", "citation", &citation.to_string()))
+            }
+            Content::ResponsivePrayer(prayer) => {
+                let lines = prayer
+                    .iter()
+                    .enumerate()
+                    .map(|(idx, line)| {
+                        if idx % 2 == 1 {
+                            format!(r#"<strong class="response">{}</strong>"#, lines(line))
+                        } else {
+                            lines(line)
+                        }
+                    })
+                    .collect::<Vec<_>>()
+                    .join("<br/>");
+                self.push(format!(r#"<p class="responsive-prayer">{}</p>"#, lines));
+            }
+            Content::Rubric(rubric) => self.push(element("p", "rubric", &rubric.to_string())),
+            Content::Sentence(sentence) => self.add_sentence(sentence),
+            Content::Text(text) => {
+                let response = text
+                    .response
+                    .as_ref()
+                    .map(|response| {
+                        format!(r#" <strong class="response">{}</strong>"#, lines(response))
+                    })
+                    .unwrap_or_default();
+                self.push(format!("<p>{}{}</p>", lines(&text.text), response));
+            }
+        }
+    }
+
+    fn add_biblical_reading(&mut self, reading: &BiblicalReading) {
+        if let Some(intro) = &reading.intro {
+            self.add_content(&Document::from(intro.clone()));
+        }
+
+        self.push(element("h3", "", &reading.citation));
+
+        // paragraph breaks are marked by a double line break at the end of a verse
+        let mut paragraph = String::new();
+        for (verse, text) in &reading.text {
+            paragraph.push_str(&format!(
+                r#"<sup class="verse-number">{}</sup>{} "#,
+                verse.verse,
+                escape(text.trim())
+            ));
+            if text.ends_with("\n\n") {
+                self.push(format!("<p>{}</p>", paragraph.trim_end()));
+                paragraph.clear();
+            }
+        }
+        if !paragraph.is_empty() {
+            self.push(format!("<p>{}</p>", paragraph.trim_end()));
+        }
+    }
+
+    fn add_canticle(&mut self, canticle: &Canticle) {
+        self.push(element(
+            "h3",
+            "",
+            &format!("{}. {}", canticle.number, canticle.local_name),
+        ));
+        if let Some(latin_name) = &canticle.latin_name {
+            self.push(element("p", "latin-name", latin_name));
+        }
+        if let Some(citation) = &canticle.citation {
+            self.push(element("p", "citation", citation));
+        }
+        if let Some(rubric) = &canticle.rubric {
+            self.push(element("p", "rubric", rubric));
+        }
+        for section in &canticle.sections {
+            if let Some(title) = &section.title {
+                self.push(element("h4", "", title));
+            }
+            for verse in &section.verses {
+                self.push(verse_element(None, &verse.a, &verse.b));
+            }
+        }
+        if let Some(gloria_patri) = &canticle.gloria_patri {
+            self.add_gloria_patri(gloria_patri);
+        }
+    }
+
+    fn add_gloria_patri(&mut self, gloria_patri: &GloriaPatri) {
+        let (a, b, c, d) = &gloria_patri.text;
+        self.push(verse_element(
+            None,
+            &format!("{} {}", a.trim(), b.trim()),
+            &format!("{} {}", c.trim(), d.trim()),
+        ));
+    }
+
+    fn add_heading(&mut self, heading: &Heading) {
+        match heading {
+            Heading::InsertDate => {}
+            Heading::InsertDay => {}
+            Heading::Date(date) => self.push(element("p", "date", date)),
+            Heading::Day {
+                name,
+                proper,
+                holy_days,
+            } => {
+                self.push(element("h2", "day", name));
+                if let Some(proper) = proper {
+                    self.push(element("p", "proper", proper));
+                }
+                if let Some(holy_days) = holy_days {
+                    let holy_days = holy_days
+                        .iter()
+                        .map(|(_, name)| escape(name))
+                        .collect::<Vec<_>>()
+                        .join("<br/>");
+                    self.push(format!(r#"<p class="holy-days">{}</p>"#, holy_days));
+                }
+            }
+            Heading::Text(level, text) => {
+                let text = text.split_whitespace().collect::<Vec<_>>().join(" ");
+                match level {
+                    HeadingLevel::Heading1 => self.push(element("h1", "", &text)),
+                    // major divisions of a liturgy (e.g., "The Lessons") are listed in the table of contents
+                    HeadingLevel::Heading2 => self.push_nav_heading("h2", &text),
+                    HeadingLevel::Heading3 => self.push(element("h3", "", &text)),
+                    HeadingLevel::Heading4 => self.push(element("h4", "", &text)),
+                    HeadingLevel::Heading5 => self.push(element("h5", "", &text)),
+                }
+            }
+        }
+    }
+
+    fn add_invitatory(&mut self, invitatory: &Invitatory) {
+        self.push(element("h3", "", &invitatory.local_name));
+        if let Some(latin_name) = &invitatory.latin_name {
+            self.push(element("p", "latin-name", latin_name));
+        }
+        if let Some(citation) = &invitatory.citation {
+            self.push(element("p", "citation", citation));
+        }
+        if let SeasonalAntiphon::Antiphon(antiphon) = &invitatory.antiphon {
+            self.push(element("p", "antiphon", &antiphon.to_string()));
+        }
+        for section in &invitatory.sections {
+            for verse in &section.verses {
+                self.push(verse_element(None, &verse.a, &verse.b));
+            }
+        }
+        if let Some(gloria_patri) = &invitatory.gloria_patri {
+            self.add_gloria_patri(gloria_patri);
+        }
+        if let SeasonalAntiphon::Antiphon(antiphon) = &invitatory.antiphon {
+            self.push(element("p", "antiphon", &antiphon.to_string()));
+        }
+    }
+
+    pub fn add_psalm(&mut self, psalm: &Psalm) {
+        let heading = format!("Psalm {}", psalm.number);
+        self.push(element("h3", "", &heading));
+        if let Some(citation) = psalm
+            .citation
+            .as_ref()
+            .filter(|citation| **citation != heading)
+        {
+            self.push(element("p", "citation", citation));
+        }
+        for section in psalm.filtered_sections() {
+            if !section.local_name.is_empty() || !section.latin_name.is_empty() {
+                let local_name = if section.local_name.is_empty() {
+                    String::new()
+                } else {
+                    format!("{} ", escape(&section.local_name))
+                };
+                self.push(format!(
+                    r#"<p class="psalm-section">{}<em class="latin-name">{}</em></p>"#,
+                    local_name,
+                    escape(&section.latin_name)
+                ));
+            }
+            for verse in &section.verses {
+                self.push(verse_element(Some(verse.number), &verse.a, &verse.b));
+            }
+        }
+    }
+
+    fn add_sentence(&mut self, sentence: &Sentence) {
+        match sentence
+            .response
+            .as_deref()
+            .map(|response| &response.content)
+        {
+            // short responses, like "Alleluia," follow the sentence on the same line
+            Some(Content::Text(response)) if response.text.len() < 10 => self.push(format!(
+                r#"<p>{} <strong class="response">{}</strong></p>"#,
+                lines(&sentence.text),
+                escape(&response.text)
+            )),
+            Some(_) => {
+                self.push(format!("<p>{}</p>", lines(&sentence.text)));
+                if let Some(response) = &sentence.response {
+                    self.add_content(response);
+                }
+            }
+            None => self.push(format!("<p>{}</p>", lines(&sentence.text))),
+        }
+        if let Some(citation) = &sentence.citation {
+            self.push(element("p", "citation", citation));
+        }
+    }
+}
+
+/// A psalm or canticle verse, with an asterisk marking the division between its halves
+fn verse_element(number: Option<u8>, a: &str, b: &str) -> String {
+    // some texts already include the asterisk at the end of the first half-verse
+    let a = a.trim().trim_end_matches('*').trim_end();
+    let number = number
+        .map(|number| format!(r#"<sup class="verse-number">{}</sup> "#, number))
+        .unwrap_or_default();
+    if b.trim().is_empty() {
+        format!(r#"<p class="verse">{}{}</p>"#, number, lines(a))
+    } else {
+        format!(
+            r#"<p class="verse">{}{}&#160;<span class="pointing">*</span><br/><span class="half-verse">{}</span></p>"#,
+            number,
+            lines(a),
+            lines(b.trim())
+        )
+    }
+}
+
+fn element(tag: &str, class: &str, text: &str) -> String {
+    if class.is_empty() {
+        format!("<{}>{}</{}>", tag, lines(text.trim()), tag)
+    } else {
+        format!(
+            r#"<{} class="{}">{}</{}>"#,
+            tag,
+            class,
+            lines(text.trim()),
+            tag
+        )
+    }
+}
+
+/// Escapes text, preserving line breaks
+fn lines(text: &str) -> String {
+    text.split('\n')
+        .map(|line| escape(line.trim_end()))
+        .collect::<Vec<_>>()
+        .join("<br/>")
+}
+
+pub(crate) fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use calendar::Feast;
+    use canticle_table::{CanticleId, CanticleNumber};
+    use hymnal::{HymnNumber, Hymnals};
+    use reference_parser::{BibleVerse, BibleVersePart, Book};
+
+    fn html(doc: impl Into<Document>) -> String {
+        let mut html = Html::default();
+        html.add_content(&doc.into());
+        html.body
+    }
+
+    /// The expected body, one element per line
+    fn body(elements: &[&str]) -> String {
+        elements
+            .iter()
+            .map(|element| format!("{}\n", element))
+            .collect()
+    }
+
+    fn gloria_patri() -> GloriaPatri {
+        GloriaPatri::from((
+            "Glory to the Father, and to the Son,",
+            "and to the Holy Spirit: *",
+            "as it was in the beginning, is now,",
+            "and will be for ever. Amen.",
+        ))
+    }
+
+    const GLORIA_PATRI: &str = r#"<p class="verse">Glory to the Father, and to the Son, and to the Holy Spirit:&#160;<span class="pointing">*</span><br/><span class="half-verse">as it was in the beginning, is now, and will be for ever. Amen.</span></p>"#;
+
+    fn psalm() -> Psalm {
+        Psalm {
+            number: 117,
+            citation: None,
+            sections: vec![PsalmSection {
+                reference: Reference::from(760),
+                local_name: String::new(),
+                latin_name: "Laudate Dominum".into(),
+                verses: vec![
+                    PsalmVerse {
+                        number: 1,
+                        a: "Praise the LORD, all you nations; *".into(),
+                        b: "laud him, all you peoples.".into(),
+                    },
+                    PsalmVerse {
+                        number: 2,
+                        a: "For his loving-kindness toward us is great, *".into(),
+                        b: "and the faithfulness of the LORD endures for ever.\nHallelujah!".into(),
+                    },
+                ],
+            }],
+        }
+    }
+
+    #[test]
+    fn psalm_has_verse_numbers_and_asterisks() {
+        assert_eq!(
+            html(psalm()),
+            body(&[
+                "<h3>Psalm 117</h3>",
+                r#"<p class="psalm-section"><em class="latin-name">Laudate Dominum</em></p>"#,
+                r#"<p class="verse"><sup class="verse-number">1</sup> Praise the LORD, all you nations;&#160;<span class="pointing">*</span><br/><span class="half-verse">laud him, all you peoples.</span></p>"#,
+                r#"<p class="verse"><sup class="verse-number">2</sup> For his loving-kindness toward us is great,&#160;<span class="pointing">*</span><br/><span class="half-verse">and the faithfulness of the LORD endures for ever.<br/>Hallelujah!</span></p>"#,
+            ])
+        );
+    }
+
+    #[test]
+    fn canticle_and_gloria_patri_have_asterisks() {
+        let canticle = Canticle {
+            number: CanticleId::Canticle13,
+            changeable: None,
+            citation: Some("Song of the Three Young Men, 29-34".into()),
+            local_name: "A Song of Praise".into(),
+            latin_name: Some("Benedictus es, Domine".into()),
+            rubric: None,
+            sections: vec![CanticleSection {
+                title: None,
+                verses: vec![CanticleVerse::from((
+                    "Glory to you, Lord God of our fathers; *",
+                    "you are worthy of praise; glory to you.",
+                ))],
+            }],
+            gloria_patri: Some(gloria_patri()),
+        };
+        assert_eq!(
+            html(canticle),
+            body(&[
+                "<h3>13. A Song of Praise</h3>",
+                r#"<p class="latin-name">Benedictus es, Domine</p>"#,
+                r#"<p class="citation">Song of the Three Young Men, 29-34</p>"#,
+                r#"<p class="verse">Glory to you, Lord God of our fathers;&#160;<span class="pointing">*</span><br/><span class="half-verse">you are worthy of praise; glory to you.</span></p>"#,
+                GLORIA_PATRI,
+            ])
+        );
+        assert_eq!(html(gloria_patri()), body(&[GLORIA_PATRI]));
+    }
+
+    #[test]
+    fn invitatory_repeats_its_antiphon() {
+        let invitatory = Invitatory {
+            local_name: "Jubilate".into(),
+            latin_name: None,
+            citation: Some("Psalm 100".into()),
+            antiphon: SeasonalAntiphon::Antiphon(Antiphon::from("The Lord is risen indeed.")),
+            sections: vec![InvitatorySection {
+                verses: vec![InvitatoryVerse {
+                    a: "Be joyful in the LORD, all you lands; *".into(),
+                    b: "serve the LORD with gladness.".into(),
+                }],
+            }],
+            gloria_patri: None,
+        };
+        assert_eq!(
+            html(invitatory),
+            body(&[
+                "<h3>Jubilate</h3>",
+                r#"<p class="citation">Psalm 100</p>"#,
+                r#"<p class="antiphon">The Lord is risen indeed.</p>"#,
+                r#"<p class="verse">Be joyful in the LORD, all you lands;&#160;<span class="pointing">*</span><br/><span class="half-verse">serve the LORD with gladness.</span></p>"#,
+                r#"<p class="antiphon">The Lord is risen indeed.</p>"#,
+            ])
+        );
+    }
+
+    #[test]
+    fn responses_are_bold() {
+        assert_eq!(
+            html(ResponsivePrayer::from([
+                "Show us your mercy, O Lord;",
+                "And grant us your salvation."
+            ])),
+            body(&[
+                r#"<p class="responsive-prayer">Show us your mercy, O Lord;<br/><strong class="response">And grant us your salvation.</strong></p>"#
+            ])
+        );
+        assert_eq!(
+            html(Preces::from([
+                ("V.", "Show us your mercy, O Lord;"),
+                ("R.", "And grant us your salvation.")
+            ])),
+            body(&[concat!(
+                r#"<table class="preces">"#,
+                r#"<tr><td class="label"><em>V.</em></td><td>Show us your mercy, O Lord;</td></tr>"#,
+                r#"<tr><td class="label"><em>R.</em></td><td><strong class="response">And grant us your salvation.</strong></td></tr>"#,
+                "</table>"
+            )])
+        );
+        assert_eq!(
+            html(Litany::from((
+                "Lord, have mercy.",
+                ["For the peace of the world,"]
+            ))),
+            body(&[
+                r#"<p class="litany">For the peace of the world,<br/><strong class="response">Lord, have mercy.</strong></p>"#
+            ])
+        );
+        assert_eq!(
+            html(Text::from("The Lord be with you.").response("And also with you.")),
+            body(&[
+                r#"<p>The Lord be with you. <strong class="response">And also with you.</strong></p>"#
+            ])
+        );
+        assert_eq!(
+            html(
+                Sentence::from("Christ is risen from the dead.")
+                    .response(Text::from("Alleluia."))
+                    .citation("1 Corinthians 15:20")
+            ),
+            body(&[
+                r#"<p>Christ is risen from the dead. <strong class="response">Alleluia.</strong></p>"#,
+                r#"<p class="citation">1 Corinthians 15:20</p>"#,
+            ])
+        );
+    }
+
+    #[test]
+    fn choice_shows_only_the_selected_option() {
+        let mut choice = Choice::from([
+            Document::from("The first option."),
+            Document::from("The second option."),
+        ]);
+        choice.selected = 1;
+        assert_eq!(html(choice), body(&["<p>The second option.</p>"]));
+    }
+
+    #[test]
+    fn parallel_labels_each_version() {
+        let parallel = Parallel::from([
+            Document::from("And with thy spirit.").version(Version::RiteI),
+            Document::from("And also with you.")
+                .version(Version::RiteII)
+                .version_label("Contemporary"),
+        ]);
+        assert_eq!(
+            html(parallel),
+            body(&[
+                r#"<div class="parallel">"#,
+                r#"<h4 class="version">Rite I</h4>"#,
+                "<p>And with thy spirit.</p>",
+                "</div>",
+                r#"<div class="parallel">"#,
+                r#"<h4 class="version">Contemporary</h4>"#,
+                "<p>And also with you.</p>",
+                "</div>",
+            ])
+        );
+    }
+
+    #[test]
+    fn series_and_liturgy_render_their_children() {
+        let children = vec![
+            Document::from(Rubric::from("The People stand.")),
+            Document::from(Heading::from((HeadingLevel::Heading3, "The Collect"))),
+        ];
+        let expected = body(&[
+            r#"<p class="rubric">The People stand.</p>"#,
+            "<h3>The Collect</h3>",
+        ]);
+        assert_eq!(html(Series::from(children.clone())), expected);
+        assert_eq!(html(Liturgy::from(children)), expected);
+    }
+
+    #[test]
+    fn headings() {
+        assert_eq!(
+            html(Heading::Day {
+                name: "Tuesday, November 30".into(),
+                proper: Some("Week of the First Sunday of Advent".into()),
+                holy_days: Some(vec![(Feast::Andrew, "Saint Andrew the Apostle".into())]),
+            }),
+            body(&[
+                r#"<h2 class="day">Tuesday, November 30</h2>"#,
+                r#"<p class="proper">Week of the First Sunday of Advent</p>"#,
+                r#"<p class="holy-days">Saint Andrew the Apostle</p>"#,
+            ])
+        );
+        assert_eq!(
+            html(Heading::Date("November 30, 2021".into())),
+            body(&[r#"<p class="date">November 30, 2021</p>"#])
+        );
+        assert_eq!(
+            html(Heading::from((HeadingLevel::Heading1, "Morning   Prayer"))),
+            body(&["<h1>Morning Prayer</h1>"])
+        );
+        assert_eq!(html(Heading::InsertDay), "");
+        assert_eq!(html(Heading::InsertDate), "");
+
+        // second-level headings are listed in the table of contents
+        let mut html = Html::default();
+        html.add_content(&Document::from(Heading::from((
+            HeadingLevel::Heading2,
+            "The Word of God",
+        ))));
+        assert_eq!(html.body, body(&[r#"<h2 id="h1">The Word of God</h2>"#]));
+        assert_eq!(
+            html.headings,
+            vec![("h1".to_string(), "The Word of God".to_string())]
+        );
+    }
+
+    #[test]
+    fn readings_and_citations() {
+        let verse = |verse| BibleVerse {
+            book: Book::John,
+            chapter: 1,
+            verse,
+            verse_part: BibleVersePart::All,
+        };
+        let reading = BiblicalReading {
+            citation: "John 1:1-2".into(),
+            text: vec![
+                (verse(1), "In the beginning was the Word.\n\n".into()),
+                (verse(2), "He was in the beginning with God.".into()),
+            ],
+            intro: Some(BiblicalReadingIntro::from(
+                "A Reading from the Gospel of John.",
+            )),
+        };
+        assert_eq!(
+            html(reading),
+            body(&[
+                "<p>A Reading from the Gospel of John.</p>",
+                "<h3>John 1:1-2</h3>",
+                r#"<p><sup class="verse-number">1</sup>In the beginning was the Word.</p>"#,
+                r#"<p><sup class="verse-number">2</sup>He was in the beginning with God.</p>"#,
+            ])
+        );
+        assert_eq!(
+            html(BiblicalCitation::from("John 1:1-18".to_string())),
+            body(&[r#"<p class="citation">John 1:1-18</p>"#])
+        );
+        assert_eq!(
+            html(PsalmCitation::from("Psalm 117")),
+            body(&[r#"<p class="citation">Psalm 117</p>"#])
+        );
+        assert_eq!(
+            html(Antiphon::from("Alleluia. Christ is risen.")),
+            body(&[r#"<p class="antiphon">Alleluia. Christ is risen.</p>"#])
+        );
+    }
+
+    #[test]
+    fn other_content() {
+        assert_eq!(
+            html(Rubric::from("The People kneel.")),
+            body(&[r#"<p class="rubric">The People kneel.</p>"#])
+        );
+        assert_eq!(
+            html(HymnLink::Hymn(Hymnals::Hymnal1982, HymnNumber::H(390))),
+            body(&[r#"<p class="hymn">H82 390</p>"#])
+        );
+        assert_eq!(html(HymnLink::Hymnals), "");
+        assert_eq!(
+            html(DocumentError::from("No reading found.")),
+            body(&[r#"<p class="error">No reading found.</p>"#])
+        );
+        assert_eq!(
+            html(Content::CollectOfTheDay {
+                allow_multiple: true
+            }),
+            body(&["<h3>The Collect of the Day</h3>"])
+        );
+        assert_eq!(
+            html(Content::PrefaceOfTheDay),
+            body(&["<h3>The Proper Preface</h3>"])
+        );
+    }
+
+    #[test]
+    fn placeholders_are_omitted() {
+        assert_eq!(html(Content::Empty), "");
+        assert_eq!(
+            html(Content::DocumentLink {
+                label: "The Great Litany".into(),
+                path: SlugPath::from([Slug::GreatLitany]),
+                rotate: false,
+                link_only: true
+            }),
+            ""
+        );
+        assert_eq!(
+            html(CanticleTableEntry {
+                nth: CanticleNumber::One,
+                table: CanticleTableChoice::Selected(CanticleTables::BCP1979RiteII)
+            }),
+            ""
+        );
+        assert_eq!(html(LectionaryReading::default()), "");
+        assert_eq!(html(Document::from("Not shown.").display(Show::Hidden)), "");
+    }
+
+    #[test]
+    fn label_and_subtitle_come_first() {
+        assert_eq!(
+            html(
+                Document::from("Almighty God, unto whom all hearts are open.")
+                    .label("The Collect for Purity")
+                    .subtitle("said by the Celebrant")
+            ),
+            body(&[
+                "<h3>The Collect for Purity</h3>",
+                r#"<p class="subtitle">said by the Celebrant</p>"#,
+                "<p>Almighty God, unto whom all hearts are open.</p>",
+            ])
+        );
+    }
+
+    #[test]
+    fn escapes_html() {
+        assert_eq!(
+            html(Text::from("Faith & <works>\n\"Amen\"")),
+            body(&["<p>Faith &amp; &lt;works&gt;<br/>&quot;Amen&quot;</p>"])
+        );
+    }
+}
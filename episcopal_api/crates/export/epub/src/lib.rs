@@ -0,0 +1,450 @@
+use std::io::{Seek, Write};
+use thiserror::Error;
+
+use language::Language;
+use library::{Contents, Section};
+use liturgy::*;
+use zip::{write::FileOptions, CompressionMethod, ZipWriter};
+
+mod html;
+use html::*;
+mod psalter;
+pub use crate::psalter::*;
+
+/// An e-book made up of compiled [Document]s, sections of a [TableOfContents](library::TableOfContents),
+/// and/or the Psalter, with a navigable table of contents
+/// ```
+/// # use epub::{Epub, PsalterCycle};
+/// # use library::{CommonPrayer, Library};
+/// # use liturgy::{Slug, SlugPath};
+/// # use psalter::bcp1979::BCP1979_PSALTER;
+/// let office = CommonPrayer::contents()
+///     .contents_at_path(&SlugPath::from([Slug::Office]))
+///     .unwrap();
+/// let book = Epub::new("The Daily Office")
+///     .add_contents(&office)
+///     .add_psalter("The Psalter", &BCP1979_PSALTER, PsalterCycle::ThirtyDay);
+///
+/// let psalter = book.nav().last().unwrap();
+/// assert_eq!(psalter.label, "The Psalter");
+/// assert_eq!(psalter.children.len(), 30);
+/// assert_eq!(psalter.children[0].children[1].label, "Evening Prayer");
+///
+/// let mut file = std::io::Cursor::new(Vec::new());
+/// book.write(&mut file).unwrap();
+/// ```
+#[derive(Clone, Debug)]
+pub struct Epub {
+    title: String,
+    language: Language,
+    identifier: String,
+    chapters: Vec<Chapter>,
+    nav: Vec<NavPoint>,
+}
+
+/// An entry in the e-book's table of contents
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NavPoint {
+    pub label: String,
+    /// Path to the chapter (and, optionally, an anchor within it)
+    pub href: String,
+    pub children: Vec<NavPoint>,
+}
+
+#[derive(Clone, Debug)]
+struct Chapter {
+    file: String,
+    title: String,
+    body: String,
+}
+
+#[derive(Error, Debug)]
+pub enum EpubError {
+    #[error("error writing EPUB file")]
+    Write(#[from] zip::result::ZipError),
+    #[error("error writing EPUB file")]
+    Io(#[from] std::io::Error),
+}
+
+impl Epub {
+    pub fn new(title: impl Into<String>) -> Self {
+        let title = title.into();
+        let identifier = format!(
+            "commonprayer:{}",
+            title
+                .to_lowercase()
+                .split(|c: char| !c.is_alphanumeric())
+                .filter(|part| !part.is_empty())
+                .collect::<Vec<_>>()
+                .join("-")
+        );
+        Self {
+            title,
+            language: Language::default(),
+            identifier,
+            chapters: Vec::new(),
+            nav: Vec::new(),
+        }
+    }
+
+    #[must_use]
+    pub fn language(mut self, language: Language) -> Self {
+        self.language = language;
+        self
+    }
+
+    /// A unique identifier for the book; defaults to one derived from its title
+    #[must_use]
+    pub fn identifier(mut self, identifier: impl Into<String>) -> Self {
+        self.identifier = identifier.into();
+        self
+    }
+
+    /// Adds a single document as its own chapter
+    #[must_use]
+    pub fn add_document(mut self, doc: &Document) -> Self {
+        let label = doc.best_label().unwrap_or_else(|| self.title.clone());
+        let nav = self.document_chapter(&label, std::slice::from_ref(doc));
+        self.nav.push(nav);
+        self
+    }
+
+    /// Adds every document in a table-of-contents subtree, with categories and sections
+    /// nested in the table of contents
+    #[must_use]
+    pub fn add_contents(mut self, contents: &Contents) -> Self {
+        if let Some(nav) = self.contents_chapters(contents) {
+            self.nav.push(nav);
+        }
+        self
+    }
+
+    /// Adds the Psalter, with one chapter for each day of the cycle
+    #[must_use]
+    pub fn add_psalter(
+        mut self,
+        label: &str,
+        psalter: &::psalter::Psalter,
+        cycle: PsalterCycle,
+    ) -> Self {
+        let mut nav = self.title_chapter(label);
+        for (day, offices) in cycle.days() {
+            let mut html = Html::default();
+            html.push(format!("<h1>{}</h1>", escape(&day)));
+            for (office, citations) in offices {
+                if !office.is_empty() {
+                    html.push_nav_heading("h2", office);
+                }
+                for psalm in psalms_for_office(psalter, &citations) {
+                    html.add_psalm(&psalm);
+                }
+            }
+            nav.children.push(self.push_chapter(&day, html));
+        }
+        self.nav.push(nav);
+        self
+    }
+
+    /// The book's table of contents
+    pub fn nav(&self) -> &[NavPoint] {
+        &self.nav
+    }
+
+    pub fn write<W>(&self, w: W) -> Result<(), EpubError>
+    where
+        W: Write + Seek,
+    {
+        let mut zip = ZipWriter::new(w);
+        let stored = FileOptions::default().compression_method(CompressionMethod::Stored);
+        let deflated = FileOptions::default().compression_method(CompressionMethod::Deflated);
+
+        // the mimetype must be the first file in the archive, and must not be compressed
+        zip.start_file("mimetype", stored)?;
+        zip.write_all(b"application/epub+zip")?;
+
+        zip.start_file("META-INF/container.xml", deflated)?;
+        zip.write_all(CONTAINER_XML.as_bytes())?;
+
+        zip.start_file("OEBPS/style.css", deflated)?;
+        zip.write_all(STYLE_CSS.as_bytes())?;
+
+        zip.start_file("OEBPS/content.opf", deflated)?;
+        zip.write_all(self.package().as_bytes())?;
+
+        zip.start_file("OEBPS/nav.xhtml", deflated)?;
+        zip.write_all(self.nav_xhtml().as_bytes())?;
+
+        zip.start_file("OEBPS/toc.ncx", deflated)?;
+        zip.write_all(self.ncx().as_bytes())?;
+
+        for chapter in &self.chapters {
+            zip.start_file(format!("OEBPS/{}", chapter.file), deflated)?;
+            zip.write_all(self.xhtml(&chapter.title, &chapter.body).as_bytes())?;
+        }
+
+        zip.finish()?;
+        Ok(())
+    }
+
+    fn lang(&self) -> String {
+        self.language.to_string().to_lowercase()
+    }
+
+    fn push_chapter(&mut self, title: &str, html: Html) -> NavPoint {
+        let file = format!("chapter-{}.xhtml", self.chapters.len() + 1);
+        let children = html
+            .headings
+            .iter()
+            .map(|(id, label)| NavPoint {
+                label: label.clone(),
+                href: format!("{}#{}", file, id),
+                children: Vec::new(),
+            })
+            .collect();
+        self.chapters.push(Chapter {
+            file: file.clone(),
+            title: title.to_string(),
+            body: html.body,
+        });
+        NavPoint {
+            label: title.to_string(),
+            href: file,
+            children,
+        }
+    }
+
+    /// A chapter with only a title, to introduce a category or section
+    fn title_chapter(&mut self, label: &str) -> NavPoint {
+        let mut html = Html::default();
+        html.push(format!(r#"<h1 class="title">{}</h1>"#, escape(label)));
+        self.push_chapter(label, html)
+    }
+
+    fn document_chapter(&mut self, label: &str, docs: &[Document]) -> NavPoint {
+        let mut html = Html::default();
+        for doc in docs {
+            html.add_content(doc);
+        }
+        self.push_chapter(label, html)
+    }
+
+    fn contents_chapters(&mut self, contents: &Contents) -> Option<NavPoint> {
+        match contents {
+            Contents::Category { label, contents } => {
+                let mut nav = self.title_chapter(label);
+                nav.children = contents
+                    .iter()
+                    .filter_map(|(_, child)| self.contents_chapters(child))
+                    .collect();
+                Some(nav)
+            }
+            Contents::Sections { label, contents } => {
+                let mut nav = self.title_chapter(label);
+                for Section { label, contents } in contents {
+                    let children = match label {
+                        Some(label) => {
+                            let mut section = self.title_chapter(label);
+                            section.children = contents
+                                .iter()
+                                .filter_map(|(_, child)| self.contents_chapters(child))
+                                .collect();
+                            vec![section]
+                        }
+                        None => contents
+                            .iter()
+                            .filter_map(|(_, child)| self.contents_chapters(child))
+                            .collect(),
+                    };
+                    nav.children.extend(children);
+                }
+                Some(nav)
+            }
+            Contents::Document(doc) => {
+                let label = doc.best_label().unwrap_or_default();
+                Some(self.document_chapter(&label, std::slice::from_ref(*doc)))
+            }
+            Contents::ByVersion { label, documents } => {
+                let mut nav = self.title_chapter(label);
+                nav.children = documents
+                    .iter()
+                    .map(|doc| {
+                        let version = doc
+                            .version_label
+                            .clone()
+                            .unwrap_or_else(|| doc.version.to_string());
+                        self.document_chapter(&version, std::slice::from_ref(*doc))
+                    })
+                    .collect();
+                Some(nav)
+            }
+            Contents::MultiDocument {
+                label,
+                documents,
+                hidden_in_toc,
+            } => {
+                if *hidden_in_toc {
+                    None
+                } else {
+                    Some(self.document_chapter(label, documents))
+                }
+            }
+            // parallels and static pages only make sense on the website
+            Contents::Parallels { .. } | Contents::Page { .. } => None,
+        }
+    }
+
+    fn xhtml(&self, title: &str, body: &str) -> String {
+        format!(
+            r#"<?xml version="1.0" encoding="UTF-8"?>
+<!DOCTYPE html>
+<html xmlns="http://www.w3.org/1999/xhtml" xmlns:epub="http://www.idpf.org/2007/ops" xml:lang="{lang}" lang="{lang}">
+<head>
+<meta charset="UTF-8"/>
+<title>{title}</title>
+<link rel="stylesheet" type="text/css" href="style.css"/>
+</head>
+<body>
+{body}</body>
+</html>
+"#,
+            lang = self.lang(),
+            title = escape(title),
+            body = body
+        )
+    }
+
+    fn package(&self) -> String {
+        let manifest = self
+            .chapters
+            .iter()
+            .enumerate()
+            .map(|(idx, chapter)| {
+                format!(
+                    r#"    <item id="chapter-{}" href="{}" media-type="application/xhtml+xml"/>"#,
+                    idx + 1,
+                    chapter.file
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+        let spine = (1..=self.chapters.len())
+            .map(|idx| format!(r#"    <itemref idref="chapter-{}"/>"#, idx))
+            .collect::<Vec<_>>()
+            .join("\n");
+        format!(
+            r#"<?xml version="1.0" encoding="UTF-8"?>
+<package xmlns="http://www.idpf.org/2007/opf" version="3.0" unique-identifier="book-id" xml:lang="{lang}">
+  <metadata xmlns:dc="http://purl.org/dc/elements/1.1/">
+    <dc:identifier id="book-id">{identifier}</dc:identifier>
+    <dc:title>{title}</dc:title>
+    <dc:language>{lang}</dc:language>
+    <meta property="dcterms:modified">{modified}</meta>
+  </metadata>
+  <manifest>
+    <item id="nav" href="nav.xhtml" media-type="application/xhtml+xml" properties="nav"/>
+    <item id="ncx" href="toc.ncx" media-type="application/x-dtbncx+xml"/>
+    <item id="style" href="style.css" media-type="text/css"/>
+{manifest}
+  </manifest>
+  <spine toc="ncx">
+{spine}
+  </spine>
+</package>
+"#,
+            lang = self.lang(),
+            identifier = escape(&self.identifier),
+            title = escape(&self.title),
+            modified = chrono::Utc::now().format("%Y-%m-%dT%H:%M:%SZ"),
+            manifest = manifest,
+            spine = spine
+        )
+    }
+
+    fn nav_xhtml(&self) -> String {
+        fn list(points: &[NavPoint]) -> String {
+            let items = points
+                .iter()
+                .map(|point| {
+                    let children = if point.children.is_empty() {
+                        String::new()
+                    } else {
+                        list(&point.children)
+                    };
+                    format!(
+                        r#"<li><a href="{}">{}</a>{}</li>"#,
+                        point.href,
+                        escape(&point.label),
+                        children
+                    )
+                })
+                .collect::<String>();
+            format!("<ol>{}</ol>", items)
+        }
+
+        self.xhtml(
+            &self.title,
+            &format!(
+                "<nav epub:type=\"toc\" id=\"toc\">\n<h1>{}</h1>\n{}\n</nav>\n",
+                escape(&self.title),
+                list(&self.nav)
+            ),
+        )
+    }
+
+    /// Table of contents in the EPUB 2 format, for older e-readers
+    fn ncx(&self) -> String {
+        fn nav_points(points: &[NavPoint], order: &mut usize) -> String {
+            points
+                .iter()
+                .map(|point| {
+                    *order += 1;
+                    let id = *order;
+                    format!(
+                        r#"<navPoint id="nav-{id}" playOrder="{id}"><navLabel><text>{}</text></navLabel><content src="{}"/>{}</navPoint>"#,
+                        escape(&point.label),
+                        point.href,
+                        nav_points(&point.children, order)
+                    )
+                })
+                .collect()
+        }
+
+        format!(
+            r#"<?xml version="1.0" encoding="UTF-8"?>
+<ncx xmlns="http://www.daisy.org/z3986/2005/ncx/" version="2005-1">
+  <head>
+    <meta name="dtb:uid" content="{}"/>
+  </head>
+  <docTitle><text>{}</text></docTitle>
+  <navMap>{}</navMap>
+</ncx>
+"#,
+            escape(&self.identifier),
+            escape(&self.title),
+            nav_points(&self.nav, &mut 0)
+        )
+    }
+}
+
+const CONTAINER_XML: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<container version="1.0" xmlns="urn:oasis:names:tc:opendocument:xmlns:container">
+  <rootfiles>
+    <rootfile full-path="OEBPS/content.opf" media-type="application/oebps-package+xml"/>
+  </rootfiles>
+</container>
+"#;
+
+const STYLE_CSS: &str = r#"body { font-family: serif; line-height: 1.4; }
+h1.title { text-align: center; margin-top: 30%; }
+h1, h2 { text-align: center; }
+.rubric { color: #a00; font-style: italic; }
+.response { font-weight: bold; }
+.antiphon, .latin-name, .subtitle, .date, .proper { font-style: italic; }
+.citation { font-style: italic; text-align: right; }
+.error { color: #a00; font-weight: bold; }
+.verse { margin: 0 0 0.5em 0; }
+.half-verse { display: block; padding-left: 2em; }
+.pointing { color: #a00; }
+.verse-number { font-size: 0.7em; }
+table.preces td.label { padding-right: 1em; vertical-align: top; }
+"#;
@@ -0,0 +1,69 @@
+use calendar::LiturgicalDayId;
+use lectionary::{ReadingType, BCP1979_30_DAY_PSALTER};
+use psalter::Psalter;
+
+/// How the psalms are divided into chapters when the Psalter is exported
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum PsalterCycle {
+    /// One chapter for each day of the month, with the psalms appointed for Morning Prayer
+    /// and Evening Prayer on that day, as in the BCP (1979), pp. 585–808
+    ThirtyDay,
+    /// One chapter for each office of the 30-day cycle, so that the whole Psalter is read
+    /// once every sixty days when praying one office a day
+    SixtyDay,
+}
+
+/// One day of a Psalter cycle: its label and, for each office, the office's name and the psalms appointed
+pub(crate) type PsalterDay = (String, Vec<(&'static str, Vec<&'static str>)>);
+
+impl PsalterCycle {
+    /// The days of the cycle, each with the citations of its psalms
+    pub(crate) fn days(&self) -> Vec<PsalterDay> {
+        let office = |day: u8, reading_type: ReadingType| {
+            BCP1979_30_DAY_PSALTER
+                .readings
+                .iter()
+                .filter(|(id, _, search_type, _)| {
+                    *id == LiturgicalDayId::DayOfMonth(day) && *search_type == reading_type
+                })
+                .map(|(_, _, _, citation)| *citation)
+                .collect::<Vec<_>>()
+        };
+
+        match self {
+            PsalterCycle::ThirtyDay => (1..=30)
+                .map(|day| {
+                    (
+                        format!("Day {}", day),
+                        vec![
+                            ("Morning Prayer", office(day, ReadingType::MorningPsalm)),
+                            ("Evening Prayer", office(day, ReadingType::EveningPsalm)),
+                        ],
+                    )
+                })
+                .collect(),
+            PsalterCycle::SixtyDay => (1..=30)
+                .flat_map(|day| {
+                    [
+                        (
+                            format!("Day {}", day * 2 - 1),
+                            vec![("", office(day, ReadingType::MorningPsalm))],
+                        ),
+                        (
+                            format!("Day {}", day * 2),
+                            vec![("", office(day, ReadingType::EveningPsalm))],
+                        ),
+                    ]
+                })
+                .collect(),
+        }
+    }
+}
+
+/// Every psalm appointed for an office, with verses filtered as in the citation
+pub(crate) fn psalms_for_office(psalter: &Psalter, citations: &[&str]) -> Vec<liturgy::Psalm> {
+    citations
+        .iter()
+        .flat_map(|citation| psalter.psalms_by_citation(citation))
+        .collect()
+}
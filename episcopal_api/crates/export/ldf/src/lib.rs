@@ -55,7 +55,9 @@ impl ConvertableDocument {
                 Some("responsive")
             }
             Content::Rubric(_) => Some("rubric"),
-            Content::Text(_) | Content::CollectOfTheDay { .. } => Some("text"),
+            Content::Text(_) | Content::CollectOfTheDay { .. } | Content::PrefaceOfTheDay => {
+                Some("text")
+            }
             _ => None,
         }
     }
@@ -0,0 +1,846 @@
+use std::fmt::Display;
+
+use liturgy::*;
+
+/// A [Document] rendered as Markdown, with one block (paragraph, heading, etc.) per compiled element
+/// ```
+/// # use liturgy::{Document, Rubric, Series, Text};
+/// # use export_markdown::MarkdownDocument;
+/// let doc = Document::from(Series::from(vec![
+///     Document::from(Rubric::from("The People stand.")),
+///     Document::from(Text::from("The Lord be with you.").response("And also with you.")),
+/// ]));
+/// assert_eq!(
+///     MarkdownDocument::from(doc).to_string(),
+///     "*The People stand.*\n\nThe Lord be with you. **And also with you.**\n"
+/// );
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct MarkdownDocument(Vec<String>);
+
+/// A [Document] rendered as plain text, without any Markdown formatting
+/// ```
+/// # use liturgy::{Document, Preces};
+/// # use export_markdown::PlainTextDocument;
+/// let doc = Document::from(Preces::from([
+///     ("V.", "O Lord, open thou our lips."),
+///     ("R.", "And our mouth shall show forth thy praise."),
+/// ]));
+/// assert_eq!(
+///     PlainTextDocument::from(doc).to_string(),
+///     "V. O Lord, open thou our lips.\nR. And our mouth shall show forth thy praise.\n"
+/// );
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct PlainTextDocument(Vec<String>);
+
+impl MarkdownDocument {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[must_use]
+    pub fn add_content(mut self, doc: &Document) -> Self {
+        add_content(&mut self.0, doc, Format::Markdown);
+        self
+    }
+}
+
+impl PlainTextDocument {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[must_use]
+    pub fn add_content(mut self, doc: &Document) -> Self {
+        add_content(&mut self.0, doc, Format::PlainText);
+        self
+    }
+}
+
+impl From<Document> for MarkdownDocument {
+    fn from(doc: Document) -> Self {
+        Self::new().add_content(&doc)
+    }
+}
+
+impl From<&Document> for MarkdownDocument {
+    fn from(doc: &Document) -> Self {
+        Self::new().add_content(doc)
+    }
+}
+
+impl From<Document> for PlainTextDocument {
+    fn from(doc: Document) -> Self {
+        Self::new().add_content(&doc)
+    }
+}
+
+impl From<&Document> for PlainTextDocument {
+    fn from(doc: &Document) -> Self {
+        Self::new().add_content(doc)
+    }
+}
+
+impl Display for MarkdownDocument {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write_blocks(f, &self.0)
+    }
+}
+
+impl Display for PlainTextDocument {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write_blocks(f, &self.0)
+    }
+}
+
+fn write_blocks(f: &mut std::fmt::Formatter<'_>, blocks: &[String]) -> std::fmt::Result {
+    for (idx, block) in blocks.iter().enumerate() {
+        if idx > 0 {
+            writeln!(f)?;
+        }
+        writeln!(f, "{}", block)?;
+    }
+    Ok(())
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum Format {
+    Markdown,
+    PlainText,
+}
+
+impl Format {
+    /// Escapes characters that Markdown would otherwise treat as formatting
+    fn text(self, text: &str) -> String {
+        match self {
+            Format::Markdown => text
+                .replace('\\', "\\\\")
+                .replace('*', "\\*")
+                .replace('_', "\\_")
+                .replace('#', "\\#"),
+            Format::PlainText => text.to_string(),
+        }
+    }
+
+    /// Joins lines within a single block; Markdown needs two trailing spaces for a hard line break
+    fn lines(self, text: &str) -> String {
+        self.join(
+            text.trim()
+                .split('\n')
+                .map(|line| self.text(line.trim_end())),
+        )
+    }
+
+    /// Joins lines with hard line breaks, treating blank lines as paragraph breaks
+    fn join(self, lines: impl Iterator<Item = String>) -> String {
+        let mut joined = String::new();
+        let mut paragraph_break = false;
+        for line in lines {
+            if line.trim().is_empty() {
+                paragraph_break = !joined.is_empty();
+            } else {
+                if paragraph_break {
+                    joined.push_str("\n\n");
+                } else if !joined.is_empty() {
+                    joined.push_str(self.line_break());
+                }
+                joined.push_str(&line);
+                paragraph_break = false;
+            }
+        }
+        joined
+    }
+
+    fn bold(self, text: &str) -> String {
+        self.wrap(text, "**")
+    }
+
+    fn italic(self, text: &str) -> String {
+        self.wrap(text, "*")
+    }
+
+    // formatting markers can't span line breaks, so each line is wrapped separately
+    fn wrap(self, text: &str, marker: &str) -> String {
+        match self {
+            Format::Markdown => self.join(text.trim().split('\n').map(|line| {
+                if line.trim().is_empty() {
+                    String::new()
+                } else {
+                    format!("{}{}{}", marker, self.text(line.trim()), marker)
+                }
+            })),
+            Format::PlainText => self.lines(text),
+        }
+    }
+
+    fn heading(self, level: usize, text: &str) -> String {
+        match self {
+            Format::Markdown => format!(
+                "{} {}",
+                "#".repeat(level),
+                self.text(&text.split_whitespace().collect::<Vec<_>>().join(" "))
+            ),
+            Format::PlainText => text.trim().to_string(),
+        }
+    }
+
+    /// The second half of a psalm or canticle verse is indented
+    fn half_verse(self, text: &str) -> String {
+        self.join(
+            text.trim()
+                .split('\n')
+                .map(|line| format!("    {}", self.text(line.trim()))),
+        )
+    }
+
+    fn line_break(self) -> &'static str {
+        match self {
+            Format::Markdown => "  \n",
+            Format::PlainText => "\n",
+        }
+    }
+}
+
+fn add_content(blocks: &mut Vec<String>, doc: &Document, format: Format) {
+    if doc.display == Show::Hidden {
+        return;
+    }
+
+    if let Some(label) = &doc.label {
+        blocks.push(format.heading(3, label));
+    }
+    if let Some(subtitle) = &doc.subtitle {
+        blocks.push(format.italic(subtitle));
+    }
+
+    match &doc.content {
+        Content::Liturgy(liturgy) => {
+            for child in liturgy.body.iter() {
+                add_content(blocks, child, format);
+            }
+        }
+        Content::Series(series) => {
+            for child in series.iter() {
+                add_content(blocks, child, format);
+            }
+        }
+        Content::Parallel(parallel) => {
+            for child in parallel.iter() {
+                let version = child
+                    .version_label
+                    .clone()
+                    .unwrap_or_else(|| child.version.to_string());
+                blocks.push(format.heading(4, &version));
+                add_content(blocks, child, format);
+            }
+        }
+        Content::Choice(choice) => {
+            if let Some(selected) = choice.options.get(choice.selected) {
+                add_content(blocks, selected, format);
+            }
+        }
+        Content::CollectOfTheDay { allow_multiple: _ } => {
+            blocks.push(format.heading(3, "The Collect of the Day"))
+        }
+        Content::PrefaceOfTheDay => blocks.push(format.heading(3, "The Proper Preface")),
+        Content::DocumentLink { .. } => {}
+        Content::Empty => {}
+        Content::Error(error) => blocks.push(format!(
+            "{} {}",
+            format.bold("Error:"),
+            format.text(&error.to_string())
+        )),
+        Content::Antiphon(antiphon) => blocks.push(format.lines(&antiphon.to_string())),
+        Content::BiblicalCitation(citation) => blocks.push(format.lines(&citation.to_string())),
+        Content::BiblicalReading(reading) => add_biblical_reading(blocks, reading, format),
+        Content::Canticle(canticle) => add_canticle(blocks, canticle, format),
+        Content::CanticleTableEntry(_) => {}
+        Content::GloriaPatri(gloria) => blocks.push(gloria_patri(gloria, format)),
+        Content::Heading(heading) => add_heading(blocks, heading, format),
+        Content::HymnLink(link) => {
+            if let HymnLink::Hymn(hymnal, number) = link {
+                blocks.push(format.text(&format!("{} {}", hymnal, number)));
+            }
+        }
+        Content::Invitatory(invitatory) => add_invitatory(blocks, invitatory, format),
+        Content::LectionaryReading(_) => {}
+        Content::Litany(litany) => {
+            for line in litany.iter() {
+                blocks.push(format!(
+                    "{}{}{}",
+                    format.lines(line),
+                    format.line_break(),
+                    format.bold(&litany.response)
+                ));
+            }
+        }
+        Content::Preces(preces) => blocks.push(
+            preces
+                .iter()
+                .enumerate()
+                .map(|(idx, (label, text))| {
+                    let text = if idx % 2 == 1 {
+                        format.bold(text)
+                    } else {
+                        format.lines(text)
+                    };
+                    if label.trim().is_empty() {
+                        text
+                    } else {
+                        format!("{} {}", format.italic(label), text)
+                    }
+                })
+                .collect::<Vec<_>>()
+                .join(format.line_break()),
+        ),
+        Content::Psalm(psalm) => add_psalm(blocks, psalm, format),
+        Content::PsalmCitation(citation) => blocks.push(format.lines(&citation.to_string())),
+        Content::ResponsivePrayer(prayer) => blocks.push(
+            prayer
+                .iter()
+                .enumerate()
+                .map(|(idx, line)| {
+                    if idx % 2 == 1 {
+                        format.bold(line)
+                    } else {
+                        format.lines(line)
+                    }
+                })
+                .collect::<Vec<_>>()
+                .join(format.line_break()),
+        ),
+        Content::Rubric(rubric) => blocks.push(format.italic(&rubric.to_string())),
+        Content::Sentence(sentence) => add_sentence(blocks, sentence, format),
+        Content::Text(text) => {
+            let mut block = format.lines(&text.text);
+            if let Some(response) = &text.response {
+                block.push(' ');
+                block.push_str(&format.bold(response));
+            }
+            blocks.push(block);
+        }
+    }
+}
+
+fn add_biblical_reading(blocks: &mut Vec<String>, reading: &BiblicalReading, format: Format) {
+    if let Some(intro) = &reading.intro {
+        add_content(blocks, &Document::from(intro.clone()), format);
+    }
+
+    blocks.push(format.heading(3, &reading.citation));
+
+    // paragraph breaks are marked by a double line break at the end of a verse
+    let text = reading
+        .text
+        .iter()
+        .map(|(_, text)| text.as_str())
+        .collect::<Vec<_>>()
+        .join(" ");
+    for paragraph in text.split("\n\n") {
+        let paragraph = paragraph.trim();
+        if !paragraph.is_empty() {
+            blocks.push(format.lines(paragraph));
+        }
+    }
+}
+
+fn add_canticle(blocks: &mut Vec<String>, canticle: &Canticle, format: Format) {
+    blocks.push(format.heading(3, &format!("{}. {}", canticle.number, canticle.local_name)));
+    if let Some(latin_name) = &canticle.latin_name {
+        blocks.push(format.italic(latin_name));
+    }
+    if let Some(citation) = &canticle.citation {
+        blocks.push(format.italic(citation));
+    }
+    if let Some(rubric) = &canticle.rubric {
+        blocks.push(format.italic(rubric));
+    }
+
+    for section in &canticle.sections {
+        if let Some(title) = &section.title {
+            blocks.push(format.heading(4, title));
+        }
+        for verse in &section.verses {
+            blocks.push(verse_block(None, &verse.a, &verse.b, format));
+        }
+    }
+
+    if let Some(gloria) = &canticle.gloria_patri {
+        blocks.push(gloria_patri(gloria, format));
+    }
+}
+
+fn add_heading(blocks: &mut Vec<String>, heading: &Heading, format: Format) {
+    match heading {
+        Heading::InsertDate => {}
+        Heading::InsertDay => {}
+        Heading::Date(date) => blocks.push(format.italic(date)),
+        Heading::Day {
+            name,
+            proper,
+            holy_days,
+        } => {
+            blocks.push(format.heading(2, name));
+            if let Some(proper) = proper {
+                blocks.push(format.italic(proper));
+            }
+            if let Some(holy_days) = holy_days {
+                blocks.push(
+                    holy_days
+                        .iter()
+                        .map(|(_, name)| format.text(name))
+                        .collect::<Vec<_>>()
+                        .join(format.line_break()),
+                );
+            }
+        }
+        Heading::Text(level, text) => {
+            let level = match level {
+                HeadingLevel::Heading1 => 1,
+                HeadingLevel::Heading2 => 2,
+                HeadingLevel::Heading3 => 3,
+                HeadingLevel::Heading4 => 4,
+                HeadingLevel::Heading5 => 5,
+            };
+            blocks.push(format.heading(level, text));
+        }
+    }
+}
+
+fn add_invitatory(blocks: &mut Vec<String>, invitatory: &Invitatory, format: Format) {
+    blocks.push(format.heading(3, &invitatory.local_name));
+    if let Some(latin_name) = &invitatory.latin_name {
+        blocks.push(format.italic(latin_name));
+    }
+    if let Some(citation) = &invitatory.citation {
+        blocks.push(format.italic(citation));
+    }
+
+    let antiphon = match &invitatory.antiphon {
+        SeasonalAntiphon::Antiphon(antiphon) => Some(format.lines(&antiphon.to_string())),
+        _ => None,
+    };
+    if let Some(antiphon) = &antiphon {
+        blocks.push(antiphon.clone());
+    }
+
+    for section in &invitatory.sections {
+        for verse in &section.verses {
+            blocks.push(verse_block(None, &verse.a, &verse.b, format));
+        }
+    }
+
+    if let Some(gloria) = &invitatory.gloria_patri {
+        blocks.push(gloria_patri(gloria, format));
+    }
+    if let Some(antiphon) = antiphon {
+        blocks.push(antiphon);
+    }
+}
+
+fn add_psalm(blocks: &mut Vec<String>, psalm: &Psalm, format: Format) {
+    blocks.push(format.heading(3, &format!("Psalm {}", psalm.number)));
+    if let Some(citation) = &psalm.citation {
+        blocks.push(format.italic(citation));
+    }
+
+    for section in psalm.filtered_sections() {
+        let names = [section.local_name.as_str(), section.latin_name.as_str()]
+            .into_iter()
+            .filter(|name| !name.is_empty())
+            .map(|name| format.italic(name))
+            .collect::<Vec<_>>();
+        if !names.is_empty() {
+            blocks.push(names.join(format.line_break()));
+        }
+        for verse in &section.verses {
+            blocks.push(verse_block(Some(verse.number), &verse.a, &verse.b, format));
+        }
+    }
+}
+
+fn add_sentence(blocks: &mut Vec<String>, sentence: &Sentence, format: Format) {
+    let text = format.lines(&sentence.text);
+    match sentence
+        .response
+        .as_deref()
+        .map(|response| &response.content)
+    {
+        // short responses, like "Alleluia," follow the sentence on the same line
+        Some(Content::Text(response)) if response.text.len() < 10 => {
+            blocks.push(format!("{} {}", text, format.bold(&response.text)))
+        }
+        Some(_) => {
+            blocks.push(text);
+            if let Some(response) = &sentence.response {
+                add_content(blocks, response, format);
+            }
+        }
+        None => blocks.push(text),
+    }
+
+    if let Some(citation) = &sentence.citation {
+        blocks.push(format!("— {}", format.italic(citation)));
+    }
+}
+
+/// A psalm or canticle verse, with an asterisk marking the division between its halves
+fn verse_block(number: Option<u8>, a: &str, b: &str, format: Format) -> String {
+    // some texts already include the asterisk at the end of the first half-verse
+    let a = format.lines(a.trim_end().trim_end_matches('*'));
+    let a = match number {
+        Some(number) => format!("{} {}", number, a),
+        None => a,
+    };
+    if b.trim().is_empty() {
+        a
+    } else {
+        let asterisk = match format {
+            Format::Markdown => "\\*",
+            Format::PlainText => "*",
+        };
+        format!(
+            "{} {}{}{}",
+            a,
+            asterisk,
+            format.line_break(),
+            format.half_verse(b)
+        )
+    }
+}
+
+fn gloria_patri(gloria: &GloriaPatri, format: Format) -> String {
+    let (a, b, c, d) = &gloria.text;
+    verse_block(
+        None,
+        &format!("{} {}", a.trim(), b.trim()),
+        &format!("{} {}", c.trim(), d.trim()),
+        format,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use calendar::Feast;
+    use canticle_table::{CanticleId, CanticleNumber};
+    use hymnal::{HymnNumber, Hymnals};
+    use reference_parser::{BibleVerse, BibleVersePart, Book};
+
+    fn markdown(doc: impl Into<Document>) -> String {
+        MarkdownDocument::from(doc.into()).to_string()
+    }
+
+    fn plain_text(doc: impl Into<Document>) -> String {
+        PlainTextDocument::from(doc.into()).to_string()
+    }
+
+    fn gloria_patri() -> GloriaPatri {
+        GloriaPatri::from((
+            "Glory to the Father, and to the Son,",
+            "and to the Holy Spirit: *",
+            "as it was in the beginning, is now,",
+            "and will be for ever. Amen.",
+        ))
+    }
+
+    fn psalm() -> Psalm {
+        Psalm {
+            number: 117,
+            citation: None,
+            sections: vec![PsalmSection {
+                reference: Reference::from(760),
+                local_name: String::new(),
+                latin_name: "Laudate Dominum".into(),
+                verses: vec![
+                    PsalmVerse {
+                        number: 1,
+                        a: "Praise the LORD, all you nations; *".into(),
+                        b: "laud him, all you peoples.".into(),
+                    },
+                    PsalmVerse {
+                        number: 2,
+                        a: "For his loving-kindness toward us is great, *".into(),
+                        b: "and the faithfulness of the LORD endures for ever.\nHallelujah!".into(),
+                    },
+                ],
+            }],
+        }
+    }
+
+    #[test]
+    fn psalm_has_verse_numbers_and_asterisks() {
+        assert_eq!(
+            markdown(psalm()),
+            "### Psalm 117\n\n\
+            *Laudate Dominum*\n\n\
+            1 Praise the LORD, all you nations; \\*  \n    laud him, all you peoples.\n\n\
+            2 For his loving-kindness toward us is great, \\*  \n    and the faithfulness of the LORD endures for ever.  \n    Hallelujah!\n"
+        );
+        assert_eq!(
+            plain_text(psalm()),
+            "Psalm 117\n\n\
+            Laudate Dominum\n\n\
+            1 Praise the LORD, all you nations; *\n    laud him, all you peoples.\n\n\
+            2 For his loving-kindness toward us is great, *\n    and the faithfulness of the LORD endures for ever.\n    Hallelujah!\n"
+        );
+    }
+
+    #[test]
+    fn canticle_and_gloria_patri_have_asterisks() {
+        let canticle = Canticle {
+            number: CanticleId::Canticle13,
+            changeable: None,
+            citation: Some("Song of the Three Young Men, 29-34".into()),
+            local_name: "A Song of Praise".into(),
+            latin_name: Some("Benedictus es, Domine".into()),
+            rubric: None,
+            sections: vec![CanticleSection {
+                title: None,
+                verses: vec![CanticleVerse::from((
+                    "Glory to you, Lord God of our fathers; *",
+                    "you are worthy of praise; glory to you.",
+                ))],
+            }],
+            gloria_patri: Some(gloria_patri()),
+        };
+        assert_eq!(
+            markdown(canticle),
+            "### 13. A Song of Praise\n\n\
+            *Benedictus es, Domine*\n\n\
+            *Song of the Three Young Men, 29-34*\n\n\
+            Glory to you, Lord God of our fathers; \\*  \n    you are worthy of praise; glory to you.\n\n\
+            Glory to the Father, and to the Son, and to the Holy Spirit: \\*  \n    as it was in the beginning, is now, and will be for ever. Amen.\n"
+        );
+    }
+
+    #[test]
+    fn invitatory_repeats_its_antiphon() {
+        let invitatory = Invitatory {
+            local_name: "Jubilate".into(),
+            latin_name: None,
+            citation: Some("Psalm 100".into()),
+            antiphon: SeasonalAntiphon::Antiphon(Antiphon::from("The Lord is risen indeed.")),
+            sections: vec![InvitatorySection {
+                verses: vec![InvitatoryVerse {
+                    a: "Be joyful in the LORD, all you lands; *".into(),
+                    b: "serve the LORD with gladness.".into(),
+                }],
+            }],
+            gloria_patri: None,
+        };
+        assert_eq!(
+            markdown(invitatory),
+            "### Jubilate\n\n\
+            *Psalm 100*\n\n\
+            The Lord is risen indeed.\n\n\
+            Be joyful in the LORD, all you lands; \\*  \n    serve the LORD with gladness.\n\n\
+            The Lord is risen indeed.\n"
+        );
+    }
+
+    #[test]
+    fn responses_are_bold() {
+        assert_eq!(
+            markdown(ResponsivePrayer::from([
+                "Show us your mercy, O Lord;",
+                "And grant us your salvation."
+            ])),
+            "Show us your mercy, O Lord;  \n**And grant us your salvation.**\n"
+        );
+        assert_eq!(
+            markdown(Preces::from([
+                ("V.", "Show us your mercy, O Lord;"),
+                ("R.", "And grant us your salvation.")
+            ])),
+            "*V.* Show us your mercy, O Lord;  \n*R.* **And grant us your salvation.**\n"
+        );
+        assert_eq!(
+            markdown(Litany::from((
+                "Lord, have mercy.",
+                ["For the peace of the world,"]
+            ))),
+            "For the peace of the world,  \n**Lord, have mercy.**\n"
+        );
+        assert_eq!(
+            markdown(Text::from("The Lord be with you.").response("And also with you.")),
+            "The Lord be with you. **And also with you.**\n"
+        );
+        assert_eq!(
+            markdown(
+                Sentence::from("Christ is risen from the dead.")
+                    .response(Text::from("Alleluia."))
+                    .citation("1 Corinthians 15:20")
+            ),
+            "Christ is risen from the dead. **Alleluia.**\n\n— *1 Corinthians 15:20*\n"
+        );
+    }
+
+    #[test]
+    fn choice_shows_only_the_selected_option() {
+        let mut choice = Choice::from([
+            Document::from("The first option."),
+            Document::from("The second option."),
+        ]);
+        choice.selected = 1;
+        assert_eq!(markdown(choice), "The second option.\n");
+    }
+
+    #[test]
+    fn parallel_labels_each_version() {
+        let parallel = Parallel::from([
+            Document::from("And with thy spirit.").version(Version::RiteI),
+            Document::from("And also with you.")
+                .version(Version::RiteII)
+                .version_label("Contemporary"),
+        ]);
+        assert_eq!(
+            markdown(parallel),
+            "#### Rite I\n\nAnd with thy spirit.\n\n#### Contemporary\n\nAnd also with you.\n"
+        );
+    }
+
+    #[test]
+    fn series_and_liturgy_render_their_children() {
+        let children = vec![
+            Document::from(Rubric::from("The People stand.")),
+            Document::from(Heading::from((HeadingLevel::Heading2, "The Word of God"))),
+        ];
+        let expected = "*The People stand.*\n\n## The Word of God\n";
+        assert_eq!(markdown(Series::from(children.clone())), expected);
+        assert_eq!(markdown(Liturgy::from(children)), expected);
+    }
+
+    #[test]
+    fn headings() {
+        assert_eq!(
+            markdown(Heading::Day {
+                name: "Tuesday, November 30".into(),
+                proper: Some("Week of the First Sunday of Advent".into()),
+                holy_days: Some(vec![(Feast::Andrew, "Saint Andrew the Apostle".into())]),
+            }),
+            "## Tuesday, November 30\n\n*Week of the First Sunday of Advent*\n\nSaint Andrew the Apostle\n"
+        );
+        assert_eq!(
+            markdown(Heading::Date("November 30, 2021".into())),
+            "*November 30, 2021*\n"
+        );
+        assert_eq!(
+            markdown(Heading::from((HeadingLevel::Heading1, "Morning   Prayer"))),
+            "# Morning Prayer\n"
+        );
+        assert_eq!(markdown(Heading::InsertDay), "");
+        assert_eq!(markdown(Heading::InsertDate), "");
+    }
+
+    #[test]
+    fn readings_and_citations() {
+        let verse = |verse| BibleVerse {
+            book: Book::John,
+            chapter: 1,
+            verse,
+            verse_part: BibleVersePart::All,
+        };
+        let reading = BiblicalReading {
+            citation: "John 1:1-2".into(),
+            text: vec![
+                (verse(1), "In the beginning was the Word.\n\n".into()),
+                (verse(2), "He was in the beginning with God.".into()),
+            ],
+            intro: Some(BiblicalReadingIntro::from(
+                "A Reading from the Gospel of John.",
+            )),
+        };
+        assert_eq!(
+            markdown(reading),
+            "A Reading from the Gospel of John.\n\n\
+            ### John 1:1-2\n\n\
+            In the beginning was the Word.\n\n\
+            He was in the beginning with God.\n"
+        );
+        assert_eq!(
+            markdown(BiblicalCitation::from("John 1:1-18".to_string())),
+            "John 1:1-18\n"
+        );
+        assert_eq!(markdown(PsalmCitation::from("Psalm 117")), "Psalm 117\n");
+        assert_eq!(
+            markdown(Antiphon::from("Alleluia. Christ is risen.")),
+            "Alleluia. Christ is risen.\n"
+        );
+    }
+
+    #[test]
+    fn other_content() {
+        assert_eq!(
+            markdown(Rubric::from("The People kneel.")),
+            "*The People kneel.*\n"
+        );
+        assert_eq!(
+            markdown(HymnLink::Hymn(Hymnals::Hymnal1982, HymnNumber::H(390))),
+            "H82 390\n"
+        );
+        assert_eq!(markdown(HymnLink::Hymnals), "");
+        assert_eq!(
+            markdown(DocumentError::from("No reading found.")),
+            "**Error:** No reading found.\n"
+        );
+        assert_eq!(
+            markdown(Content::CollectOfTheDay {
+                allow_multiple: true
+            }),
+            "### The Collect of the Day\n"
+        );
+        assert_eq!(
+            markdown(Content::PrefaceOfTheDay),
+            "### The Proper Preface\n"
+        );
+        assert_eq!(markdown(gloria_patri()), "Glory to the Father, and to the Son, and to the Holy Spirit: \\*  \n    as it was in the beginning, is now, and will be for ever. Amen.\n");
+    }
+
+    #[test]
+    fn placeholders_are_omitted() {
+        assert_eq!(markdown(Content::Empty), "");
+        assert_eq!(
+            markdown(Content::DocumentLink {
+                label: "The Great Litany".into(),
+                path: SlugPath::from([Slug::GreatLitany]),
+                rotate: false,
+                link_only: true
+            }),
+            ""
+        );
+        assert_eq!(
+            markdown(CanticleTableEntry {
+                nth: CanticleNumber::One,
+                table: CanticleTableChoice::Selected(CanticleTables::BCP1979RiteII)
+            }),
+            ""
+        );
+        assert_eq!(markdown(LectionaryReading::default()), "");
+        assert_eq!(
+            markdown(Document::from("Not shown.").display(Show::Hidden)),
+            ""
+        );
+    }
+
+    #[test]
+    fn label_and_subtitle_come_first() {
+        assert_eq!(
+            markdown(
+                Document::from("Almighty God, unto whom all hearts are open.")
+                    .label("The Collect for Purity")
+                    .subtitle("said by the Celebrant")
+            ),
+            "### The Collect for Purity\n\n*said by the Celebrant*\n\nAlmighty God, unto whom all hearts are open.\n"
+        );
+    }
+
+    #[test]
+    fn escapes_markdown_characters() {
+        assert_eq!(
+            markdown(Text::from("5 * 3 = _15_ #1")),
+            "5 \\* 3 = \\_15\\_ \\#1\n"
+        );
+    }
+}
@@ -0,0 +1,29 @@
+/// The order in which pages are printed on each side of each sheet of a saddle-stitched booklet.
+/// Each item is one side of a sheet, given as the (0-indexed) pages printed on its left and right
+/// halves, in the order the sides should be printed (front and back of the first sheet, then the
+/// second sheet, and so on). Blank pages needed to fill out the last sheet are `None`.
+/// ```
+/// # use pdf::booklet_sides;
+/// assert_eq!(
+///     booklet_sides(6),
+///     vec![
+///         [None, Some(0)],
+///         [Some(1), None],
+///         [Some(5), Some(2)],
+///         [Some(3), Some(4)],
+///     ]
+/// );
+/// ```
+pub fn booklet_sides(page_count: usize) -> Vec<[Option<usize>; 2]> {
+    // every sheet holds four pages, two on each side
+    let padded = page_count + (4 - page_count % 4) % 4;
+    let page = |idx: usize| if idx < page_count { Some(idx) } else { None };
+    (0..padded / 4)
+        .flat_map(|sheet| {
+            let outer = 2 * sheet;
+            let front = [page(padded - 1 - outer), page(outer)];
+            let back = [page(outer + 1), page(padded - 2 - outer)];
+            [front, back]
+        })
+        .collect()
+}
@@ -0,0 +1,337 @@
+use crate::{FontFamily, PdfOptions, PdfStyles, Style, TextStyle};
+
+/// A span of text in a single style
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct Run {
+    pub text: String,
+    pub style: Style,
+}
+
+impl Run {
+    pub fn new(text: impl std::fmt::Display, style: Style) -> Self {
+        Self {
+            text: text.to_string(),
+            style,
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub(crate) enum Align {
+    Left,
+    Center,
+    Right,
+}
+
+/// A line that is explicitly broken from the line before it; it will be wrapped further if it is too long
+#[derive(Clone, Debug, PartialEq)]
+struct Line {
+    runs: Vec<Run>,
+    indent: f32,
+}
+
+/// A block of text that is kept together on a page where possible
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct Paragraph {
+    lines: Vec<Line>,
+    align: Align,
+    keep_with_next: bool,
+}
+
+impl Paragraph {
+    pub fn new() -> Self {
+        Self {
+            lines: Vec::new(),
+            align: Align::Left,
+            keep_with_next: false,
+        }
+    }
+
+    /// A paragraph of text in a single style, with a new line for each `\n`
+    pub fn with_text(text: impl std::fmt::Display, style: Style) -> Self {
+        Self::new().text(text, style)
+    }
+
+    /// Adds text in a single style, with a new line for each `\n`
+    #[must_use]
+    pub fn text(self, text: impl std::fmt::Display, style: Style) -> Self {
+        text.to_string()
+            .trim()
+            .split('\n')
+            .fold(self, |para, line| para.line(vec![Run::new(line, style)]))
+    }
+
+    #[must_use]
+    pub fn line(self, runs: Vec<Run>) -> Self {
+        self.indented_line(runs, 0.0)
+    }
+
+    #[must_use]
+    pub fn indented_line(mut self, runs: Vec<Run>, indent: f32) -> Self {
+        self.lines.push(Line { runs, indent });
+        self
+    }
+
+    /// Adds runs to the end of the last line
+    #[must_use]
+    pub fn append(mut self, runs: Vec<Run>) -> Self {
+        match self.lines.last_mut() {
+            Some(line) => line.runs.extend(runs),
+            None => self.lines.push(Line { runs, indent: 0.0 }),
+        }
+        self
+    }
+
+    #[must_use]
+    pub fn align(mut self, align: Align) -> Self {
+        self.align = align;
+        self
+    }
+
+    /// Avoids a page break between this paragraph and the next, as after a heading
+    #[must_use]
+    pub fn keep_with_next(mut self) -> Self {
+        self.keep_with_next = true;
+        self
+    }
+
+    fn is_empty(&self) -> bool {
+        self.lines
+            .iter()
+            .all(|line| line.runs.iter().all(|run| run.text.trim().is_empty()))
+    }
+}
+
+/// A piece of text positioned on a page, measured in points from the top left corner
+/// of the page to the baseline of the text
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct PlacedText {
+    pub x: f32,
+    pub y: f32,
+    pub text: String,
+    pub style: Style,
+}
+
+pub(crate) type Page = Vec<PlacedText>;
+
+/// A wrapped line of text, ready to be placed on a page
+struct VisualLine {
+    segments: Vec<(f32, String, Style)>,
+    height: f32,
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub(crate) struct Layout {
+    paragraphs: Vec<Paragraph>,
+}
+
+impl Layout {
+    pub fn push(&mut self, paragraph: Paragraph) {
+        if !paragraph.is_empty() {
+            self.paragraphs.push(paragraph);
+        }
+    }
+
+    /// Breaks the paragraphs into lines and the lines into pages
+    pub fn paginate(&self, options: &PdfOptions) -> Vec<Page> {
+        let (page_width, page_height) = options.page_size.dimensions();
+        let content_width = page_width - 2.0 * options.margin;
+        let bottom = page_height - options.margin;
+        let content_height = bottom - options.margin;
+        let spacing = options.styles.paragraph_spacing;
+        let height = |lines: &[VisualLine]| lines.iter().map(|line| line.height).sum::<f32>();
+
+        let paragraphs = self
+            .paragraphs
+            .iter()
+            .map(|para| (para, wrap_paragraph(para, content_width, options)))
+            .collect::<Vec<_>>();
+
+        let mut pages = vec![Vec::new()];
+        let mut y = options.margin;
+        for (idx, (_, lines)) in paragraphs.iter().enumerate() {
+            // keep the whole paragraph together if it fits on a page, along with any paragraphs
+            // that should be kept with it
+            let mut needed = height(lines);
+            let mut next_idx = idx;
+            while paragraphs[next_idx].0.keep_with_next && next_idx + 1 < paragraphs.len() {
+                next_idx += 1;
+                let next = &paragraphs[next_idx].1;
+                needed += spacing
+                    + if height(next) <= content_height {
+                        height(next)
+                    } else {
+                        next.first().map(|line| line.height).unwrap_or(0.0)
+                    };
+            }
+            needed = needed.min(content_height);
+            let at_top = y == options.margin;
+            if !at_top && y + needed > bottom {
+                pages.push(Vec::new());
+                y = options.margin;
+            }
+
+            for line in lines {
+                if y + line.height > bottom && y > options.margin {
+                    pages.push(Vec::new());
+                    y = options.margin;
+                }
+                // the baseline sits about four-fifths of the way down the line
+                let baseline = y + line.height * 0.8;
+                let page = pages.last_mut().unwrap();
+                for (x, text, style) in &line.segments {
+                    page.push(PlacedText {
+                        x: options.margin + x,
+                        y: baseline,
+                        text: text.clone(),
+                        style: *style,
+                    });
+                }
+                y += line.height;
+            }
+            y += spacing;
+        }
+
+        if pages.last().map(|page| page.is_empty()).unwrap_or(false) && pages.len() > 1 {
+            pages.pop();
+        }
+        pages
+    }
+}
+
+#[cfg(test)]
+impl Layout {
+    /// Each line of each paragraph, given as its indent and the text and style of its runs
+    pub fn lines(&self) -> Vec<(f32, Vec<(&str, Style)>)> {
+        self.paragraphs
+            .iter()
+            .flat_map(|para| &para.lines)
+            .map(|line| {
+                let runs = line
+                    .runs
+                    .iter()
+                    .map(|run| (run.text.as_str(), run.style))
+                    .collect();
+                (line.indent, runs)
+            })
+            .collect()
+    }
+}
+
+fn wrap_paragraph(para: &Paragraph, width: f32, options: &PdfOptions) -> Vec<VisualLine> {
+    let styles = &options.styles;
+    let mut lines = Vec::new();
+
+    for line in &para.lines {
+        // continuation lines of a wrapped line are indented a little further than the line itself
+        let hanging = line.indent + styles.normal.size;
+        let words = line.runs.iter().flat_map(|run| {
+            run.text
+                .split(' ')
+                .enumerate()
+                .map(move |(idx, word)| (idx > 0, word, run.style))
+        });
+
+        let mut current: Vec<(f32, String, Style)> = Vec::new();
+        let mut x = line.indent;
+        let mut height = styles.normal.line_height();
+        let mut pending_space = false;
+        for (space_before, word, style) in words {
+            pending_space = pending_space || space_before;
+            if word.is_empty() {
+                continue;
+            }
+
+            let text_style = styles.get(style);
+            let word_width = text_width(word, text_style);
+            let space = if pending_space && !current.is_empty() {
+                text_width(" ", text_style)
+            } else {
+                0.0
+            };
+            pending_space = false;
+
+            if !current.is_empty() && x + space + word_width > width {
+                lines.push(finish_line(current, height, width, para.align, styles));
+                current = Vec::new();
+                x = hanging;
+                height = styles.normal.line_height();
+            } else {
+                x += space;
+            }
+
+            height = height.max(text_style.line_height());
+            match current.last_mut() {
+                // words in the same style are placed together, so that word spacing is left to the font
+                Some((_, text, last_style)) if *last_style == style => {
+                    if space > 0.0 {
+                        text.push(' ');
+                    }
+                    text.push_str(word);
+                }
+                _ => current.push((x, word.to_string(), style)),
+            }
+            x += word_width;
+        }
+        lines.push(finish_line(current, height, width, para.align, styles));
+    }
+
+    lines
+}
+
+fn finish_line(
+    segments: Vec<(f32, String, Style)>,
+    height: f32,
+    width: f32,
+    align: Align,
+    styles: &PdfStyles,
+) -> VisualLine {
+    let segments = segments
+        .into_iter()
+        .filter(|(_, text, _)| !text.is_empty())
+        .collect::<Vec<_>>();
+    let line_width = segments
+        .last()
+        .map(|(x, text, style)| x + text_width(text, styles.get(*style)))
+        .unwrap_or(0.0);
+    let extra = (width - line_width).max(0.0);
+    let shift = match align {
+        Align::Left => 0.0,
+        Align::Center => extra / 2.0,
+        Align::Right => extra,
+    };
+    VisualLine {
+        segments: segments
+            .into_iter()
+            .map(|(x, text, style)| (x + shift, text, style))
+            .collect(),
+        height,
+    }
+}
+
+/// The approximate width of text set in one of the standard PDF fonts, in points.
+/// The standard fonts don't come with metrics, so characters are grouped by their typical width;
+/// this errs on the wide side so that lines are never set wider than the page.
+pub(crate) fn text_width(text: &str, style: &TextStyle) -> f32 {
+    let ems = text
+        .chars()
+        .map(|c| match c {
+            ' ' => 0.25,
+            'i' | 'j' | 'l' | 'I' | '.' | ',' | ';' | ':' | '\'' | '!' | '|' | '’' | '‘' => {
+                0.28
+            }
+            'f' | 't' | 'r' | '(' | ')' | '[' | ']' | '-' | '"' | '“' | '”' => 0.36,
+            'm' | 'w' | '—' => 0.78,
+            'M' | 'W' | '@' => 0.92,
+            '0'..='9' | '*' => 0.5,
+            c if c.is_uppercase() => 0.7,
+            _ => 0.5,
+        })
+        .sum::<f32>();
+    let ems = match style.family {
+        FontFamily::Serif => ems,
+        FontFamily::SansSerif => ems * 1.08,
+    };
+    let ems = if style.bold { ems * 1.06 } else { ems };
+    ems * style.size
+}
@@ -0,0 +1,1081 @@
+use std::io::{BufWriter, Write};
+use thiserror::Error;
+
+use liturgy::*;
+use printpdf::{Color, IndirectFontRef, Mm, Pt, Rgb};
+
+mod imposition;
+pub use imposition::*;
+mod layout;
+use layout::*;
+mod styles;
+pub use styles::*;
+
+/// A [Document] laid out as a PDF, with settings given by [PdfOptions]
+/// ```
+/// # use liturgy::{Document, Rubric, Text, Series};
+/// # use pdf::{PdfDocument, PdfOptions};
+/// let doc = Document::from(Series::from(vec![
+///     Document::from(Rubric::from("The People stand.")),
+///     Document::from(Text::from("The Lord be with you.").response("And also with you.")),
+/// ]));
+/// let pdf = PdfDocument::with_options(PdfOptions::booklet()).add_content(&doc);
+/// assert_eq!(pdf.page_count(), 1);
+///
+/// let mut bytes = Vec::new();
+/// pdf.write(&mut bytes).unwrap();
+/// assert!(bytes.starts_with(b"%PDF"));
+/// ```
+pub struct PdfDocument {
+    options: PdfOptions,
+    layout: Layout,
+}
+
+#[derive(Error, Debug)]
+pub enum PdfError {
+    #[error("error writing PDF file")]
+    Write,
+}
+
+impl PdfDocument {
+    pub fn new() -> Self {
+        Self::with_options(PdfOptions::default())
+    }
+
+    pub fn with_options(options: PdfOptions) -> Self {
+        Self {
+            options,
+            layout: Layout::default(),
+        }
+    }
+
+    #[must_use]
+    pub fn add_content(mut self, doc: &Document) -> Self {
+        add_content(&mut self.layout, doc, &self.options.styles);
+        self
+    }
+
+    /// The number of pages in the document (not the number of sheets, if imposed as a booklet)
+    pub fn page_count(&self) -> usize {
+        self.layout.paginate(&self.options).len()
+    }
+
+    pub fn write<W>(self, w: W) -> Result<(), PdfError>
+    where
+        W: Write,
+    {
+        let pages = self.layout.paginate(&self.options);
+        let (page_width, page_height) = self.options.page_size.dimensions();
+        let (sheet_width, sheet_height) = self.options.sheet_dimensions();
+
+        let sides = match self.options.imposition {
+            Imposition::None => (0..pages.len()).map(|idx| vec![Some(idx)]).collect(),
+            Imposition::Booklet => booklet_sides(pages.len())
+                .into_iter()
+                .map(|side| side.to_vec())
+                .collect::<Vec<_>>(),
+        };
+
+        let (pdf, first_page, first_layer) = printpdf::PdfDocument::new(
+            "",
+            Mm::from(Pt(sheet_width)),
+            Mm::from(Pt(sheet_height)),
+            "Layer 1",
+        );
+        let fonts = Fonts::new(&pdf)?;
+
+        for (side_idx, side) in sides.iter().enumerate() {
+            let layer = if side_idx == 0 {
+                pdf.get_page(first_page).get_layer(first_layer)
+            } else {
+                let (page, layer) = pdf.add_page(
+                    Mm::from(Pt(sheet_width)),
+                    Mm::from(Pt(sheet_height)),
+                    "Layer 1",
+                );
+                pdf.get_page(page).get_layer(layer)
+            };
+
+            for (slot, page_idx) in side.iter().enumerate() {
+                let page_idx = match page_idx {
+                    Some(page_idx) => *page_idx,
+                    None => continue,
+                };
+                let offset = slot as f32 * page_width;
+                let styles = &self.options.styles;
+                let page_number = if self.options.page_numbers && pages.len() > 1 {
+                    let number = (page_idx + 1).to_string();
+                    let x = (page_width - layout::text_width(&number, &styles.normal)) / 2.0;
+                    Some(PlacedText {
+                        x,
+                        y: page_height - self.options.margin / 2.0,
+                        text: number,
+                        style: Style::Normal,
+                    })
+                } else {
+                    None
+                };
+
+                for text in pages[page_idx].iter().chain(page_number.iter()) {
+                    let style = styles.get(text.style);
+                    let (r, g, b) = style.color;
+                    layer.set_fill_color(Color::Rgb(Rgb::new(r, g, b, None)));
+                    layer.use_text(
+                        text.text.clone(),
+                        style.size,
+                        Mm::from(Pt(offset + text.x)),
+                        // PDF coordinates start from the bottom of the page
+                        Mm::from(Pt(page_height - text.y)),
+                        fonts.get(style),
+                    );
+                }
+            }
+        }
+
+        pdf.save(&mut BufWriter::new(w))
+            .map_err(|_| PdfError::Write)
+    }
+}
+
+impl From<Document> for PdfDocument {
+    fn from(doc: Document) -> Self {
+        Self::new().add_content(&doc)
+    }
+}
+
+impl Default for PdfDocument {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+struct Fonts(Vec<(printpdf::BuiltinFont, IndirectFontRef)>);
+
+impl Fonts {
+    fn new(pdf: &printpdf::PdfDocumentReference) -> Result<Self, PdfError> {
+        use printpdf::BuiltinFont::*;
+        [
+            TimesRoman,
+            TimesBold,
+            TimesItalic,
+            TimesBoldItalic,
+            Helvetica,
+            HelveticaBold,
+            HelveticaOblique,
+            HelveticaBoldOblique,
+        ]
+        .into_iter()
+        .map(|font| {
+            pdf.add_builtin_font(font)
+                .map(|font_ref| (font, font_ref))
+                .map_err(|_| PdfError::Write)
+        })
+        .collect::<Result<Vec<_>, _>>()
+        .map(Self)
+    }
+
+    fn get(&self, style: &TextStyle) -> &IndirectFontRef {
+        let font = style.builtin_font();
+        self.0
+            .iter()
+            .find(|(search, _)| *search == font)
+            .map(|(_, font_ref)| font_ref)
+            .unwrap_or(&self.0[0].1)
+    }
+}
+
+fn add_content(layout: &mut Layout, doc: &Document, styles: &PdfStyles) {
+    if doc.display == Show::Hidden {
+        return;
+    }
+
+    if let Some(label) = &doc.label {
+        layout.push(Paragraph::with_text(label, Style::Heading3).keep_with_next());
+    }
+    if let Some(subtitle) = &doc.subtitle {
+        layout.push(Paragraph::with_text(subtitle, Style::Citation).keep_with_next());
+    }
+
+    match &doc.content {
+        Content::Liturgy(liturgy) => {
+            for child in liturgy.body.iter() {
+                add_content(layout, child, styles);
+            }
+        }
+        Content::Series(series) => {
+            for child in series.iter() {
+                add_content(layout, child, styles);
+            }
+        }
+        // parallel texts are printed one after the other, as columns would be too narrow for a bulletin
+        Content::Parallel(parallel) => {
+            for child in parallel.iter() {
+                let version = child
+                    .version_label
+                    .clone()
+                    .unwrap_or_else(|| child.version.to_string());
+                layout.push(Paragraph::with_text(version, Style::Heading4).keep_with_next());
+                add_content(layout, child, styles);
+            }
+        }
+        Content::Choice(choice) => {
+            if let Some(selected_doc) = choice.options.get(choice.selected) {
+                add_content(layout, selected_doc, styles);
+            }
+        }
+        Content::CollectOfTheDay { allow_multiple: _ } => layout
+            .push(Paragraph::with_text("The Collect of the Day", Style::Heading3).keep_with_next()),
+        Content::PrefaceOfTheDay => layout
+            .push(Paragraph::with_text("The Proper Preface", Style::Heading3).keep_with_next()),
+        Content::DocumentLink { .. } => {}
+        Content::Empty => {}
+        Content::Error(content) => content.add_to_pdf(layout, styles),
+        Content::Antiphon(content) => content.add_to_pdf(layout, styles),
+        Content::BiblicalCitation(content) => content.add_to_pdf(layout, styles),
+        Content::BiblicalReading(content) => content.add_to_pdf(layout, styles),
+        Content::Canticle(content) => content.add_to_pdf(layout, styles),
+        Content::CanticleTableEntry(_) => {}
+        Content::GloriaPatri(content) => content.add_to_pdf(layout, styles),
+        Content::Heading(content) => content.add_to_pdf(layout, styles),
+        Content::HymnLink(content) => content.add_to_pdf(layout, styles),
+        Content::Invitatory(content) => content.add_to_pdf(layout, styles),
+        Content::LectionaryReading(_) => {}
+        Content::Litany(content) => content.add_to_pdf(layout, styles),
+        Content::Preces(content) => content.add_to_pdf(layout, styles),
+        Content::Psalm(content) => content.add_to_pdf(layout, styles),
+        Content::PsalmCitation(content) => content.add_to_pdf(layout, styles),
+        Content::ResponsivePrayer(content) => content.add_to_pdf(layout, styles),
+        Content::Rubric(content) => content.add_to_pdf(layout, styles),
+        Content::Sentence(content) => content.add_to_pdf(layout, styles),
+        Content::Text(content) => content.add_to_pdf(layout, styles),
+    }
+}
+
+/// A verse of a psalm or canticle, broken at the asterisk, with the second half indented
+fn verse_paragraph(number: Option<u8>, a: &str, b: &str, styles: &PdfStyles) -> Paragraph {
+    // some texts already include the asterisk at the end of the first half-verse
+    let a = a.trim().trim_end_matches('*').trim_end();
+    let a_lines = a.split('\n').collect::<Vec<_>>();
+    let a_len = a_lines.len();
+    let para = a_lines
+        .into_iter()
+        .enumerate()
+        .fold(Paragraph::new(), |para, (idx, line)| {
+            let mut runs = Vec::new();
+            if idx == 0 {
+                if let Some(number) = number {
+                    runs.push(Run::new(format!("{} ", number), Style::VerseNumber));
+                }
+            }
+            runs.push(Run::new(line.trim(), Style::Normal));
+            if idx == a_len - 1 && !b.trim().is_empty() {
+                runs.push(Run::new(" *", Style::Pointing));
+            }
+            para.line(runs)
+        });
+    b.trim()
+        .split('\n')
+        .filter(|line| !line.trim().is_empty())
+        .fold(para, |para, line| {
+            para.indented_line(
+                vec![Run::new(line.trim(), Style::Normal)],
+                styles.half_verse_indent,
+            )
+        })
+}
+
+trait AddToPdf {
+    fn add_to_pdf(&self, layout: &mut Layout, styles: &PdfStyles);
+}
+
+impl AddToPdf for DocumentError {
+    fn add_to_pdf(&self, layout: &mut Layout, _styles: &PdfStyles) {
+        layout.push(Paragraph::with_text(self, Style::Error))
+    }
+}
+
+impl AddToPdf for Antiphon {
+    fn add_to_pdf(&self, layout: &mut Layout, _styles: &PdfStyles) {
+        layout.push(Paragraph::with_text(self, Style::Antiphon))
+    }
+}
+
+impl AddToPdf for BiblicalCitation {
+    fn add_to_pdf(&self, layout: &mut Layout, _styles: &PdfStyles) {
+        layout.push(Paragraph::with_text(self, Style::Normal))
+    }
+}
+
+impl AddToPdf for BiblicalReading {
+    fn add_to_pdf(&self, layout: &mut Layout, styles: &PdfStyles) {
+        if let Some(intro) = &self.intro {
+            add_content(layout, &Document::from(intro.clone()), styles);
+        }
+
+        layout.push(Paragraph::with_text(&self.citation, Style::Heading3).keep_with_next());
+
+        // paragraph breaks are marked by a double line break at the end of a verse
+        let text = self
+            .text
+            .iter()
+            .map(|(_, text)| text.as_str())
+            .collect::<Vec<_>>()
+            .join(" ");
+        for paragraph in text.split("\n\n") {
+            let paragraph = paragraph.split_whitespace().collect::<Vec<_>>().join(" ");
+            layout.push(Paragraph::with_text(paragraph, Style::Normal));
+        }
+    }
+}
+
+impl AddToPdf for Canticle {
+    fn add_to_pdf(&self, layout: &mut Layout, styles: &PdfStyles) {
+        layout.push(
+            Paragraph::with_text(
+                format!("{}. {}", self.number, self.local_name),
+                Style::Heading3,
+            )
+            .keep_with_next(),
+        );
+        let mut subtitle = Vec::new();
+        if let Some(latin_name) = &self.latin_name {
+            subtitle.push(Run::new(latin_name, Style::Antiphon));
+        }
+        if let Some(citation) = &self.citation {
+            if !subtitle.is_empty() {
+                subtitle.push(Run::new("   ", Style::Normal));
+            }
+            subtitle.push(Run::new(citation, Style::Citation));
+        }
+        layout.push(Paragraph::new().line(subtitle).keep_with_next());
+        if let Some(rubric) = &self.rubric {
+            layout.push(Paragraph::with_text(rubric, Style::Rubric).keep_with_next());
+        }
+
+        for section in &self.sections {
+            if let Some(title) = &section.title {
+                layout.push(Paragraph::with_text(title, Style::Heading4).keep_with_next());
+            }
+            for verse in &section.verses {
+                layout.push(verse_paragraph(None, &verse.a, &verse.b, styles));
+            }
+        }
+
+        if let Some(gloria_patri) = &self.gloria_patri {
+            gloria_patri.add_to_pdf(layout, styles);
+        }
+    }
+}
+
+impl AddToPdf for GloriaPatri {
+    fn add_to_pdf(&self, layout: &mut Layout, styles: &PdfStyles) {
+        let (a, b, c, d) = &self.text;
+        layout.push(verse_paragraph(
+            None,
+            &format!("{} {}", a.trim(), b.trim()),
+            &format!("{} {}", c.trim(), d.trim()),
+            styles,
+        ))
+    }
+}
+
+impl AddToPdf for Heading {
+    fn add_to_pdf(&self, layout: &mut Layout, _styles: &PdfStyles) {
+        match self {
+            Heading::InsertDate => {}
+            Heading::InsertDay => {}
+            Heading::Date(text) => layout.push(
+                Paragraph::with_text(text, Style::Citation)
+                    .align(Align::Center)
+                    .keep_with_next(),
+            ),
+            Heading::Day {
+                name,
+                proper,
+                holy_days,
+            } => {
+                layout.push(
+                    Paragraph::with_text(name, Style::Heading2)
+                        .align(Align::Center)
+                        .keep_with_next(),
+                );
+                if let Some(proper) = proper {
+                    layout.push(
+                        Paragraph::with_text(proper, Style::Citation)
+                            .align(Align::Center)
+                            .keep_with_next(),
+                    );
+                }
+                if let Some(holy_days) = holy_days {
+                    layout.push(
+                        holy_days
+                            .iter()
+                            .fold(Paragraph::new(), |para, (_, holy_day)| {
+                                para.line(vec![Run::new(holy_day, Style::Normal)])
+                            })
+                            .align(Align::Center),
+                    );
+                }
+            }
+            Heading::Text(level, text) => {
+                let text = text.split_whitespace().collect::<Vec<_>>().join(" ");
+                let (style, align) = match level {
+                    HeadingLevel::Heading1 => (Style::Heading1, Align::Center),
+                    HeadingLevel::Heading2 => (Style::Heading2, Align::Center),
+                    HeadingLevel::Heading3 => (Style::Heading3, Align::Left),
+                    HeadingLevel::Heading4 => (Style::Heading4, Align::Left),
+                    HeadingLevel::Heading5 => (Style::Heading4, Align::Left),
+                };
+                layout.push(
+                    Paragraph::with_text(text, style)
+                        .align(align)
+                        .keep_with_next(),
+                )
+            }
+        }
+    }
+}
+
+impl AddToPdf for HymnLink {
+    fn add_to_pdf(&self, layout: &mut Layout, _styles: &PdfStyles) {
+        if let HymnLink::Hymn(hymnal, number) = self {
+            layout.push(Paragraph::with_text(
+                format!("{} {}", hymnal, number),
+                Style::Normal,
+            ))
+        }
+    }
+}
+
+impl AddToPdf for Invitatory {
+    fn add_to_pdf(&self, layout: &mut Layout, styles: &PdfStyles) {
+        layout.push(Paragraph::with_text(&self.local_name, Style::Heading3).keep_with_next());
+        let mut subtitle = Vec::new();
+        if let Some(latin_name) = &self.latin_name {
+            subtitle.push(Run::new(latin_name, Style::Antiphon));
+        }
+        if let Some(citation) = &self.citation {
+            if !subtitle.is_empty() {
+                subtitle.push(Run::new("   ", Style::Normal));
+            }
+            subtitle.push(Run::new(citation, Style::Citation));
+        }
+        layout.push(Paragraph::new().line(subtitle).keep_with_next());
+
+        if let SeasonalAntiphon::Antiphon(antiphon) = &self.antiphon {
+            antiphon.add_to_pdf(layout, styles);
+        }
+        for section in &self.sections {
+            for verse in &section.verses {
+                layout.push(verse_paragraph(None, &verse.a, &verse.b, styles));
+            }
+        }
+        if let Some(gloria_patri) = &self.gloria_patri {
+            gloria_patri.add_to_pdf(layout, styles);
+        }
+        if let SeasonalAntiphon::Antiphon(antiphon) = &self.antiphon {
+            antiphon.add_to_pdf(layout, styles);
+        }
+    }
+}
+
+impl AddToPdf for Litany {
+    fn add_to_pdf(&self, layout: &mut Layout, _styles: &PdfStyles) {
+        for line in self.iter() {
+            layout.push(
+                Paragraph::with_text(line, Style::Normal)
+                    .line(vec![Run::new(&self.response, Style::Response)]),
+            )
+        }
+    }
+}
+
+impl AddToPdf for Preces {
+    fn add_to_pdf(&self, layout: &mut Layout, _styles: &PdfStyles) {
+        layout.push(
+            self.iter()
+                .enumerate()
+                .fold(Paragraph::new(), |para, (idx, (label, text))| {
+                    let style = if idx % 2 == 1 {
+                        Style::Response
+                    } else {
+                        Style::Normal
+                    };
+                    let mut runs = Vec::new();
+                    if !label.trim().is_empty() {
+                        runs.push(Run::new(format!("{} ", label.trim()), Style::Antiphon));
+                    }
+                    runs.push(Run::new(text.trim(), style));
+                    para.line(runs)
+                }),
+        )
+    }
+}
+
+impl AddToPdf for Psalm {
+    fn add_to_pdf(&self, layout: &mut Layout, styles: &PdfStyles) {
+        layout.push(
+            Paragraph::with_text(format!("Psalm {}", self.number), Style::Heading3)
+                .keep_with_next(),
+        );
+        if let Some(citation) = &self.citation {
+            layout.push(Paragraph::with_text(citation, Style::Citation).keep_with_next());
+        }
+
+        for section in self.filtered_sections() {
+            let mut names = Vec::new();
+            if !section.local_name.is_empty() {
+                names.push(Run::new(&section.local_name, Style::Normal));
+            }
+            if !section.latin_name.is_empty() {
+                if !names.is_empty() {
+                    names.push(Run::new("   ", Style::Normal));
+                }
+                names.push(Run::new(&section.latin_name, Style::Antiphon));
+            }
+            layout.push(Paragraph::new().line(names).keep_with_next());
+
+            for verse in &section.verses {
+                layout.push(verse_paragraph(
+                    Some(verse.number),
+                    &verse.a,
+                    &verse.b,
+                    styles,
+                ));
+            }
+        }
+    }
+}
+
+impl AddToPdf for PsalmCitation {
+    fn add_to_pdf(&self, layout: &mut Layout, _styles: &PdfStyles) {
+        layout.push(Paragraph::with_text(self, Style::Normal))
+    }
+}
+
+impl AddToPdf for ResponsivePrayer {
+    fn add_to_pdf(&self, layout: &mut Layout, _styles: &PdfStyles) {
+        layout.push(
+            self.iter()
+                .enumerate()
+                .fold(Paragraph::new(), |para, (idx, line)| {
+                    if idx % 2 == 1 {
+                        para.text(line, Style::Response)
+                    } else {
+                        para.text(line, Style::Normal)
+                    }
+                }),
+        )
+    }
+}
+
+impl AddToPdf for Rubric {
+    fn add_to_pdf(&self, layout: &mut Layout, _styles: &PdfStyles) {
+        // a rubric describes what follows, so it shouldn't be separated from it
+        let para = Paragraph::with_text(self, Style::Rubric);
+        layout.push(if self.long {
+            para
+        } else {
+            para.keep_with_next()
+        })
+    }
+}
+
+impl AddToPdf for Sentence {
+    fn add_to_pdf(&self, layout: &mut Layout, styles: &PdfStyles) {
+        let text = Paragraph::with_text(&self.text, Style::Normal);
+
+        match self.response.as_deref().map(|response| &response.content) {
+            // short responses, like "Alleluia," follow the sentence on the same line
+            Some(Content::Text(response)) if response.text.len() < 10 => layout.push(text.append(
+                vec![Run::new(format!(" {}", response.text), Style::Response)],
+            )),
+            Some(_) => {
+                layout.push(text);
+                if let Some(response) = &self.response {
+                    add_content(layout, response, styles);
+                }
+            }
+            None => layout.push(text),
+        }
+
+        if let Some(citation) = &self.citation {
+            layout.push(Paragraph::with_text(citation, Style::Citation).align(Align::Right));
+        }
+    }
+}
+
+impl AddToPdf for Text {
+    fn add_to_pdf(&self, layout: &mut Layout, _styles: &PdfStyles) {
+        let para = Paragraph::with_text(&self.text, Style::Normal);
+        let para = if let Some(response) = &self.response {
+            para.append(vec![Run::new(format!(" {}", response), Style::Response)])
+        } else {
+            para
+        };
+        layout.push(para)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use calendar::Feast;
+    use canticle_table::{CanticleId, CanticleNumber};
+    use hymnal::{HymnNumber, Hymnals};
+    use reference_parser::{BibleVerse, BibleVersePart, Book};
+
+    /// The runs of each line laid out for the document, ignoring indentation
+    fn lines(doc: impl Into<Document>) -> Vec<Vec<(String, Style)>> {
+        indented_lines(doc)
+            .into_iter()
+            .map(|(_, runs)| runs)
+            .collect()
+    }
+
+    fn indented_lines(doc: impl Into<Document>) -> Vec<(f32, Vec<(String, Style)>)> {
+        let mut layout = Layout::default();
+        add_content(&mut layout, &doc.into(), &PdfStyles::default());
+        layout
+            .lines()
+            .into_iter()
+            .map(|(indent, runs)| {
+                let runs = runs
+                    .into_iter()
+                    .map(|(text, style)| (text.to_string(), style))
+                    .collect();
+                (indent, runs)
+            })
+            .collect()
+    }
+
+    fn line(runs: &[(&str, Style)]) -> Vec<(String, Style)> {
+        runs.iter()
+            .map(|(text, style)| (text.to_string(), *style))
+            .collect()
+    }
+
+    fn gloria_patri() -> GloriaPatri {
+        GloriaPatri::from((
+            "Glory to the Father, and to the Son,",
+            "and to the Holy Spirit: *",
+            "as it was in the beginning, is now,",
+            "and will be for ever. Amen.",
+        ))
+    }
+
+    fn psalm() -> Psalm {
+        Psalm {
+            number: 117,
+            citation: None,
+            sections: vec![PsalmSection {
+                reference: Reference::from(760),
+                local_name: String::new(),
+                latin_name: "Laudate Dominum".into(),
+                verses: vec![
+                    PsalmVerse {
+                        number: 1,
+                        a: "Praise the LORD, all you nations; *".into(),
+                        b: "laud him, all you peoples.".into(),
+                    },
+                    PsalmVerse {
+                        number: 2,
+                        a: "For his loving-kindness toward us is great, *".into(),
+                        b: "and the faithfulness of the LORD endures for ever.\nHallelujah!".into(),
+                    },
+                ],
+            }],
+        }
+    }
+
+    #[test]
+    fn psalm_has_verse_numbers_and_asterisks() {
+        let indent = PdfStyles::default().half_verse_indent;
+        assert_eq!(
+            indented_lines(psalm()),
+            vec![
+                (0.0, line(&[("Psalm 117", Style::Heading3)])),
+                (0.0, line(&[("Laudate Dominum", Style::Antiphon)])),
+                (
+                    0.0,
+                    line(&[
+                        ("1 ", Style::VerseNumber),
+                        ("Praise the LORD, all you nations;", Style::Normal),
+                        (" *", Style::Pointing)
+                    ])
+                ),
+                (
+                    indent,
+                    line(&[("laud him, all you peoples.", Style::Normal)])
+                ),
+                (
+                    0.0,
+                    line(&[
+                        ("2 ", Style::VerseNumber),
+                        ("For his loving-kindness toward us is great,", Style::Normal),
+                        (" *", Style::Pointing)
+                    ])
+                ),
+                (
+                    indent,
+                    line(&[(
+                        "and the faithfulness of the LORD endures for ever.",
+                        Style::Normal
+                    )])
+                ),
+                (indent, line(&[("Hallelujah!", Style::Normal)])),
+            ]
+        );
+    }
+
+    #[test]
+    fn canticle_and_gloria_patri_have_asterisks() {
+        let canticle = Canticle {
+            number: CanticleId::Canticle13,
+            changeable: None,
+            citation: Some("Song of the Three Young Men, 29-34".into()),
+            local_name: "A Song of Praise".into(),
+            latin_name: Some("Benedictus es, Domine".into()),
+            rubric: None,
+            sections: vec![CanticleSection {
+                title: None,
+                verses: vec![CanticleVerse::from((
+                    "Glory to you, Lord God of our fathers; *",
+                    "you are worthy of praise; glory to you.",
+                ))],
+            }],
+            gloria_patri: Some(gloria_patri()),
+        };
+        assert_eq!(
+            lines(canticle),
+            vec![
+                line(&[("13. A Song of Praise", Style::Heading3)]),
+                line(&[
+                    ("Benedictus es, Domine", Style::Antiphon),
+                    ("   ", Style::Normal),
+                    ("Song of the Three Young Men, 29-34", Style::Citation)
+                ]),
+                line(&[
+                    ("Glory to you, Lord God of our fathers;", Style::Normal),
+                    (" *", Style::Pointing)
+                ]),
+                line(&[("you are worthy of praise; glory to you.", Style::Normal)]),
+                line(&[
+                    (
+                        "Glory to the Father, and to the Son, and to the Holy Spirit:",
+                        Style::Normal
+                    ),
+                    (" *", Style::Pointing)
+                ]),
+                line(&[(
+                    "as it was in the beginning, is now, and will be for ever. Amen.",
+                    Style::Normal
+                )]),
+            ]
+        );
+    }
+
+    #[test]
+    fn invitatory_repeats_its_antiphon() {
+        let invitatory = Invitatory {
+            local_name: "Jubilate".into(),
+            latin_name: None,
+            citation: Some("Psalm 100".into()),
+            antiphon: SeasonalAntiphon::Antiphon(Antiphon::from("The Lord is risen indeed.")),
+            sections: vec![InvitatorySection {
+                verses: vec![InvitatoryVerse {
+                    a: "Be joyful in the LORD, all you lands; *".into(),
+                    b: "serve the LORD with gladness.".into(),
+                }],
+            }],
+            gloria_patri: None,
+        };
+        assert_eq!(
+            lines(invitatory),
+            vec![
+                line(&[("Jubilate", Style::Heading3)]),
+                line(&[("Psalm 100", Style::Citation)]),
+                line(&[("The Lord is risen indeed.", Style::Antiphon)]),
+                line(&[
+                    ("Be joyful in the LORD, all you lands;", Style::Normal),
+                    (" *", Style::Pointing)
+                ]),
+                line(&[("serve the LORD with gladness.", Style::Normal)]),
+                line(&[("The Lord is risen indeed.", Style::Antiphon)]),
+            ]
+        );
+    }
+
+    #[test]
+    fn responses_are_bold() {
+        assert_eq!(
+            lines(ResponsivePrayer::from([
+                "Show us your mercy, O Lord;",
+                "And grant us your salvation."
+            ])),
+            vec![
+                line(&[("Show us your mercy, O Lord;", Style::Normal)]),
+                line(&[("And grant us your salvation.", Style::Response)]),
+            ]
+        );
+        assert_eq!(
+            lines(Preces::from([
+                ("V.", "Show us your mercy, O Lord;"),
+                ("R.", "And grant us your salvation.")
+            ])),
+            vec![
+                line(&[
+                    ("V. ", Style::Antiphon),
+                    ("Show us your mercy, O Lord;", Style::Normal)
+                ]),
+                line(&[
+                    ("R. ", Style::Antiphon),
+                    ("And grant us your salvation.", Style::Response)
+                ]),
+            ]
+        );
+        assert_eq!(
+            lines(Litany::from((
+                "Lord, have mercy.",
+                ["For the peace of the world,"]
+            ))),
+            vec![
+                line(&[("For the peace of the world,", Style::Normal)]),
+                line(&[("Lord, have mercy.", Style::Response)]),
+            ]
+        );
+        assert_eq!(
+            lines(Text::from("The Lord be with you.").response("And also with you.")),
+            vec![line(&[
+                ("The Lord be with you.", Style::Normal),
+                (" And also with you.", Style::Response)
+            ])]
+        );
+        assert_eq!(
+            lines(
+                Sentence::from("Christ is risen from the dead.")
+                    .response(Text::from("Alleluia."))
+                    .citation("1 Corinthians 15:20")
+            ),
+            vec![
+                line(&[
+                    ("Christ is risen from the dead.", Style::Normal),
+                    (" Alleluia.", Style::Response)
+                ]),
+                line(&[("1 Corinthians 15:20", Style::Citation)]),
+            ]
+        );
+    }
+
+    #[test]
+    fn choice_shows_only_the_selected_option() {
+        let mut choice = Choice::from([
+            Document::from("The first option."),
+            Document::from("The second option."),
+        ]);
+        choice.selected = 1;
+        assert_eq!(
+            lines(choice),
+            vec![line(&[("The second option.", Style::Normal)])]
+        );
+    }
+
+    #[test]
+    fn parallel_labels_each_version() {
+        let parallel = Parallel::from([
+            Document::from("And with thy spirit.").version(Version::RiteI),
+            Document::from("And also with you.")
+                .version(Version::RiteII)
+                .version_label("Contemporary"),
+        ]);
+        assert_eq!(
+            lines(parallel),
+            vec![
+                line(&[("Rite I", Style::Heading4)]),
+                line(&[("And with thy spirit.", Style::Normal)]),
+                line(&[("Contemporary", Style::Heading4)]),
+                line(&[("And also with you.", Style::Normal)]),
+            ]
+        );
+    }
+
+    #[test]
+    fn series_and_liturgy_render_their_children() {
+        let children = vec![
+            Document::from(Rubric::from("The People stand.")),
+            Document::from(Heading::from((HeadingLevel::Heading2, "The Word of God"))),
+        ];
+        let expected = vec![
+            line(&[("The People stand.", Style::Rubric)]),
+            line(&[("The Word of God", Style::Heading2)]),
+        ];
+        assert_eq!(lines(Series::from(children.clone())), expected);
+        assert_eq!(lines(Liturgy::from(children)), expected);
+    }
+
+    #[test]
+    fn headings() {
+        assert_eq!(
+            lines(Heading::Day {
+                name: "Tuesday, November 30".into(),
+                proper: Some("Week of the First Sunday of Advent".into()),
+                holy_days: Some(vec![(Feast::Andrew, "Saint Andrew the Apostle".into())]),
+            }),
+            vec![
+                line(&[("Tuesday, November 30", Style::Heading2)]),
+                line(&[("Week of the First Sunday of Advent", Style::Citation)]),
+                line(&[("Saint Andrew the Apostle", Style::Normal)]),
+            ]
+        );
+        assert_eq!(
+            lines(Heading::Date("November 30, 2021".into())),
+            vec![line(&[("November 30, 2021", Style::Citation)])]
+        );
+        assert_eq!(
+            lines(Heading::from((HeadingLevel::Heading1, "Morning   Prayer"))),
+            vec![line(&[("Morning Prayer", Style::Heading1)])]
+        );
+        assert!(lines(Heading::InsertDay).is_empty());
+        assert!(lines(Heading::InsertDate).is_empty());
+    }
+
+    #[test]
+    fn readings_and_citations() {
+        let verse = |verse| BibleVerse {
+            book: Book::John,
+            chapter: 1,
+            verse,
+            verse_part: BibleVersePart::All,
+        };
+        let reading = BiblicalReading {
+            citation: "John 1:1-2".into(),
+            text: vec![
+                (verse(1), "In the beginning was the Word.\n\n".into()),
+                (verse(2), "He was in the beginning with God.".into()),
+            ],
+            intro: Some(BiblicalReadingIntro::from(
+                "A Reading from the Gospel of John.",
+            )),
+        };
+        assert_eq!(
+            lines(reading),
+            vec![
+                line(&[("A Reading from the Gospel of John.", Style::Normal)]),
+                line(&[("John 1:1-2", Style::Heading3)]),
+                line(&[("In the beginning was the Word.", Style::Normal)]),
+                line(&[("He was in the beginning with God.", Style::Normal)]),
+            ]
+        );
+        assert_eq!(
+            lines(BiblicalCitation::from("John 1:1-18".to_string())),
+            vec![line(&[("John 1:1-18", Style::Normal)])]
+        );
+        assert_eq!(
+            lines(PsalmCitation::from("Psalm 117")),
+            vec![line(&[("Psalm 117", Style::Normal)])]
+        );
+        assert_eq!(
+            lines(Antiphon::from("Alleluia. Christ is risen.")),
+            vec![line(&[("Alleluia. Christ is risen.", Style::Antiphon)])]
+        );
+    }
+
+    #[test]
+    fn other_content() {
+        assert_eq!(
+            lines(Rubric::from("The People kneel.")),
+            vec![line(&[("The People kneel.", Style::Rubric)])]
+        );
+        assert_eq!(
+            lines(HymnLink::Hymn(Hymnals::Hymnal1982, HymnNumber::H(390))),
+            vec![line(&[("H82 390", Style::Normal)])]
+        );
+        assert!(lines(HymnLink::Hymnals).is_empty());
+        assert_eq!(
+            lines(DocumentError::from("No reading found.")),
+            vec![line(&[("No reading found.", Style::Error)])]
+        );
+        assert_eq!(
+            lines(Content::CollectOfTheDay {
+                allow_multiple: true
+            }),
+            vec![line(&[("The Collect of the Day", Style::Heading3)])]
+        );
+        assert_eq!(
+            lines(Content::PrefaceOfTheDay),
+            vec![line(&[("The Proper Preface", Style::Heading3)])]
+        );
+        assert_eq!(
+            lines(gloria_patri()),
+            vec![
+                line(&[
+                    (
+                        "Glory to the Father, and to the Son, and to the Holy Spirit:",
+                        Style::Normal
+                    ),
+                    (" *", Style::Pointing)
+                ]),
+                line(&[(
+                    "as it was in the beginning, is now, and will be for ever. Amen.",
+                    Style::Normal
+                )]),
+            ]
+        );
+    }
+
+    #[test]
+    fn placeholders_are_omitted() {
+        assert!(lines(Content::Empty).is_empty());
+        assert!(lines(Content::DocumentLink {
+            label: "The Great Litany".into(),
+            path: SlugPath::from([Slug::GreatLitany]),
+            rotate: false,
+            link_only: true
+        })
+        .is_empty());
+        assert!(lines(CanticleTableEntry {
+            nth: CanticleNumber::One,
+            table: CanticleTableChoice::Selected(CanticleTables::BCP1979RiteII)
+        })
+        .is_empty());
+        assert!(lines(LectionaryReading::default()).is_empty());
+        assert!(lines(Document::from("Not shown.").display(Show::Hidden)).is_empty());
+    }
+
+    #[test]
+    fn label_and_subtitle_come_first() {
+        assert_eq!(
+            lines(
+                Document::from("Almighty God, unto whom all hearts are open.")
+                    .label("The Collect for Purity")
+                    .subtitle("said by the Celebrant")
+            ),
+            vec![
+                line(&[("The Collect for Purity", Style::Heading3)]),
+                line(&[("said by the Celebrant", Style::Citation)]),
+                line(&[(
+                    "Almighty God, unto whom all hearts are open.",
+                    Style::Normal
+                )]),
+            ]
+        );
+    }
+
+    #[test]
+    fn writes_verses_and_responses() {
+        let doc = Document::from(Series::from(vec![
+            Document::from(psalm()),
+            Document::from(gloria_patri()),
+            Document::from(Preces::from([
+                ("V.", "O Lord, open our lips."),
+                ("R.", "And our mouth shall proclaim your praise."),
+            ])),
+            Document::from(Content::CollectOfTheDay {
+                allow_multiple: false,
+            }),
+        ]));
+        let mut bytes = Vec::new();
+        PdfDocument::from(doc).write(&mut bytes).unwrap();
+        assert!(bytes.starts_with(b"%PDF"));
+    }
+}
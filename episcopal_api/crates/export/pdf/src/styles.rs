@@ -0,0 +1,303 @@
+use printpdf::BuiltinFont;
+
+/// Points per inch; all measurements in PDF exports are given in points
+pub const POINTS_PER_INCH: f32 = 72.0;
+const POINTS_PER_MM: f32 = POINTS_PER_INCH / 25.4;
+
+/// The size of each page of the exported document (i.e., of each page of a booklet, not of the sheet it is printed on)
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub enum PageSize {
+    /// US Letter, 8.5 × 11 inches
+    #[default]
+    Letter,
+    /// Half of a US Letter sheet, 5.5 × 8.5 inches, as in a booklet-style bulletin
+    HalfLetter,
+    /// US Legal, 8.5 × 14 inches
+    Legal,
+    /// A4, 210 × 297 mm
+    A4,
+    /// A5 (half of an A4 sheet), 148 × 210 mm
+    A5,
+    /// Any other page size, in points
+    Custom { width: f32, height: f32 },
+}
+
+impl PageSize {
+    /// The width and height of the page, in points
+    /// ```
+    /// # use pdf::PageSize;
+    /// assert_eq!(PageSize::HalfLetter.dimensions(), (396.0, 612.0));
+    /// ```
+    pub fn dimensions(&self) -> (f32, f32) {
+        match self {
+            PageSize::Letter => (8.5 * POINTS_PER_INCH, 11.0 * POINTS_PER_INCH),
+            PageSize::HalfLetter => (5.5 * POINTS_PER_INCH, 8.5 * POINTS_PER_INCH),
+            PageSize::Legal => (8.5 * POINTS_PER_INCH, 14.0 * POINTS_PER_INCH),
+            PageSize::A4 => (210.0 * POINTS_PER_MM, 297.0 * POINTS_PER_MM),
+            PageSize::A5 => (148.0 * POINTS_PER_MM, 210.0 * POINTS_PER_MM),
+            PageSize::Custom { width, height } => (*width, *height),
+        }
+    }
+}
+
+impl From<&str> for PageSize {
+    fn from(s: &str) -> Self {
+        match s.to_lowercase().replace(['-', '_', ' '], "").as_str() {
+            "halfletter" => Self::HalfLetter,
+            "legal" => Self::Legal,
+            "a4" => Self::A4,
+            "a5" => Self::A5,
+            _ => Self::Letter,
+        }
+    }
+}
+
+/// How pages are arranged on the printed sheets
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum Imposition {
+    /// One page per sheet
+    #[default]
+    None,
+    /// Two pages side by side on each side of a sheet twice as wide as the page, ordered such that
+    /// the printed sheets can be stacked, folded in half, and stapled into a booklet
+    Booklet,
+}
+
+/// The typeface family used for a [TextStyle]. Only the standard PDF fonts are available,
+/// so that documents can be generated without any font files.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum FontFamily {
+    Serif,
+    SansSerif,
+}
+
+/// Typographic settings for one kind of text
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct TextStyle {
+    pub family: FontFamily,
+    /// Font size, in points
+    pub size: f32,
+    pub bold: bool,
+    pub italic: bool,
+    /// RGB color, with each component between 0 and 1
+    pub color: (f32, f32, f32),
+}
+
+impl TextStyle {
+    pub fn new(size: f32) -> Self {
+        Self {
+            family: FontFamily::Serif,
+            size,
+            bold: false,
+            italic: false,
+            color: (0.0, 0.0, 0.0),
+        }
+    }
+
+    #[must_use]
+    pub fn family(mut self, family: FontFamily) -> Self {
+        self.family = family;
+        self
+    }
+
+    #[must_use]
+    pub fn bold(mut self) -> Self {
+        self.bold = true;
+        self
+    }
+
+    #[must_use]
+    pub fn italic(mut self) -> Self {
+        self.italic = true;
+        self
+    }
+
+    #[must_use]
+    pub fn color(mut self, r: f32, g: f32, b: f32) -> Self {
+        self.color = (r, g, b);
+        self
+    }
+
+    /// The distance between the baselines of two lines in this style
+    pub fn line_height(&self) -> f32 {
+        self.size * 1.25
+    }
+
+    pub(crate) fn builtin_font(&self) -> BuiltinFont {
+        match (self.family, self.bold, self.italic) {
+            (FontFamily::Serif, false, false) => BuiltinFont::TimesRoman,
+            (FontFamily::Serif, true, false) => BuiltinFont::TimesBold,
+            (FontFamily::Serif, false, true) => BuiltinFont::TimesItalic,
+            (FontFamily::Serif, true, true) => BuiltinFont::TimesBoldItalic,
+            (FontFamily::SansSerif, false, false) => BuiltinFont::Helvetica,
+            (FontFamily::SansSerif, true, false) => BuiltinFont::HelveticaBold,
+            (FontFamily::SansSerif, false, true) => BuiltinFont::HelveticaOblique,
+            (FontFamily::SansSerif, true, true) => BuiltinFont::HelveticaBoldOblique,
+        }
+    }
+}
+
+/// The kinds of text that can be styled separately
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+pub enum Style {
+    Normal,
+    Heading1,
+    Heading2,
+    Heading3,
+    Heading4,
+    Rubric,
+    Response,
+    Antiphon,
+    Citation,
+    /// Verse numbers in psalms
+    VerseNumber,
+    /// The asterisk that marks the division between the two halves of a psalm or canticle verse
+    Pointing,
+    Error,
+}
+
+/// The [TextStyle] for each [Style] of text, along with paragraph spacing
+#[derive(Clone, Debug, PartialEq)]
+pub struct PdfStyles {
+    pub normal: TextStyle,
+    pub heading_1: TextStyle,
+    pub heading_2: TextStyle,
+    pub heading_3: TextStyle,
+    pub heading_4: TextStyle,
+    pub rubric: TextStyle,
+    pub response: TextStyle,
+    pub antiphon: TextStyle,
+    pub citation: TextStyle,
+    pub verse_number: TextStyle,
+    pub pointing: TextStyle,
+    pub error: TextStyle,
+    /// Space between paragraphs, in points
+    pub paragraph_spacing: f32,
+    /// Indentation of the second half of each psalm or canticle verse, in points
+    pub half_verse_indent: f32,
+}
+
+impl PdfStyles {
+    /// Default styles at a given body text size, with headings and other styles scaled to match
+    pub fn with_size(size: f32) -> Self {
+        Self {
+            normal: TextStyle::new(size),
+            heading_1: TextStyle::new(size * 1.75).bold(),
+            heading_2: TextStyle::new(size * 1.4).bold(),
+            heading_3: TextStyle::new(size * 1.15).bold(),
+            heading_4: TextStyle::new(size).bold().italic(),
+            rubric: TextStyle::new(size * 0.9).italic().color(0.7, 0.0, 0.0),
+            response: TextStyle::new(size).bold(),
+            antiphon: TextStyle::new(size).italic(),
+            citation: TextStyle::new(size * 0.9).italic(),
+            verse_number: TextStyle::new(size * 0.75),
+            pointing: TextStyle::new(size).color(0.7, 0.0, 0.0),
+            error: TextStyle::new(size).bold().color(0.8, 0.0, 0.0),
+            paragraph_spacing: size * 0.6,
+            half_verse_indent: size * 2.0,
+        }
+    }
+
+    pub fn get(&self, style: Style) -> &TextStyle {
+        match style {
+            Style::Normal => &self.normal,
+            Style::Heading1 => &self.heading_1,
+            Style::Heading2 => &self.heading_2,
+            Style::Heading3 => &self.heading_3,
+            Style::Heading4 => &self.heading_4,
+            Style::Rubric => &self.rubric,
+            Style::Response => &self.response,
+            Style::Antiphon => &self.antiphon,
+            Style::Citation => &self.citation,
+            Style::VerseNumber => &self.verse_number,
+            Style::Pointing => &self.pointing,
+            Style::Error => &self.error,
+        }
+    }
+}
+
+impl Default for PdfStyles {
+    fn default() -> Self {
+        Self::with_size(12.0)
+    }
+}
+
+/// Page and typographic settings for a PDF export
+/// ```
+/// # use pdf::{Imposition, PageSize, PdfOptions};
+/// let options = PdfOptions::default()
+///     .page_size(PageSize::HalfLetter)
+///     .imposition(Imposition::Booklet);
+/// assert_eq!(options.sheet_dimensions(), (792.0, 612.0));
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct PdfOptions {
+    pub page_size: PageSize,
+    /// Margin on every side of the page, in points
+    pub margin: f32,
+    pub imposition: Imposition,
+    pub page_numbers: bool,
+    pub styles: PdfStyles,
+}
+
+impl PdfOptions {
+    /// Settings for a bulletin printed as a folded booklet of half-letter pages
+    pub fn booklet() -> Self {
+        Self::default()
+            .page_size(PageSize::HalfLetter)
+            .margin(0.5 * POINTS_PER_INCH)
+            .imposition(Imposition::Booklet)
+            .styles(PdfStyles::with_size(11.0))
+    }
+
+    #[must_use]
+    pub fn page_size(mut self, page_size: PageSize) -> Self {
+        self.page_size = page_size;
+        self
+    }
+
+    #[must_use]
+    pub fn margin(mut self, margin: f32) -> Self {
+        self.margin = margin;
+        self
+    }
+
+    #[must_use]
+    pub fn imposition(mut self, imposition: Imposition) -> Self {
+        self.imposition = imposition;
+        self
+    }
+
+    #[must_use]
+    pub fn page_numbers(mut self, page_numbers: bool) -> Self {
+        self.page_numbers = page_numbers;
+        self
+    }
+
+    #[must_use]
+    pub fn styles(mut self, styles: PdfStyles) -> Self {
+        self.styles = styles;
+        self
+    }
+
+    /// The width and height of each printed sheet, in points
+    pub fn sheet_dimensions(&self) -> (f32, f32) {
+        let (width, height) = self.page_size.dimensions();
+        match self.imposition {
+            Imposition::None => (width, height),
+            Imposition::Booklet => (width * 2.0, height),
+        }
+    }
+}
+
+impl Default for PdfOptions {
+    fn default() -> Self {
+        Self {
+            page_size: PageSize::default(),
+            margin: POINTS_PER_INCH,
+            imposition: Imposition::default(),
+            page_numbers: true,
+            styles: PdfStyles::default(),
+        }
+    }
+}
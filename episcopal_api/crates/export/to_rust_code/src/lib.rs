@@ -126,12 +126,17 @@ impl ToRustCode for Document {
         .collect();
 
         let content = self.content.to_rust_code(start_tabs + 1);
+        let translations: String = self
+            .translations
+            .iter()
+            .map(|translation| format!("\n\t.translation({})", translation.to_rust_code(0)))
+            .collect();
         let tabs = (0..start_tabs).map(|_| '\t').collect::<String>();
 
         if metadata.is_empty() {
-            format!("{tabs}Document::from({content}){condition}")
+            format!("{tabs}Document::from({content}){condition}{translations}")
         } else {
-            format!("{tabs}Document::new(){condition}{metadata}\n\t.content({content})")
+            format!("{tabs}Document::new(){condition}{metadata}\n\t.content({content}){translations}")
         }
         .replace('\n', &format!("\n{tabs}"))
     }
@@ -244,6 +249,7 @@ impl ToRustCode for Content {
                 "Content::CollectOfTheDay {{ allow_multiple: {} }}",
                 allow_multiple
             ),
+            Content::PrefaceOfTheDay => "Content::PrefaceOfTheDay".to_string(),
             Content::Empty => "Content::Empty".to_string(),
             Content::Error(content) => {
                 format!("Content::Error(DocumentError::from({:?}))", content)
@@ -357,6 +363,7 @@ impl ToRustCode for Condition {
         let c = match self {
             Condition::Day(day) => format!("Day({})", day.to_rust_code(0)),
             Condition::Feast(feast) => format!("Feast(Feast::{})", feast),
+            Condition::WithinOctave(feast) => format!("WithinOctave(Feast::{})", feast),
             Condition::Season(season) => format!("Season(Season::{})", season),
             Condition::ObservedSeason(season) => format!("ObservedSeason(Season::{})", season),
             Condition::Week(week) => format!("Week(LiturgicalWeek::{})", week),
@@ -9,11 +9,21 @@ use thiserror::Error;
 mod el_himnario;
 mod h82;
 mod levas;
+mod meter;
+mod metrical_index;
+mod search;
+#[cfg(any(feature = "browser", feature = "server"))]
+mod suggestions;
 mod wlp;
 
 pub use el_himnario::*;
 pub use h82::*;
 pub use levas::*;
+pub use meter::*;
+pub use metrical_index::*;
+pub use search::*;
+#[cfg(any(feature = "browser", feature = "server"))]
+pub use suggestions::*;
 pub use wlp::*;
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -103,59 +113,17 @@ impl Hymnal {
         }
     }
 
+    /// Hymns in this hymnal that match the search, from most to least relevant,
+    /// using the prebuilt [HYMN_SEARCH_INDEX]. See [HymnSearchIndex] for the search syntax.
     pub fn search(&self, search: &str) -> impl Iterator<Item = HymnMetadata> + '_ {
-        if search.is_empty() {
-            Box::new(self.hymns.iter().map(Hymn::to_metadata)) as Box<dyn Iterator<Item = HymnMetadata>>
-        } else {
-            let orig_search = search.to_string();
-            let search = orig_search.clone();
-
-            let tag = if search.starts_with("tag:") {
-                Some(search.replace("tag:", ""))
-            } else {
-                None
-            };
-
-            Box::new(self
-                .hymns
-                .iter()
-                .filter(move |hymn| {
-                    if let Some(tag) = &tag {
-                        hymn.tags.contains(tag)
-                    } else {
-                        let search = strip_non_word_characters(&search.to_lowercase());
-                        let number = strip_non_word_characters(&hymn.number.to_string().to_lowercase());
-                        let title = strip_non_word_characters(&hymn.title.to_lowercase());
-                        let tune = strip_non_word_characters(&hymn.tune.to_lowercase());
-                        let authors = strip_non_word_characters(&hymn.authors.to_lowercase());
-                        let composers = strip_non_word_characters(&hymn.composers.to_lowercase());
-                        let text = strip_non_word_characters(&hymn.text.to_lowercase());
-
-                        number.contains(&search)
-                            || title.contains(&search)
-                            || tune.contains(&search)
-                            || authors.contains(&search)
-                            || composers.contains(&search)
-                            || hymn.meter.contains(&orig_search)
-                            || text.contains(&search)
-                    }
-                })
-                .map(|hymn| hymn.to_metadata()))
-            }
+        HYMN_SEARCH_INDEX
+            .search(search)
+            .into_iter()
+            .filter(move |hymn| hymn.source == self.id)
     }
 }
 
-fn strip_non_word_characters(original: &str) -> String {
-    original.chars().filter(|ch| 
-        // so that date ranges don't get read as numbers, i.e., "111" should not match "1711-1759"
-        ch == &'-'
-        // letters
-        || ('a'..='z').contains(ch)
-        // digits so can search by hymn number
-        || ('0'..='9').contains(ch)).collect()
-}
-
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Hash, Eq)]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize, Hash, Eq)]
 pub struct Hymn {
     #[serde(skip_serializing_if = "Hymnals::is_default", default)]
     pub source: Hymnals,
@@ -0,0 +1,264 @@
+use std::str::FromStr;
+
+use serde::{
+    de::{Error, Unexpected},
+    Deserialize, Serialize,
+};
+use thiserror::Error;
+
+/// The meter of a hymn: the number of syllables in each line of a stanza, as in "8.7.8.7 D" or "CM".
+///
+/// Doubled meters are stored with every line written out, so that "8.7.8.7 D" and "8.7.8.7.8.7.8.7"
+/// are the same meter, as are "CM" and "8.6.8.6".
+/// ```
+/// # use hymnal::Meter;
+/// let meter: Meter = "8.7.8.7 D".parse().unwrap();
+/// assert_eq!(meter.lines, vec![8, 7, 8, 7, 8, 7, 8, 7]);
+/// assert_eq!(meter.to_string(), "8.7.8.7 D");
+///
+/// assert_eq!("CM".parse::<Meter>(), "8.6.8.6".parse::<Meter>());
+/// assert_eq!("L.M.D.".parse::<Meter>(), "8.8.8.8 D".parse::<Meter>());
+/// assert_eq!("8 7 8 7 d".parse::<Meter>(), "8.7.8.7 D".parse::<Meter>());
+/// assert_eq!("8.6.8.6".parse::<Meter>().unwrap().name(), Some("CM"));
+///
+/// let meter: Meter = "7.7.7.7 with alleluias".parse().unwrap();
+/// assert!(meter.alleluias);
+/// assert_ne!(meter, "7.7.7.7".parse().unwrap());
+///
+/// assert!("Irregular".parse::<Meter>().unwrap().is_irregular());
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Meter {
+    /// Syllables in each line; empty if the meter is irregular
+    pub lines: Vec<u8>,
+    /// Whether each stanza is followed by a refrain
+    pub refrain: bool,
+    /// Whether each stanza includes or is followed by alleluias
+    pub alleluias: bool,
+}
+
+/// Common names for frequently-used meters
+const NAMED_METERS: [(&str, [u8; 4]); 3] = [
+    ("CM", [8, 6, 8, 6]),
+    ("LM", [8, 8, 8, 8]),
+    ("SM", [6, 6, 8, 6]),
+];
+
+impl Meter {
+    /// An irregular meter, i.e., one without a fixed number of syllables in each line
+    pub fn irregular() -> Self {
+        Self::default()
+    }
+
+    pub fn is_irregular(&self) -> bool {
+        self.lines.is_empty()
+    }
+
+    /// The common name of the meter, if any, e.g., "CM" for 8.6.8.6 or "LMD" for 8.8.8.8 D
+    pub fn name(&self) -> Option<&'static str> {
+        if self.refrain || self.alleluias {
+            return None;
+        }
+        NAMED_METERS.iter().find_map(|(name, lines)| {
+            if self.lines == lines {
+                Some(*name)
+            } else if self.lines.len() == 8
+                && self.lines[..4] == *lines
+                && self.lines[4..] == *lines
+            {
+                match *name {
+                    "CM" => Some("CMD"),
+                    "LM" => Some("LMD"),
+                    "SM" => Some("SMD"),
+                    _ => None,
+                }
+            } else {
+                None
+            }
+        })
+    }
+
+    /// The first half of the lines, if the meter is doubled and is conventionally written with a "D"
+    fn undoubled(&self) -> Option<&[u8]> {
+        // halves can only be equal if the number of lines is even
+        let (first, second) = self.lines.split_at(self.lines.len() / 2);
+        let all_equal = self.lines.iter().all(|line| *line == self.lines[0]);
+        if first == second && (first.len() >= 4 || (first.len() == 3 && !all_equal)) {
+            Some(first)
+        } else {
+            None
+        }
+    }
+}
+
+impl std::fmt::Display for Meter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.is_irregular() {
+            write!(f, "Irregular")?;
+        } else {
+            let (lines, doubled) = match self.undoubled() {
+                Some(lines) => (lines, true),
+                None => (self.lines.as_slice(), false),
+            };
+            let lines = lines
+                .iter()
+                .map(|line| line.to_string())
+                .collect::<Vec<_>>()
+                .join(".");
+            write!(f, "{}{}", lines, if doubled { " D" } else { "" })?;
+        }
+        match (self.alleluias, self.refrain) {
+            (true, true) => write!(f, " with alleluias and refrain"),
+            (true, false) => write!(f, " with alleluias"),
+            (false, true) => write!(f, " with refrain"),
+            (false, false) => Ok(()),
+        }
+    }
+}
+
+#[derive(Clone, Error, Debug, PartialEq, Eq, Hash)]
+pub enum MeterFromStrError {
+    #[error("no meter given")]
+    Empty,
+    #[error("could not parse line length in meter: {0}")]
+    InvalidLine(String),
+}
+
+impl FromStr for Meter {
+    type Err = MeterFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim().to_lowercase();
+        if s.is_empty() {
+            return Err(MeterFromStrError::Empty);
+        }
+
+        let (lines, additions) = match s.split_once(" with ") {
+            Some((lines, additions)) => (lines.trim(), additions),
+            None => (s.as_str(), ""),
+        };
+        let refrain = additions.contains("refrain");
+        let alleluias = additions.contains("alleluia");
+
+        if lines == "irregular" {
+            return Ok(Self {
+                lines: Vec::new(),
+                refrain,
+                alleluias,
+            });
+        }
+
+        // spaces separate lines like periods, so "8 7 8 7 D" is the same as "8.7.8.7 D";
+        // "8.7.8.7 D", "8.7.8.7D", "LMD", and "L.M.D." are all doubled
+        let lines = lines.split_whitespace().collect::<Vec<_>>().join(".");
+        let (lines, doubled) = match lines.trim_end_matches('.').strip_suffix('d') {
+            Some(lines) => (lines.trim_end_matches('.').to_string(), true),
+            None => (lines, false),
+        };
+
+        let named = NAMED_METERS
+            .iter()
+            .find(|(name, _)| lines.replace('.', "") == name.to_lowercase());
+        let mut lines = if let Some((_, lines)) = named {
+            lines.to_vec()
+        } else {
+            lines
+                .split('.')
+                .filter(|line| !line.is_empty())
+                .map(|line| {
+                    line.parse::<u8>()
+                        .map_err(|_| MeterFromStrError::InvalidLine(line.to_string()))
+                })
+                .collect::<Result<Vec<_>, _>>()?
+        };
+        if lines.is_empty() {
+            return Err(MeterFromStrError::Empty);
+        }
+        if doubled {
+            lines.extend(lines.clone());
+        }
+
+        Ok(Self {
+            lines,
+            refrain,
+            alleluias,
+        })
+    }
+}
+
+impl Serialize for Meter {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for Meter {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let st = String::deserialize(deserializer)?;
+        st.parse()
+            .map_err(|_| D::Error::invalid_value(Unexpected::Str(&st), &"a hymn meter"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_every_meter_in_hymnals() {
+        for hymnal in [
+            &*crate::HYMNAL_1982,
+            &*crate::LEVAS,
+            &*crate::WLP,
+            &*crate::EL_HIMNARIO,
+        ] {
+            for hymn in hymnal.hymns.iter().filter(|hymn| !hymn.meter.is_empty()) {
+                assert!(
+                    hymn.meter.parse::<Meter>().is_ok(),
+                    "could not parse meter of {} {}: {:?}",
+                    hymnal.id,
+                    hymn.number,
+                    hymn.meter
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn round_trips_meters() {
+        for meter in [
+            "8.7.8.7 D",
+            "7.7.6 D",
+            "8.8.8.8.8.8",
+            "6.6.6.6 with refrain",
+            "8.8 with alleluias and refrain",
+            "Irregular with refrain",
+            "11.10.11.10",
+        ] {
+            assert_eq!(meter.parse::<Meter>().unwrap().to_string(), meter);
+        }
+    }
+
+    #[test]
+    fn equates_doubled_and_written_out_meters() {
+        assert_eq!("10.10 D".parse::<Meter>(), "10.10.10.10".parse::<Meter>());
+        assert_eq!("LMD".parse::<Meter>(), "8.8.8.8 D".parse::<Meter>());
+        assert_eq!("S.M.".parse::<Meter>(), "6.6.8.6".parse::<Meter>());
+        assert_eq!("10.9.10.9.".parse::<Meter>(), "10.9.10.9".parse::<Meter>());
+    }
+
+    #[test]
+    fn rejects_invalid_meters() {
+        assert_eq!("".parse::<Meter>(), Err(MeterFromStrError::Empty));
+        assert_eq!(
+            "8.x.8.7".parse::<Meter>(),
+            Err(MeterFromStrError::InvalidLine("x".into()))
+        );
+    }
+}
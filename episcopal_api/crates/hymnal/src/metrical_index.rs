@@ -0,0 +1,169 @@
+use std::collections::BTreeMap;
+
+use lazy_static::lazy_static;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    HymnMetadata, HymnNumber, Hymnal, Hymnals, Meter, EL_HIMNARIO, HYMNAL_1982, LEVAS, WLP,
+};
+
+lazy_static! {
+    /// A metrical index of every hymn in every [Hymnal](crate::Hymnal)
+    pub static ref METRICAL_INDEX: MetricalIndex =
+        MetricalIndex::new([&*HYMNAL_1982, &*LEVAS, &*WLP, &*EL_HIMNARIO]);
+}
+
+/// Hymns grouped by their [Meter], as in the metrical index at the back of a hymnal.
+/// Hymns with no meter given are not included.
+/// ```
+/// # use hymnal::{HymnNumber, Hymnals, Meter, METRICAL_INDEX};
+/// let common_meter: Meter = "CM".parse().unwrap();
+/// let hymns = METRICAL_INDEX.hymns_in_meter(&common_meter);
+/// // "Amazing grace! how sweet the sound"
+/// assert!(hymns.iter().any(|hymn| hymn.source == Hymnals::Hymnal1982 && hymn.number == HymnNumber::H(671)));
+///
+/// // ...which can be sung to any other tune in common meter
+/// let tunes = METRICAL_INDEX.alternative_tunes(Hymnals::Hymnal1982, HymnNumber::H(671));
+/// assert!(tunes.iter().all(|tune| tune.meter == common_meter && tune.tune != "NEW BRITAIN"));
+/// assert!(tunes.iter().any(|tune| tune.tune == "ST. ANNE"));
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct MetricalIndex {
+    meters: BTreeMap<Meter, Vec<HymnMetadata>>,
+}
+
+/// A tune that could be used for a text in the same meter, with the hymns it is already set to
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct AlternativeTune {
+    pub tune: String,
+    pub meter: Meter,
+    pub hymns: Vec<HymnMetadata>,
+}
+
+impl MetricalIndex {
+    pub fn new<'a>(hymnals: impl IntoIterator<Item = &'a Hymnal>) -> Self {
+        let mut meters: BTreeMap<Meter, Vec<HymnMetadata>> = BTreeMap::new();
+        for hymn in hymnals.into_iter().flat_map(|hymnal| hymnal.hymns.iter()) {
+            if let Ok(meter) = hymn.meter.parse() {
+                meters.entry(meter).or_default().push(hymn.to_metadata());
+            }
+        }
+        Self { meters }
+    }
+
+    /// Every meter in the index, in order, with the hymns in that meter
+    pub fn meters(&self) -> impl Iterator<Item = (&Meter, &[HymnMetadata])> {
+        self.meters
+            .iter()
+            .map(|(meter, hymns)| (meter, hymns.as_slice()))
+    }
+
+    /// Every hymn in the given meter
+    pub fn hymns_in_meter(&self, meter: &Meter) -> &[HymnMetadata] {
+        self.meters
+            .get(meter)
+            .map(|hymns| hymns.as_slice())
+            .unwrap_or_default()
+    }
+
+    /// Other tunes in the same meter as the given hymn, to which its text could be sung.
+    /// Tunes set to more hymns, which are more likely to be familiar, come first.
+    ///
+    /// Irregular meters are not interchangeable, so hymns in an irregular meter,
+    /// or with no meter given, have no alternative tunes.
+    pub fn alternative_tunes(&self, hymnal: Hymnals, number: HymnNumber) -> Vec<AlternativeTune> {
+        let hymn = self
+            .meters
+            .values()
+            .flatten()
+            .find(|hymn| hymn.source == hymnal && hymn.number == number);
+        let (hymn, meter) =
+            match hymn.and_then(|hymn| Some((hymn, hymn.meter.parse::<Meter>().ok()?))) {
+                Some((hymn, meter)) if !meter.is_irregular() => (hymn, meter),
+                _ => return Vec::new(),
+            };
+        let own_tune = tune_name(&hymn.tune);
+
+        let mut tunes: BTreeMap<String, AlternativeTune> = BTreeMap::new();
+        for other in self.hymns_in_meter(&meter) {
+            let name = tune_name(&other.tune);
+            // service music lists its first line in brackets in place of a tune name
+            if name.is_empty() || name.starts_with('[') || name == own_tune {
+                continue;
+            }
+            tunes
+                .entry(name)
+                .or_insert_with(|| AlternativeTune {
+                    tune: other.tune.trim().to_string(),
+                    meter: meter.clone(),
+                    hymns: Vec::new(),
+                })
+                .hymns
+                .push(other.clone());
+        }
+
+        let mut tunes = tunes.into_values().collect::<Vec<_>>();
+        // stable sort, so tunes used equally often stay in alphabetical order
+        tunes.sort_by_key(|tune| std::cmp::Reverse(tune.hymns.len()));
+        tunes
+    }
+}
+
+/// Tune names in a comparable form, as different hymnals capitalize and space them differently
+fn tune_name(tune: &str) -> String {
+    tune.split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .to_uppercase()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn indexes_doubled_meters_together() {
+        let meter: Meter = "8.7.8.7 D".parse().unwrap();
+        let hymns = METRICAL_INDEX.hymns_in_meter(&meter);
+        assert!(!hymns.is_empty());
+        assert!(hymns
+            .iter()
+            .all(|hymn| hymn.meter == "8.7.8.7 D" || hymn.meter == "8.7.8.7.8.7.8.7"));
+    }
+
+    #[test]
+    fn orders_meters() {
+        let meters = METRICAL_INDEX
+            .meters()
+            .map(|(meter, _)| meter.clone())
+            .collect::<Vec<_>>();
+        let mut sorted = meters.clone();
+        sorted.sort();
+        assert_eq!(meters, sorted);
+    }
+
+    #[test]
+    fn finds_tunes_across_hymnals() {
+        // "All people that on earth do dwell", in long meter
+        let tunes = METRICAL_INDEX.alternative_tunes(Hymnals::Hymnal1982, HymnNumber::H(377));
+        assert!(tunes.iter().all(|tune| tune.tune != "OLD 100TH"));
+        assert!(tunes.iter().any(|tune| tune
+            .hymns
+            .iter()
+            .any(|hymn| hymn.source != Hymnals::Hymnal1982)));
+        assert!(tunes
+            .windows(2)
+            .all(|pair| pair[0].hymns.len() >= pair[1].hymns.len()));
+    }
+
+    #[test]
+    fn no_alternatives_for_irregular_meters() {
+        let irregular = METRICAL_INDEX
+            .hymns_in_meter(&Meter::irregular())
+            .first()
+            .unwrap();
+        assert!(METRICAL_INDEX
+            .alternative_tunes(irregular.source, irregular.number)
+            .is_empty());
+    }
+}
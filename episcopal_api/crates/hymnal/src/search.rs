@@ -0,0 +1,461 @@
+use std::collections::{BTreeMap, HashMap};
+
+use lazy_static::lazy_static;
+
+use crate::{Hymn, HymnMetadata, Hymnal, Meter, EL_HIMNARIO, HYMNAL_1982, LEVAS, WLP};
+
+lazy_static! {
+    /// A search index of every hymn in every [Hymnal](crate::Hymnal)
+    pub static ref HYMN_SEARCH_INDEX: HymnSearchIndex =
+        HymnSearchIndex::new([&*HYMNAL_1982, &*LEVAS, &*WLP, &*EL_HIMNARIO]);
+}
+
+/// The parts of a hymn that are indexed, in order of their relevance to an unqualified search
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+enum Field {
+    Title,
+    Number,
+    FirstLine,
+    Tune,
+    Author,
+    Composer,
+    Text,
+}
+
+impl Field {
+    fn weight(&self) -> u32 {
+        match self {
+            Field::Title => 10,
+            Field::Number => 8,
+            Field::FirstLine => 6,
+            Field::Tune => 4,
+            Field::Author => 3,
+            Field::Composer => 2,
+            Field::Text => 1,
+        }
+    }
+}
+
+/// One occurrence of a term in an indexed [Field] of a hymn
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+struct Posting {
+    hymn: usize,
+    field: Field,
+}
+
+/// An inverted index of hymns, supporting relevance-ranked, accent-insensitive searches.
+///
+/// Searches can be limited to particular fields with `tune:`, `meter:`, `author:`, and `tag:`.
+/// Each of these takes everything that follows it, up to the next field, so any unqualified
+/// search terms should come first.
+/// ```
+/// # use hymnal::{HymnNumber, Hymnals, HYMN_SEARCH_INDEX};
+/// // titles rank above matches in the text
+/// let results = HYMN_SEARCH_INDEX.search("amazing grace");
+/// assert_eq!(results[0].number, HymnNumber::H(671));
+///
+/// // accents are ignored
+/// let results = HYMN_SEARCH_INDEX.search("senor es mi pastor");
+/// assert!(results.iter().any(|hymn| hymn.source == Hymnals::ElHimnario && hymn.title.contains("Señor es mi Pastor")));
+///
+/// // fielded searches can be combined with ordinary search terms
+/// let results = HYMN_SEARCH_INDEX.search("praise tune:old 100th");
+/// assert!(!results.is_empty());
+/// assert!(results.iter().all(|hymn| hymn.tune.to_lowercase().contains("old 100th")));
+/// ```
+#[derive(Clone, Debug)]
+pub struct HymnSearchIndex {
+    hymns: Vec<HymnMetadata>,
+    /// Normalized tags for each hymn, for `tag:` searches
+    tags: Vec<Vec<String>>,
+    /// Meter of each hymn, if given, for `meter:` searches
+    meters: Vec<Option<Meter>>,
+    /// Normalized title and first line of each hymn, for matching whole phrases
+    phrases: Vec<(String, String)>,
+    terms: BTreeMap<String, Vec<Posting>>,
+}
+
+impl HymnSearchIndex {
+    pub fn new<'a>(hymnals: impl IntoIterator<Item = &'a Hymnal>) -> Self {
+        let mut index = Self {
+            hymns: Vec::new(),
+            tags: Vec::new(),
+            meters: Vec::new(),
+            phrases: Vec::new(),
+            terms: BTreeMap::new(),
+        };
+        for hymn in hymnals.into_iter().flat_map(|hymnal| hymnal.hymns.iter()) {
+            index.add(hymn);
+        }
+        index
+    }
+
+    fn add(&mut self, hymn: &Hymn) {
+        let idx = self.hymns.len();
+        let first_line = first_line(&hymn.text);
+
+        for (field, value) in [
+            (Field::Title, hymn.title.as_str()),
+            (Field::FirstLine, first_line),
+            (Field::Tune, hymn.tune.as_str()),
+            (Field::Author, hymn.authors.as_str()),
+            (Field::Composer, hymn.composers.as_str()),
+            (Field::Text, hymn.text.as_str()),
+        ] {
+            for term in terms(value) {
+                self.add_posting(term, idx, field);
+            }
+        }
+        self.add_posting(hymn.number.to_string().to_lowercase(), idx, Field::Number);
+
+        self.hymns.push(hymn.to_metadata());
+        self.tags
+            .push(hymn.tags.iter().map(|tag| normalize(tag)).collect());
+        self.meters.push(hymn.meter.parse().ok());
+        self.phrases
+            .push((words(&hymn.title).join(" "), words(first_line).join(" ")));
+    }
+
+    fn add_posting(&mut self, term: String, hymn: usize, field: Field) {
+        let postings = self.terms.entry(term).or_default();
+        let posting = Posting { hymn, field };
+        if !postings.contains(&posting) {
+            postings.push(posting);
+        }
+    }
+
+    /// Hymns that match every part of the search, from most to least relevant.
+    /// An empty search returns every hymn, in order.
+    pub fn search(&self, search: &str) -> Vec<HymnMetadata> {
+        let query = Query::from(search);
+        if query.is_empty() {
+            return self.hymns.clone();
+        }
+
+        // every hymn starts as a candidate with a score of 0, and is removed if any part of the query fails to match
+        let mut scores: HashMap<usize, u32> = (0..self.hymns.len()).map(|idx| (idx, 0)).collect();
+
+        // free-text terms can match any field; the last term also matches as a prefix, so results can update while typing
+        let term_count = query.terms.len();
+        for (n, term) in query.terms.iter().enumerate() {
+            let matches = self.matches(term, n + 1 == term_count, None);
+            scores.retain(|idx, _| matches.contains_key(idx));
+            for (idx, weight) in matches {
+                if let Some(score) = scores.get_mut(&idx) {
+                    *score += weight;
+                }
+            }
+        }
+
+        // fields can contain several words, all of which must match that field
+        for (field, value) in [(Field::Tune, &query.tune), (Field::Author, &query.author)] {
+            if let Some(value) = value {
+                for term in terms(value) {
+                    let matches = self.matches(&term, false, Some(field));
+                    scores.retain(|idx, _| matches.contains_key(idx));
+                }
+            }
+        }
+        if let Some(meter) = &query.meter {
+            let meter = meter.parse::<Meter>().ok();
+            scores.retain(|idx, _| meter.is_some() && self.meters[*idx] == meter);
+        }
+        if let Some(tag) = &query.tag {
+            let tag = normalize(tag);
+            scores.retain(|idx, _| self.tags[*idx].contains(&tag));
+        }
+
+        // whole phrases count for more than the same words scattered
+        let phrase = query
+            .terms
+            .iter()
+            .map(String::as_str)
+            .collect::<Vec<_>>()
+            .join(" ");
+        if !phrase.is_empty() {
+            for (idx, score) in scores.iter_mut() {
+                let (title, first_line) = &self.phrases[*idx];
+                if title.contains(&phrase) {
+                    *score += Field::Title.weight() * 2;
+                } else if first_line.contains(&phrase) {
+                    *score += Field::FirstLine.weight() * 2;
+                }
+            }
+        }
+
+        let mut results = scores.into_iter().collect::<Vec<_>>();
+        results.sort_by(|(a_idx, a_score), (b_idx, b_score)| {
+            b_score.cmp(a_score).then(a_idx.cmp(b_idx))
+        });
+        results
+            .into_iter()
+            .map(|(idx, _)| self.hymns[idx].clone())
+            .collect()
+    }
+
+    /// Each hymn that contains the term, with the weight of the most relevant field in which it appears
+    fn matches(&self, term: &str, prefix: bool, field: Option<Field>) -> HashMap<usize, u32> {
+        let stemmed = stem(term);
+        let postings: Box<dyn Iterator<Item = &Posting>> = if prefix {
+            Box::new(
+                self.terms
+                    .range(term.to_string()..)
+                    .take_while(|(s_term, _)| s_term.starts_with(term))
+                    .chain(self.terms.get_key_value(&stemmed))
+                    .flat_map(|(_, postings)| postings),
+            )
+        } else {
+            Box::new(self.terms.get(&stemmed).into_iter().flatten())
+        };
+
+        let mut matches = HashMap::new();
+        for posting in postings {
+            if field.map(|field| field == posting.field).unwrap_or(true) {
+                let weight = matches.entry(posting.hymn).or_insert(0);
+                *weight = (*weight).max(posting.field.weight());
+            }
+        }
+        matches
+    }
+}
+
+/// A parsed search, e.g., `praise tune:old hundredth meter:LM`
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+struct Query {
+    terms: Vec<String>,
+    tune: Option<String>,
+    meter: Option<String>,
+    author: Option<String>,
+    tag: Option<String>,
+}
+
+impl Query {
+    fn is_empty(&self) -> bool {
+        self.terms.is_empty()
+            && self.tune.is_none()
+            && self.meter.is_none()
+            && self.author.is_none()
+            && self.tag.is_none()
+    }
+}
+
+const QUERY_FIELDS: [&str; 4] = ["tune:", "meter:", "author:", "tag:"];
+
+impl From<&str> for Query {
+    fn from(search: &str) -> Self {
+        let mut query = Query::default();
+
+        // find where each field begins; the offsets are taken from `search` itself, because
+        // lowercasing can change the length of non-ASCII text (the field names are all ASCII)
+        let mut starts = search
+            .char_indices()
+            .filter(|(idx, _)| *idx == 0 || search[..*idx].ends_with(char::is_whitespace))
+            .flat_map(|(idx, _)| {
+                QUERY_FIELDS
+                    .iter()
+                    .filter(move |field| {
+                        search
+                            .get(idx..idx + field.len())
+                            .map_or(false, |s| s.eq_ignore_ascii_case(field))
+                    })
+                    .map(move |field| (idx, *field))
+            })
+            .collect::<Vec<_>>();
+        starts.sort_unstable();
+
+        let free_text = &search[..starts.first().map(|(idx, _)| *idx).unwrap_or(search.len())];
+        query.terms = words(free_text);
+
+        for (n, (start, field)) in starts.iter().enumerate() {
+            let end = starts
+                .get(n + 1)
+                .map(|(idx, _)| *idx)
+                .unwrap_or(search.len());
+            let value = search[start + field.len()..end]
+                .trim()
+                .trim_matches('"')
+                .to_string();
+            if value.is_empty() {
+                continue;
+            }
+            match *field {
+                "tune:" => query.tune = Some(value),
+                "meter:" => query.meter = Some(value),
+                "author:" => query.author = Some(value),
+                "tag:" => query.tag = Some(value),
+                _ => {}
+            }
+        }
+
+        query
+    }
+}
+
+/// The first line of a hymn's text, without its verse number
+fn first_line(text: &str) -> &str {
+    text.lines()
+        .map(|line| {
+            line.trim()
+                .trim_start_matches(|ch: char| ch.is_ascii_digit() || ch == '.')
+                .trim()
+        })
+        .find(|line| !line.is_empty())
+        .unwrap_or_default()
+}
+
+/// Lowercases text and removes accents, so that, e.g., "Señor" and "senor" match
+fn normalize(text: &str) -> String {
+    text.chars()
+        .flat_map(char::to_lowercase)
+        .map(|ch| match ch {
+            'á' | 'à' | 'â' | 'ä' | 'ã' => 'a',
+            'é' | 'è' | 'ê' | 'ë' => 'e',
+            'í' | 'ì' | 'î' | 'ï' => 'i',
+            'ó' | 'ò' | 'ô' | 'ö' | 'õ' => 'o',
+            'ú' | 'ù' | 'û' | 'ü' => 'u',
+            'ñ' => 'n',
+            'ç' => 'c',
+            // typographic apostrophes, as in "Lamb’s"
+            '’' | '‘' => '\'',
+            _ => ch,
+        })
+        .collect()
+}
+
+/// The normalized words in some text, with apostrophes removed (so "Lamb’s" => "lambs")
+fn words(text: &str) -> Vec<String> {
+    normalize(text)
+        .replace('\'', "")
+        .split(|ch: char| !ch.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(String::from)
+        .collect()
+}
+
+/// The stemmed, normalized words in some text, as they are stored in the index
+fn terms(text: &str) -> impl Iterator<Item = String> {
+    words(text).into_iter().map(|word| stem(&word))
+}
+
+/// Strips common English and Spanish inflections, so that, e.g., "praising" matches "praise"
+/// and "cantos" matches "canto"
+fn stem(word: &str) -> String {
+    const SUFFIXES: [&str; 6] = ["ing", "ed", "es", "s", "e", "a"];
+    if word.chars().count() <= 4 || word.chars().any(|ch| ch.is_ascii_digit()) {
+        return word.to_string();
+    }
+    SUFFIXES
+        .iter()
+        .find_map(|suffix| word.strip_suffix(suffix))
+        .filter(|stem| stem.chars().count() >= 3)
+        .unwrap_or(word)
+        .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{HymnNumber, Hymnals};
+
+    #[test]
+    fn parses_fielded_queries() {
+        assert_eq!(
+            Query::from("praise Tune:Old 100th meter:LM"),
+            Query {
+                terms: vec!["praise".into()],
+                tune: Some("Old 100th".into()),
+                meter: Some("LM".into()),
+                ..Default::default()
+            }
+        );
+        assert_eq!(
+            Query::from("tag:Holy Week"),
+            Query {
+                tag: Some("Holy Week".into()),
+                ..Default::default()
+            }
+        );
+    }
+
+    #[test]
+    fn stems_words() {
+        assert_eq!(stem("praising"), stem("praise"));
+        assert_eq!(stem("cantos"), "canto");
+        assert_eq!(stem("holy"), "holy");
+    }
+
+    #[test]
+    fn searches_by_number() {
+        let results = HYMN_SEARCH_INDEX.search("S280");
+        assert_eq!(results[0].number, HymnNumber::S(280));
+    }
+
+    #[test]
+    fn searches_by_tag() {
+        let results = HYMN_SEARCH_INDEX.search("tag:holy week");
+        assert!(!results.is_empty());
+        assert!(results
+            .iter()
+            .all(|hymn| hymn.tags.contains(&"Holy Week".to_string())));
+    }
+
+    #[test]
+    fn parses_fields_after_non_ascii_text() {
+        // "İ" is longer when lowercased, so offsets in the lowercased text don't fit the original
+        assert_eq!(
+            Query::from("İ tune:ñ"),
+            Query {
+                terms: words("İ"),
+                tune: Some("ñ".into()),
+                ..Default::default()
+            }
+        );
+        assert_eq!(
+            Query::from("Señor TUNE:Bunessan"),
+            Query {
+                terms: words("Señor"),
+                tune: Some("Bunessan".into()),
+                ..Default::default()
+            }
+        );
+    }
+
+    #[test]
+    fn searches_by_meter() {
+        let results = HYMN_SEARCH_INDEX.search("meter:8.7.8.7 D");
+        assert!(!results.is_empty());
+        assert!(results.iter().all(|hymn| hymn.meter == "8.7.8.7 D"));
+
+        let results = HYMN_SEARCH_INDEX.search("meter:CM");
+        assert!(!results.is_empty());
+        assert!(results.iter().all(|hymn| hymn.meter == "8.6.8.6"));
+
+        // the same meter written in other ways
+        assert_eq!(
+            HYMN_SEARCH_INDEX.search("meter:8 7 8 7 d"),
+            HYMN_SEARCH_INDEX.search("meter:8.7.8.7 D")
+        );
+        assert_eq!(
+            HYMN_SEARCH_INDEX.search("meter:LMD"),
+            HYMN_SEARCH_INDEX.search("meter:8.8.8.8 D")
+        );
+    }
+
+    #[test]
+    fn matches_prefixes_of_last_term() {
+        let results = HYMN_SEARCH_INDEX.search("amazing gra");
+        assert!(results
+            .iter()
+            .any(|hymn| hymn.title.to_lowercase().starts_with("amazing grace")));
+    }
+
+    #[test]
+    fn ignores_accents() {
+        let accented = HYMN_SEARCH_INDEX.search("Jesús");
+        let unaccented = HYMN_SEARCH_INDEX.search("jesus");
+        assert_eq!(accented, unaccented);
+        assert!(accented
+            .iter()
+            .any(|hymn| hymn.source == Hymnals::ElHimnario));
+    }
+}
@@ -0,0 +1,554 @@
+use calendar::{Calendar, Feast, LiturgicalDay, LiturgicalDayId, Season};
+use language::Language;
+use lectionary::Reading;
+use reference_parser::{BibleReference, Book};
+use serde::{Deserialize, Serialize};
+
+use crate::{Hymn, HymnMetadata, HymnNumber, Hymnal};
+
+/// Points added to a hymn's score for each kind of match
+const SCRIPTURE_WEIGHT: u16 = 5;
+const FEAST_TITLE_WEIGHT: u16 = 4;
+const SEASON_TAG_WEIGHT: u16 = 3;
+const SEASON_TITLE_WEIGHT: u16 = 2;
+const FEAST_TEXT_WEIGHT: u16 = 1;
+
+/// A hymn recommended for a particular day, along with the reasons it was chosen
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct HymnSuggestion {
+    pub hymn: HymnMetadata,
+    /// Relative strength of the suggestion; higher is better
+    pub score: u16,
+    pub reasons: Vec<SuggestionReason>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SuggestionReason {
+    /// The hymn is listed under, or its title names, the day's season
+    Season { season: Season, matched: String },
+    /// The hymn's text is based on a passage that overlaps with one of the day's readings
+    Scripture { source: String, reading: String },
+    /// The hymn's title or text names the feast being observed
+    Feast { name: String, matched: String },
+}
+
+impl std::fmt::Display for SuggestionReason {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SuggestionReason::Season { matched, .. } => write!(f, "Suitable for {}", matched),
+            SuggestionReason::Scripture { source, reading } => {
+                write!(
+                    f,
+                    "Based on {}, which is related to the reading {}",
+                    source, reading
+                )
+            }
+            SuggestionReason::Feast { name, matched } => {
+                write!(f, "Mentions “{}” ({})", matched, name)
+            }
+        }
+    }
+}
+
+/// Ranks the hymns in the given [Hymnal]s by how well they suit a day, based on
+/// 1. the season under which the hymnal lists them, or which their titles name
+/// 2. passages of Scripture, named in their titles or text sources, that overlap with the day's readings
+/// 3. mentions of the feast being observed
+///
+/// Service music is not included. Suggestions are returned from best to worst, and only hymns
+/// that match in at least one of these ways are included.
+/// ```
+/// # use calendar::{Date, BCP1979_CALENDAR};
+/// # use hymnal::{suggest_hymns, HymnNumber, SuggestionReason, HYMNAL_1982};
+/// # use lectionary::{rcl_readings, RCLTrack};
+/// // The Visitation, whose Gospel includes the Song of Mary
+/// let day = BCP1979_CALENDAR.liturgical_day(Date::from_ymd(2022, 5, 31), false);
+/// let readings = rcl_readings(&day.observed, &day, RCLTrack::One).collect::<Vec<_>>();
+/// let suggestions = suggest_hymns(&BCP1979_CALENDAR, &day, &readings, [&*HYMNAL_1982]);
+/// let tell_out_my_soul = suggestions
+///     .iter()
+///     .find(|suggestion| suggestion.hymn.number == HymnNumber::H(437))
+///     .unwrap();
+/// assert!(tell_out_my_soul
+///     .reasons
+///     .iter()
+///     .any(|reason| matches!(reason, SuggestionReason::Scripture { .. })));
+/// ```
+pub fn suggest_hymns<'a>(
+    calendar: &Calendar,
+    day: &LiturgicalDay,
+    readings: &[Reading],
+    hymnals: impl IntoIterator<Item = &'a Hymnal>,
+) -> Vec<HymnSuggestion> {
+    let criteria = Criteria::new(calendar, day, readings);
+
+    let mut suggestions = hymnals
+        .into_iter()
+        .flat_map(|hymnal| hymnal.hymns.iter())
+        // service music (S1, S2, etc.) is chosen by its place in the liturgy, not by the day
+        .filter(|hymn| matches!(hymn.number, HymnNumber::H(_)))
+        .filter_map(|hymn| criteria.suggestion(hymn))
+        .collect::<Vec<_>>();
+    // sort is stable, so hymns with the same score stay in hymnal order
+    suggestions.sort_by_key(|suggestion| std::cmp::Reverse(suggestion.score));
+    suggestions
+}
+
+/// Everything about a day that a hymn can be matched against
+struct Criteria {
+    season: Season,
+    /// Whether the season is a true season, rather than a marker for a saint's day or other feast
+    true_season: bool,
+    feast_name: Option<String>,
+    feast_keywords: Vec<String>,
+    readings: Vec<(String, BibleReference)>,
+}
+
+impl Criteria {
+    fn new(calendar: &Calendar, day: &LiturgicalDay, readings: &[Reading]) -> Self {
+        let season = calendar.season(day);
+        let true_season = season.is_true_season();
+        // on saints' days and other feasts, fall back to the season in which they fall
+        let season = if true_season {
+            season
+        } else {
+            calendar.base_season(day)
+        };
+
+        let feast = match day.observed {
+            LiturgicalDayId::Feast(feast) | LiturgicalDayId::TransferredFeast(feast) => Some(feast),
+            _ => None,
+        };
+        let feast_name = feast.and_then(|feast| calendar.feast_name(feast, Language::En));
+        let feast_keywords = feast
+            .map(|feast| feast_keywords(calendar, feast))
+            .unwrap_or_default();
+
+        let readings = readings
+            .iter()
+            .map(|reading| (reading.citation.clone(), reference(&reading.citation)))
+            .collect();
+
+        Self {
+            season,
+            true_season,
+            feast_name,
+            feast_keywords,
+            readings,
+        }
+    }
+
+    fn suggestion(&self, hymn: &Hymn) -> Option<HymnSuggestion> {
+        let title = hymn.title.to_lowercase();
+        let text = hymn.text.to_lowercase();
+        let mut score = 0;
+        let mut reasons = Vec::new();
+
+        // Season
+        let (tags, keywords) = season_tags_and_keywords(self.season);
+        // a season that is only the background for a feast counts for less
+        let season_weight = |weight: u16| if self.true_season { weight } else { weight / 2 };
+        if let Some(tag) = tags
+            .iter()
+            .find(|tag| hymn.tags.iter().any(|s_tag| s_tag == *tag))
+        {
+            score += season_weight(SEASON_TAG_WEIGHT);
+            reasons.push(SuggestionReason::Season {
+                season: self.season,
+                matched: tag.to_string(),
+            });
+        } else if let Some(keyword) = keywords
+            .iter()
+            .find(|keyword| contains_word(&title, keyword))
+        {
+            score += season_weight(SEASON_TITLE_WEIGHT);
+            reasons.push(SuggestionReason::Season {
+                season: self.season,
+                matched: keyword.to_string(),
+            });
+        }
+
+        // Scripture
+        // each source counts once, even if it overlaps with several alternative readings
+        for (source, hymn_reference) in hymn_passages(hymn) {
+            let reading = self
+                .readings
+                .iter()
+                .find(|(_, reading_reference)| hymn_reference.overlaps(reading_reference));
+            if let Some((reading, _)) = reading {
+                score += SCRIPTURE_WEIGHT;
+                reasons.push(SuggestionReason::Scripture {
+                    source,
+                    reading: reading.clone(),
+                });
+            }
+        }
+
+        // Feast
+        if let Some(name) = &self.feast_name {
+            if let Some(keyword) = self
+                .feast_keywords
+                .iter()
+                .find(|keyword| contains_word(&title, keyword))
+            {
+                score += FEAST_TITLE_WEIGHT;
+                reasons.push(SuggestionReason::Feast {
+                    name: name.clone(),
+                    matched: keyword.clone(),
+                });
+            } else if let Some(keyword) = self
+                .feast_keywords
+                .iter()
+                .find(|keyword| contains_word(&text, keyword))
+            {
+                score += FEAST_TEXT_WEIGHT;
+                reasons.push(SuggestionReason::Feast {
+                    name: name.clone(),
+                    matched: keyword.clone(),
+                });
+            }
+        }
+
+        if score > 0 {
+            Some(HymnSuggestion {
+                hymn: hymn.to_metadata(),
+                score,
+                reasons,
+            })
+        } else {
+            None
+        }
+    }
+}
+
+/// The tags under which hymnals list hymns for a season, and words that identify
+/// hymns for that season by their titles
+fn season_tags_and_keywords(season: Season) -> (&'static [&'static str], &'static [&'static str]) {
+    match season {
+        Season::Advent => (&["Advent"], &["advent", "adviento"]),
+        Season::Christmas => (
+            &["Christmas"],
+            &[
+                "christmas",
+                "navidad",
+                "bethlehem",
+                "belén",
+                "nativity",
+                "manger",
+            ],
+        ),
+        Season::Epiphany => (&["Epiphany"], &["epiphany", "epifanía", "magi", "magos"]),
+        Season::Lent => (&["Lent"], &["lent", "cuaresma"]),
+        Season::HolyWeek => (
+            &["Holy Week", "The Liturgy of the Palms"],
+            &["calvary", "calvario", "passion", "pasión"],
+        ),
+        Season::Easter => (
+            &["Easter"],
+            &[
+                "easter",
+                "pascua",
+                "risen",
+                "resucitó",
+                "resurrección",
+                "resurrection",
+            ],
+        ),
+        Season::Ascension => (&["Ascension"], &["ascension", "ascensión", "ascended"]),
+        Season::Pentecost => (
+            &["Pentecost", "The Holy Spirit"],
+            &["pentecost", "pentecostés", "spirit", "espíritu"],
+        ),
+        Season::Trinity => (&["The Holy Trinity"], &["trinity", "trinidad"]),
+        Season::Kingdom => (&["The Kingdom of God"], &["kingdom", "reino"]),
+        Season::Saints => (&["The Church Triumphant"], &["saints", "santos"]),
+        Season::National => (&["National Songs"], &[]),
+        Season::Thanksgiving => (&[], &["thanksgiving", "harvest", "thankful", "gracias"]),
+        Season::Rogation => (&[], &["harvest", "plow", "fields", "cosecha"]),
+        Season::OrdinaryTime | Season::Ember | Season::Mary | Season::Incarnation => (&[], &[]),
+    }
+}
+
+/// Words that are too common in feast names to identify hymns for a particular feast
+const FEAST_NAME_STOPWORDS: [&str; 34] = [
+    "saint",
+    "saints",
+    "the",
+    "and",
+    "our",
+    "lord",
+    "jesus",
+    "christ",
+    "apostle",
+    "apostles",
+    "evangelist",
+    "martyr",
+    "martyrs",
+    "deacon",
+    "bishop",
+    "priest",
+    "holy",
+    "blessed",
+    "virgin",
+    "sunday",
+    "feast",
+    "with",
+    "day",
+    "eve",
+    "san",
+    "santo",
+    "santa",
+    "nuestro",
+    "señor",
+    "jesucristo",
+    "cristo",
+    "apóstol",
+    "evangelista",
+    "mártir",
+];
+
+/// Distinctive words from a feast's English and Spanish names
+fn feast_keywords(calendar: &Calendar, feast: Feast) -> Vec<String> {
+    let mut keywords: Vec<String> = Vec::new();
+    for language in [Language::En, Language::Es] {
+        if let Some(name) = calendar.feast_name(feast, language) {
+            for word in name.split(|ch: char| !ch.is_alphanumeric()) {
+                let word = word.to_lowercase();
+                if word.chars().count() >= 4
+                    && !FEAST_NAME_STOPWORDS.contains(&word.as_str())
+                    && !keywords.contains(&word)
+                {
+                    keywords.push(word);
+                }
+            }
+        }
+    }
+    keywords
+}
+
+/// Whether `word` appears in `haystack` as a whole word (both should already be lowercase)
+fn contains_word(haystack: &str, word: &str) -> bool {
+    haystack.match_indices(word).any(|(idx, _)| {
+        let before = haystack[..idx].chars().next_back();
+        let after = haystack[idx + word.len()..].chars().next();
+        !before.map(char::is_alphanumeric).unwrap_or(false)
+            && !after.map(char::is_alphanumeric).unwrap_or(false)
+    })
+}
+
+/// Canticles and other texts that are often paraphrased in hymns, with the passages they come from
+const CANTICLE_SOURCES: [(&str, &str); 20] = [
+    ("song of mary", "Luke 1:46-55"),
+    ("magnificat", "Luke 1:46-55"),
+    ("song of zechariah", "Luke 1:68-79"),
+    ("benedictus dominus", "Luke 1:68-79"),
+    ("song of simeon", "Luke 2:29-32"),
+    ("nunc dimittis", "Luke 2:29-32"),
+    ("first song of isaiah", "Isaiah 12:2-6"),
+    ("second song of isaiah", "Isaiah 55:6-11"),
+    ("third song of isaiah", "Isaiah 60:1-3, 11, 14, 18-19"),
+    ("song of moses", "Exodus 15:1-6, 11-13, 17-18"),
+    ("cantemus domino", "Exodus 15:1-6, 11-13, 17-18"),
+    ("song to the lamb", "Revelation 4:11, 5:9-10, 13"),
+    ("song of the lamb", "Revelation 19:1, 5-7"),
+    ("song of the redeemed", "Revelation 15:3-4"),
+    ("magna et mirabilia", "Revelation 15:3-4"),
+    ("song of hannah", "1 Samuel 2:1-10"),
+    ("venite", "Psalm 95:1-7"),
+    ("jubilate", "Psalm 100"),
+    (
+        "christ our passover",
+        "1 Corinthians 5:7-8; Romans 6:9-11; 1 Corinthians 15:20-22",
+    ),
+    (
+        "pascha nostrum",
+        "1 Corinthians 5:7-8; Romans 6:9-11; 1 Corinthians 15:20-22",
+    ),
+];
+
+/// The passages of Scripture on which a hymn is based, with a description of each source
+fn hymn_passages(hymn: &Hymn) -> Vec<(String, BibleReference)> {
+    let sources = format!("{}\n{}", hymn.title, hymn.text_sources);
+    let lowercase_sources = sources.to_lowercase();
+
+    let canticles = CANTICLE_SOURCES
+        .iter()
+        .filter(|(name, _)| lowercase_sources.contains(name))
+        .map(|(_, citation)| (citation.to_string(), reference(citation)));
+
+    canticles
+        .chain(citations(&sources))
+        .filter(|(_, reference)| !reference.ranges.is_empty())
+        .collect()
+}
+
+/// Parses a citation like `Psalm 34:1-10, 22; Luke 1:46-55`, including alternatives
+/// like `Baruch 5:1-9 or Malachi 3:1-4`
+fn reference(citation: &str) -> BibleReference {
+    BibleReference {
+        ranges: citation
+            .split(" or ")
+            .flat_map(|part| BibleReference::from(part).ranges)
+            .collect(),
+    }
+}
+
+/// Finds citations like `Psalm 23` or `1 Juan 4:7-8` within a longer text, like a hymn's title or text sources
+fn citations(text: &str) -> Vec<(String, BibleReference)> {
+    // byte offset and text of each word, without surrounding punctuation (except the period of an abbreviation)
+    let words = text
+        .split_whitespace()
+        .map(|word| {
+            let word = word
+                .trim_start_matches(|ch: char| !ch.is_alphanumeric())
+                .trim_end_matches(|ch: char| !ch.is_alphanumeric() && ch != '.');
+            (word.as_ptr() as usize - text.as_ptr() as usize, word)
+        })
+        .filter(|(_, word)| !word.is_empty())
+        .collect::<Vec<_>>();
+
+    // (start, end) of each capitalized book name, preferring the longest, so "1 Juan" is not read as "Juan"
+    let mut book_names = Vec::new();
+    let mut idx = 0;
+    while idx < words.len() {
+        let book_name = (1..=3)
+            .rev()
+            .filter(|len| idx + len <= words.len())
+            .find_map(|len| {
+                let (start, first) = words[idx];
+                let (last_start, last) = words[idx + len - 1];
+                let end = last_start + last.len();
+                let is_capitalized =
+                    first.starts_with(|ch: char| ch.is_uppercase() || ch.is_ascii_digit());
+                (is_capitalized && Book::from_name(&text[start..end]).is_some())
+                    .then(|| (start, end, len))
+            });
+        match book_name {
+            Some((start, end, len)) => {
+                book_names.push((start, end));
+                idx += len;
+            }
+            None => idx += 1,
+        }
+    }
+
+    // each book name is followed by the chapters and verses, up to the next book name
+    book_names
+        .iter()
+        .enumerate()
+        .filter_map(|(idx, (start, end))| {
+            let next_start = book_names
+                .get(idx + 1)
+                .map(|(next_start, _)| *next_start)
+                .unwrap_or(text.len());
+            let rest = &text[*end..next_start];
+            if !rest.starts_with(' ') {
+                return None;
+            }
+            let spec = rest
+                .trim_start()
+                .split(|ch: char| !(ch.is_ascii_digit() || matches!(ch, ':' | ',' | '-' | ' ')))
+                .next()
+                .unwrap_or_default()
+                .trim_end_matches([',', ' ', '-']);
+            if spec.is_empty() {
+                None
+            } else {
+                let citation = format!("{} {}", &text[*start..*end], spec);
+                let reference = reference(&citation);
+                Some((citation, reference))
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{EL_HIMNARIO, HYMNAL_1982};
+    use calendar::{Date, BCP1979_CALENDAR};
+    use lectionary::{rcl_readings, RCLTrack};
+
+    #[test]
+    fn finds_citations_in_text() {
+        let found = citations("Psalm 34:1-10, 22; para. Isaac Watts, based on 1 John 3:1-3");
+        assert_eq!(
+            found
+                .iter()
+                .map(|(citation, _)| citation.as_str())
+                .collect::<Vec<_>>(),
+            vec!["Psalm 34:1-10, 22", "1 John 3:1-3"]
+        );
+        assert_eq!(found[1].1, BibleReference::from("1 John 3:1-3"));
+        // a citation ends where the next one begins
+        assert_eq!(
+            citations("Salmo 23, 1 Juan 4:7-8")
+                .into_iter()
+                .map(|(citation, _)| citation)
+                .collect::<Vec<_>>(),
+            vec!["Salmo 23", "1 Juan 4:7-8"]
+        );
+        // "mark" is only a book when it is capitalized and followed by a chapter
+        assert!(citations("words to mark 2 occasions; Mark the day").is_empty());
+    }
+
+    #[test]
+    fn finds_passages_in_hymn_sources() {
+        let hymn = Hymn {
+            title: "Salmo 23–El Señor es mi Pastor".into(),
+            text_sources: "Basado en 1 Juan 4:7-8".into(),
+            ..Default::default()
+        };
+        let sources = hymn_passages(&hymn);
+        assert_eq!(
+            sources
+                .iter()
+                .map(|(source, _)| source.as_str())
+                .collect::<Vec<_>>(),
+            vec!["Salmo 23", "1 Juan 4:7-8"]
+        );
+    }
+
+    #[test]
+    fn suggests_seasonal_hymns() {
+        let day = BCP1979_CALENDAR.liturgical_day(Date::from_ymd(2021, 11, 28), false);
+        let readings = rcl_readings(&day.observed, &day, RCLTrack::One).collect::<Vec<_>>();
+        let suggestions = suggest_hymns(&BCP1979_CALENDAR, &day, &readings, [&*HYMNAL_1982]);
+        let best = suggestions.first().unwrap();
+        assert!(best.reasons.iter().any(|reason| matches!(
+            reason,
+            SuggestionReason::Season {
+                season: Season::Advent,
+                ..
+            }
+        )));
+        assert!(suggestions
+            .iter()
+            .all(|suggestion| !suggestion.reasons.is_empty()));
+    }
+
+    #[test]
+    fn suggests_psalm_paraphrases() {
+        // Fourth Sunday of Easter, Year A, when Psalm 23 is appointed
+        let day = BCP1979_CALENDAR.liturgical_day(Date::from_ymd(2023, 4, 30), false);
+        let readings = rcl_readings(&day.observed, &day, RCLTrack::One).collect::<Vec<_>>();
+        let suggestions = suggest_hymns(&BCP1979_CALENDAR, &day, &readings, [&*EL_HIMNARIO]);
+        assert!(suggestions
+            .iter()
+            .any(|suggestion| suggestion.hymn.title.starts_with("Salmo 23")
+                && suggestion
+                    .reasons
+                    .iter()
+                    .any(|reason| matches!(reason, SuggestionReason::Scripture { .. }))));
+    }
+
+    #[test]
+    fn suggests_hymns_for_feasts() {
+        // Saint Stephen, Deacon and Martyr
+        let day = BCP1979_CALENDAR.liturgical_day(Date::from_ymd(2022, 12, 26), false);
+        let suggestions = suggest_hymns(&BCP1979_CALENDAR, &day, &[], [&*HYMNAL_1982]);
+        assert!(suggestions
+            .iter()
+            .any(|suggestion| suggestion.hymn.number == HymnNumber::H(243)));
+    }
+}
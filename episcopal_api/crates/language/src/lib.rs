@@ -27,7 +27,41 @@ impl Language {
         self == &Self::default()
     }
 
-    // TODO i18n when other languages are added
+    /// The language to use when a string or document is not available in this language:
+    /// Spanish and French fall back to English, and Haitian Creole to French.
+    /// ```
+    /// # use language::Language;
+    /// assert_eq!(Language::Ht.fallback(), Some(Language::Fr));
+    /// assert_eq!(Language::En.fallback(), None);
+    /// ```
+    pub fn fallback(&self) -> Option<Language> {
+        match self {
+            Language::En => None,
+            Language::Es | Language::Fr => Some(Language::En),
+            Language::Ht => Some(Language::Fr),
+        }
+    }
+
+    /// This language followed by each of its fallbacks, in the order in which they should be tried.
+    /// ```
+    /// # use language::Language;
+    /// assert_eq!(
+    ///     Language::Ht.with_fallbacks().collect::<Vec<_>>(),
+    ///     vec![Language::Ht, Language::Fr, Language::En]
+    /// );
+    /// ```
+    pub fn with_fallbacks(&self) -> impl Iterator<Item = Language> {
+        std::iter::successors(Some(*self), Language::fallback)
+    }
+
+    /// Translates one of the short strings used when generating headings (weekday abbreviations,
+    /// "after", "Transferred"); any other string is returned unchanged.
+    /// ```
+    /// # use language::Language;
+    /// assert_eq!(Language::En.i18n("Mon"), "Monday");
+    /// assert_eq!(Language::Es.i18n("Mon"), "Lunes");
+    /// assert_eq!(Language::Fr.i18n("Transferred"), "Transférée");
+    /// ```
     pub fn i18n(&self, string: &str) -> String {
         match (self, string) {
             (Language::En, "Mon") => "Monday",
@@ -37,11 +71,97 @@ impl Language {
             (Language::En, "Fri") => "Friday",
             (Language::En, "Sat") => "Saturday",
             (Language::En, "Sun") => "Sunday",
+            (Language::Es, "Mon") => "Lunes",
+            (Language::Es, "Tue") => "Martes",
+            (Language::Es, "Wed") => "Miércoles",
+            (Language::Es, "Thu") => "Jueves",
+            (Language::Es, "Fri") => "Viernes",
+            (Language::Es, "Sat") => "Sábado",
+            (Language::Es, "Sun") => "Domingo",
+            (Language::Es, "after") => "después de",
+            (Language::Es, "Transferred") => "Trasladada",
+            (Language::Fr, "Mon") => "Lundi",
+            (Language::Fr, "Tue") => "Mardi",
+            (Language::Fr, "Wed") => "Mercredi",
+            (Language::Fr, "Thu") => "Jeudi",
+            (Language::Fr, "Fri") => "Vendredi",
+            (Language::Fr, "Sat") => "Samedi",
+            (Language::Fr, "Sun") => "Dimanche",
+            (Language::Fr, "after") => "après",
+            (Language::Fr, "Transferred") => "Transférée",
+            (Language::Ht, "Mon") => "Lendi",
+            (Language::Ht, "Tue") => "Madi",
+            (Language::Ht, "Wed") => "Mèkredi",
+            (Language::Ht, "Thu") => "Jedi",
+            (Language::Ht, "Fri") => "Vandredi",
+            (Language::Ht, "Sat") => "Samdi",
+            (Language::Ht, "Sun") => "Dimanch",
+            (Language::Ht, "after") => "apre",
+            (Language::Ht, "Transferred") => "Transfere",
             _ => string,
         }
         .to_string()
     }
 
+    /// The name of a weekday that falls in the week following a Sunday, e.g., “Monday after
+    /// the First Sunday of Advent.” `weekday` is a three-letter abbreviation as accepted by
+    /// [Language::i18n], and `week_name` is the name of the Sunday in this language.
+    /// ```
+    /// # use language::Language;
+    /// assert_eq!(
+    ///     Language::En.weekday_after("Mon", "The First Sunday of Advent"),
+    ///     "Monday after the First Sunday of Advent"
+    /// );
+    /// assert_eq!(
+    ///     Language::Es.weekday_after("Mon", "El Primer Domingo de Adviento"),
+    ///     "Lunes después del Primer Domingo de Adviento"
+    /// );
+    /// assert_eq!(
+    ///     Language::Fr.weekday_after("Tue", "L’Épiphanie"),
+    ///     "Mardi après l’Épiphanie"
+    /// );
+    /// ```
+    pub fn weekday_after(&self, weekday: &str, week_name: &str) -> String {
+        let weekday = self.i18n(weekday);
+        match self {
+            Language::Es => {
+                if let Some(rest) = week_name.strip_prefix("El ") {
+                    format!("{} después del {}", weekday, rest)
+                } else if let Some(rest) = week_name.strip_prefix("La ") {
+                    format!("{} después de la {}", weekday, rest)
+                } else {
+                    format!("{} después de {}", weekday, week_name)
+                }
+            }
+            Language::Fr | Language::Ht => {
+                let week_name = ["Le ", "La ", "Les ", "L’"]
+                    .iter()
+                    .find_map(|article| {
+                        week_name
+                            .strip_prefix(article)
+                            .map(|rest| format!("{}{}", article.to_lowercase(), rest))
+                    })
+                    .unwrap_or_else(|| week_name.to_string());
+                format!("{} {} {}", weekday, self.i18n("after"), week_name)
+            }
+            Language::En => format!(
+                "{} {} {}",
+                weekday,
+                self.i18n("after"),
+                week_name.replace("The", "the")
+            ),
+        }
+    }
+
+    /// The name of a month, numbered from 1, as it appears within a date: capitalized in English,
+    /// lowercase in the other languages.
+    /// ```
+    /// # use language::Language;
+    /// assert_eq!(Language::En.month_name(10), "October");
+    /// assert_eq!(Language::Es.month_name(10), "octubre");
+    /// assert_eq!(Language::Fr.month_name(8), "août");
+    /// assert_eq!(Language::Fr.month_name(13), "");
+    /// ```
     pub fn month_name(&self, month: u8) -> &'static str {
         match (self, month) {
             (Language::En, 1) => "January",
@@ -56,7 +176,72 @@ impl Language {
             (Language::En, 10) => "October",
             (Language::En, 11) => "November",
             (Language::En, 12) => "December",
-            _ => todo!(),
+            (Language::Es, 1) => "enero",
+            (Language::Es, 2) => "febrero",
+            (Language::Es, 3) => "marzo",
+            (Language::Es, 4) => "abril",
+            (Language::Es, 5) => "mayo",
+            (Language::Es, 6) => "junio",
+            (Language::Es, 7) => "julio",
+            (Language::Es, 8) => "agosto",
+            (Language::Es, 9) => "septiembre",
+            (Language::Es, 10) => "octubre",
+            (Language::Es, 11) => "noviembre",
+            (Language::Es, 12) => "diciembre",
+            (Language::Fr, 1) => "janvier",
+            (Language::Fr, 2) => "février",
+            (Language::Fr, 3) => "mars",
+            (Language::Fr, 4) => "avril",
+            (Language::Fr, 5) => "mai",
+            (Language::Fr, 6) => "juin",
+            (Language::Fr, 7) => "juillet",
+            (Language::Fr, 8) => "août",
+            (Language::Fr, 9) => "septembre",
+            (Language::Fr, 10) => "octobre",
+            (Language::Fr, 11) => "novembre",
+            (Language::Fr, 12) => "décembre",
+            (Language::Ht, 1) => "janvye",
+            (Language::Ht, 2) => "fevriye",
+            (Language::Ht, 3) => "mas",
+            (Language::Ht, 4) => "avril",
+            (Language::Ht, 5) => "me",
+            (Language::Ht, 6) => "jen",
+            (Language::Ht, 7) => "jiyè",
+            (Language::Ht, 8) => "out",
+            (Language::Ht, 9) => "septanm",
+            (Language::Ht, 10) => "oktòb",
+            (Language::Ht, 11) => "novanm",
+            (Language::Ht, 12) => "desanm",
+            _ => "",
+        }
+    }
+
+    /// A date written out in this language, e.g., “October 18, 2026,” “18 de octubre de 2026,”
+    /// or “18 octobre 2026”; the year is omitted if `year` is `None`.
+    /// ```
+    /// # use language::Language;
+    /// assert_eq!(Language::En.format_date(Some(2026), 10, 18), "October 18, 2026");
+    /// assert_eq!(Language::Es.format_date(Some(2026), 10, 18), "18 de octubre de 2026");
+    /// assert_eq!(Language::Fr.format_date(None, 1, 1), "1er janvier");
+    /// ```
+    pub fn format_date(&self, year: Option<u16>, month: u8, day: u8) -> String {
+        let month_name = self.month_name(month);
+        match (self, year) {
+            (Language::En, Some(year)) => format!("{} {}, {}", month_name, day, year),
+            (Language::En, None) => format!("{} {}", month_name, day),
+            (Language::Es, Some(year)) => format!("{} de {} de {}", day, month_name, year),
+            (Language::Es, None) => format!("{} de {}", day, month_name),
+            (Language::Fr | Language::Ht, year) => {
+                let day = if day == 1 && *self == Language::Fr {
+                    "1er".to_string()
+                } else {
+                    day.to_string()
+                };
+                match year {
+                    Some(year) => format!("{} {} {}", day, month_name, year),
+                    None => format!("{} {}", day, month_name),
+                }
+            }
         }
     }
 
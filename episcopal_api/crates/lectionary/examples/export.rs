@@ -0,0 +1,33 @@
+//! Exports each of the built-in lectionaries as CSV and JSON into the given directory,
+//! in the formats that can be loaded by [OwnedLectionary](lectionary::OwnedLectionary).
+//!
+//! `cargo run -p lectionary --example export -- path/to/directory`
+use std::{env, error::Error, fs, path::PathBuf};
+
+use lectionary::{
+    Lectionary, OwnedLectionary, BCP1979_30_DAY_PSALTER, BCP1979_DAILY_OFFICE_LECTIONARY,
+    BCP1979_DAILY_OFFICE_PSALTER, COMMON_WORSHIP_LECTIONARY, LFF2018_LECTIONARY, RCL, RCL_TRACK_1,
+    RCL_TRACK_2,
+};
+
+const LECTIONARIES: [(&str, &Lectionary); 8] = [
+    ("bcp1979_30_day_psalter", &BCP1979_30_DAY_PSALTER),
+    ("bcp1979_daily_office_psalter", &BCP1979_DAILY_OFFICE_PSALTER),
+    ("bcp1979_daily_office", &BCP1979_DAILY_OFFICE_LECTIONARY),
+    ("common_worship", &COMMON_WORSHIP_LECTIONARY),
+    ("lff2018", &LFF2018_LECTIONARY),
+    ("rcl", &RCL),
+    ("rcl_track_1", &RCL_TRACK_1),
+    ("rcl_track_2", &RCL_TRACK_2),
+];
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let dir = PathBuf::from(env::args().nth(1).unwrap_or_else(|| ".".to_string()));
+    fs::create_dir_all(&dir)?;
+    for (name, lectionary) in LECTIONARIES {
+        let owned = OwnedLectionary::from(lectionary);
+        fs::write(dir.join(format!("{}.csv", name)), owned.to_csv()?)?;
+        fs::write(dir.join(format!("{}.json", name)), owned.to_json()?)?;
+    }
+    Ok(())
+}
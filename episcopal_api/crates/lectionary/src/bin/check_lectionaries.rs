@@ -0,0 +1,33 @@
+//! Checks the built-in lectionaries for missing, duplicate, or unparseable readings
+//! over a range of dates, and prints the results as JSON.
+//!
+//! `cargo run -p lectionary --features server --bin check_lectionaries -- 2021-11-28 2022-11-26`
+use std::process::exit;
+
+use calendar::Date;
+use lectionary::{LectionaryCheck, LectionaryReport};
+
+fn parse_date(arg: Option<String>) -> Date {
+    match arg.as_deref().map(|s| Date::parse_from_str(s, "%Y-%m-%d")) {
+        Some(Ok(date)) => date,
+        _ => {
+            eprintln!("usage: check_lectionaries START END (dates as YYYY-MM-DD)");
+            exit(2);
+        }
+    }
+}
+
+fn main() {
+    let mut args = std::env::args().skip(1);
+    let start = parse_date(args.next());
+    let end = parse_date(args.next());
+
+    let report = LectionaryReport::new(&LectionaryCheck::all(), start, end);
+    println!(
+        "{}",
+        serde_json::to_string_pretty(&report).expect("could not serialize report")
+    );
+    if !report.is_ok() {
+        exit(1);
+    }
+}
@@ -0,0 +1,275 @@
+use calendar::{
+    Feast, LiturgicalDay, LiturgicalDayId, LiturgicalWeek, RCLYear, Weekday, Year, YearType,
+};
+
+use crate::{rcl_readings, Lectionary, RCLTrack, Reading, ReadingType};
+
+/// Sunday and festival readings from the *Common Worship* Principal Service Lectionary,
+/// for use with [COMMON_WORSHIP_CALENDAR](calendar::COMMON_WORSHIP_CALENDAR).
+///
+/// The Principal Service Lectionary is an adaptation of the Revised Common Lectionary:
+/// the Sundays before Lent follow the RCL’s Fifth, Sixth, and Seventh Sundays after the Epiphany,
+/// and the Sundays after Trinity and the Kingdom season follow the RCL Propers by date.
+/// ```
+/// # use calendar::{Date, LiturgicalWeek, COMMON_WORSHIP_CALENDAR};
+/// # use lectionary::{common_worship_readings, RCLTrack, ReadingType};
+/// // the Second Sunday before Lent, Year C
+/// let day = COMMON_WORSHIP_CALENDAR.liturgical_day(Date::from_ymd(2022, 2, 20), false);
+/// assert_eq!(day.week, LiturgicalWeek::SecondBeforeLent);
+/// let gospel = common_worship_readings(&day.observed, &day, RCLTrack::Two)
+///     .find(|reading| reading.reading_type == ReadingType::Gospel)
+///     .unwrap();
+/// assert_eq!(gospel.citation, "Luke 8:22-25");
+///
+/// // the Fourth Sunday before Lent follows the RCL’s Sixth Sunday after the Epiphany
+/// let day = COMMON_WORSHIP_CALENDAR.liturgical_day(Date::from_ymd(2022, 2, 6), false);
+/// let gospel = common_worship_readings(&day.observed, &day, RCLTrack::Two)
+///     .find(|reading| reading.reading_type == ReadingType::Gospel)
+///     .unwrap();
+/// assert_eq!(gospel.citation, "Luke 6:17-26");
+/// ```
+pub fn common_worship_readings(
+    observed: &LiturgicalDayId,
+    day: &LiturgicalDay,
+    track_if_needed: RCLTrack,
+) -> impl Iterator<Item = Reading> {
+    let observed = match (observed, day.week) {
+        // the Fourth Sunday before Advent has its own readings, rather than those of the Proper
+        (LiturgicalDayId::ProperAndDay(_, weekday), LiturgicalWeek::FourthBeforeAdvent) => {
+            LiturgicalDayId::WeekAndDay(LiturgicalWeek::FourthBeforeAdvent, *weekday)
+        }
+        _ => *observed,
+    };
+    let readings = COMMON_WORSHIP_LECTIONARY
+        .readings_by_day(&observed, day)
+        .collect::<Vec<_>>();
+    if readings.is_empty() {
+        rcl_readings(&rcl_equivalent(observed), day, track_if_needed).collect()
+    } else {
+        readings
+    }
+    .into_iter()
+}
+
+/// The day in the Revised Common Lectionary whose readings are used for the given day
+fn rcl_equivalent(observed: LiturgicalDayId) -> LiturgicalDayId {
+    match observed {
+        LiturgicalDayId::WeekAndDay(LiturgicalWeek::FifthBeforeLent, weekday) => {
+            LiturgicalDayId::WeekAndDay(LiturgicalWeek::Epiphany5, weekday)
+        }
+        LiturgicalDayId::WeekAndDay(LiturgicalWeek::FourthBeforeLent, weekday) => {
+            LiturgicalDayId::WeekAndDay(LiturgicalWeek::Epiphany6, weekday)
+        }
+        LiturgicalDayId::WeekAndDay(LiturgicalWeek::ThirdBeforeLent, weekday) => {
+            LiturgicalDayId::WeekAndDay(LiturgicalWeek::Epiphany7, weekday)
+        }
+        _ => observed,
+    }
+}
+
+/// Readings that are proper to the *Common Worship* Principal Service Lectionary.
+/// Readings shared with the Revised Common Lectionary are found instead in [RCL](crate::RCL);
+/// see [common_worship_readings].
+pub const COMMON_WORSHIP_LECTIONARY: Lectionary = Lectionary {
+    year_type: YearType::Rcl,
+    readings: &[
+        (
+            LiturgicalDayId::WeekAndDay(LiturgicalWeek::SecondBeforeLent, Weekday::Sun),
+            Year::Rcl(RCLYear::A),
+            ReadingType::FirstReading,
+            "Genesis 1:1-2:3",
+        ),
+        (
+            LiturgicalDayId::WeekAndDay(LiturgicalWeek::SecondBeforeLent, Weekday::Sun),
+            Year::Rcl(RCLYear::A),
+            ReadingType::Psalm,
+            "Psalm 136:1-9,23-26",
+        ),
+        (
+            LiturgicalDayId::WeekAndDay(LiturgicalWeek::SecondBeforeLent, Weekday::Sun),
+            Year::Rcl(RCLYear::A),
+            ReadingType::SecondReading,
+            "Romans 8:18-25",
+        ),
+        (
+            LiturgicalDayId::WeekAndDay(LiturgicalWeek::SecondBeforeLent, Weekday::Sun),
+            Year::Rcl(RCLYear::A),
+            ReadingType::Gospel,
+            "Matthew 6:25-34",
+        ),
+        (
+            LiturgicalDayId::WeekAndDay(LiturgicalWeek::SecondBeforeLent, Weekday::Sun),
+            Year::Rcl(RCLYear::B),
+            ReadingType::FirstReading,
+            "Proverbs 8:1,22-31",
+        ),
+        (
+            LiturgicalDayId::WeekAndDay(LiturgicalWeek::SecondBeforeLent, Weekday::Sun),
+            Year::Rcl(RCLYear::B),
+            ReadingType::Psalm,
+            "Psalm 104:25-35",
+        ),
+        (
+            LiturgicalDayId::WeekAndDay(LiturgicalWeek::SecondBeforeLent, Weekday::Sun),
+            Year::Rcl(RCLYear::B),
+            ReadingType::SecondReading,
+            "Colossians 1:15-20",
+        ),
+        (
+            LiturgicalDayId::WeekAndDay(LiturgicalWeek::SecondBeforeLent, Weekday::Sun),
+            Year::Rcl(RCLYear::B),
+            ReadingType::Gospel,
+            "John 1:1-14",
+        ),
+        (
+            LiturgicalDayId::WeekAndDay(LiturgicalWeek::SecondBeforeLent, Weekday::Sun),
+            Year::Rcl(RCLYear::C),
+            ReadingType::FirstReading,
+            "Genesis 2:4b-9,15-25",
+        ),
+        (
+            LiturgicalDayId::WeekAndDay(LiturgicalWeek::SecondBeforeLent, Weekday::Sun),
+            Year::Rcl(RCLYear::C),
+            ReadingType::Psalm,
+            "Psalm 65",
+        ),
+        (
+            LiturgicalDayId::WeekAndDay(LiturgicalWeek::SecondBeforeLent, Weekday::Sun),
+            Year::Rcl(RCLYear::C),
+            ReadingType::SecondReading,
+            "Revelation 4",
+        ),
+        (
+            LiturgicalDayId::WeekAndDay(LiturgicalWeek::SecondBeforeLent, Weekday::Sun),
+            Year::Rcl(RCLYear::C),
+            ReadingType::Gospel,
+            "Luke 8:22-25",
+        ),
+        (
+            LiturgicalDayId::WeekAndDay(LiturgicalWeek::FourthBeforeAdvent, Weekday::Sun),
+            Year::Rcl(RCLYear::A),
+            ReadingType::FirstReading,
+            "Micah 3:5-12",
+        ),
+        (
+            LiturgicalDayId::WeekAndDay(LiturgicalWeek::FourthBeforeAdvent, Weekday::Sun),
+            Year::Rcl(RCLYear::A),
+            ReadingType::Psalm,
+            "Psalm 43",
+        ),
+        (
+            LiturgicalDayId::WeekAndDay(LiturgicalWeek::FourthBeforeAdvent, Weekday::Sun),
+            Year::Rcl(RCLYear::A),
+            ReadingType::SecondReading,
+            "1 Thessalonians 2:9-13",
+        ),
+        (
+            LiturgicalDayId::WeekAndDay(LiturgicalWeek::FourthBeforeAdvent, Weekday::Sun),
+            Year::Rcl(RCLYear::A),
+            ReadingType::Gospel,
+            "Matthew 24:1-14",
+        ),
+        (
+            LiturgicalDayId::WeekAndDay(LiturgicalWeek::FourthBeforeAdvent, Weekday::Sun),
+            Year::Rcl(RCLYear::B),
+            ReadingType::FirstReading,
+            "Deuteronomy 6:1-9",
+        ),
+        (
+            LiturgicalDayId::WeekAndDay(LiturgicalWeek::FourthBeforeAdvent, Weekday::Sun),
+            Year::Rcl(RCLYear::B),
+            ReadingType::Psalm,
+            "Psalm 119:1-8",
+        ),
+        (
+            LiturgicalDayId::WeekAndDay(LiturgicalWeek::FourthBeforeAdvent, Weekday::Sun),
+            Year::Rcl(RCLYear::B),
+            ReadingType::SecondReading,
+            "Hebrews 9:11-14",
+        ),
+        (
+            LiturgicalDayId::WeekAndDay(LiturgicalWeek::FourthBeforeAdvent, Weekday::Sun),
+            Year::Rcl(RCLYear::B),
+            ReadingType::Gospel,
+            "Mark 12:28-34",
+        ),
+        (
+            LiturgicalDayId::WeekAndDay(LiturgicalWeek::FourthBeforeAdvent, Weekday::Sun),
+            Year::Rcl(RCLYear::C),
+            ReadingType::FirstReading,
+            "Isaiah 1:10-18",
+        ),
+        (
+            LiturgicalDayId::WeekAndDay(LiturgicalWeek::FourthBeforeAdvent, Weekday::Sun),
+            Year::Rcl(RCLYear::C),
+            ReadingType::Psalm,
+            "Psalm 32:1-8",
+        ),
+        (
+            LiturgicalDayId::WeekAndDay(LiturgicalWeek::FourthBeforeAdvent, Weekday::Sun),
+            Year::Rcl(RCLYear::C),
+            ReadingType::SecondReading,
+            "2 Thessalonians 1",
+        ),
+        (
+            LiturgicalDayId::WeekAndDay(LiturgicalWeek::FourthBeforeAdvent, Weekday::Sun),
+            Year::Rcl(RCLYear::C),
+            ReadingType::Gospel,
+            "Luke 19:1-10",
+        ),
+        (
+            LiturgicalDayId::Feast(Feast::George),
+            Year::Any,
+            ReadingType::FirstReading,
+            "1 Maccabees 2:59-64",
+        ),
+        (
+            LiturgicalDayId::Feast(Feast::George),
+            Year::Any,
+            ReadingType::FirstReading,
+            "Revelation 12:7-12",
+        ),
+        (
+            LiturgicalDayId::Feast(Feast::George),
+            Year::Any,
+            ReadingType::Psalm,
+            "Psalm 126",
+        ),
+        (
+            LiturgicalDayId::Feast(Feast::George),
+            Year::Any,
+            ReadingType::SecondReading,
+            "2 Timothy 2:3-13",
+        ),
+        (
+            LiturgicalDayId::Feast(Feast::George),
+            Year::Any,
+            ReadingType::Gospel,
+            "John 15:18-21",
+        ),
+        (
+            LiturgicalDayId::Feast(Feast::ThanksgivingForHolyCommunion),
+            Year::Any,
+            ReadingType::FirstReading,
+            "Genesis 14:18-20",
+        ),
+        (
+            LiturgicalDayId::Feast(Feast::ThanksgivingForHolyCommunion),
+            Year::Any,
+            ReadingType::Psalm,
+            "Psalm 116:10-17",
+        ),
+        (
+            LiturgicalDayId::Feast(Feast::ThanksgivingForHolyCommunion),
+            Year::Any,
+            ReadingType::SecondReading,
+            "1 Corinthians 11:23-26",
+        ),
+        (
+            LiturgicalDayId::Feast(Feast::ThanksgivingForHolyCommunion),
+            Year::Any,
+            ReadingType::Gospel,
+            "John 6:51-58",
+        ),
+    ],
+};
@@ -1,6 +1,7 @@
 mod bcp1979_30_day_psalter;
 mod bcp1979_daily_office_psalter;
 mod bcp1979_office;
+mod common_worship;
 mod lff2018;
 mod rcl;
 mod rcl1;
@@ -9,6 +10,7 @@ mod rcl2;
 pub use bcp1979_30_day_psalter::BCP1979_30_DAY_PSALTER;
 pub use bcp1979_daily_office_psalter::BCP1979_DAILY_OFFICE_PSALTER;
 pub use bcp1979_office::BCP1979_DAILY_OFFICE_LECTIONARY;
+pub use common_worship::{common_worship_readings, COMMON_WORSHIP_LECTIONARY};
 pub use lff2018::LFF2018_LECTIONARY;
 pub use rcl::{rcl_readings, RCLTrack, RCL};
 pub use rcl1::RCL_TRACK_1;
@@ -16,9 +18,53 @@ pub use rcl2::RCL_TRACK_2;
 
 #[cfg(test)]
 mod tests {
-    use calendar::{Date, LiturgicalDayId, LiturgicalWeek, Rank, Weekday, BCP1979_CALENDAR};
+    use calendar::{
+        Date, Feast, LiturgicalDayId, LiturgicalWeek, Rank, Weekday, BCP1979_CALENDAR,
+        COMMON_WORSHIP_CALENDAR,
+    };
 
-    use crate::{rcl_readings, RCLTrack};
+    use crate::{
+        common_worship_readings, rcl_readings, Lectionary, OwnedLectionary, RCLTrack, ReadingType,
+        BCP1979_30_DAY_PSALTER, BCP1979_DAILY_OFFICE_LECTIONARY, BCP1979_DAILY_OFFICE_PSALTER,
+        COMMON_WORSHIP_LECTIONARY, LFF2018_LECTIONARY, RCL, RCL_TRACK_1, RCL_TRACK_2,
+    };
+
+    const ALL_LECTIONARIES: [&Lectionary; 8] = [
+        &BCP1979_30_DAY_PSALTER,
+        &BCP1979_DAILY_OFFICE_PSALTER,
+        &BCP1979_DAILY_OFFICE_LECTIONARY,
+        &COMMON_WORSHIP_LECTIONARY,
+        &LFF2018_LECTIONARY,
+        &RCL,
+        &RCL_TRACK_1,
+        &RCL_TRACK_2,
+    ];
+
+    #[test]
+    fn lectionaries_round_trip_through_csv() {
+        for lectionary in ALL_LECTIONARIES {
+            let owned = OwnedLectionary::from(lectionary);
+            let csv = owned.to_csv().unwrap();
+            assert_eq!(csv.lines().count(), lectionary.readings.len() + 1);
+            assert_eq!(
+                OwnedLectionary::from_csv(owned.year_type, &csv).unwrap(),
+                owned
+            );
+        }
+    }
+
+    #[test]
+    fn lectionaries_round_trip_through_json() {
+        for lectionary in ALL_LECTIONARIES {
+            let owned = OwnedLectionary::from(lectionary);
+            let json = serde_json::to_string(lectionary).unwrap();
+            assert_eq!(OwnedLectionary::from_json(&json).unwrap(), owned);
+            assert_eq!(
+                OwnedLectionary::from_json(&owned.to_json().unwrap()).unwrap(),
+                owned
+            );
+        }
+    }
 
     #[test]
     fn rcl_readings_for_every_sunday() {
@@ -94,4 +140,65 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn common_worship_readings_for_every_sunday() {
+        for year in 2018..=2021 {
+            for month in 1..=12 {
+                for day in 1..=28 {
+                    let date = Date::from_ymd(year, month, day);
+                    let liturgical_day = COMMON_WORSHIP_CALENDAR.liturgical_day(date, false);
+
+                    if date.weekday() == Weekday::Sun
+                        && matches!(
+                            liturgical_day.observed,
+                            LiturgicalDayId::WeekAndDay(_, _) | LiturgicalDayId::ProperAndDay(_, _)
+                        )
+                    {
+                        assert!(
+                            common_worship_readings(
+                                &liturgical_day.observed,
+                                &liturgical_day,
+                                RCLTrack::Two
+                            )
+                            .count()
+                                >= 4,
+                            "missing readings for {:?}",
+                            liturgical_day.observed
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn rcl_readings_for_ember_and_rogation_days() {
+        for year in 2018..=2021 {
+            for feast in [Feast::EmberDay, Feast::RogationDay] {
+                for date in BCP1979_CALENDAR.dates_of(feast, year..=year) {
+                    let liturgical_day = BCP1979_CALENDAR.liturgical_day(date, false);
+                    // each of the three sets of readings is complete
+                    for reading_type in [
+                        ReadingType::FirstReading,
+                        ReadingType::Psalm,
+                        ReadingType::SecondReading,
+                        ReadingType::Gospel,
+                    ] {
+                        let count = RCL
+                            .readings_by_day(&LiturgicalDayId::Feast(feast), &liturgical_day)
+                            .filter(|reading| reading.reading_type == reading_type)
+                            .count();
+                        assert!(
+                            count >= 3,
+                            "missing {:?} for {:?} on {}",
+                            reading_type,
+                            feast,
+                            date
+                        );
+                    }
+                }
+            }
+        }
+    }
 }
@@ -1,5 +1,6 @@
 use calendar::{
-    Feast, LiturgicalDay, LiturgicalDayId, LiturgicalWeek, Proper, RCLYear, Weekday, Year, YearType,
+    Feast, LiturgicalDay, LiturgicalDayId, LiturgicalWeek, Proper, RCLYear, VariousOccasions,
+    Weekday, Year, YearType,
 };
 use serde::{Deserialize, Serialize};
 use strum_macros::{Display, EnumString};
@@ -5095,5 +5096,181 @@ pub const RCL: Lectionary = Lectionary {
             ReadingType::Gospel,
             "Luke 23:33-43",
         ),
+        // Ember Days and Rogation Days (Various Occasions); each set is listed in turn,
+        // and alternative psalms follow the first
+        // Ember Days I. For those to be ordained
+        (
+            LiturgicalDayId::VariousOccasions(VariousOccasions::EmberDays),
+            Year::Any,
+            ReadingType::FirstReading,
+            "Numbers 11:16-17,24-29",
+        ),
+        (
+            LiturgicalDayId::VariousOccasions(VariousOccasions::EmberDays),
+            Year::Any,
+            ReadingType::Psalm,
+            "Psalm 99",
+        ),
+        (
+            LiturgicalDayId::VariousOccasions(VariousOccasions::EmberDays),
+            Year::Any,
+            ReadingType::Psalm,
+            "Psalm 27:1-9",
+        ),
+        (
+            LiturgicalDayId::VariousOccasions(VariousOccasions::EmberDays),
+            Year::Any,
+            ReadingType::SecondReading,
+            "Ephesians 4:7,11-16",
+        ),
+        (
+            LiturgicalDayId::VariousOccasions(VariousOccasions::EmberDays),
+            Year::Any,
+            ReadingType::Gospel,
+            "Matthew 9:35-38",
+        ),
+        // Ember Days II. For the choice of fit persons for the ministry
+        (
+            LiturgicalDayId::VariousOccasions(VariousOccasions::EmberDays),
+            Year::Any,
+            ReadingType::FirstReading,
+            "Jeremiah 1:4-9",
+        ),
+        (
+            LiturgicalDayId::VariousOccasions(VariousOccasions::EmberDays),
+            Year::Any,
+            ReadingType::Psalm,
+            "Psalm 84",
+        ),
+        (
+            LiturgicalDayId::VariousOccasions(VariousOccasions::EmberDays),
+            Year::Any,
+            ReadingType::Psalm,
+            "Psalm 84:7-11",
+        ),
+        (
+            LiturgicalDayId::VariousOccasions(VariousOccasions::EmberDays),
+            Year::Any,
+            ReadingType::SecondReading,
+            "Acts 20:28-32",
+        ),
+        (
+            LiturgicalDayId::VariousOccasions(VariousOccasions::EmberDays),
+            Year::Any,
+            ReadingType::Gospel,
+            "John 4:31-38",
+        ),
+        // Ember Days III. For all Christians in their vocation
+        (
+            LiturgicalDayId::VariousOccasions(VariousOccasions::EmberDays),
+            Year::Any,
+            ReadingType::FirstReading,
+            "Exodus 19:3-8",
+        ),
+        (
+            LiturgicalDayId::VariousOccasions(VariousOccasions::EmberDays),
+            Year::Any,
+            ReadingType::Psalm,
+            "Psalm 15",
+        ),
+        (
+            LiturgicalDayId::VariousOccasions(VariousOccasions::EmberDays),
+            Year::Any,
+            ReadingType::SecondReading,
+            "1 Peter 4:7-11",
+        ),
+        (
+            LiturgicalDayId::VariousOccasions(VariousOccasions::EmberDays),
+            Year::Any,
+            ReadingType::Gospel,
+            "Matthew 25:14-30",
+        ),
+        // Rogation Days I. For fruitful seasons
+        (
+            LiturgicalDayId::VariousOccasions(VariousOccasions::RogationDays),
+            Year::Any,
+            ReadingType::FirstReading,
+            "Deuteronomy 11:10-15",
+        ),
+        (
+            LiturgicalDayId::VariousOccasions(VariousOccasions::RogationDays),
+            Year::Any,
+            ReadingType::Psalm,
+            "Psalm 147",
+        ),
+        (
+            LiturgicalDayId::VariousOccasions(VariousOccasions::RogationDays),
+            Year::Any,
+            ReadingType::Psalm,
+            "Psalm 147:1-13",
+        ),
+        (
+            LiturgicalDayId::VariousOccasions(VariousOccasions::RogationDays),
+            Year::Any,
+            ReadingType::SecondReading,
+            "Romans 8:18-25",
+        ),
+        (
+            LiturgicalDayId::VariousOccasions(VariousOccasions::RogationDays),
+            Year::Any,
+            ReadingType::Gospel,
+            "Mark 4:26-32",
+        ),
+        // Rogation Days II. For commerce and industry
+        (
+            LiturgicalDayId::VariousOccasions(VariousOccasions::RogationDays),
+            Year::Any,
+            ReadingType::FirstReading,
+            "Ecclesiasticus 38:27-32",
+        ),
+        (
+            LiturgicalDayId::VariousOccasions(VariousOccasions::RogationDays),
+            Year::Any,
+            ReadingType::Psalm,
+            "Psalm 107:1-9",
+        ),
+        (
+            LiturgicalDayId::VariousOccasions(VariousOccasions::RogationDays),
+            Year::Any,
+            ReadingType::SecondReading,
+            "1 Corinthians 3:10-14",
+        ),
+        (
+            LiturgicalDayId::VariousOccasions(VariousOccasions::RogationDays),
+            Year::Any,
+            ReadingType::Gospel,
+            "Matthew 6:19-24",
+        ),
+        // Rogation Days III. For stewardship of creation
+        (
+            LiturgicalDayId::VariousOccasions(VariousOccasions::RogationDays),
+            Year::Any,
+            ReadingType::FirstReading,
+            "Job 38:1-11,16-18",
+        ),
+        (
+            LiturgicalDayId::VariousOccasions(VariousOccasions::RogationDays),
+            Year::Any,
+            ReadingType::Psalm,
+            "Psalm 104:25-37",
+        ),
+        (
+            LiturgicalDayId::VariousOccasions(VariousOccasions::RogationDays),
+            Year::Any,
+            ReadingType::Psalm,
+            "Psalm 104:1,13-15,25-32",
+        ),
+        (
+            LiturgicalDayId::VariousOccasions(VariousOccasions::RogationDays),
+            Year::Any,
+            ReadingType::SecondReading,
+            "1 Timothy 4:1-5",
+        ),
+        (
+            LiturgicalDayId::VariousOccasions(VariousOccasions::RogationDays),
+            Year::Any,
+            ReadingType::Gospel,
+            "Luke 12:13-21",
+        ),
     ],
 };
@@ -1,12 +1,20 @@
 mod lectionaries;
+mod owned;
 mod reading;
 mod reading_type;
+#[cfg(any(feature = "browser", feature = "server"))]
+mod validation;
 
 pub use lectionaries::*;
+pub use owned::*;
 pub use reading::Reading;
 pub use reading_type::*;
+#[cfg(any(feature = "browser", feature = "server"))]
+pub use validation::*;
 
-use calendar::{DailyOfficeYear, LiturgicalDay, LiturgicalDayId, Year, YearType};
+use calendar::{
+    DailyOfficeYear, Date, Feast, LiturgicalDay, LiturgicalDayId, VariousOccasions, Year, YearType,
+};
 use serde::Serialize;
 
 /// Represents a given lectionary cycle of readings, e.g., the Revised Common Lectionary
@@ -26,28 +34,13 @@ impl Lectionary {
         day: &LiturgicalDay,
     ) -> impl Iterator<Item = Reading> {
         let date = day.date;
-
-        let year = match self.year_type {
-            YearType::Rcl => Year::Rcl(day.rcl_year),
-            YearType::DailyOffice => Year::DailyOffice(day.daily_office_year),
-            YearType::None => Year::Any,
-        };
-
-        let observed = if let LiturgicalDayId::TransferredFeast(feast) = observed {
-            LiturgicalDayId::Feast(*feast)
-        } else {
-            *observed
-        };
+        let year = year_for_day(self.year_type, day);
+        let observed = observed_for_lookup(observed);
 
         self.readings
             .iter()
             .filter(move |(search_id, search_year, _, _)| {
-                let matches_year = *search_year == year || *search_year == Year::Any;
-                let matches_day = match search_id {
-                    LiturgicalDayId::DayOfMonth(n) => *n == date.day(),
-                    _ => *search_id == observed,
-                };
-                matches_day && matches_year
+                entry_matches(search_id, search_year, &observed, &year, date)
             })
             .map(|(_, _, reading_type, citation)| Reading::new(*reading_type, citation.to_string()))
     }
@@ -58,20 +51,7 @@ impl Lectionary {
         day: &LiturgicalDay,
         reading_type: ReadingType,
     ) -> impl Iterator<Item = Reading> {
-        let mut reading_type = reading_type;
-        let mut day = day.clone();
-
-        if ReadingType::FirstReadingAlternateYear == reading_type {
-            reading_type = ReadingType::FirstReading;
-            let alternate_year = match day.daily_office_year {
-                DailyOfficeYear::One => DailyOfficeYear::Two,
-                DailyOfficeYear::Two => DailyOfficeYear::One,
-            };
-            day = LiturgicalDay {
-                daily_office_year: alternate_year,
-                ..day.clone()
-            };
-        }
+        let (day, reading_type) = day_and_reading_type(day, reading_type);
 
         self.readings_by_day(observed, &day)
             .filter(move |reading| reading.reading_type == reading_type)
@@ -100,3 +80,81 @@ impl Lectionary {
         }
     }
 }
+
+/// The concrete [Year] that should be used to look up readings for the given day
+pub(crate) fn year_for_day(year_type: YearType, day: &LiturgicalDay) -> Year {
+    match year_type {
+        YearType::Rcl => Year::Rcl(day.rcl_year),
+        YearType::DailyOffice => Year::DailyOffice(day.daily_office_year),
+        YearType::None => Year::Any,
+    }
+}
+
+/// The observance under which readings for a day are listed in a lectionary: transferred feasts
+/// use the same readings as the feast itself, and Ember and Rogation Days use the readings
+/// appointed for those [VariousOccasions].
+/// ```
+/// # use calendar::{Feast, LiturgicalDayId, VariousOccasions};
+/// # use lectionary::observed_for_lookup;
+/// assert_eq!(
+///     observed_for_lookup(&LiturgicalDayId::TransferredFeast(Feast::Luke)),
+///     LiturgicalDayId::Feast(Feast::Luke)
+/// );
+/// assert_eq!(
+///     observed_for_lookup(&LiturgicalDayId::Feast(Feast::EmberDay)),
+///     LiturgicalDayId::VariousOccasions(VariousOccasions::EmberDays)
+/// );
+/// ```
+pub fn observed_for_lookup(observed: &LiturgicalDayId) -> LiturgicalDayId {
+    match observed {
+        LiturgicalDayId::Feast(Feast::EmberDay)
+        | LiturgicalDayId::TransferredFeast(Feast::EmberDay) => {
+            LiturgicalDayId::VariousOccasions(VariousOccasions::EmberDays)
+        }
+        LiturgicalDayId::Feast(Feast::RogationDay)
+        | LiturgicalDayId::TransferredFeast(Feast::RogationDay) => {
+            LiturgicalDayId::VariousOccasions(VariousOccasions::RogationDays)
+        }
+        LiturgicalDayId::TransferredFeast(feast) => LiturgicalDayId::Feast(*feast),
+        _ => *observed,
+    }
+}
+
+/// Whether an entry in a lectionary table applies to the given day and year
+pub(crate) fn entry_matches(
+    search_id: &LiturgicalDayId,
+    search_year: &Year,
+    observed: &LiturgicalDayId,
+    year: &Year,
+    date: Date,
+) -> bool {
+    let matches_year = search_year == year || *search_year == Year::Any;
+    let matches_day = match search_id {
+        LiturgicalDayId::DayOfMonth(n) => *n == date.day(),
+        _ => search_id == observed,
+    };
+    matches_day && matches_year
+}
+
+/// Handles [ReadingType::FirstReadingAlternateYear], which is the first reading from the other
+/// year of the Daily Office lectionary
+pub(crate) fn day_and_reading_type(
+    day: &LiturgicalDay,
+    reading_type: ReadingType,
+) -> (LiturgicalDay, ReadingType) {
+    if ReadingType::FirstReadingAlternateYear == reading_type {
+        let alternate_year = match day.daily_office_year {
+            DailyOfficeYear::One => DailyOfficeYear::Two,
+            DailyOfficeYear::Two => DailyOfficeYear::One,
+        };
+        (
+            LiturgicalDay {
+                daily_office_year: alternate_year,
+                ..day.clone()
+            },
+            ReadingType::FirstReading,
+        )
+    } else {
+        (day.clone(), reading_type)
+    }
+}
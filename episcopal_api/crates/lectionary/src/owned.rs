@@ -0,0 +1,246 @@
+use calendar::{LiturgicalDay, LiturgicalDayId, Year, YearType};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::{
+    day_and_reading_type, entry_matches, observed_for_lookup, year_for_day, Lectionary, Reading,
+    ReadingType,
+};
+
+/// A [Lectionary] that owns its readings, so that it can be loaded at runtime
+/// (e.g., from JSON or CSV) rather than compiled in as a `'static` table.
+///
+/// The JSON format is the same as the serialized form of a [Lectionary], so any of the built-in
+/// lectionaries can be exported, edited, and loaded again.
+/// ```
+/// # use calendar::{Date, BCP1979_CALENDAR};
+/// # use lectionary::{OwnedLectionary, ReadingType, RCL};
+/// let rcl = OwnedLectionary::from(&RCL);
+/// let csv = rcl.to_csv().unwrap();
+/// assert!(csv.starts_with("day,year,reading_type,citation\n"));
+/// assert!(csv.contains("Feast(AllSaintsDay),Rcl(A),FirstReading,Revelation 7:9-17\n"));
+///
+/// let loaded = OwnedLectionary::from_csv(rcl.year_type, &csv).unwrap();
+/// assert_eq!(loaded, rcl);
+///
+/// let day = BCP1979_CALENDAR.liturgical_day(Date::from_ymd(2020, 11, 1), false);
+/// let gospel = loaded.reading_by_type(&day.observed, &day, ReadingType::Gospel).next().unwrap();
+/// assert_eq!(gospel.citation, "Matthew 5:1-12");
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OwnedLectionary {
+    pub year_type: YearType,
+    pub readings: Vec<(LiturgicalDayId, Year, ReadingType, String)>,
+}
+
+#[derive(Error, Debug)]
+pub enum LectionaryFormatError {
+    #[error("could not parse lectionary from JSON")]
+    Json(#[from] serde_json::Error),
+    #[error("could not read or write lectionary as CSV")]
+    Csv(#[from] csv::Error),
+    #[error("invalid liturgical day in lectionary: {0}")]
+    InvalidDay(String),
+    #[error("invalid year in lectionary: {0}")]
+    InvalidYear(String),
+}
+
+/// One row in the CSV form of a lectionary
+#[derive(Serialize, Deserialize)]
+struct CsvEntry {
+    day: String,
+    year: String,
+    reading_type: ReadingType,
+    citation: String,
+}
+
+impl OwnedLectionary {
+    pub fn readings_by_day<'a>(
+        &'a self,
+        observed: &LiturgicalDayId,
+        day: &LiturgicalDay,
+    ) -> impl Iterator<Item = Reading> + 'a {
+        let date = day.date;
+        let year = year_for_day(self.year_type, day);
+        let observed = observed_for_lookup(observed);
+
+        self.readings
+            .iter()
+            .filter(move |(search_id, search_year, _, _)| {
+                entry_matches(search_id, search_year, &observed, &year, date)
+            })
+            .map(|(_, _, reading_type, citation)| Reading::new(*reading_type, citation.clone()))
+    }
+
+    pub fn reading_by_type<'a>(
+        &'a self,
+        observed: &LiturgicalDayId,
+        day: &LiturgicalDay,
+        reading_type: ReadingType,
+    ) -> impl Iterator<Item = Reading> + 'a {
+        let (day, reading_type) = day_and_reading_type(day, reading_type);
+
+        self.readings_by_day(observed, &day)
+            .filter(move |reading| reading.reading_type == reading_type)
+    }
+
+    pub fn from_json(json: &str) -> Result<Self, LectionaryFormatError> {
+        Ok(serde_json::from_str(json)?)
+    }
+
+    pub fn to_json(&self) -> Result<String, LectionaryFormatError> {
+        Ok(serde_json::to_string_pretty(self)?)
+    }
+
+    /// Loads a lectionary from CSV with the columns `day`, `year`, `reading_type`, and `citation`,
+    /// in the format produced by [OwnedLectionary::to_csv]. Because the CSV form of the lectionary
+    /// only contains the readings, the [YearType] must be given separately.
+    pub fn from_csv(year_type: YearType, csv: &str) -> Result<Self, LectionaryFormatError> {
+        let mut reader = csv::Reader::from_reader(csv.as_bytes());
+        let readings = reader
+            .deserialize::<CsvEntry>()
+            .map(|entry| {
+                let entry = entry?;
+                let day = entry
+                    .day
+                    .parse()
+                    .map_err(|_| LectionaryFormatError::InvalidDay(entry.day.clone()))?;
+                let year = entry
+                    .year
+                    .parse()
+                    .map_err(|_| LectionaryFormatError::InvalidYear(entry.year.clone()))?;
+                Ok((day, year, entry.reading_type, entry.citation))
+            })
+            .collect::<Result<Vec<_>, LectionaryFormatError>>()?;
+        Ok(Self {
+            year_type,
+            readings,
+        })
+    }
+
+    /// Exports the readings as CSV, one reading per line, which is easier to review and diff
+    /// than the JSON form
+    pub fn to_csv(&self) -> Result<String, LectionaryFormatError> {
+        let mut writer = csv::Writer::from_writer(Vec::new());
+        for (day, year, reading_type, citation) in &self.readings {
+            writer.serialize(CsvEntry {
+                day: day.to_string(),
+                year: year.to_string(),
+                reading_type: *reading_type,
+                citation: citation.clone(),
+            })?;
+        }
+        let bytes = writer
+            .into_inner()
+            .map_err(|e| LectionaryFormatError::Csv(e.into_error().into()))?;
+        Ok(String::from_utf8_lossy(&bytes).into_owned())
+    }
+}
+
+impl From<&Lectionary> for OwnedLectionary {
+    fn from(lectionary: &Lectionary) -> Self {
+        Self {
+            year_type: lectionary.year_type,
+            readings: lectionary
+                .readings
+                .iter()
+                .map(|(day, year, reading_type, citation)| {
+                    (*day, *year, *reading_type, citation.to_string())
+                })
+                .collect(),
+        }
+    }
+}
+
+/// Either one of the built-in lectionaries or one loaded at runtime, so that readings
+/// can be looked up in the same way from both
+/// ```
+/// # use calendar::{Date, BCP1979_CALENDAR};
+/// # use lectionary::{LectionaryRef, OwnedLectionary, ReadingType, RCL};
+/// let day = BCP1979_CALENDAR.liturgical_day(Date::from_ymd(2020, 11, 1), false);
+/// let mut corrected = OwnedLectionary::from(&RCL);
+/// for (_, _, _, citation) in corrected.readings.iter_mut() {
+///     if citation == "Matthew 5:1-12" {
+///         *citation = "Matthew 5:1-16".to_string();
+///     }
+/// }
+///
+/// let gospel = |lectionary: LectionaryRef| {
+///     lectionary
+///         .reading_by_type_with_override(&day.observed, &day, ReadingType::Gospel, None)
+///         .next()
+///         .unwrap()
+///         .citation
+/// };
+/// assert_eq!(gospel(LectionaryRef::from(&RCL)), "Matthew 5:1-12");
+/// assert_eq!(gospel(LectionaryRef::from(&corrected)), "Matthew 5:1-16");
+/// ```
+#[derive(Copy, Clone)]
+pub enum LectionaryRef<'a> {
+    Static(&'static Lectionary),
+    Owned(&'a OwnedLectionary),
+}
+
+impl From<&'static Lectionary> for LectionaryRef<'_> {
+    fn from(lectionary: &'static Lectionary) -> Self {
+        Self::Static(lectionary)
+    }
+}
+
+impl<'a> From<&'a OwnedLectionary> for LectionaryRef<'a> {
+    fn from(lectionary: &'a OwnedLectionary) -> Self {
+        Self::Owned(lectionary)
+    }
+}
+
+impl<'a> LectionaryRef<'a> {
+    pub fn readings_by_day(
+        self,
+        observed: &LiturgicalDayId,
+        day: &LiturgicalDay,
+    ) -> Box<dyn Iterator<Item = Reading> + 'a> {
+        match self {
+            Self::Static(lectionary) => Box::new(lectionary.readings_by_day(observed, day)),
+            Self::Owned(lectionary) => Box::new(lectionary.readings_by_day(observed, day)),
+        }
+    }
+
+    pub fn reading_by_type(
+        self,
+        observed: &LiturgicalDayId,
+        day: &LiturgicalDay,
+        reading_type: ReadingType,
+    ) -> Box<dyn Iterator<Item = Reading> + 'a> {
+        match self {
+            Self::Static(lectionary) => {
+                Box::new(lectionary.reading_by_type(observed, day, reading_type))
+            }
+            Self::Owned(lectionary) => {
+                Box::new(lectionary.reading_by_type(observed, day, reading_type))
+            }
+        }
+    }
+
+    /// The readings of the given type, unless there are readings of `override_type`, which are used instead
+    pub fn reading_by_type_with_override(
+        self,
+        observed: &LiturgicalDayId,
+        day: &LiturgicalDay,
+        reading_type: ReadingType,
+        override_type: Option<ReadingType>,
+    ) -> Box<dyn Iterator<Item = Reading> + 'a> {
+        match override_type {
+            Some(override_type) => {
+                let mut override_readings = self
+                    .reading_by_type(observed, day, override_type)
+                    .peekable();
+                if override_readings.peek().is_some() {
+                    Box::new(override_readings)
+                } else {
+                    self.reading_by_type(observed, day, reading_type)
+                }
+            }
+            None => self.reading_by_type(observed, day, reading_type),
+        }
+    }
+}
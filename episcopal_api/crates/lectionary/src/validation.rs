@@ -0,0 +1,491 @@
+use calendar::{
+    Calendar, Date, Feast, LiturgicalDay, LiturgicalDayId, Rank, Weekday, BCP1979_CALENDAR,
+    COMMON_WORSHIP_CALENDAR, LFF2018_CALENDAR,
+};
+use reference_parser::BibleReference;
+use serde::Serialize;
+
+use crate::{
+    common_worship_readings, rcl_readings, Lectionary, RCLTrack, Reading, ReadingType,
+    BCP1979_30_DAY_PSALTER, BCP1979_DAILY_OFFICE_LECTIONARY, BCP1979_DAILY_OFFICE_PSALTER,
+    LFF2018_LECTIONARY,
+};
+
+const DAILY_OFFICE_READINGS: [&[ReadingType]; 2] = [
+    &[
+        ReadingType::FirstReading,
+        ReadingType::SecondReading,
+        ReadingType::Gospel,
+    ],
+    &[ReadingType::Morning1, ReadingType::Morning2],
+];
+const DAILY_OFFICE_PSALMS: [&[ReadingType]; 1] =
+    [&[ReadingType::MorningPsalm, ReadingType::EveningPsalm]];
+const EUCHARISTIC_READINGS: [&[ReadingType]; 2] = [
+    &[
+        ReadingType::FirstReading,
+        ReadingType::Psalm,
+        ReadingType::SecondReading,
+        ReadingType::Gospel,
+    ],
+    // e.g., the weekdays of Easter Week, with a single reading from Acts before the Gospel
+    &[
+        ReadingType::Psalm,
+        ReadingType::SecondReading,
+        ReadingType::Gospel,
+    ],
+];
+const LESSER_FEAST_READINGS: [&[ReadingType]; 1] = [&[
+    ReadingType::FirstReading,
+    ReadingType::Psalm,
+    ReadingType::Gospel,
+]];
+
+/// Where a [LectionaryCheck] finds the readings for a day
+#[derive(Copy, Clone)]
+pub enum ReadingSource {
+    Table(&'static Lectionary),
+    Rcl(RCLTrack),
+    CommonWorship(RCLTrack),
+}
+
+impl ReadingSource {
+    fn readings(&self, observed: &LiturgicalDayId, day: &LiturgicalDay) -> Vec<Reading> {
+        match self {
+            ReadingSource::Table(lectionary) => lectionary.readings_by_day(observed, day).collect(),
+            ReadingSource::Rcl(track) => rcl_readings(observed, day, *track).collect(),
+            ReadingSource::CommonWorship(track) => {
+                common_worship_readings(observed, day, *track).collect()
+            }
+        }
+    }
+}
+
+/// Which observances a [LectionaryCheck] expects to have readings
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum CheckScope {
+    /// Whatever is observed on each day
+    EveryDay,
+    /// Sundays, and any feast with the rank of a [Rank::HolyDay] or higher
+    SundaysAndHolyDays,
+    /// Every lesser feast, whether it is observed or simply commemorated on that day
+    /// (but not Ember Days or Rogation Days, whose readings are found among the Various Occasions)
+    LesserFeasts,
+}
+
+/// Describes how to validate a single lectionary: which calendar it follows,
+/// the days for which it should provide readings, and which readings it should provide
+pub struct LectionaryCheck {
+    pub name: &'static str,
+    pub calendar: &'static Calendar<'static>,
+    pub source: ReadingSource,
+    pub scope: CheckScope,
+    /// Each observance should include every [ReadingType] in at least one of these sets
+    pub required: &'static [&'static [ReadingType]],
+}
+
+/// The results of checking one or more lectionaries over a range of dates
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct LectionaryReport {
+    pub start: Date,
+    pub end: Date,
+    pub issues: Vec<LectionaryIssue>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct LectionaryIssue {
+    pub lectionary: &'static str,
+    pub date: Date,
+    pub observance: LiturgicalDayId,
+    pub problem: LectionaryProblem,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub enum LectionaryProblem {
+    /// None of the required sets of readings is complete; lists the reading types missing from the closest one
+    MissingReadings(Vec<ReadingType>),
+    /// The same citation is given more than once for the same reading
+    DuplicateReading(ReadingType, String),
+    /// The citation can't be parsed as a [BibleReference]
+    UnparseableCitation(ReadingType, String),
+}
+
+impl LectionaryReport {
+    /// Checks each lectionary against every date from `start` to `end` (inclusive)
+    /// ```
+    /// # use calendar::Date;
+    /// # use lectionary::{LectionaryCheck, LectionaryReport};
+    /// let report = LectionaryReport::new(
+    ///     &LectionaryCheck::all(),
+    ///     Date::from_ymd(2021, 11, 28),
+    ///     Date::from_ymd(2021, 12, 4),
+    /// );
+    /// assert!(report.is_ok());
+    /// ```
+    pub fn new(checks: &[LectionaryCheck], start: Date, end: Date) -> Self {
+        Self {
+            start,
+            end,
+            issues: checks
+                .iter()
+                .flat_map(|check| check.check(start, end))
+                .collect(),
+        }
+    }
+
+    pub fn is_ok(&self) -> bool {
+        self.issues.is_empty()
+    }
+}
+
+impl LectionaryCheck {
+    /// Checks for each of the built-in lectionaries
+    pub fn all() -> Vec<Self> {
+        vec![
+            Self {
+                name: "BCP1979 Daily Office",
+                calendar: &BCP1979_CALENDAR,
+                source: ReadingSource::Table(&BCP1979_DAILY_OFFICE_LECTIONARY),
+                scope: CheckScope::EveryDay,
+                required: &DAILY_OFFICE_READINGS,
+            },
+            Self {
+                name: "BCP1979 Daily Office Psalter",
+                calendar: &BCP1979_CALENDAR,
+                source: ReadingSource::Table(&BCP1979_DAILY_OFFICE_PSALTER),
+                scope: CheckScope::EveryDay,
+                required: &DAILY_OFFICE_PSALMS,
+            },
+            Self {
+                name: "BCP1979 30-Day Psalter",
+                calendar: &BCP1979_CALENDAR,
+                source: ReadingSource::Table(&BCP1979_30_DAY_PSALTER),
+                scope: CheckScope::EveryDay,
+                required: &DAILY_OFFICE_PSALMS,
+            },
+            Self {
+                name: "RCL (Track 1)",
+                calendar: &BCP1979_CALENDAR,
+                source: ReadingSource::Rcl(RCLTrack::One),
+                scope: CheckScope::SundaysAndHolyDays,
+                required: &EUCHARISTIC_READINGS,
+            },
+            Self {
+                name: "RCL (Track 2)",
+                calendar: &BCP1979_CALENDAR,
+                source: ReadingSource::Rcl(RCLTrack::Two),
+                scope: CheckScope::SundaysAndHolyDays,
+                required: &EUCHARISTIC_READINGS,
+            },
+            Self {
+                name: "Lesser Feasts and Fasts 2018",
+                calendar: &LFF2018_CALENDAR,
+                source: ReadingSource::Table(&LFF2018_LECTIONARY),
+                scope: CheckScope::LesserFeasts,
+                required: &LESSER_FEAST_READINGS,
+            },
+            Self {
+                name: "Common Worship (Track 1)",
+                calendar: &COMMON_WORSHIP_CALENDAR,
+                source: ReadingSource::CommonWorship(RCLTrack::One),
+                scope: CheckScope::SundaysAndHolyDays,
+                required: &EUCHARISTIC_READINGS,
+            },
+            Self {
+                name: "Common Worship (Track 2)",
+                calendar: &COMMON_WORSHIP_CALENDAR,
+                source: ReadingSource::CommonWorship(RCLTrack::Two),
+                scope: CheckScope::SundaysAndHolyDays,
+                required: &EUCHARISTIC_READINGS,
+            },
+        ]
+    }
+
+    /// Checks this lectionary against every date from `start` to `end` (inclusive)
+    pub fn check(&self, start: Date, end: Date) -> Vec<LectionaryIssue> {
+        self.calendar
+            .liturgical_days(start..=end, false)
+            .flat_map(|day| {
+                let date = day.date;
+                self.observances(&day)
+                    .into_iter()
+                    .flat_map(|observance| {
+                        let readings = self.source.readings(&observance, &day);
+                        self.problems(&readings)
+                            .into_iter()
+                            .map(move |problem| LectionaryIssue {
+                                lectionary: self.name,
+                                date,
+                                observance,
+                                problem,
+                            })
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect()
+    }
+
+    fn observances(&self, day: &LiturgicalDay) -> Vec<LiturgicalDayId> {
+        let is_feast =
+            |feast: &Feast| !self.calendar.feast_is_eve(feast) && !matches!(feast, Feast::Local(_));
+        match self.scope {
+            CheckScope::EveryDay => vec![day.observed],
+            CheckScope::SundaysAndHolyDays => {
+                let is_sunday = day.weekday == Weekday::Sun
+                    && matches!(
+                        day.observed,
+                        LiturgicalDayId::WeekAndDay(_, _) | LiturgicalDayId::ProperAndDay(_, _)
+                    );
+                let is_holy_day = match day.observed {
+                    LiturgicalDayId::Feast(feast) | LiturgicalDayId::TransferredFeast(feast) => {
+                        is_feast(&feast) && self.calendar.feast_day_rank(&feast) >= Rank::HolyDay
+                    }
+                    _ => false,
+                };
+                if is_sunday || is_holy_day {
+                    vec![day.observed]
+                } else {
+                    Vec::new()
+                }
+            }
+            CheckScope::LesserFeasts => {
+                let observed = match day.observed {
+                    LiturgicalDayId::Feast(feast) | LiturgicalDayId::TransferredFeast(feast) => {
+                        Some(feast)
+                    }
+                    _ => None,
+                };
+                observed
+                    .into_iter()
+                    .chain(day.holy_days.iter().copied())
+                    .filter(|feast| {
+                        is_feast(feast)
+                            && self.calendar.feast_day_rank(feast) == Rank::OptionalObservance
+                    })
+                    .map(LiturgicalDayId::Feast)
+                    .collect()
+            }
+        }
+    }
+
+    fn problems(&self, readings: &[Reading]) -> Vec<LectionaryProblem> {
+        let mut problems = Vec::new();
+
+        // missing readings: compare against whichever set of readings is closest to complete
+        let missing = self
+            .required
+            .iter()
+            .map(|set| {
+                set.iter()
+                    .filter(|reading_type| {
+                        !readings
+                            .iter()
+                            .any(|reading| reading.reading_type == **reading_type)
+                    })
+                    .copied()
+                    .collect::<Vec<_>>()
+            })
+            .min_by_key(|missing| missing.len());
+        if let Some(missing) = missing {
+            if !missing.is_empty() {
+                problems.push(LectionaryProblem::MissingReadings(missing));
+            }
+        }
+
+        for (idx, reading) in readings.iter().enumerate() {
+            if readings[..idx].contains(reading) {
+                problems.push(LectionaryProblem::DuplicateReading(
+                    reading.reading_type,
+                    reading.citation.clone(),
+                ));
+            }
+            if !is_parseable(&reading.citation) {
+                problems.push(LectionaryProblem::UnparseableCitation(
+                    reading.reading_type,
+                    reading.citation.clone(),
+                ));
+            }
+        }
+
+        problems
+    }
+}
+
+/// Whether a citation can be parsed into a [BibleReference] in which every range has a book and a chapter or verse.
+/// References to canticles are not checked.
+fn is_parseable(citation: &str) -> bool {
+    if citation.starts_with("canticle-")
+        || citation.starts_with("Canticle ")
+        || citation.starts_with("Cántico")
+    {
+        return true;
+    }
+    let reference = BibleReference::from(citation);
+    !reference.ranges.is_empty()
+        && reference.ranges.iter().all(|range| {
+            range.start.book.is_some()
+                && (range.start.chapter.is_some() || range.start.verse.is_some())
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use calendar::{LiturgicalWeek, Year, YearType};
+
+    #[test]
+    fn all_citations_can_be_parsed() {
+        let report = LectionaryReport::new(
+            &LectionaryCheck::all(),
+            Date::from_ymd(2021, 11, 28),
+            Date::from_ymd(2022, 11, 26),
+        );
+        let unparseable = report
+            .issues
+            .iter()
+            .filter(|issue| matches!(issue.problem, LectionaryProblem::UnparseableCitation(_, _)))
+            .collect::<Vec<_>>();
+        assert_eq!(unparseable, Vec::<&LectionaryIssue>::new());
+    }
+
+    #[test]
+    fn reports_missing_lesser_feast_readings() {
+        let report = LectionaryReport::new(
+            &LectionaryCheck::all(),
+            Date::from_ymd(2022, 6, 28),
+            Date::from_ymd(2022, 6, 28),
+        );
+        assert_eq!(
+            report.issues,
+            vec![LectionaryIssue {
+                lectionary: "Lesser Feasts and Fasts 2018",
+                date: Date::from_ymd(2022, 6, 28),
+                observance: LiturgicalDayId::Feast(Feast::IrenaeusOfLyons),
+                problem: LectionaryProblem::MissingReadings(vec![
+                    ReadingType::FirstReading,
+                    ReadingType::Psalm,
+                    ReadingType::Gospel
+                ])
+            }]
+        );
+    }
+
+    #[test]
+    fn reports_missing_readings() {
+        static INCOMPLETE: Lectionary = Lectionary {
+            year_type: YearType::None,
+            readings: &[
+                (
+                    LiturgicalDayId::WeekAndDay(LiturgicalWeek::Advent1, Weekday::Sun),
+                    Year::Any,
+                    ReadingType::FirstReading,
+                    "Isa. 2:1-5",
+                ),
+                (
+                    LiturgicalDayId::WeekAndDay(LiturgicalWeek::Advent1, Weekday::Sun),
+                    Year::Any,
+                    ReadingType::Psalm,
+                    "Ps. 122",
+                ),
+                (
+                    LiturgicalDayId::WeekAndDay(LiturgicalWeek::Advent1, Weekday::Sun),
+                    Year::Any,
+                    ReadingType::SecondReading,
+                    "Rom. 13:11-14",
+                ),
+            ],
+        };
+        static EMPTY: Lectionary = Lectionary {
+            year_type: YearType::None,
+            readings: &[],
+        };
+        let check = |lectionary: &'static Lectionary| LectionaryCheck {
+            name: "Test",
+            calendar: &BCP1979_CALENDAR,
+            source: ReadingSource::Table(lectionary),
+            scope: CheckScope::EveryDay,
+            required: &[&[
+                ReadingType::FirstReading,
+                ReadingType::Psalm,
+                ReadingType::SecondReading,
+                ReadingType::Gospel,
+            ]],
+        };
+        let advent_1 = Date::from_ymd(2021, 11, 28);
+
+        assert_eq!(
+            check(&INCOMPLETE).check(advent_1, advent_1),
+            vec![LectionaryIssue {
+                lectionary: "Test",
+                date: advent_1,
+                observance: LiturgicalDayId::WeekAndDay(LiturgicalWeek::Advent1, Weekday::Sun),
+                problem: LectionaryProblem::MissingReadings(vec![ReadingType::Gospel])
+            }]
+        );
+        assert_eq!(
+            check(&EMPTY).check(advent_1, advent_1),
+            vec![LectionaryIssue {
+                lectionary: "Test",
+                date: advent_1,
+                observance: LiturgicalDayId::WeekAndDay(LiturgicalWeek::Advent1, Weekday::Sun),
+                problem: LectionaryProblem::MissingReadings(vec![
+                    ReadingType::FirstReading,
+                    ReadingType::Psalm,
+                    ReadingType::SecondReading,
+                    ReadingType::Gospel
+                ])
+            }]
+        );
+    }
+
+    #[test]
+    fn no_missing_eucharistic_readings_over_a_full_year() {
+        let report = LectionaryReport::new(
+            &LectionaryCheck::all(),
+            Date::from_ymd(2021, 11, 28),
+            Date::from_ymd(2022, 11, 26),
+        );
+        let missing = report
+            .issues
+            .iter()
+            .filter(|issue| {
+                matches!(
+                    issue.lectionary,
+                    "RCL (Track 1)"
+                        | "RCL (Track 2)"
+                        | "Common Worship (Track 1)"
+                        | "Common Worship (Track 2)"
+                ) && matches!(issue.problem, LectionaryProblem::MissingReadings(_))
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(missing, Vec::<&LectionaryIssue>::new());
+    }
+
+    #[test]
+    fn ember_and_rogation_days_are_not_lesser_feasts() {
+        let report = LectionaryReport::new(
+            &LectionaryCheck::all(),
+            Date::from_ymd(2021, 11, 28),
+            Date::from_ymd(2022, 11, 26),
+        );
+        let ember_or_rogation = report
+            .issues
+            .iter()
+            .filter(|issue| {
+                matches!(
+                    issue.observance,
+                    LiturgicalDayId::Feast(Feast::EmberDay)
+                        | LiturgicalDayId::Feast(Feast::RogationDay)
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(ember_or_rogation, Vec::<&LectionaryIssue>::new());
+    }
+
+    #[test]
+    fn canticles_are_not_parsed_as_citations() {
+        assert!(is_parseable("canticle-9"));
+        assert!(is_parseable("Psalm 119:1-24"));
+        assert!(!is_parseable("Not a citation"));
+    }
+}
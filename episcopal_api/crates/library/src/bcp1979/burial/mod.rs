@@ -1,9 +1,13 @@
 pub mod parallels;
 
 use lazy_static::lazy_static;
+#[cfg(any(feature = "browser", feature = "server"))]
+use liturgy::parallel_table::{build_aligned_parallel_table, ParallelDocument};
 use liturgy::*;
 
 use self::parallels::*;
+#[cfg(any(feature = "browser", feature = "server"))]
+use crate::{bos, eow, rite1, rite2};
 
 lazy_static! {
     pub static ref AN_ORDER_FOR_BURIAL : Document = Document::new()
@@ -40,3 +44,19 @@ lazy_static! {
             Document::from(Rubric::from("The death of a member of the Church should be reported as soon as possible to, and arrangements for the funeral should be made in consultation with, the Minister of the Congregation.\n\nBaptized Christians are properly buried from the church. The service should be held at a time when the congregation has opportunity to be present.\n\nThe coffin is to be closed before the service, and it remains closed thereafter. It is appropriate that it be covered with a pall or other suitable covering. If necessary, or if desired, all or part of the service of Committal may be said in the church. If preferred, the Committal service may take place before the service in the church. It may also be used prior to cremation.\n\nA priest normally presides at the service. It is appropriate that the bishop, when present, preside at the Eucharist and pronounce the Commendation. \n\nIt is desirable that the Lesson from the Old Testament, and the Epistle, be read by lay persons.\n\nWhen the services of a priest cannot be obtained, a deacon or lay reader may preside at the service.\n\nAt the burial of a child, the passages from Lamentations, 1 John, and John 6, together with Psalm 23, are recommended.\n\nIt is customary that the celebrant meet the body and go before it into the church or towards the grave.\n\nThe anthems at the beginning of the service are sung or said as the body is borne into the church, or during the entrance of the ministers, or by the celebrant standing in the accustomed place.").long())
         ]))));
 }
+
+#[cfg(any(feature = "browser", feature = "server"))]
+lazy_static! {
+    /// The burial rites of Rite I, Rite II, Enriching Our Worship, and the Book of Occasional Services, with An Order for Burial,
+    /// aligned automatically within each of the [BURIAL_PARALLEL_TAGS]. Cached here because the table of contents is rebuilt whenever it is used.
+    pub static ref BURIAL_PARALLELS: Vec<Vec<(ParallelDocument, usize)>> = build_aligned_parallel_table(
+        BURIAL_PARALLEL_TAGS,
+        &[
+            (&SlugPath::from([Slug::Burial, Slug::Burial, Slug::Version(Version::RiteI)]), &*rite1::burial::BURIAL_RITE_I),
+            (&SlugPath::from([Slug::Burial, Slug::Burial, Slug::Version(Version::RiteII)]), &*rite2::burial::BURIAL_RITE_II),
+            (&SlugPath::from([Slug::Burial, Slug::BurialOfAChild]), &*eow::volume_2::burial_of_a_child::BURIAL_OF_A_CHILD),
+            (&SlugPath::from([Slug::Burial, Slug::BurialOfANonChristian]), &*bos::BURIAL_OF_A_NON_CHRISTIAN),
+            (&SlugPath::from([Slug::Burial, Slug::Order]), &*AN_ORDER_FOR_BURIAL)
+        ]
+    );
+}
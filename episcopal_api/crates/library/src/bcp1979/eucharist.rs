@@ -1,6 +1,10 @@
 use lazy_static::lazy_static;
+#[cfg(any(feature = "browser", feature = "server"))]
+use liturgy::parallel_table::{build_aligned_parallel_table, ParallelDocument};
 use liturgy::*;
 
+use crate::{eow, rite1, rite2};
+
 lazy_static! {
     pub static ref PRAYERS_OF_THE_PEOPLE: Vec<Document> = vec![
         pop::FORM_I.clone(),
@@ -19,6 +23,25 @@ lazy_static! {
     ])).label("Form for Consecrating Additional Bread and Wine");
 }
 
+#[cfg(any(feature = "browser", feature = "server"))]
+lazy_static! {
+    /// The Eucharistic Prayers of Rite I, Rite II, and Enriching Our Worship, aligned automatically
+    /// by their structure and wording. Cached here because the table of contents is rebuilt whenever it is used.
+    pub static ref EUCHARISTIC_PRAYER_PARALLELS: Vec<Vec<(ParallelDocument, usize)>> = build_aligned_parallel_table::<[&str; 0], &str>(
+        [],
+        &[
+            (&SlugPath::from([Slug::Eucharist, Slug::GreatThanksgiving, Slug::Version(Version::RiteI), Slug::PrayerI]), &*rite1::eucharist::PRAYER_I),
+            (&SlugPath::from([Slug::Eucharist, Slug::GreatThanksgiving, Slug::Version(Version::RiteI), Slug::PrayerII]), &*rite1::eucharist::PRAYER_II),
+            (&SlugPath::from([Slug::Eucharist, Slug::GreatThanksgiving, Slug::Version(Version::RiteII), Slug::PrayerA]), &*rite2::eucharist::PRAYER_A),
+            (&SlugPath::from([Slug::Eucharist, Slug::GreatThanksgiving, Slug::Version(Version::RiteII), Slug::PrayerB]), &*rite2::eucharist::PRAYER_B),
+            (&SlugPath::from([Slug::Eucharist, Slug::GreatThanksgiving, Slug::Version(Version::RiteII), Slug::PrayerC]), &*rite2::eucharist::PRAYER_C),
+            (&SlugPath::from([Slug::Eucharist, Slug::GreatThanksgiving, Slug::Version(Version::RiteII), Slug::PrayerD]), &*rite2::eucharist::PRAYER_D),
+            (&SlugPath::from([Slug::Eucharist, Slug::GreatThanksgiving, Slug::Version(Version::EOW), Slug::Prayer1]), &*eow::eucharist::PRAYER_1),
+            (&SlugPath::from([Slug::Eucharist, Slug::GreatThanksgiving, Slug::Version(Version::EOW), Slug::Prayer2]), &*eow::eucharist::PRAYER_2),
+        ]
+    );
+}
+
 pub mod pop {
     use liturgy::*;
 
@@ -25,6 +25,9 @@ impl Library for CommonPrayer {
             Lectionaries::BCP1979ThirtyDayPsalms => &lectionary::BCP1979_30_DAY_PSALTER,
             Lectionaries::RCLTrack1 => &lectionary::RCL_TRACK_1,
             Lectionaries::RCLTrack2 => &lectionary::RCL_TRACK_2,
+            Lectionaries::CommonWorshipTrack1 | Lectionaries::CommonWorshipTrack2 => {
+                &lectionary::COMMON_WORSHIP_LECTIONARY
+            }
         }
     }
 
@@ -541,8 +544,10 @@ impl Library for CommonPrayer {
                                     Slug::ProperPrefaces,
                                     Contents::MultiDocument { 
                                         label: "Proper Prefaces".into(),
-                                        documents: vec![
-                                        ],
+                                        documents: rite1::eucharist::prefaces::PROPER_PREFACES_TRADITIONAL.iter()
+                                            .chain(rite2::eucharist::prefaces::PROPER_PREFACES_CONTEMPORARY.iter())
+                                            .map(|(_, document)| document.clone())
+                                            .collect(),
                                         hidden_in_toc: false
                                     }
                                 )
@@ -612,6 +617,15 @@ impl Library for CommonPrayer {
                                         (
                                             Slug::Version(Version::LibroDeOracionComun),
                                             Contents::Document(&*loc::eucharist::RITE_II_LOC_EUCHARIST_PARALLEL)
+                                        ),
+                                        #[cfg(any(feature = "browser", feature = "server"))]
+                                        (
+                                            Slug::GreatThanksgiving,
+                                            Contents::Parallels {
+                                                label: "Eucharistic Prayer Parallels".into(),
+                                                intro: "This page aligns the Eucharistic Prayers of Rite I, Rite II, and Enriching Our Worship 1 by matching similar passages across the prayers, so that the shared structure of the Great Thanksgiving (Sanctus, institution narrative, memorial acclamation, epiclesis, and doxology) can be read side by side.".into(),
+                                                parallels: bcp1979::eucharist::EUCHARISTIC_PRAYER_PARALLELS.clone()
+                                            }
                                         )
                                     ]
                                 }
@@ -730,6 +744,16 @@ impl Library for CommonPrayer {
                                         label: Some("Parallels".into()),
                                         contents: vec![
                                             (Slug::Readings, Contents::Document(&*bcp1979::burial::parallels::PARALLEL_READINGS)),
+                                            #[cfg(any(feature = "browser", feature = "server"))]
+                                            (
+                                                Slug::Parallels,
+                                                Contents::Parallels {
+                                                    label: "Burial Service Parallels".into(),
+                                                    intro: "This page aligns the burial rites of Rite I, Rite II, Enriching Our Worship 2, and the Book of Occasional Services, with An Order for Burial, by matching similar passages within each part of the service.".into(),
+                                                    parallels: bcp1979::burial::BURIAL_PARALLELS.clone()
+                                                }
+                                            ),
+                                            #[cfg(not(any(feature = "browser", feature = "server")))]
                                             (
                                                 Slug::Parallels,
                                                 Contents::Parallels {
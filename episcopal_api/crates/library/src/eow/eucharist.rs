@@ -24,12 +24,7 @@ lazy_static! {
             Document::from(Rubric::from("The following Preface may be used at any time.")),
             Document::from(Text::from("You have filled us and all creation with your blessing\nand fed us with your constant love;\nyou have redeemed us in Jesus Christ\nand knit us into one body.\nThrough your Spirit you replenish us\nand call us to fullness of life.")),
             Document::from(Rubric::from("In place of the preceding, a Proper Preface from the Book of Common Prayer may be used.")),
-            Document::from(Content::DocumentLink {
-                label: "Proper Prefaces".into(),
-                path: SlugPath::from([Slug::Eucharist, Slug::ProperPrefaces, Slug::Version(Version::RiteII)]),
-                rotate: true,
-                link_only: false
-            }),
+            Document::new().version(Version::RiteII).content(Content::PrefaceOfTheDay),
             Document::from(Text::from("Therefore, joining with Angels and Archangels\nand with the faithful of every generation,\nwe lift our voices with all creation as we sing (say):")),
             Document::from(Rubric::from("Celebrant and People")),
             Document::from(Text::from("Holy, holy, holy Lord, God of power and might,\nheaven and earth are full of your glory.\n\tHosanna in the highest.\nBlessed is the one who comes in the name of the Lord.\n\tHosanna in the highest.").display_format(DisplayFormat::Unison)),
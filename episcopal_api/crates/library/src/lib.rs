@@ -1,11 +1,14 @@
 use std::convert::TryFrom;
 
-use bible::OfflineBible;
+use bible::BibleProvider;
 use calendar::{Calendar, LiturgicalDay, LiturgicalDayId, Rank, Weekday};
 use canticle_table::{CanticleId, CanticleNumber, CanticleTable};
 use itertools::Itertools;
 use language::Language;
-use lectionary::{rcl_readings, Lectionary, RCLTrack, Reading, ReadingType};
+use lectionary::{
+    common_worship_readings, rcl_readings, Lectionary, LectionaryRef, RCLTrack, Reading,
+    ReadingType,
+};
 use liturgy::*;
 use loc::collects::COLECTAS;
 use psalter::Psalter;
@@ -29,10 +32,20 @@ pub use common_prayer::*;
 pub mod conditions;
 pub mod eow;
 pub mod lff2018;
+mod lint;
+pub use lint::*;
 pub mod loc;
+mod local_calendar;
+pub use local_calendar::*;
 pub mod marriage_alternatives;
 pub mod rite1;
 pub mod rite2;
+mod preface;
+pub use preface::*;
+mod service_plan;
+pub use service_plan::*;
+mod sources;
+pub use sources::*;
 pub mod summary;
 mod table_of_contents;
 pub use collect::*;
@@ -49,14 +62,81 @@ pub trait Library {
 
     fn contents<'a>() -> TableOfContents<'a>;
 
+    /// Compiles a document for a particular day, drawing only on the default [CompileSources]
+    /// (i.e., Biblical readings from the translations installed locally as USX files, no local
+    /// feasts, and only the built-in lectionaries).
     #[cfg(any(feature = "browser", feature = "server"))]
     fn compile(
+        document: Document,
+        calendar: &Calendar,
+        day: &LiturgicalDay,
+        observed: &LiturgicalDayId,
+        prefs: &impl ClientPreferences,
+        liturgy_prefs: &LiturgyPreferences,
+    ) -> Option<Document> {
+        Self::compile_with_sources(
+            document,
+            calendar,
+            day,
+            observed,
+            prefs,
+            liturgy_prefs,
+            CompileSources::default(),
+        )
+    }
+
+    /// Compiles a document like [Library::compile], loading the text of Biblical readings from
+    /// the given [CompileSources], and using the collects and readings of its [LocalCalendar]
+    /// (if any) for local feasts.
+    /// ```
+    /// # use calendar::{Date, Feast, LiturgicalDayId};
+    /// # use library::{CommonPrayer, CompileSources, Library, LocalCalendar};
+    /// # use library::rite2::eucharist::HOLY_EUCHARIST_II;
+    /// # use liturgy::{Content, Document, LiturgyPreferences};
+    /// # use std::collections::HashMap;
+    /// let local = LocalCalendar::from_json(r#"{
+    ///     "feasts": [{
+    ///         "id": 1,
+    ///         "date": { "Date": [10, 13] },
+    ///         "rank": "HolyDay",
+    ///         "names": { "En": "Saint Edward the Confessor" },
+    ///         "collect": "O God, who gave to your servant Edward...",
+    ///         "readings": [["FirstReading", "Sirach 44:1-10"], ["Gospel", "Matthew 5:1-12"]]
+    ///     }]
+    /// }"#).unwrap();
+    /// let local_feasts = local.calendar();
+    /// let calendar = local_feasts.as_calendar();
+    /// // 10/13/2021 is a Wednesday
+    /// let day = calendar.liturgical_day(Date::from_ymd(2021, 10, 13), false);
+    /// assert_eq!(day.observed, LiturgicalDayId::Feast(Feast::Local(1)));
+    ///
+    /// let doc = CommonPrayer::compile_with_sources(
+    ///     HOLY_EUCHARIST_II.clone(),
+    ///     &calendar,
+    ///     &day,
+    ///     &day.observed,
+    ///     &HashMap::new(),
+    ///     &LiturgyPreferences::default(),
+    ///     CompileSources::default().with_local_calendar(&local),
+    /// )
+    /// .unwrap();
+    /// let contents = doc.flatten();
+    /// assert!(contents.iter().any(|doc| matches!(&doc.content,
+    ///     Content::Text(text) if text.text == "O God, who gave to your servant Edward..."
+    /// )));
+    /// assert!(contents.iter().any(|doc| matches!(&doc.content,
+    ///     Content::BiblicalCitation(citation) if citation.citation == "Matthew 5:1-12"
+    /// )));
+    /// ```
+    #[cfg(any(feature = "browser", feature = "server"))]
+    fn compile_with_sources(
         mut document: Document,
         calendar: &Calendar,
         day: &LiturgicalDay,
         observed: &LiturgicalDayId,
         prefs: &impl ClientPreferences,
         liturgy_prefs: &LiturgyPreferences,
+        sources: CompileSources,
     ) -> Option<Document> {
         document.is_compiled = true;
 
@@ -66,6 +146,22 @@ pub trait Library {
                 .or_else(|| liturgy_prefs.default_value_for_key(key))
         };
 
+        // Use the document's translation into the preferred language, if any; content that is generated
+        // while compiling (dates, day names, collects) is generated in the preferred language, except
+        // for the preface, which is part of the text of the Eucharistic Prayer around it
+        if let Some(PreferenceValue::Language(language)) =
+            preference_value_for_key(&PreferenceKey::from(GlobalPref::Language))
+        {
+            document = document.translated(*language);
+            if matches!(
+                document.content,
+                Content::Heading(Heading::InsertDate | Heading::InsertDay)
+                    | Content::CollectOfTheDay { .. }
+            ) {
+                document.language = *language;
+            }
+        }
+
         let include = document.include(calendar, day, prefs, liturgy_prefs)
             && document.display != Show::TemplateOnly
             && document.display != Show::Hidden;
@@ -89,7 +185,15 @@ pub trait Library {
 
                         Document::choice_or_document(&mut docs)
                             .and_then(|docs| {
-                                Self::compile(docs, calendar, day, observed, prefs, liturgy_prefs)
+                                Self::compile_with_sources(
+                                    docs,
+                                    calendar,
+                                    day,
+                                    observed,
+                                    prefs,
+                                    liturgy_prefs,
+                                    sources,
+                                )
                             })
                             .map(|mut doc| {
                                 if let Content::Choice(ref mut choice) = doc.content {
@@ -122,11 +226,28 @@ pub trait Library {
                         ReadingTypeTable::Selected(reading_type) => Some(*reading_type),
                     };
 
-                    let lectionary = Self::lectionary(chosen_lectionary);
+                    // a lectionary loaded at runtime takes the place of the built-in table
+                    let imported_lectionary = sources.lectionary(chosen_lectionary);
+                    let lectionary = match imported_lectionary {
+                        Some(lectionary) => LectionaryRef::from(lectionary),
+                        None => LectionaryRef::from(Self::lectionary(chosen_lectionary)),
+                    };
 
                     if let Some(reading_type) = reading_type {
-                        let readings = if chosen_lectionary == Lectionaries::RCLTrack1
-                            || chosen_lectionary == Lectionaries::RCLTrack2
+                        // a local feast's own readings are used in place of the lectionary's
+                        let local_readings = sources
+                            .local_calendar
+                            .map(|local_calendar| local_calendar.readings(observed))
+                            .unwrap_or_default()
+                            .into_iter()
+                            .filter(|reading| reading.reading_type == reading_type)
+                            .collect::<Vec<_>>();
+
+                        let readings = if !local_readings.is_empty() {
+                            Box::new(local_readings.into_iter()) as Box<dyn Iterator<Item = Reading>>
+                        } else if imported_lectionary.is_none()
+                            && (chosen_lectionary == Lectionaries::RCLTrack1
+                                || chosen_lectionary == Lectionaries::RCLTrack2)
                         {
                             let track = if chosen_lectionary == Lectionaries::RCLTrack1 {
                                 RCLTrack::One
@@ -137,13 +258,26 @@ pub trait Library {
                                 rcl_readings(observed, day, track)
                                     .filter(|reading| reading.reading_type == reading_type),
                             ) as Box<dyn Iterator<Item = Reading>>
+                        } else if imported_lectionary.is_none()
+                            && (chosen_lectionary == Lectionaries::CommonWorshipTrack1
+                                || chosen_lectionary == Lectionaries::CommonWorshipTrack2)
+                        {
+                            let track = if chosen_lectionary == Lectionaries::CommonWorshipTrack1 {
+                                RCLTrack::One
+                            } else {
+                                RCLTrack::Two
+                            };
+                            Box::new(
+                                common_worship_readings(observed, day, track)
+                                    .filter(|reading| reading.reading_type == reading_type),
+                            ) as Box<dyn Iterator<Item = Reading>>
                         } else {
-                            Box::new(lectionary.reading_by_type_with_override(
+                            lectionary.reading_by_type_with_override(
                                 observed,
                                 day,
                                 reading_type,
                                 lectionary_reading.reading_type_overridden_by,
-                            )) as Box<dyn Iterator<Item = Reading>>
+                            )
                         };
 
                         let mut docs = readings.map(|reading| {
@@ -157,13 +291,14 @@ pub trait Library {
                                         Document::from(DocumentError::from(reading.citation))
                                     })
                             } else if reading_type.is_psalm() {
-                                Self::compile(
+                                Self::compile_with_sources(
                                     Document::from(PsalmCitation::from(reading.citation)),
                                     calendar,
                                     day,
                                     observed,
                                     prefs,
                                     liturgy_prefs,
+                                    sources,
                                 )
                                 .unwrap()
                             } else {
@@ -177,7 +312,13 @@ pub trait Library {
                                         _ => None,
                                     })
                                     .unwrap_or(Version::NRSV);
-                                biblical_reading(&document, &reading.citation, intro, version)
+                                biblical_reading(
+                                    &document,
+                                    &reading.citation,
+                                    intro,
+                                    version,
+                                    sources.bible_provider,
+                                )
                             }
                         });
 
@@ -324,13 +465,9 @@ pub trait Library {
                                 if day.weekday == Weekday::Sun {
                                     name.to_string()
                                 } else {
-                                    let weekday_name = day.weekday.to_string();
-                                    format!(
-                                        "{} {} {}",
-                                        document.language.i18n(&weekday_name),
-                                        document.language.i18n("after"),
-                                        name.replace("The", "the")
-                                    )
+                                    document
+                                        .language
+                                        .weekday_after(day.weekday.as_ref(), name)
                                 }
                             }),
                         }
@@ -377,7 +514,7 @@ pub trait Library {
                 // Insert seasonal antiphon for invitatories
                 Content::Invitatory(invitatory) => match invitatory.antiphon {
                     SeasonalAntiphon::Insert => {
-                        if let Some(antiphon) = Self::compile(
+                        if let Some(antiphon) = Self::compile_with_sources(
                             Document::from(Content::DocumentLink {
                                 label: String::new(),
                                 path: SlugPath::from([Slug::Office, Slug::InvitatoryAntiphons]),
@@ -389,6 +526,7 @@ pub trait Library {
                             observed,
                             prefs,
                             liturgy_prefs,
+                            sources,
                         ) {
                             match (&antiphon.content, &mut document.content) {
                                 (
@@ -465,6 +603,14 @@ pub trait Library {
                             (_, false, false) => Box::new(COLLECTS_CONTEMPORARY.iter()),
                         };
                     let collects = collects.collect::<Vec<_>>();
+                    let local_collects = sources
+                        .local_calendar
+                        .map(|local_calendar| local_calendar.collects(&collects))
+                        .unwrap_or_default();
+                    let collects = local_collects
+                        .iter()
+                        .chain(collects)
+                        .collect::<Vec<_>>();
 
                     let day_rank = calendar.rank(day);
                     let holy_day_collect = match observed {
@@ -474,7 +620,11 @@ pub trait Library {
 
                             let id = COLLECT_LINKS.linked_id(&CollectId::Feast(feast));
 
-                            if day_rank >= Rank::PrecedenceOverWeekday {
+                            // a lesser observance (like an Ember Day) that has been chosen in place of the
+                            // day's own observance still takes its own collect
+                            let is_chosen_observance = *observed != day.observed;
+
+                            if day_rank >= Rank::PrecedenceOverWeekday || is_chosen_observance {
                                 Some(Document::choice_or_document(
                                     &mut collects
                                         .iter()
@@ -590,6 +740,26 @@ pub trait Library {
                         collects.get(0).cloned()
                     }
                 }
+                Content::PrefaceOfTheDay => {
+                    let prefaces = ProperPreface::for_day(calendar, day, observed);
+                    match document.language {
+                        // the texts of the prefaces of the Libro de Oración Común are not part of
+                        // this library, so a Spanish liturgy only names the preface appointed
+                        Language::Es => {
+                            Document::choice_or_document(&mut prefaces.iter().map(|preface| {
+                                Document::from(Rubric::from(preface.name(Language::Es)))
+                                    .language(Language::Es)
+                                    .version(document.version)
+                            }))
+                        }
+                        _ => Document::choice_or_document(
+                            &mut prefaces
+                                .iter()
+                                .flat_map(|preface| preface.documents(document.version))
+                                .cloned(),
+                        ),
+                    }
+                }
                 // Collection types
                 Content::Liturgy(liturgy) => Some(Document {
                     content: Content::Liturgy(Liturgy {
@@ -598,13 +768,14 @@ pub trait Library {
                                 .body
                                 .iter()
                                 .filter_map(|doc| {
-                                    Self::compile(
+                                    Self::compile_with_sources(
                                         doc.clone(),
                                         calendar,
                                         day,
                                         observed,
                                         prefs,
                                         liturgy_prefs,
+                                        sources,
                                     )
                                 })
                                 .collect::<Vec<_>>(),
@@ -618,13 +789,14 @@ pub trait Library {
                     content: Content::Series(Series::from(
                         sub.iter()
                             .filter_map(|doc| {
-                                Self::compile(
+                                Self::compile_with_sources(
                                     doc.clone(),
                                     calendar,
                                     day,
                                     observed,
                                     prefs,
                                     liturgy_prefs,
+                                    sources,
                                 )
                             })
                             .collect::<Vec<_>>(),
@@ -635,13 +807,14 @@ pub trait Library {
                     content: Content::Parallel(Parallel::from(
                         sub.iter()
                             .filter_map(|doc| {
-                                Self::compile(
+                                Self::compile_with_sources(
                                     doc.clone(),
                                     calendar,
                                     day,
                                     observed,
                                     prefs,
                                     liturgy_prefs,
+                                    sources,
                                 )
                             })
                             .collect::<Vec<_>>(),
@@ -659,13 +832,14 @@ pub trait Library {
                             .options
                             .iter()
                             .filter_map(|doc| {
-                                Self::compile(
+                                Self::compile_with_sources(
                                     doc.clone(),
                                     calendar,
                                     day,
                                     observed,
                                     prefs,
                                     liturgy_prefs,
+                                    sources,
                                 )
                             })
                             .collect(),
@@ -695,12 +869,20 @@ fn biblical_reading(
     citation: &str,
     intro: Option<BiblicalReadingIntro>,
     version: Version,
+    provider: &dyn BibleProvider,
 ) -> Document {
     let language = Language::from(version);
-    // Versions that are available offline => BiblicalReading with content loaded synchronously
-    if version == Version::RV09 {
-        bible::ReinaValera::get_citation(citation)
-            .unwrap_or_else(|e| Document::from(DocumentError::from(e.to_string())))
+    // Versions that are available from the provider => BiblicalReading with content loaded synchronously
+    if provider.has_version(version) {
+        match provider.get_reading(version, citation) {
+            Ok(reading) => Document {
+                content: Content::BiblicalReading(BiblicalReading { intro, ..reading }),
+                version,
+                language,
+                ..document.clone()
+            },
+            Err(e) => Document::from(DocumentError::from(e.to_string())),
+        }
     }
     // Other versions get a BiblicalCitation and are loaded asynchronously
     else {
@@ -0,0 +1,571 @@
+use std::{
+    collections::{HashMap, HashSet},
+    ops::RangeInclusive,
+};
+
+use calendar::{Calendar, Date, LiturgicalDay};
+use itertools::Itertools;
+use liturgy::{
+    Condition, Content, Document, LiturgyPreferences, PreferenceKey, PreferenceValue, SlugPath,
+};
+use thiserror::Error;
+
+use crate::{Contents, Library, TableOfContents};
+
+/// A problem found in a [Document] by a [Linter]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LintWarning {
+    /// Path to the document in the library’s [TableOfContents](crate::TableOfContents)
+    pub path: SlugPath,
+    /// Indices of the sub-document within that document, following its [Series](liturgy::Series),
+    /// [Choice](liturgy::Choice), [Parallel](liturgy::Parallel), and [Liturgy](liturgy::Liturgy) children
+    pub location: Vec<usize>,
+    pub error: LintError,
+}
+
+impl std::fmt::Display for LintWarning {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "/{} {:?}: {}", self.path, self.location, self.error)
+    }
+}
+
+#[derive(Error, Clone, Debug, PartialEq, Eq)]
+pub enum LintError {
+    #[error("link to {0} does not lead to any document")]
+    BrokenLink(SlugPath),
+    #[error("condition uses preference {0:?}, which is not defined for this liturgy")]
+    UndefinedPreference(PreferenceKey),
+    #[error("condition expects {1:?} for preference {0:?}, which is not one of its options")]
+    UnknownPreferenceValue(PreferenceKey, PreferenceValue),
+    #[error("choice has no options")]
+    EmptyChoice,
+    #[error("condition is never met, so this is never included: {0:?}")]
+    Unreachable(Condition),
+    #[error("error while compiling for {0}: {1}")]
+    Compile(Date, String),
+}
+
+/// Checks every [Document] in a [Library] for mistakes that would otherwise only be found
+/// by visiting the page on which they appear: links that lead nowhere, conditions that refer to
+/// preferences the liturgy does not have or that can never be met, and empty choices.
+///
+/// Conditions are tested against every day (morning and evening) of the given years,
+/// and every combination of the preferences they refer to.
+/// ```
+/// # use calendar::BCP1979_CALENDAR;
+/// # use library::{CommonPrayer, Linter};
+/// # use liturgy::Slug;
+/// let linter = Linter::new(&BCP1979_CALENDAR, [2022]);
+/// let warnings = linter.lint::<CommonPrayer>();
+/// assert!(warnings
+///     .iter()
+///     .all(|warning| warning.path.as_slice().first() != Some(&Slug::Eucharist)));
+/// ```
+pub struct Linter<'a> {
+    calendar: &'a Calendar<'a>,
+    years: Vec<u16>,
+    days: Vec<LiturgicalDay>,
+}
+
+impl<'a> Linter<'a> {
+    pub fn new(calendar: &'a Calendar<'a>, years: impl IntoIterator<Item = u16>) -> Self {
+        let years = years.into_iter().collect::<Vec<_>>();
+        let days = years
+            .iter()
+            .flat_map(|year| {
+                [false, true].map(|evening| calendar.liturgical_days(whole_year(*year), evening))
+            })
+            .flatten()
+            .collect();
+        Self {
+            calendar,
+            years,
+            days,
+        }
+    }
+
+    /// Statically checks every document in the library’s table of contents
+    pub fn lint<L: Library>(&self) -> Vec<LintWarning> {
+        self.lint_contents(&L::contents())
+    }
+
+    fn lint_contents(&self, contents: &TableOfContents) -> Vec<LintWarning> {
+        toc_documents(contents)
+            .into_iter()
+            .flat_map(|(path, documents)| {
+                documents
+                    .into_iter()
+                    .flat_map(|(location, document)| {
+                        self.lint_document(
+                            contents,
+                            &path,
+                            location,
+                            &document,
+                            None,
+                            &mut Vec::new(),
+                        )
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect()
+    }
+
+    /// Compiles every [Liturgy](liturgy::Liturgy) in the library for every day of the given years,
+    /// using its default preferences, and reports any [Content::Error] in the result. An error that
+    /// recurs at the same place is only reported for the first date on which it occurs.
+    #[cfg(any(feature = "browser", feature = "server"))]
+    pub fn lint_compiled<L: Library>(&self) -> Vec<LintWarning> {
+        let mut warnings = Vec::new();
+        let mut seen = HashSet::new();
+        let contents = L::contents();
+        for (path, documents) in toc_documents(&contents) {
+            for (location, document) in documents {
+                let (evening, liturgy_prefs) = match &document.content {
+                    Content::Liturgy(liturgy) => (liturgy.evening, &liturgy.preferences),
+                    _ => continue,
+                };
+                let days = self
+                    .years
+                    .iter()
+                    .flat_map(|year| self.calendar.liturgical_days(whole_year(*year), evening));
+                for day in days {
+                    let compiled = L::compile(
+                        document.clone(),
+                        self.calendar,
+                        &day,
+                        &day.observed,
+                        &HashMap::new(),
+                        liturgy_prefs,
+                    );
+                    if let Some(compiled) = compiled {
+                        for (error_location, error) in errors(&compiled, Vec::new()) {
+                            let location = location
+                                .iter()
+                                .chain(error_location.iter())
+                                .copied()
+                                .collect::<Vec<_>>();
+                            if seen.insert((path.clone(), location.clone(), error.clone())) {
+                                warnings.push(LintWarning {
+                                    path: path.clone(),
+                                    location,
+                                    error: LintError::Compile(day.date, error),
+                                });
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        warnings
+    }
+
+    /// Checks a document and its children, given the conditions under which it is included
+    fn lint_document<'d>(
+        &self,
+        contents: &TableOfContents,
+        path: &SlugPath,
+        location: Vec<usize>,
+        document: &'d Document,
+        liturgy_prefs: Option<&'d LiturgyPreferences>,
+        conditions: &mut Vec<&'d Condition>,
+    ) -> Vec<LintWarning> {
+        let mut warnings = Vec::new();
+        let mut warn = |error| {
+            warnings.push(LintWarning {
+                path: path.clone(),
+                location: location.clone(),
+                error,
+            })
+        };
+
+        if let Some(condition) = &document.condition {
+            if let Some(liturgy_prefs) = liturgy_prefs {
+                for (key, value) in preferences_in_condition(condition) {
+                    if let PreferenceKey::Local(_) = key {
+                        match liturgy_prefs.iter().find(|pref| pref.key == *key) {
+                            None => warn(LintError::UndefinedPreference(key.clone())),
+                            Some(pref) => {
+                                let known = pref.default_value.as_ref() == Some(value)
+                                    || pref.choices().any(|choice| choice.value == *value);
+                                if !known {
+                                    warn(LintError::UnknownPreferenceValue(
+                                        key.clone(),
+                                        value.clone(),
+                                    ));
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+
+            conditions.push(condition);
+            let reachable =
+                self.satisfiable(conditions, liturgy_prefs.cloned().unwrap_or_default());
+            if !reachable {
+                warn(LintError::Unreachable(condition.clone()));
+                // everything inside is unreachable as well
+                conditions.pop();
+                return warnings;
+            }
+        }
+
+        match &document.content {
+            Content::DocumentLink {
+                path: link,
+                link_only,
+                ..
+            } => {
+                let resolves = match contents.contents_at_path(link) {
+                    Some(Contents::Page { .. }) => *link_only,
+                    Some(contents) => *link_only || contents.as_documents().next().is_some(),
+                    None => false,
+                };
+                if !resolves {
+                    warn(LintError::BrokenLink(link.clone()));
+                }
+            }
+            Content::Choice(choice) if choice.options.is_empty() => warn(LintError::EmptyChoice),
+            _ => {}
+        }
+
+        // as in compiling, a liturgy nested in another uses the preferences of the outer liturgy
+        let liturgy_prefs = match &document.content {
+            Content::Liturgy(liturgy) => liturgy_prefs.or(Some(&liturgy.preferences)),
+            _ => liturgy_prefs,
+        };
+        let children: Box<dyn Iterator<Item = &Document>> = match &document.content {
+            Content::Liturgy(liturgy) => Box::new(liturgy.body.iter()),
+            Content::Series(series) => Box::new(series.iter()),
+            Content::Parallel(parallel) => Box::new(parallel.iter()),
+            Content::Choice(choice) => Box::new(choice.options.iter()),
+            _ => Box::new(std::iter::empty()),
+        };
+        for (idx, child) in children.enumerate() {
+            let mut child_location = location.clone();
+            child_location.push(idx);
+            warnings.extend(self.lint_document(
+                contents,
+                path,
+                child_location,
+                child,
+                liturgy_prefs,
+                conditions,
+            ));
+        }
+
+        if document.condition.is_some() {
+            conditions.pop();
+        }
+        warnings
+    }
+
+    /// Whether all the conditions are met together on any day, for any combination of the preferences they use
+    fn satisfiable(&self, conditions: &[&Condition], liturgy_prefs: LiturgyPreferences) -> bool {
+        // each preference may be unset (using the liturgy’s default), or set to any of its options
+        // or any value the conditions test for
+        let mut values: HashMap<PreferenceKey, Vec<Option<PreferenceValue>>> = HashMap::new();
+        for (key, value) in conditions
+            .iter()
+            .flat_map(|condition| preferences_in_condition(condition))
+        {
+            let options = values.entry(key.clone()).or_insert_with(|| {
+                std::iter::once(None)
+                    .chain(
+                        liturgy_prefs
+                            .iter()
+                            .filter(|pref| pref.key == *key)
+                            .flat_map(|pref| pref.choices())
+                            .map(|choice| Some(choice.value.clone())),
+                    )
+                    .collect()
+            });
+            if !options.contains(&Some(value.clone())) {
+                options.push(Some(value.clone()));
+            }
+        }
+
+        let combinations: Vec<HashMap<PreferenceKey, PreferenceValue>> = if values.is_empty() {
+            vec![HashMap::new()]
+        } else {
+            let keys = values.keys().cloned().collect::<Vec<_>>();
+            keys.iter()
+                .map(|key| values[key].clone())
+                .multi_cartesian_product()
+                .map(|combination| {
+                    keys.iter()
+                        .cloned()
+                        .zip(combination)
+                        .filter_map(|(key, value)| value.map(|value| (key, value)))
+                        .collect()
+                })
+                .collect()
+        };
+
+        combinations.iter().any(|prefs| {
+            self.days.iter().any(|day| {
+                conditions
+                    .iter()
+                    .all(|condition| condition.include(self.calendar, day, prefs, &liturgy_prefs))
+            })
+        })
+    }
+}
+
+/// A document, with its location within a page
+type LocatedDocument = (Vec<usize>, Document);
+
+/// Every document in the library’s table of contents, with its path and, for pages with several
+/// documents, its index on that page
+fn toc_documents(contents: &TableOfContents) -> Vec<(SlugPath, Vec<LocatedDocument>)> {
+    let mut seen = HashSet::new();
+    contents
+        .flatten()
+        // each node is listed both by its parent and by itself
+        .filter(|(path, _)| seen.insert(path.clone()))
+        .filter_map(|(path, contents)| match contents {
+            Contents::Document(document) => Some((path, vec![(Vec::new(), document.clone())])),
+            Contents::MultiDocument { documents, .. } => Some((
+                path,
+                documents
+                    .into_iter()
+                    .enumerate()
+                    .map(|(idx, document)| (vec![idx], document))
+                    .collect(),
+            )),
+            _ => None,
+        })
+        .collect()
+}
+
+fn whole_year(year: u16) -> RangeInclusive<Date> {
+    Date::from_ymd(year, 1, 1)..=Date::from_ymd(year, 12, 31)
+}
+
+/// All the [PreferenceKey]s tested in a condition, with the values they are tested against
+fn preferences_in_condition(condition: &Condition) -> Vec<(&PreferenceKey, &PreferenceValue)> {
+    match condition {
+        Condition::Preference(key, value) => vec![(key, value)],
+        Condition::Not(condition) => preferences_in_condition(condition),
+        Condition::And(a, b) | Condition::Or(a, b) => preferences_in_condition(a)
+            .into_iter()
+            .chain(preferences_in_condition(b))
+            .collect(),
+        Condition::Any(conditions) | Condition::All(conditions) | Condition::None(conditions) => {
+            conditions
+                .iter()
+                .flat_map(preferences_in_condition)
+                .collect()
+        }
+        _ => Vec::new(),
+    }
+}
+
+/// Every [Content::Error] in a compiled document, with its location
+#[cfg(any(feature = "browser", feature = "server"))]
+fn errors(document: &Document, location: Vec<usize>) -> Vec<(Vec<usize>, String)> {
+    match &document.content {
+        Content::Error(error) => vec![(location, error.to_string())],
+        Content::Liturgy(liturgy) => children_errors(liturgy.body.iter(), location),
+        Content::Series(series) => children_errors(series.iter(), location),
+        Content::Parallel(parallel) => children_errors(parallel.iter(), location),
+        Content::Choice(choice) => children_errors(choice.options.iter(), location),
+        _ => Vec::new(),
+    }
+}
+
+#[cfg(any(feature = "browser", feature = "server"))]
+fn children_errors<'a>(
+    children: impl Iterator<Item = &'a Document>,
+    location: Vec<usize>,
+) -> Vec<(Vec<usize>, String)> {
+    children
+        .enumerate()
+        .flat_map(|(idx, child)| {
+            let mut child_location = location.clone();
+            child_location.push(idx);
+            errors(child, child_location)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use calendar::{Season, BCP1979_CALENDAR};
+    use liturgy::{Choice, Liturgy, LiturgyPreference, LiturgyPreferenceOption, Series, Slug};
+
+    use super::*;
+
+    /// Lints a single document, found in the table of contents at `/office/noonday-prayer`
+    fn lint(document: Document) -> Vec<LintError> {
+        let contents = TableOfContents::from(vec![(
+            Slug::Office,
+            Contents::Category {
+                label: "Daily Office".into(),
+                contents: vec![(Slug::NoondayPrayer, Contents::Document(&document))],
+            },
+        )]);
+        Linter::new(&BCP1979_CALENDAR, [2022])
+            .lint_contents(&contents)
+            .into_iter()
+            .map(|warning| warning.error)
+            .collect()
+    }
+
+    fn link(path: impl IntoIterator<Item = Slug>) -> Document {
+        Document::from(Content::DocumentLink {
+            label: "Link".into(),
+            path: SlugPath::from(path),
+            rotate: false,
+            link_only: false,
+        })
+    }
+
+    /// A liturgy with a local preference `ending`, which may be `short` or `long`
+    fn liturgy(body: Document) -> Document {
+        Document::from(Liturgy::from(Series::from(vec![body])).preferences([
+            LiturgyPreference::from((
+                PreferenceKey::from("ending"),
+                "Ending",
+                [
+                    LiturgyPreferenceOption::from((
+                        "Short",
+                        PreferenceValue::Local("short".into()),
+                    )),
+                    LiturgyPreferenceOption::from(("Long", PreferenceValue::Local("long".into()))),
+                ],
+            )),
+        ]))
+    }
+
+    fn text_if(key: &str, value: &str) -> Document {
+        Document::from("Amen.").condition(Condition::Preference(
+            PreferenceKey::from(key),
+            PreferenceValue::Local(value.into()),
+        ))
+    }
+
+    #[test]
+    fn broken_link() {
+        assert_eq!(
+            lint(link([Slug::Eucharist])),
+            vec![LintError::BrokenLink(SlugPath::from([Slug::Eucharist]))]
+        );
+        assert_eq!(lint(link([Slug::Office, Slug::NoondayPrayer])), vec![]);
+    }
+
+    #[test]
+    fn undefined_preference() {
+        assert_eq!(
+            lint(liturgy(text_if("closing", "short"))),
+            vec![LintError::UndefinedPreference(PreferenceKey::from(
+                "closing"
+            ))]
+        );
+        assert_eq!(lint(liturgy(text_if("ending", "short"))), vec![]);
+    }
+
+    #[test]
+    fn unknown_preference_value() {
+        assert_eq!(
+            lint(liturgy(text_if("ending", "medium"))),
+            vec![LintError::UnknownPreferenceValue(
+                PreferenceKey::from("ending"),
+                PreferenceValue::Local("medium".into())
+            )]
+        );
+        assert_eq!(lint(liturgy(text_if("ending", "long"))), vec![]);
+    }
+
+    #[test]
+    fn empty_choice() {
+        assert_eq!(
+            lint(Document::from(Choice::from(Vec::<Document>::new()))),
+            vec![LintError::EmptyChoice]
+        );
+        assert_eq!(
+            lint(Document::from(Choice::from(vec![Document::from("Amen.")]))),
+            vec![]
+        );
+    }
+
+    #[test]
+    fn unreachable() {
+        let never = Condition::And(
+            Box::new(Condition::Season(Season::Lent)),
+            Box::new(Condition::Season(Season::Easter)),
+        );
+        assert_eq!(
+            lint(Document::from("Alleluia.").condition(never.clone())),
+            vec![LintError::Unreachable(never)]
+        );
+        assert_eq!(
+            lint(Document::from("Alleluia.").condition(Condition::Season(Season::Easter))),
+            vec![]
+        );
+    }
+
+    #[cfg(any(feature = "browser", feature = "server"))]
+    mod compiled {
+        use calendar::Date;
+        use canticle_table::{CanticleId, CanticleTable};
+        use lectionary::Lectionary;
+        use liturgy::{CanticleTables, DocumentError, Lectionaries, Version};
+        use psalter::Psalter;
+
+        use super::*;
+        use crate::CommonPrayer;
+
+        /// A library whose only page holds two liturgies, the second of which always has an error
+        struct TestLibrary;
+
+        impl Library for TestLibrary {
+            fn psalter(psalter: Version) -> &'static Psalter<'static> {
+                CommonPrayer::psalter(psalter)
+            }
+
+            fn lectionary(lectionary: Lectionaries) -> &'static Lectionary {
+                CommonPrayer::lectionary(lectionary)
+            }
+
+            fn canticle_table(table: CanticleTables) -> &'static CanticleTable {
+                CommonPrayer::canticle_table(table)
+            }
+
+            fn canticle(canticle: CanticleId, version: Version) -> Option<Document> {
+                CommonPrayer::canticle(canticle, version)
+            }
+
+            fn contents<'a>() -> TableOfContents<'a> {
+                TableOfContents::from(vec![(
+                    Slug::NoondayPrayer,
+                    Contents::MultiDocument {
+                        label: "Noonday Prayer".into(),
+                        documents: vec![
+                            Document::from(Liturgy::from(Series::from(vec![Document::from(
+                                "Amen.",
+                            )]))),
+                            Document::from(Liturgy::from(Series::from(vec![Document::from(
+                                DocumentError::from("missing text"),
+                            )]))),
+                        ],
+                        hidden_in_toc: false,
+                    },
+                )])
+            }
+        }
+
+        #[test]
+        fn compile_errors() {
+            let warnings = Linter::new(&BCP1979_CALENDAR, [2022]).lint_compiled::<TestLibrary>();
+            // reported once, for the first day, and only for the second liturgy
+            assert_eq!(warnings.len(), 1);
+            assert_eq!(warnings[0].path, SlugPath::from([Slug::NoondayPrayer]));
+            assert_eq!(warnings[0].location[0], 1);
+            assert_eq!(
+                warnings[0].error,
+                LintError::Compile(Date::from_ymd(2022, 1, 1), "missing text".into())
+            );
+        }
+    }
+}
@@ -0,0 +1,183 @@
+use calendar::{
+    feasts::CommonOfSaints, CalendarWithLocalFeasts, Feast, LiturgicalDayId, LocalFeast, Sanctoral,
+    BCP1979_CALENDAR, COMMON_WORSHIP_CALENDAR, LFF2018_CALENDAR,
+};
+use lectionary::{Reading, ReadingType};
+use liturgy::{Document, Text};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::{rite2::collects::COLLECTS_CONTEMPORARY, CollectData, CollectId};
+
+/// A set of locally-observed feasts (like a parish’s patronal feast or a diocesan saint),
+/// along with their collects and propers, which can be loaded from JSON or TOML
+/// and layered onto one of the built-in calendars.
+/// ```
+/// # use calendar::{Date, Feast, LiturgicalDayId};
+/// # use language::Language;
+/// # use lectionary::ReadingType;
+/// # use library::LocalCalendar;
+/// let local = LocalCalendar::from_json(r#"{
+///     "base": "LFF2018",
+///     "feasts": [{
+///         "id": 1,
+///         "date": { "Date": [10, 13] },
+///         "rank": "HolyDay",
+///         "names": { "En": "Saint Edward the Confessor" },
+///         "collect": "O God, who gave to your servant Edward...",
+///         "readings": [["FirstReading", "Sirach 44:1-10"], ["Gospel", "Matthew 5:1-12"]]
+///     }]
+/// }"#).unwrap();
+/// let local_feasts = local.calendar();
+/// let calendar = local_feasts.as_calendar();
+/// // 10/13/2021 is a Wednesday
+/// let day = calendar.liturgical_day(Date::from_ymd(2021, 10, 13), false);
+/// assert_eq!(day.observed, LiturgicalDayId::Feast(Feast::Local(1)));
+/// assert_eq!(calendar.feast_name(Feast::Local(1), Language::En), Some("Saint Edward the Confessor".to_string()));
+/// assert!(local.collect(&Feast::Local(1)).is_some());
+/// assert_eq!(local.readings(&day.observed).len(), 2);
+/// assert_eq!(local.readings(&day.observed)[1].reading_type, ReadingType::Gospel);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LocalCalendar {
+    /// The calendar onto which the local feasts are layered (by default, the 1979 BCP)
+    #[serde(default)]
+    pub base: Option<Sanctoral>,
+    #[serde(default)]
+    pub feasts: Vec<LocalObservance>,
+}
+
+/// A [LocalFeast], with its collect and propers
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LocalObservance {
+    #[serde(flatten)]
+    pub feast: LocalFeast,
+    /// The text of the collect for the feast
+    #[serde(default)]
+    pub collect: Option<String>,
+    /// If no collect is given, the collect(s) will be drawn from this Common of Saints
+    #[serde(default)]
+    pub common: Option<CommonOfSaints>,
+    /// Citations for the readings appointed for the feast
+    #[serde(default)]
+    pub readings: Vec<(ReadingType, String)>,
+}
+
+#[derive(Error, Debug)]
+pub enum LocalCalendarError {
+    #[error("could not parse local calendar from JSON")]
+    Json(#[from] serde_json::Error),
+    #[error("could not parse local calendar from TOML")]
+    Toml(#[from] toml::de::Error),
+}
+
+impl LocalCalendar {
+    pub fn from_json(json: &str) -> Result<Self, LocalCalendarError> {
+        Ok(serde_json::from_str(json)?)
+    }
+
+    /// ```
+    /// # use calendar::{Date, Feast, LiturgicalDayId, Rank};
+    /// # use library::LocalCalendar;
+    /// let local = LocalCalendar::from_toml(r#"
+    ///     [[feasts]]
+    ///     id = 1
+    ///     date = { Date = [7, 15] }
+    ///     rank = "HolyDay"
+    ///     common = "Pastor"
+    ///     names = { En = "Saint Swithun" }
+    /// "#).unwrap();
+    /// let local_feasts = local.calendar();
+    /// let calendar = local_feasts.as_calendar();
+    /// assert_eq!(calendar.feast_day_rank(&Feast::Local(1)), Rank::HolyDay);
+    /// // 7/15/2018 is a Sunday, so the feast is transferred to Monday
+    /// let day = calendar.liturgical_day(Date::from_ymd(2018, 7, 16), false);
+    /// assert_eq!(day.observed, LiturgicalDayId::TransferredFeast(Feast::Local(1)));
+    /// assert!(local.collect(&Feast::Local(1)).is_some());
+    /// ```
+    pub fn from_toml(toml: &str) -> Result<Self, LocalCalendarError> {
+        Ok(toml::from_str(toml)?)
+    }
+
+    /// Builds a calendar that includes the local feasts, which can be used as a [Calendar]
+    /// through [CalendarWithLocalFeasts::as_calendar]. This copies the feasts, so it should
+    /// be called once for a given set of local feasts, and the result reused.
+    pub fn calendar(&self) -> CalendarWithLocalFeasts {
+        let base = match self.base {
+            Some(Sanctoral::LFF2018) => LFF2018_CALENDAR,
+            Some(Sanctoral::CommonWorship) => COMMON_WORSHIP_CALENDAR,
+            _ => BCP1979_CALENDAR,
+        };
+        let feasts = self
+            .feasts
+            .iter()
+            .map(|observance| observance.feast.clone())
+            .collect::<Vec<_>>();
+        CalendarWithLocalFeasts::new(base, feasts)
+    }
+
+    /// The local definition of the given feast, if any
+    pub fn observance(&self, feast: &Feast) -> Option<&LocalObservance> {
+        self.feasts
+            .iter()
+            .find(|observance| observance.feast.feast() == *feast)
+    }
+
+    /// The collect for a local feast, either as given or from the appropriate Common of Saints
+    /// in contemporary language
+    pub fn collect(&self, feast: &Feast) -> Option<Document> {
+        let contemporary = COLLECTS_CONTEMPORARY.iter().collect::<Vec<_>>();
+        Document::choice_or_document(
+            &mut self
+                .collects(&contemporary)
+                .into_iter()
+                .filter(|(id, _)| *id == CollectId::Feast(*feast))
+                .map(|(_, data)| data.document),
+        )
+    }
+
+    /// The collect(s) for each local feast, either as given or drawn from the appropriate Common
+    /// of Saints in the given set of collects, so that they can be found with the other collects
+    pub fn collects(
+        &self,
+        collects: &[&(CollectId, CollectData)],
+    ) -> Vec<(CollectId, CollectData)> {
+        self.feasts
+            .iter()
+            .flat_map(|observance| {
+                let id = CollectId::Feast(observance.feast.feast());
+                match (&observance.collect, observance.common) {
+                    (Some(collect), _) => vec![(
+                        id,
+                        CollectData::from(Document::from(Text::from(collect.as_str()))),
+                    )],
+                    (None, Some(common)) => collects
+                        .iter()
+                        .filter(|(s_id, _)| *s_id == CollectId::CommonOfSaints(common))
+                        .map(|(_, data)| (id, data.clone()))
+                        .collect(),
+                    (None, None) => Vec::new(),
+                }
+            })
+            .collect()
+    }
+
+    /// The readings appointed for the observance, if it is a local feast
+    pub fn readings(&self, observed: &LiturgicalDayId) -> Vec<Reading> {
+        match observed {
+            LiturgicalDayId::Feast(feast) | LiturgicalDayId::TransferredFeast(feast) => self
+                .observance(feast)
+                .map(|observance| {
+                    observance
+                        .readings
+                        .iter()
+                        .map(|(reading_type, citation)| {
+                            Reading::new(*reading_type, citation.to_string())
+                        })
+                        .collect()
+                })
+                .unwrap_or_default(),
+            _ => Vec::new(),
+        }
+    }
+}
@@ -0,0 +1,356 @@
+use calendar::{
+    feasts::CommonOfSaints, Calendar, Feast, LiturgicalDay, LiturgicalDayId, Rank, Season, Weekday,
+};
+use itertools::Itertools;
+use language::Language;
+use liturgy::{Document, Version};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    rite1::eucharist::prefaces::PROPER_PREFACES_TRADITIONAL,
+    rite2::{collects::COLLECTS_CONTEMPORARY, eucharist::prefaces::PROPER_PREFACES_CONTEMPORARY},
+    CollectId, COLLECT_LINKS,
+};
+
+/// One of the Proper Prefaces of the Holy Eucharist, which are inserted into the
+/// Eucharistic Prayer on Sundays and on other occasions as appointed
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq, Serialize, Deserialize)]
+pub enum ProperPreface {
+    LordsDay,
+    Advent,
+    Incarnation,
+    Epiphany,
+    Lent,
+    HolyWeek,
+    Easter,
+    Ascension,
+    Pentecost,
+    TrinitySunday,
+    AllSaints,
+    Saint,
+    Apostles,
+    DedicationOfAChurch,
+    Baptism,
+    Marriage,
+    CommemorationOfTheDead,
+}
+
+/// A preface as it is appointed in the propers, in the `preface` field of [CollectData](crate::CollectData)
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq, Serialize, Deserialize)]
+pub enum AppointedPreface {
+    Preface(ProperPreface),
+    /// “Preface of the Season”
+    OfTheSeason,
+    /// “No Proper Preface is used.”
+    NoProperPreface,
+}
+
+impl AppointedPreface {
+    /// The prefaces appointed in the propers with the given [CollectId], in order of preference
+    /// ```
+    /// # use calendar::{Feast, LiturgicalWeek};
+    /// # use library::{AppointedPreface, CollectId, ProperPreface};
+    /// assert_eq!(
+    ///     AppointedPreface::for_collect(&CollectId::Week(LiturgicalWeek::Epiphany2)),
+    ///     vec![
+    ///         AppointedPreface::Preface(ProperPreface::Epiphany),
+    ///         AppointedPreface::Preface(ProperPreface::LordsDay)
+    ///     ]
+    /// );
+    /// assert_eq!(
+    ///     AppointedPreface::for_collect(&CollectId::Feast(Feast::Michael)),
+    ///     vec![AppointedPreface::Preface(ProperPreface::TrinitySunday)]
+    /// );
+    /// assert!(AppointedPreface::for_collect(&CollectId::Feast(Feast::ThomasAquinas)).is_empty());
+    /// ```
+    pub fn for_collect(id: &CollectId) -> Vec<Self> {
+        Self::appointed(COLLECT_LINKS.linked_id(id))
+    }
+
+    /// The prefaces appointed in the propers of the 1979 Book of Common Prayer, as given in the
+    /// `preface` field of their [CollectData](crate::CollectData)
+    fn appointed(id: CollectId) -> Vec<Self> {
+        let appointed = COLLECTS_CONTEMPORARY
+            .iter()
+            .filter(|(collect_id, _)| *collect_id == id)
+            .flat_map(|(_, data)| Self::parse(&data.preface))
+            .unique()
+            .collect::<Vec<_>>();
+        if appointed.is_empty() {
+            Self::without_collect_preface(id).to_vec()
+        } else {
+            appointed
+        }
+    }
+
+    /// Parses the `preface` of a [CollectData](crate::CollectData), like
+    /// “Preface of the Epiphany, or of the Lord’s Day”
+    /// ```
+    /// # use library::{AppointedPreface, ProperPreface};
+    /// assert_eq!(
+    ///     AppointedPreface::parse("Preface of Baptism, or of the Season"),
+    ///     vec![
+    ///         AppointedPreface::Preface(ProperPreface::Baptism),
+    ///         AppointedPreface::OfTheSeason
+    ///     ]
+    /// );
+    /// assert_eq!(
+    ///     AppointedPreface::parse("No Proper Preface is used."),
+    ///     vec![AppointedPreface::NoProperPreface]
+    /// );
+    /// assert!(AppointedPreface::parse("").is_empty());
+    /// ```
+    pub fn parse(preface: &str) -> Vec<Self> {
+        use AppointedPreface::{NoProperPreface, OfTheSeason, Preface};
+        use ProperPreface::*;
+
+        if preface.starts_with("No Proper Preface") {
+            return vec![NoProperPreface];
+        }
+
+        preface
+            .trim()
+            .trim_start_matches("Preface of ")
+            .split(", or of ")
+            .filter_map(|name| match name.trim() {
+                "the Lord’s Day" => Some(Preface(LordsDay)),
+                "Advent" => Some(Preface(Advent)),
+                "the Incarnation" => Some(Preface(Incarnation)),
+                "the Epiphany" => Some(Preface(Epiphany)),
+                "Lent" => Some(Preface(Lent)),
+                "Holy Week" => Some(Preface(HolyWeek)),
+                "Easter" => Some(Preface(Easter)),
+                "the Ascension" => Some(Preface(Ascension)),
+                "Pentecost" => Some(Preface(Pentecost)),
+                "Trinity Sunday" => Some(Preface(TrinitySunday)),
+                "All Saints" => Some(Preface(AllSaints)),
+                "a Saint" => Some(Preface(Saint)),
+                "Apostles" => Some(Preface(Apostles)),
+                "the Dedication of a Church" => Some(Preface(DedicationOfAChurch)),
+                "Baptism" => Some(Preface(Baptism)),
+                "Marriage" => Some(Preface(Marriage)),
+                "the Commemoration of the Dead" => Some(Preface(CommemorationOfTheDead)),
+                "the Season" => Some(OfTheSeason),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Days whose collects don't name a preface, but which take one from the season they begin
+    fn without_collect_preface(id: CollectId) -> &'static [Self] {
+        use AppointedPreface::Preface;
+        use ProperPreface::*;
+
+        match id {
+            CollectId::Feast(Feast::ChristmasEve) => &[Preface(Incarnation)],
+            CollectId::Feast(Feast::EasterVigil) => &[Preface(Easter)],
+            _ => &[],
+        }
+    }
+}
+
+impl ProperPreface {
+    /// The preface used in a [Season], when the propers call for the “Preface of the Season.”
+    /// Seasons without a preface of their own, like the season after Pentecost, have none.
+    /// ```
+    /// # use calendar::Season;
+    /// # use library::ProperPreface;
+    /// assert_eq!(ProperPreface::for_season(Season::Christmas), Some(ProperPreface::Incarnation));
+    /// assert_eq!(ProperPreface::for_season(Season::OrdinaryTime), None);
+    /// ```
+    pub fn for_season(season: Season) -> Option<Self> {
+        match season {
+            Season::Advent => Some(Self::Advent),
+            Season::Christmas | Season::Incarnation => Some(Self::Incarnation),
+            Season::Epiphany => Some(Self::Epiphany),
+            Season::Lent => Some(Self::Lent),
+            Season::HolyWeek => Some(Self::HolyWeek),
+            Season::Easter => Some(Self::Easter),
+            Season::Ascension => Some(Self::Ascension),
+            Season::Saints => Some(Self::Saint),
+            _ => None,
+        }
+    }
+
+    /// The prefaces appointed for a [Feast]; “the Season” is resolved with the given [Season].
+    /// ```
+    /// # use calendar::{Feast, Season};
+    /// # use library::ProperPreface;
+    /// assert_eq!(
+    ///     ProperPreface::for_feast(Feast::AllSaintsDay, Season::Saints),
+    ///     vec![ProperPreface::AllSaints]
+    /// );
+    /// assert_eq!(
+    ///     ProperPreface::for_feast(Feast::ThomasAquinas, Season::Saints),
+    ///     Vec::new()
+    /// );
+    /// ```
+    pub fn for_feast(feast: Feast, season: Season) -> Vec<Self> {
+        Self::for_collect(&CollectId::Feast(feast), season)
+    }
+
+    /// The prefaces appointed in the Common of Saints
+    /// ```
+    /// # use calendar::{feasts::CommonOfSaints, Season};
+    /// # use library::ProperPreface;
+    /// assert_eq!(
+    ///     ProperPreface::for_common_of_saints(CommonOfSaints::Martyr, Season::Saints),
+    ///     vec![ProperPreface::Saint]
+    /// );
+    /// ```
+    pub fn for_common_of_saints(common: CommonOfSaints, season: Season) -> Vec<Self> {
+        Self::for_collect(&CollectId::CommonOfSaints(common), season)
+    }
+
+    /// The prefaces appointed in the propers with the given [CollectId]
+    pub fn for_collect(id: &CollectId, season: Season) -> Vec<Self> {
+        Self::resolve(AppointedPreface::for_collect(id), season)
+    }
+
+    fn resolve(appointed: Vec<AppointedPreface>, season: Season) -> Vec<Self> {
+        appointed
+            .into_iter()
+            .filter_map(|appointed| match appointed {
+                AppointedPreface::Preface(preface) => Some(preface),
+                AppointedPreface::OfTheSeason => Self::for_season(season),
+                AppointedPreface::NoProperPreface => None,
+            })
+            .unique()
+            .collect()
+    }
+
+    /// The prefaces that may be used on a given day, in order of preference: those appointed for a
+    /// holy day, or for the Sunday, or else the preface of the season. A Sunday with nothing else
+    /// appointed uses the Preface of the Lord’s Day.
+    /// ```
+    /// # use calendar::{BCP1979_CALENDAR, Date};
+    /// # use library::ProperPreface;
+    /// let prefaces_for = |y, m, d| {
+    ///     let day = BCP1979_CALENDAR.liturgical_day(Date::from_ymd(y, m, d), false);
+    ///     ProperPreface::for_day(&BCP1979_CALENDAR, &day, &day.observed)
+    /// };
+    /// // First Sunday of Advent
+    /// assert_eq!(prefaces_for(2021, 11, 28), vec![ProperPreface::Advent]);
+    /// // a weekday in Lent
+    /// assert_eq!(prefaces_for(2022, 3, 15), vec![ProperPreface::Lent]);
+    /// // Proper 10, Year B
+    /// assert_eq!(prefaces_for(2021, 7, 11), vec![ProperPreface::LordsDay]);
+    /// // a weekday after Pentecost
+    /// assert!(prefaces_for(2021, 7, 13).is_empty());
+    /// // St. Andrew
+    /// assert_eq!(prefaces_for(2021, 11, 30), vec![ProperPreface::Apostles]);
+    /// ```
+    pub fn for_day(
+        calendar: &Calendar,
+        day: &LiturgicalDay,
+        observed: &LiturgicalDayId,
+    ) -> Vec<Self> {
+        let season = calendar.season(day);
+
+        let holy_day = match observed {
+            LiturgicalDayId::Feast(feast) | LiturgicalDayId::TransferredFeast(feast)
+                if calendar.rank(day) >= Rank::PrecedenceOverWeekday =>
+            {
+                let feast = calendar.feast_eve_following_day(feast).unwrap_or(*feast);
+                Some(CollectId::Feast(feast))
+            }
+            _ => None,
+        };
+        if let Some(id) = holy_day {
+            let appointed = AppointedPreface::for_collect(&id);
+            if appointed.contains(&AppointedPreface::NoProperPreface) {
+                return Vec::new();
+            } else if !appointed.is_empty() {
+                return Self::resolve(appointed, season);
+            }
+        }
+
+        if day.weekday == Weekday::Sun {
+            let week_id = if let Some(proper) = day.proper {
+                CollectId::Proper(proper)
+            } else {
+                CollectId::Week(day.week)
+            };
+            let appointed = AppointedPreface::for_collect(&week_id);
+            if appointed.contains(&AppointedPreface::NoProperPreface) {
+                Vec::new()
+            } else if appointed.is_empty() {
+                vec![Self::LordsDay]
+            } else {
+                Self::resolve(appointed, season)
+            }
+        } else {
+            Self::for_season(season)
+                .or_else(|| Self::for_season(calendar.base_season(day)))
+                .into_iter()
+                .collect()
+        }
+    }
+
+    /// The text of the preface, which has several alternative forms for some occasions
+    /// ```
+    /// # use library::ProperPreface;
+    /// # use liturgy::Version;
+    /// assert_eq!(ProperPreface::LordsDay.documents(Version::RiteII).count(), 3);
+    /// assert!(ProperPreface::Advent
+    ///     .documents(Version::RiteI)
+    ///     .all(|doc| doc.version == Version::RiteI));
+    /// ```
+    pub fn documents(&self, version: Version) -> impl Iterator<Item = &'static Document> {
+        let prefaces = match version {
+            Version::RiteI => &*PROPER_PREFACES_TRADITIONAL,
+            _ => &*PROPER_PREFACES_CONTEMPORARY,
+        };
+        let preface = *self;
+        prefaces
+            .iter()
+            .filter(move |(s_preface, _)| *s_preface == preface)
+            .map(|(_, document)| document)
+    }
+
+    /// The name of the preface, as given in the propers
+    pub fn name(&self, language: Language) -> &'static str {
+        match language {
+            Language::Es => match self {
+                Self::LordsDay => "Prefacio del Día del Señor",
+                Self::Advent => "Prefacio de Adviento",
+                Self::Incarnation => "Prefacio de la Encarnación",
+                Self::Epiphany => "Prefacio de la Epifanía",
+                Self::Lent => "Prefacio de Cuaresma",
+                Self::HolyWeek => "Prefacio de Semana Santa",
+                Self::Easter => "Prefacio de Pascua",
+                Self::Ascension => "Prefacio de la Ascensión",
+                Self::Pentecost => "Prefacio de Pentecostés",
+                Self::TrinitySunday => "Prefacio del Domingo de Trinidad",
+                Self::AllSaints => "Prefacio de Todos los Santos",
+                Self::Saint => "Prefacio de un Santo",
+                Self::Apostles => "Prefacio de Apóstoles",
+                Self::DedicationOfAChurch => "Prefacio de la Dedicación de una Iglesia",
+                Self::Baptism => "Prefacio del Bautismo",
+                Self::Marriage => "Prefacio del Matrimonio",
+                Self::CommemorationOfTheDead => {
+                    "Prefacio de la Conmemoración de los Fieles Difuntos"
+                }
+            },
+            _ => match self {
+                Self::LordsDay => "Preface of the Lord’s Day",
+                Self::Advent => "Preface of Advent",
+                Self::Incarnation => "Preface of the Incarnation",
+                Self::Epiphany => "Preface of the Epiphany",
+                Self::Lent => "Preface of Lent",
+                Self::HolyWeek => "Preface of Holy Week",
+                Self::Easter => "Preface of Easter",
+                Self::Ascension => "Preface of the Ascension",
+                Self::Pentecost => "Preface of Pentecost",
+                Self::TrinitySunday => "Preface of Trinity Sunday",
+                Self::AllSaints => "Preface of All Saints",
+                Self::Saint => "Preface of a Saint",
+                Self::Apostles => "Preface of Apostles",
+                Self::DedicationOfAChurch => "Preface of the Dedication of a Church",
+                Self::Baptism => "Preface of Baptism",
+                Self::Marriage => "Preface of Marriage",
+                Self::CommemorationOfTheDead => "Preface of the Commemoration of the Dead",
+            },
+        }
+    }
+}
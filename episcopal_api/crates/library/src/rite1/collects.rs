@@ -181,7 +181,7 @@ lazy_static! {
                 .page(162)
                 .tags(["Seasons of the Year"])
                 .version(Version::RiteI),
-                preface: "Preface of the Incarnation The Epiphany January 6".into(),
+                preface: "Preface of the Incarnation".into(),
                 rubric_before: None,
                 rubric_after: None
             }
@@ -1668,7 +1668,7 @@ lazy_static! {
                 .page(190)
                 .tags(["Holy Days"])
                 .version(Version::RiteI),
-                preface: "Preface of Apostles Independence Day July 4".into(),
+                preface: "Preface of Apostles".into(),
                 rubric_before: None,
                 rubric_after: None
             }
@@ -0,0 +1,174 @@
+use lazy_static::lazy_static;
+use liturgy::{Document, Text, Version};
+
+use crate::ProperPreface;
+
+lazy_static! {
+    pub static ref PROPER_PREFACES_TRADITIONAL: Vec<(ProperPreface, Document)> = vec![
+        (
+            ProperPreface::LordsDay,
+            Document::from(Text::from("For thou art the source of light and life; thou madest us in thine image, and didst call us to new life in Jesus Christ our Lord."))
+                .label("The Lord’s Day")
+                .subtitle("Of God the Father")
+                .version_label("Of God the Father")
+                .page(344)
+                .version(Version::RiteI)
+        ),
+        (
+            ProperPreface::LordsDay,
+            Document::from(Text::from("Through Jesus Christ our Lord; who on the first day of the week overcame death and the grave, and by his glorious resurrection opened to us the way of everlasting life."))
+                .label("The Lord’s Day")
+                .subtitle("Of God the Son")
+                .version_label("Of God the Son")
+                .page(345)
+                .version(Version::RiteI)
+        ),
+        (
+            ProperPreface::LordsDay,
+            Document::from(Text::from("Who by water and the Holy Spirit hast made us a new people in Jesus Christ our Lord, to show forth thy glory in all the world."))
+                .label("The Lord’s Day")
+                .subtitle("Of God the Holy Spirit")
+                .version_label("Of God the Holy Spirit")
+                .page(345)
+                .version(Version::RiteI)
+        ),
+        (
+            ProperPreface::Advent,
+            Document::from(Text::from("Because thou didst send thy beloved Son to redeem us from sin and death, and to make us heirs in him of everlasting life; that when he shall come again in power and great triumph to judge the world, we may without shame or fear rejoice to behold his appearing."))
+                .label("Advent")
+                .page(345)
+                .version(Version::RiteI)
+        ),
+        (
+            ProperPreface::Incarnation,
+            Document::from(Text::from("Because thou didst give Jesus Christ, thine only Son, to be born for us; who, by the mighty power of the Holy Ghost, was made very Man of the substance of the Virgin Mary his mother; that we might be delivered from the bondage of sin, and receive power to become thy children."))
+                .label("Incarnation")
+                .page(345)
+                .version(Version::RiteI)
+        ),
+        (
+            ProperPreface::Epiphany,
+            Document::from(Text::from("Because in the mystery of the Word made flesh, thou hast caused a new light to shine in our hearts, to give the knowledge of thy glory in the face of thy Son Jesus Christ our Lord."))
+                .label("Epiphany")
+                .page(346)
+                .version(Version::RiteI)
+        ),
+        (
+            ProperPreface::Lent,
+            Document::from(Text::from("Through Jesus Christ our Lord, who was in every way tempted as we are, yet did not sin; by whose grace we are able to triumph over every evil, and to live no longer unto ourselves, but unto him who died for us and rose again."))
+                .label("Lent")
+                .version_label("Lent (1)")
+                .page(346)
+                .version(Version::RiteI)
+        ),
+        (
+            ProperPreface::Lent,
+            Document::from(Text::from("Who dost bid thy faithful people cleanse their hearts, and prepare with joy for the Paschal feast; that, fervent in prayer and in works of mercy, and renewed by thy Word and Sacraments, they may come to the fullness of grace which thou hast prepared for those who love thee."))
+                .label("Lent")
+                .version_label("Lent (2)")
+                .page(346)
+                .version(Version::RiteI)
+        ),
+        (
+            ProperPreface::HolyWeek,
+            Document::from(Text::from("Through Jesus Christ our Lord; who for our sins was lifted high upon the cross, that he might draw the whole world unto himself; who by his suffering and death became the author of eternal salvation for all who put their trust in him."))
+                .label("Holy Week")
+                .page(346)
+                .version(Version::RiteI)
+        ),
+        (
+            ProperPreface::Easter,
+            Document::from(Text::from("But chiefly are we bound to praise thee for the glorious resurrection of thy Son Jesus Christ our Lord; for he is the very Paschal Lamb, who was sacrificed for us, and hath taken away the sin of the world; who by his death hath destroyed death, and by his rising to life again hath won for us everlasting life."))
+                .label("Easter")
+                .page(346)
+                .version(Version::RiteI)
+        ),
+        (
+            ProperPreface::Ascension,
+            Document::from(Text::from("Through thy dearly beloved Son Jesus Christ our Lord; who after his glorious resurrection manifestly appeared to his disciples, and in their sight ascended up into heaven, to prepare a place for us; that where he is, thither we might also ascend, and reign with him in glory."))
+                .label("Ascension")
+                .page(347)
+                .version(Version::RiteI)
+        ),
+        (
+            ProperPreface::Pentecost,
+            Document::from(Text::from("Through Jesus Christ our Lord; according to whose most true promise, the Holy Ghost came down on this day from heaven, lighting upon the disciples, to teach them and to lead them into all truth; uniting peoples of many tongues in the confession of one faith, and giving to thy Church the power to serve thee as a royal priesthood, and to preach the Gospel unto all nations."))
+                .label("Pentecost")
+                .page(347)
+                .version(Version::RiteI)
+        ),
+        (
+            ProperPreface::TrinitySunday,
+            Document::from(Text::from("For with thy co-eternal Son and Holy Ghost, thou art one God, one Lord, in Trinity of Persons and in Unity of Substance; and we celebrate the one and equal glory of thee, O Father, and of the Son, and of the Holy Ghost."))
+                .label("Trinity Sunday")
+                .page(347)
+                .version(Version::RiteI)
+        ),
+        (
+            ProperPreface::AllSaints,
+            Document::from(Text::from("Who, in the multitude of thy saints, hast compassed us about with so great a cloud of witnesses that we, rejoicing in their fellowship, may run with patience the race that is set before us, and, together with them, may receive the crown of glory that fadeth not away."))
+                .label("All Saints")
+                .page(347)
+                .version(Version::RiteI)
+        ),
+        (
+            ProperPreface::Saint,
+            Document::from(Text::from("For the wonderful grace and virtue declared in all thy saints, who have been the choice vessels of thy grace, and the lights of the world in their several generations."))
+                .label("A Saint")
+                .version_label("A Saint (1)")
+                .page(348)
+                .version(Version::RiteI)
+        ),
+        (
+            ProperPreface::Saint,
+            Document::from(Text::from("Who in the obedience of thy saints hast given us an example of righteousness, and in their eternal joy a glorious pledge of the hope of our calling."))
+                .label("A Saint")
+                .version_label("A Saint (2)")
+                .page(348)
+                .version(Version::RiteI)
+        ),
+        (
+            ProperPreface::Saint,
+            Document::from(Text::from("Because thou art greatly glorified in the assembly of thy saints. All thy creatures praise thee, and thy faithful servants bless thee, confessing before the rulers of this world the great Name of thine only Son."))
+                .label("A Saint")
+                .version_label("A Saint (3)")
+                .page(348)
+                .version(Version::RiteI)
+        ),
+        (
+            ProperPreface::Apostles,
+            Document::from(Text::from("Through the great shepherd of thy flock, Jesus Christ our Lord; who after his resurrection sent forth his apostles to preach the Gospel and to teach all nations; and promised to be with them always, even unto the end of the ages."))
+                .label("Apostles and Ordinations")
+                .page(348)
+                .version(Version::RiteI)
+        ),
+        (
+            ProperPreface::DedicationOfAChurch,
+            Document::from(Text::from("Through Jesus Christ our great High Priest; in whom we are built up as living stones of a holy temple, that we might offer before thee a sacrifice of praise and prayer which is holy and pleasing in thy sight."))
+                .label("Dedication of a Church")
+                .page(349)
+                .version(Version::RiteI)
+        ),
+        (
+            ProperPreface::Baptism,
+            Document::from(Text::from("Because in Jesus Christ our Lord thou hast received us as thy sons and daughters, made us citizens of thy kingdom, and given us the Holy Spirit to guide us into all truth."))
+                .label("Baptism")
+                .page(349)
+                .version(Version::RiteI)
+        ),
+        (
+            ProperPreface::Marriage,
+            Document::from(Text::from("Because in the love of wife and husband, thou hast given us an image of the heavenly Jerusalem, adorned as a bride for her bridegroom, thy Son Jesus Christ our Lord; who loveth her and gave himself for her, that he might make the whole creation new."))
+                .label("Marriage")
+                .page(349)
+                .version(Version::RiteI)
+        ),
+        (
+            ProperPreface::CommemorationOfTheDead,
+            Document::from(Text::from("Through Jesus Christ our Lord; who rose victorious from the dead, and doth comfort us with the blessed hope of everlasting life; for to thy faithful people, O Lord, life is changed, not taken away; and the house of this earthly tabernacle being dissolved, there is prepared for us an everlasting dwelling place in the heavens."))
+                .label("The Commemoration of the Dead")
+                .page(349)
+                .version(Version::RiteI)
+        ),
+    ];
+}
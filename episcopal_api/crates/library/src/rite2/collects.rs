@@ -1725,7 +1725,7 @@ lazy_static! {
                     .subtitle("September 29")
                 .page(244)
                 .tags(["Holy Days"]),
-                preface: "Preface of Trinity Sunday Saint Luke October 18".into(),
+                preface: "Preface of Trinity Sunday".into(),
                 rubric_before: None,
                 rubric_after: None
             }
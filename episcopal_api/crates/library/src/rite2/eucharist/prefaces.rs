@@ -0,0 +1,174 @@
+use lazy_static::lazy_static;
+use liturgy::{Document, Text, Version};
+
+use crate::ProperPreface;
+
+lazy_static! {
+    pub static ref PROPER_PREFACES_CONTEMPORARY: Vec<(ProperPreface, Document)> = vec![
+        (
+            ProperPreface::LordsDay,
+            Document::from(Text::from("Creator of the light and source of life, who have made us in your image, and called us to new life in Jesus Christ our Lord."))
+                .label("The Lord’s Day")
+                .subtitle("Of God the Father")
+                .version_label("Of God the Father")
+                .page(377)
+                .version(Version::RiteII)
+        ),
+        (
+            ProperPreface::LordsDay,
+            Document::from(Text::from("Through Jesus Christ our Lord; who on the first day of the week overcame death and the grave, and by his glorious resurrection opened to us the way of everlasting life."))
+                .label("The Lord’s Day")
+                .subtitle("Of God the Son")
+                .version_label("Of God the Son")
+                .page(377)
+                .version(Version::RiteII)
+        ),
+        (
+            ProperPreface::LordsDay,
+            Document::from(Text::from("For by water and the Holy Spirit you have made us a new people in Jesus Christ our Lord, to show forth your glory in all the world."))
+                .label("The Lord’s Day")
+                .subtitle("Of God the Holy Spirit")
+                .version_label("Of God the Holy Spirit")
+                .page(377)
+                .version(Version::RiteII)
+        ),
+        (
+            ProperPreface::Advent,
+            Document::from(Text::from("Because you sent your beloved Son to redeem us from sin and death, and to make us heirs in him of everlasting life; that when he shall come again in power and great triumph to judge the world, we may without shame or fear rejoice to behold his appearing."))
+                .label("Advent")
+                .page(378)
+                .version(Version::RiteII)
+        ),
+        (
+            ProperPreface::Incarnation,
+            Document::from(Text::from("Because you gave Jesus Christ, your only Son, to be born for us; who, by the mighty power of the Holy Spirit, was made perfect Man of the flesh of the Virgin Mary his mother; so that we might be delivered from the bondage of sin, and receive power to become your children."))
+                .label("Incarnation")
+                .page(378)
+                .version(Version::RiteII)
+        ),
+        (
+            ProperPreface::Epiphany,
+            Document::from(Text::from("Because in the mystery of the Word made flesh, you have caused a new light to shine in our hearts, to give the knowledge of your glory in the face of your Son Jesus Christ our Lord."))
+                .label("Epiphany")
+                .page(378)
+                .version(Version::RiteII)
+        ),
+        (
+            ProperPreface::Lent,
+            Document::from(Text::from("You bid your faithful people cleanse their hearts, and prepare with joy for the Paschal feast; that, fervent in prayer and in works of mercy, and renewed by your Word and Sacraments, they may come to the fullness of grace which you have prepared for those who love you."))
+                .label("Lent")
+                .version_label("Lent (1)")
+                .page(379)
+                .version(Version::RiteII)
+        ),
+        (
+            ProperPreface::Lent,
+            Document::from(Text::from("Through Jesus Christ our Lord, who was tempted in every way as we are, yet did not sin. By his grace we are able to triumph over every evil, and to live no longer for ourselves alone, but for him who died for us and rose again."))
+                .label("Lent")
+                .version_label("Lent (2)")
+                .page(379)
+                .version(Version::RiteII)
+        ),
+        (
+            ProperPreface::HolyWeek,
+            Document::from(Text::from("Through Jesus Christ our Lord. For our sins he was lifted high upon the cross, that he might draw the whole world to himself; and, by his suffering and death, he became the source of eternal salvation for all who put their trust in him."))
+                .label("Holy Week")
+                .page(379)
+                .version(Version::RiteII)
+        ),
+        (
+            ProperPreface::Easter,
+            Document::from(Text::from("But chiefly are we bound to praise you for the glorious resurrection of your Son Jesus Christ our Lord; for he is the true Paschal Lamb, who was sacrificed for us, and has taken away the sin of the world. By his death he has destroyed death, and by his rising to life again he has won for us everlasting life."))
+                .label("Easter")
+                .page(379)
+                .version(Version::RiteII)
+        ),
+        (
+            ProperPreface::Ascension,
+            Document::from(Text::from("Through your dearly beloved Son Jesus Christ our Lord. After his glorious resurrection he openly appeared to his disciples, and in their sight was taken up into heaven, to prepare a place for us; that where he is, there we might also be, and reign with him in glory."))
+                .label("Ascension")
+                .page(380)
+                .version(Version::RiteII)
+        ),
+        (
+            ProperPreface::Pentecost,
+            Document::from(Text::from("Through Jesus Christ our Lord. In fulfillment of his true promise, the Holy Spirit came down on this day from heaven, lighting upon the disciples, to teach them and to lead them into all truth; uniting peoples of many tongues in the confession of one faith, and giving to your Church the power to serve you as a royal priesthood, and to preach the Gospel to all nations."))
+                .label("Pentecost")
+                .page(380)
+                .version(Version::RiteII)
+        ),
+        (
+            ProperPreface::TrinitySunday,
+            Document::from(Text::from("For with your co-eternal Son and Holy Spirit, you are one God, one Lord, in Trinity of Persons and in Unity of Being; and we celebrate the one and equal glory of you, O Father, and of the Son, and of the Holy Spirit."))
+                .label("Trinity Sunday")
+                .page(380)
+                .version(Version::RiteII)
+        ),
+        (
+            ProperPreface::AllSaints,
+            Document::from(Text::from("For in the multitude of your saints, you have surrounded us with a great cloud of witnesses, that we might rejoice in their fellowship, and run with endurance the race that is set before us; and, together with them, receive the crown of glory that never fades away."))
+                .label("All Saints")
+                .page(380)
+                .version(Version::RiteII)
+        ),
+        (
+            ProperPreface::Saint,
+            Document::from(Text::from("For the wonderful grace and virtue declared in all your saints, who have been the chosen vessels of your grace, and the lights of the world in their generations."))
+                .label("A Saint")
+                .version_label("A Saint (1)")
+                .page(380)
+                .version(Version::RiteII)
+        ),
+        (
+            ProperPreface::Saint,
+            Document::from(Text::from("Because in the obedience of your saints you have given us an example of righteousness, and in their eternal joy a glorious pledge of the hope of our calling."))
+                .label("A Saint")
+                .version_label("A Saint (2)")
+                .page(380)
+                .version(Version::RiteII)
+        ),
+        (
+            ProperPreface::Saint,
+            Document::from(Text::from("Because you are greatly glorified in the assembly of your saints. All your creatures praise you, and your faithful servants bless you, confessing before the rulers of this world the great Name of your only Son."))
+                .label("A Saint")
+                .version_label("A Saint (3)")
+                .page(381)
+                .version(Version::RiteII)
+        ),
+        (
+            ProperPreface::Apostles,
+            Document::from(Text::from("Through the great shepherd of your flock, Jesus Christ our Lord; who after his resurrection sent forth his apostles to preach the Gospel and to teach all nations; and promised to be with them always, even to the end of the ages."))
+                .label("Apostles and Ordinations")
+                .page(381)
+                .version(Version::RiteII)
+        ),
+        (
+            ProperPreface::DedicationOfAChurch,
+            Document::from(Text::from("Through Jesus Christ our great High Priest, in whom we are built up as living stones of a holy temple, that we might offer before you a sacrifice of praise and prayer which is holy and pleasing in your sight."))
+                .label("Dedication of a Church")
+                .page(381)
+                .version(Version::RiteII)
+        ),
+        (
+            ProperPreface::Baptism,
+            Document::from(Text::from("Because in Jesus Christ our Lord you have received us as your sons and daughters, made us citizens of your kingdom, and given us the Holy Spirit to guide us into all truth."))
+                .label("Baptism")
+                .page(381)
+                .version(Version::RiteII)
+        ),
+        (
+            ProperPreface::Marriage,
+            Document::from(Text::from("Because in the love of wife and husband, you have given us an image of the heavenly Jerusalem, adorned as a bride for her bridegroom, your Son Jesus Christ our Lord; who loves her and gave himself for her, that he might make the whole creation new."))
+                .label("Marriage")
+                .page(381)
+                .version(Version::RiteII)
+        ),
+        (
+            ProperPreface::CommemorationOfTheDead,
+            Document::from(Text::from("Through Jesus Christ our Lord; who rose victorious from the dead, and comforts us with the blessed hope of everlasting life. For to your faithful people, O Lord, life is changed, not ended; and when our mortal body lies in death, there is prepared for us a dwelling place eternal in the heavens."))
+                .label("The Commemoration of the Dead")
+                .page(382)
+                .version(Version::RiteII)
+        ),
+    ];
+}
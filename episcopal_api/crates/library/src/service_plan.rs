@@ -0,0 +1,374 @@
+use calendar::{Date, Sanctoral};
+use liturgy::{Content, Document, HymnLink, PreferenceKey, PreferenceValue, Series, SlugPath};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::collections::HashMap;
+use thiserror::Error;
+
+#[cfg(any(feature = "browser", feature = "server"))]
+use crate::{Contents, Library};
+#[cfg(any(feature = "browser", feature = "server"))]
+use calendar::{BCP1979_CALENDAR, COMMON_WORSHIP_CALENDAR, LFF2018_CALENDAR};
+
+/// Everything needed to prepare a particular service: the date, the liturgy to use as a template,
+/// the choices among its options, and the hymns and anthems to be sung. A plan can be saved as JSON
+/// and compiled into a single [Document] for a bulletin.
+/// ```
+/// # use calendar::Date;
+/// # use hymnal::{HymnNumber, Hymnals};
+/// # use library::rite2::eucharist::parallel::{OPENING_HYMN, COMMUNION_HYMN};
+/// # use library::ServicePlan;
+/// # use liturgy::{HymnLink, Slug, SlugPath, Version};
+/// let plan = ServicePlan::new(
+///         Date::from_ymd(2021, 11, 28),
+///         SlugPath::from([Slug::Eucharist, Slug::Eucharist, Slug::Version(Version::RiteII)])
+///     )
+///     .hymn(OPENING_HYMN, HymnLink::Hymn(Hymnals::Hymnal1982, HymnNumber::H(57)))
+///     .hymn(COMMUNION_HYMN, HymnLink::Hymn(Hymnals::Hymnal1982, HymnNumber::H(65)))
+///     .hymn(COMMUNION_HYMN, HymnLink::Hymn(Hymnals::Hymnal1982, HymnNumber::H(56)));
+/// assert_eq!(plan.hymns_for_slot(COMMUNION_HYMN).len(), 2);
+///
+/// let json = plan.to_json().unwrap();
+/// assert_eq!(ServicePlan::from_json(&json).unwrap(), plan);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ServicePlan {
+    pub date: Date,
+    /// Path to the liturgy in the library’s [TableOfContents](crate::TableOfContents)
+    pub template: SlugPath,
+    /// The calendar used to find the liturgical day (by default, the 1979 BCP)
+    #[serde(default)]
+    pub calendar: Option<Sanctoral>,
+    /// Whether to use the alternate observance for the day, if there is one
+    #[serde(default)]
+    pub alternate: bool,
+    /// Choices for the liturgy’s preferences; those not given use the liturgy’s defaults.
+    /// (Saved as a list of pairs, because JSON map keys must be strings.)
+    #[serde(
+        default,
+        serialize_with = "serialize_preferences",
+        deserialize_with = "deserialize_preferences"
+    )]
+    pub preferences: HashMap<PreferenceKey, PreferenceValue>,
+    /// Hymns, psalms, or anthems for each slot, identified by the tag of the
+    /// [HymnLink] that marks that slot in the template (e.g., [OPENING_HYMN](crate::rite2::eucharist::parallel::OPENING_HYMN))
+    #[serde(default)]
+    pub hymns: Vec<(String, Vec<HymnLink>)>,
+}
+
+#[derive(Error, Debug)]
+pub enum ServicePlanError {
+    #[error("could not parse service plan from JSON")]
+    Json(#[from] serde_json::Error),
+    #[error("no liturgy found at {0}")]
+    TemplateNotFound(SlugPath),
+    #[error("the template has no hymn slot tagged {0:?}")]
+    SlotNotFound(String),
+    #[error("nothing in the liturgy applies on {0}")]
+    Empty(Date),
+}
+
+impl ServicePlan {
+    pub fn new(date: Date, template: SlugPath) -> Self {
+        Self {
+            date,
+            template,
+            calendar: None,
+            alternate: false,
+            preferences: HashMap::new(),
+            hymns: Vec::new(),
+        }
+    }
+
+    #[must_use]
+    pub fn calendar(mut self, calendar: Sanctoral) -> Self {
+        self.calendar = Some(calendar);
+        self
+    }
+
+    #[must_use]
+    pub fn alternate(mut self, alternate: bool) -> Self {
+        self.alternate = alternate;
+        self
+    }
+
+    /// Sets a preference, replacing any previous value for the same key
+    #[must_use]
+    pub fn preference(mut self, key: PreferenceKey, value: PreferenceValue) -> Self {
+        self.preferences.insert(key, value);
+        self
+    }
+
+    /// Adds a hymn or anthem to the slot with the given tag, after any already chosen for that slot
+    #[must_use]
+    pub fn hymn(mut self, slot: impl std::fmt::Display, hymn: HymnLink) -> Self {
+        let slot = slot.to_string();
+        match self.hymns.iter_mut().find(|(s_slot, _)| *s_slot == slot) {
+            Some((_, hymns)) => hymns.push(hymn),
+            None => self.hymns.push((slot, vec![hymn])),
+        }
+        self
+    }
+
+    /// The hymns and anthems chosen for the slot with the given tag
+    pub fn hymns_for_slot(&self, slot: &str) -> &[HymnLink] {
+        self.hymns
+            .iter()
+            .find(|(s_slot, _)| s_slot == slot)
+            .map(|(_, hymns)| hymns.as_slice())
+            .unwrap_or_default()
+    }
+
+    pub fn from_json(json: &str) -> Result<Self, ServicePlanError> {
+        Ok(serde_json::from_str(json)?)
+    }
+
+    pub fn to_json(&self) -> Result<String, ServicePlanError> {
+        Ok(serde_json::to_string_pretty(self)?)
+    }
+
+    /// The template with each hymn slot filled in with the hymns chosen for it, before compiling
+    pub fn fill_hymn_slots(&self, mut template: Document) -> Result<Document, ServicePlanError> {
+        for (slot, hymns) in &self.hymns {
+            if !fill_slot(&mut template, slot, hymns) {
+                return Err(ServicePlanError::SlotNotFound(slot.clone()));
+            }
+        }
+        Ok(template)
+    }
+
+    /// Compiles the plan into a single [Document] for the date, with its propers, chosen
+    /// preferences, and hymns, which can then be passed to any exporter.
+    /// ```
+    /// # use calendar::Date;
+    /// # use hymnal::{HymnNumber, Hymnals};
+    /// # use library::rite2::eucharist::parallel::OPENING_HYMN;
+    /// # use library::{CommonPrayer, ServicePlan};
+    /// # use liturgy::{Content, HymnLink, Slug, SlugPath, Version};
+    /// let plan = ServicePlan::new(
+    ///         Date::from_ymd(2021, 11, 28),
+    ///         SlugPath::from([Slug::Eucharist, Slug::Eucharist, Slug::Version(Version::RiteII)])
+    ///     )
+    ///     .hymn(OPENING_HYMN, HymnLink::Hymn(Hymnals::Hymnal1982, HymnNumber::H(57)));
+    /// let doc = plan.compile::<CommonPrayer>().unwrap();
+    /// assert!(doc.is_compiled);
+    /// assert!(doc.children_with_tag(OPENING_HYMN.into()).any(|doc|
+    ///     doc.content == Content::HymnLink(HymnLink::Hymn(Hymnals::Hymnal1982, HymnNumber::H(57)))
+    /// ));
+    ///
+    /// // hymns can only be added where the template has a place for them
+    /// assert!(ServicePlan::new(Date::from_ymd(2021, 11, 28), plan.template.clone())
+    ///     .hymn("Recessional", HymnLink::Hymn(Hymnals::Hymnal1982, HymnNumber::H(57)))
+    ///     .compile::<CommonPrayer>()
+    ///     .is_err());
+    /// ```
+    #[cfg(any(feature = "browser", feature = "server"))]
+    pub fn compile<L: Library>(&self) -> Result<Document, ServicePlanError> {
+        let template = match L::contents().contents_at_path(&self.template) {
+            Some(Contents::Document(document)) => document.clone(),
+            _ => return Err(ServicePlanError::TemplateNotFound(self.template.clone())),
+        };
+        let (evening, liturgy_prefs) = match &template.content {
+            Content::Liturgy(liturgy) => (liturgy.evening, liturgy.preferences.clone()),
+            _ => (false, Default::default()),
+        };
+        let template = self.fill_hymn_slots(template)?;
+
+        let calendar = match self.calendar {
+            Some(Sanctoral::LFF2018) => LFF2018_CALENDAR,
+            Some(Sanctoral::CommonWorship) => COMMON_WORSHIP_CALENDAR,
+            _ => BCP1979_CALENDAR,
+        };
+        let day = calendar.liturgical_day(self.date, evening);
+        let observed = if self.alternate {
+            day.alternate.unwrap_or(day.observed)
+        } else {
+            day.observed
+        };
+        L::compile(
+            template,
+            &calendar,
+            &day,
+            &observed,
+            &self.preferences,
+            &liturgy_prefs,
+        )
+        .ok_or(ServicePlanError::Empty(self.date))
+    }
+}
+
+fn serialize_preferences<S: Serializer>(
+    preferences: &HashMap<PreferenceKey, PreferenceValue>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.collect_seq(preferences)
+}
+
+fn deserialize_preferences<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<HashMap<PreferenceKey, PreferenceValue>, D::Error> {
+    let pairs = Vec::<(PreferenceKey, PreferenceValue)>::deserialize(deserializer)?;
+    Ok(pairs.into_iter().collect())
+}
+
+/// Replaces every [HymnLink] tagged with the slot name, wherever it is nested in the document
+/// (including each option of a [Choice](liturgy::Choice) or [Parallel](liturgy::Parallel)),
+/// with the chosen hymns, returning `false` if no such slot was found
+fn fill_slot(document: &mut Document, slot: &str, hymns: &[HymnLink]) -> bool {
+    if matches!(document.content, Content::HymnLink(_))
+        && document.tags.iter().any(|tag| tag == slot)
+    {
+        document.content = match hymns {
+            [hymn] => Content::HymnLink(hymn.clone()),
+            _ => Content::Series(Series::from(
+                hymns
+                    .iter()
+                    .map(|hymn| Document::from(hymn.clone()).tags([slot]))
+                    .collect::<Vec<_>>(),
+            )),
+        };
+        true
+    } else {
+        let children = match &mut document.content {
+            Content::Liturgy(liturgy) => liturgy.body.as_mut_slice(),
+            Content::Series(series) => series.as_mut_slice(),
+            Content::Parallel(parallel) => parallel.as_mut_slice(),
+            Content::Choice(choice) => choice.options.as_mut_slice(),
+            _ => return false,
+        };
+        // every child is visited, so that each copy of the slot is filled
+        let mut found = false;
+        for child in children {
+            found |= fill_slot(child, slot, hymns);
+        }
+        found
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use hymnal::{HymnNumber, Hymnals};
+    use liturgy::{Choice, GlobalPref, Lectionaries, Parallel, Slug, Text, Version};
+
+    use super::*;
+
+    const SLOT: &str = "Sequence Hymn";
+
+    fn hymn(number: u16) -> HymnLink {
+        HymnLink::Hymn(Hymnals::Hymnal1982, HymnNumber::H(number))
+    }
+
+    fn slot() -> Document {
+        Document::from(HymnLink::Hymnals).tags([SLOT])
+    }
+
+    fn plan() -> ServicePlan {
+        ServicePlan::new(
+            Date::from_ymd(2021, 11, 28),
+            SlugPath::from([
+                Slug::Eucharist,
+                Slug::Eucharist,
+                Slug::Version(Version::RiteII),
+            ]),
+        )
+    }
+
+    #[test]
+    fn round_trips_through_json() {
+        let plan = plan()
+            .calendar(Sanctoral::LFF2018)
+            .alternate(true)
+            .preference(
+                GlobalPref::Lectionary.into(),
+                PreferenceValue::Lectionary(Lectionaries::RCLTrack1),
+            )
+            .preference("gloria".into(), PreferenceValue::Bool(true))
+            .hymn(SLOT, hymn(57))
+            .hymn(SLOT, hymn(65));
+        let json = plan.to_json().unwrap();
+        assert_eq!(ServicePlan::from_json(&json).unwrap(), plan);
+    }
+
+    #[test]
+    fn loads_a_plan_with_only_a_date_and_template() {
+        let json = plan().to_json().unwrap();
+        let minimal = json
+            .lines()
+            .filter(|line| {
+                !["calendar", "alternate", "preferences", "hymns"]
+                    .iter()
+                    .any(|field| line.contains(field))
+            })
+            .collect::<String>()
+            .replace(",}", "}");
+        assert_eq!(ServicePlan::from_json(&minimal).unwrap(), plan());
+        assert!(ServicePlan::from_json("{}").is_err());
+    }
+
+    #[test]
+    fn later_preferences_replace_earlier_ones() {
+        let plan = plan()
+            .preference("gloria".into(), PreferenceValue::Bool(true))
+            .preference("gloria".into(), PreferenceValue::Bool(false));
+        assert_eq!(plan.preferences.len(), 1);
+        assert_eq!(
+            plan.preferences.get(&"gloria".into()),
+            Some(&PreferenceValue::Bool(false))
+        );
+    }
+
+    #[test]
+    fn fills_slots_nested_in_every_container() {
+        let template = Document::from(Series::from(vec![
+            Document::from(Choice::from(vec![
+                slot(),
+                Document::from(Text::from("Silence")),
+            ])),
+            Document::from(Parallel::from(vec![
+                Document::from(Text::from("Reading")),
+                slot(),
+            ])),
+        ]));
+        let filled = plan()
+            .hymn(SLOT, hymn(57))
+            .fill_hymn_slots(template)
+            .unwrap();
+        let chosen = filled
+            .flatten()
+            .into_iter()
+            .filter(|doc| doc.content == Content::HymnLink(hymn(57)))
+            .count();
+        assert_eq!(chosen, 2);
+        assert!(!filled
+            .flatten()
+            .iter()
+            .any(|doc| doc.content == Content::HymnLink(HymnLink::Hymnals)));
+    }
+
+    #[test]
+    fn fills_a_slot_with_several_hymns_in_order() {
+        let filled = plan()
+            .hymn(SLOT, hymn(57))
+            .hymn(SLOT, hymn(65))
+            .fill_hymn_slots(Document::from(Series::from(vec![slot()])))
+            .unwrap();
+        let hymns = filled
+            .flatten()
+            .into_iter()
+            .map(|doc| doc.content.clone())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            hymns,
+            vec![Content::HymnLink(hymn(57)), Content::HymnLink(hymn(65))]
+        );
+    }
+
+    #[test]
+    fn errors_if_the_template_has_no_such_slot() {
+        let result = plan()
+            .hymn("Recessional", hymn(57))
+            .fill_hymn_slots(Document::from(Series::from(vec![slot()])));
+        assert!(
+            matches!(result, Err(ServicePlanError::SlotNotFound(slot)) if slot == "Recessional")
+        );
+    }
+}
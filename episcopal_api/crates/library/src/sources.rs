@@ -0,0 +1,103 @@
+use bible::BibleProvider;
+use lectionary::OwnedLectionary;
+use liturgy::Lectionaries;
+
+use crate::LocalCalendar;
+
+/// Sources of text beyond the [Library](crate::Library) itself on which
+/// [Library::compile_with_sources](crate::Library::compile_with_sources) can draw
+#[derive(Clone, Copy)]
+pub struct CompileSources<'a> {
+    /// Source for the text of Biblical readings that can be loaded synchronously while compiling;
+    /// readings in any version it doesn't provide are left as a [BiblicalCitation](liturgy::BiblicalCitation)
+    /// to be loaded later.
+    pub bible_provider: &'a dyn BibleProvider,
+    /// Collects and readings for feasts that are observed locally
+    pub local_calendar: Option<&'a LocalCalendar>,
+    /// Lectionaries loaded at runtime, each of which is used in place of the built-in table
+    /// for the given choice of lectionary. Each must include every reading for that choice:
+    /// e.g., one of the RCL tracks includes the readings shared by both tracks.
+    pub lectionaries: &'a [(Lectionaries, OwnedLectionary)],
+}
+
+impl Default for CompileSources<'_> {
+    /// Readings from the translations installed locally as USX files, no local feasts,
+    /// and only the built-in lectionaries
+    fn default() -> Self {
+        Self {
+            bible_provider: &*bible::OFFLINE_BIBLES,
+            local_calendar: None,
+            lectionaries: &[],
+        }
+    }
+}
+
+impl<'a> CompileSources<'a> {
+    #[must_use]
+    pub fn with_bible_provider(self, bible_provider: &'a dyn BibleProvider) -> Self {
+        Self {
+            bible_provider,
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn with_local_calendar(self, local_calendar: &'a LocalCalendar) -> Self {
+        Self {
+            local_calendar: Some(local_calendar),
+            ..self
+        }
+    }
+
+    /// ```
+    /// # use calendar::{Date, BCP1979_CALENDAR};
+    /// # use lectionary::{OwnedLectionary, RCL, RCL_TRACK_1};
+    /// # use library::{CommonPrayer, CompileSources, Library};
+    /// # use library::rite2::eucharist::HOLY_EUCHARIST_II;
+    /// # use liturgy::{Content, Lectionaries, LiturgyPreferences};
+    /// # use std::collections::HashMap;
+    /// let mut corrected = OwnedLectionary::from(&RCL_TRACK_1);
+    /// corrected.readings.extend(OwnedLectionary::from(&RCL).readings);
+    /// for (_, _, _, citation) in corrected.readings.iter_mut() {
+    ///     if citation == "Matthew 5:1-12" {
+    ///         *citation = "Matthew 5:1-16".to_string();
+    ///     }
+    /// }
+    /// let lectionaries = [(Lectionaries::RCLTrack1, corrected)];
+    /// let liturgy_prefs = match &HOLY_EUCHARIST_II.content {
+    ///     Content::Liturgy(liturgy) => liturgy.preferences.clone(),
+    ///     _ => LiturgyPreferences::default(),
+    /// };
+    ///
+    /// // All Saints' Day, 11/1/2020
+    /// let day = BCP1979_CALENDAR.liturgical_day(Date::from_ymd(2020, 11, 1), false);
+    /// let doc = CommonPrayer::compile_with_sources(
+    ///     HOLY_EUCHARIST_II.clone(),
+    ///     &BCP1979_CALENDAR,
+    ///     &day,
+    ///     &day.observed,
+    ///     &HashMap::new(),
+    ///     &liturgy_prefs,
+    ///     CompileSources::default().with_lectionaries(&lectionaries),
+    /// )
+    /// .unwrap();
+    /// assert!(doc.flatten().iter().any(|doc| matches!(&doc.content,
+    ///     Content::BiblicalCitation(citation) if citation.citation == "Matthew 5:1-16"
+    /// )));
+    /// ```
+    #[must_use]
+    pub fn with_lectionaries(self, lectionaries: &'a [(Lectionaries, OwnedLectionary)]) -> Self {
+        Self {
+            lectionaries,
+            ..self
+        }
+    }
+
+    /// The lectionary loaded at runtime to be used for the given choice of lectionary, if any
+    pub fn lectionary(&self, lectionary: Lectionaries) -> Option<&'a OwnedLectionary> {
+        self.lectionaries
+            .iter()
+            .find(|(s_lectionary, _)| *s_lectionary == lectionary)
+            .map(|(_, lectionary)| lectionary)
+    }
+}
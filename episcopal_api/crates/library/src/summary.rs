@@ -5,7 +5,7 @@ use api::summary::{
     FirstLessonAndPsalm, ObservanceSummary, PartialDailySummary, TrackedReadings,
 };
 use calendar::{
-    Calendar, Date, Feast, LiturgicalDay, LiturgicalDayId, Weekday, BCP1979_CALENDAR,
+    Calendar, Color, Date, Feast, LiturgicalDay, LiturgicalDayId, Weekday, BCP1979_CALENDAR,
     LFF2018_CALENDAR,
 };
 use canticle_table::CanticleId;
@@ -51,9 +51,15 @@ impl CommonPrayer {
         language: Language,
     ) -> EucharisticLectionarySummary {
         let psalter = &BCP1979_PSALTER;
+        // Ember and Rogation Days can be kept in place of the propers of the day
+        let ember_and_rogation_days = day
+            .holy_days
+            .iter()
+            .filter(|feast| matches!(feast, Feast::EmberDay | Feast::RogationDay));
         let alternates = day
             .alternative_services
             .iter()
+            .chain(ember_and_rogation_days)
             .map(|alternate| {
                 summarize_eucharistic_observance(
                     &day,
@@ -102,6 +108,7 @@ fn summarize_eucharistic_observance(
     psalter: &Psalter,
 ) -> EucharisticObservanceSummary {
     let localized_name = localize_day_name(day, observance, &BCP1979_CALENDAR, language);
+    let (color, color_alternatives) = observance_colors(day, observance);
     let collects = CommonPrayer::compile(
         Document::from(Content::CollectOfTheDay {
             allow_multiple: false,
@@ -144,6 +151,8 @@ fn summarize_eucharistic_observance(
     EucharisticObservanceSummary {
         observance: *observance,
         localized_name,
+        color,
+        color_alternatives,
         collects,
         vigil_readings,
         tracked_readings,
@@ -301,6 +310,7 @@ fn summarize_observance(
     language: Language,
 ) -> ObservanceSummary {
     let localized_name = localize_day_name(day, observance, &BCP1979_CALENDAR, language);
+    let (color, color_alternatives) = observance_colors(day, observance);
     let bcp_black_letter_days = black_letter_days(&BCP1979_CALENDAR, day, &day.holy_days, language);
     let lff_black_letter_days = black_letter_days(&LFF2018_CALENDAR, day, lff_holy_days, language);
 
@@ -339,6 +349,8 @@ fn summarize_observance(
     ObservanceSummary {
         observance: *observance,
         localized_name,
+        color,
+        color_alternatives,
         bcp_black_letter_days,
         lff_black_letter_days,
         daily_office_readings,
@@ -347,6 +359,19 @@ fn summarize_observance(
     }
 }
 
+// colors are given for the observance being summarized, which may be an alternative to the one observed
+#[cfg(any(feature = "browser", feature = "server"))]
+fn observance_colors(day: &LiturgicalDay, observance: &LiturgicalDayId) -> (Color, Vec<Color>) {
+    let day = LiturgicalDay {
+        observed: *observance,
+        ..day.clone()
+    };
+    (
+        BCP1979_CALENDAR.color(&day),
+        BCP1979_CALENDAR.color_alternatives(&day),
+    )
+}
+
 fn black_letter_days(
     calendar: &Calendar,
     day: &LiturgicalDay,
@@ -383,16 +408,10 @@ pub fn localize_day_name(
             calendar.feast_name(*feast, language).map(String::from)
         }
         _ => calendar.week_name(day.week, language).map(|name| {
-            let weekday_name = day.weekday.to_string();
             if day.weekday == Weekday::Sun {
                 name.to_string()
             } else {
-                format!(
-                    "{} {} {}",
-                    language.i18n(&weekday_name),
-                    language.i18n("after"),
-                    name.replace("The", "the")
-                )
+                language.weekday_after(day.weekday.as_ref(), name)
             }
         }),
     }
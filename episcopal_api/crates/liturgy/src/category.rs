@@ -38,15 +38,33 @@ pub enum Categories {
 }
 
 impl Categories {
-    // TODO i18n when we add other languages
-    pub fn localized_name(&self, _language: Language) -> &'static str {
-        match self {
-            Categories::OpeningSentences => "Opening Sentences",
-            Categories::ClosingSentences => "Closing Sentences",
-            Categories::OffertorySentences => "Offertory Sentences",
-            Categories::InvitatoryAntiphons => "Invitatory Antiphons",
-            Categories::PrayersAndThanksgivings => "Prayers and Thanksgivings",
-            Categories::AdditionalPrayers => "Additional Prayers",
+    pub fn localized_name(&self, language: Language) -> &'static str {
+        match language {
+            Language::Es => match self {
+                Categories::OpeningSentences => "Frases de Apertura",
+                Categories::ClosingSentences => "Frases de Conclusión",
+                Categories::OffertorySentences => "Frases para el Ofertorio",
+                Categories::InvitatoryAntiphons => "Antífonas del Invitatorio",
+                Categories::PrayersAndThanksgivings => "Oraciones y Acciones de Gracias",
+                Categories::AdditionalPrayers => "Oraciones Adicionales",
+            },
+            // Haitian Creole falls back to French
+            Language::Fr | Language::Ht => match self {
+                Categories::OpeningSentences => "Phrases d’ouverture",
+                Categories::ClosingSentences => "Phrases de conclusion",
+                Categories::OffertorySentences => "Phrases d’offertoire",
+                Categories::InvitatoryAntiphons => "Antiennes de l’invitatoire",
+                Categories::PrayersAndThanksgivings => "Prières et actions de grâce",
+                Categories::AdditionalPrayers => "Prières supplémentaires",
+            },
+            Language::En => match self {
+                Categories::OpeningSentences => "Opening Sentences",
+                Categories::ClosingSentences => "Closing Sentences",
+                Categories::OffertorySentences => "Offertory Sentences",
+                Categories::InvitatoryAntiphons => "Invitatory Antiphons",
+                Categories::PrayersAndThanksgivings => "Prayers and Thanksgivings",
+                Categories::AdditionalPrayers => "Additional Prayers",
+            },
         }
     }
 }
@@ -34,6 +34,23 @@ pub enum Condition {
     /// assert_eq!(condition.include(&BCP1979_CALENDAR, &good_friday, &prefs, &liturgy_prefs), false);
     /// ```
     Feast(Feast),
+    /// Included if the given day falls within the [Octave](calendar::Octave) of the given [Feast](crate::Feast),
+    /// from the feast itself through its octave day, whatever is observed on each of those days.
+    /// ```
+    /// # use crate::liturgy::{Condition, PreferenceKey, PreferenceValue, LiturgyPreferences};
+    /// # use calendar::{Date, LiturgicalDayId, Feast, BCP1979_CALENDAR};
+    /// # let prefs : [(PreferenceKey, PreferenceValue); 0] = [];
+    /// # let liturgy_prefs = LiturgyPreferences::default();
+    /// let holy_innocents = BCP1979_CALENDAR.liturgical_day(Date::from_ymd(2022, 12, 28), false);
+    /// let condition = Condition::WithinOctave(Feast::ChristmasDay);
+    /// assert_eq!(condition.include(&BCP1979_CALENDAR, &holy_innocents, &prefs, &liturgy_prefs), true);
+    /// let january_2 = BCP1979_CALENDAR.liturgical_day(Date::from_ymd(2023, 1, 2), false);
+    /// assert_eq!(condition.include(&BCP1979_CALENDAR, &january_2, &prefs, &liturgy_prefs), false);
+    /// let easter_friday = BCP1979_CALENDAR.liturgical_day(Date::from_ymd(2022, 4, 22), false);
+    /// let condition = Condition::WithinOctave(Feast::EasterSunday);
+    /// assert_eq!(condition.include(&BCP1979_CALENDAR, &easter_friday, &prefs, &liturgy_prefs), true);
+    /// ```
+    WithinOctave(Feast),
     /// Included if the given day falls in this [Season](calendar::Season). This matches either the day’s
     /// observed season or its base season, so e.g., would be `true` for either `Saints` or `Lent` for a saint’s day
     /// in Lent, unlike [Condition::ObservedSeason].
@@ -347,6 +364,9 @@ impl Condition {
                 LiturgicalDayId::Feast(s_feast) => s_feast == feast,
                 _ => false,
             },
+            Condition::WithinOctave(feast) => {
+                matches!(day.octave, Some(octave) if octave.feast == *feast)
+            }
             Condition::ObservedSeason(season) => calendar.season(day) == *season,
             Condition::Season(season) => {
                 let day_season = calendar.season(day);
@@ -424,4 +444,331 @@ impl Condition {
             _ => true,
         }
     }
+
+    /// Evaluates the condition like [Condition::include], but returns a trace of whether each
+    /// sub-condition was met, to show why a piece of a liturgy was included or not.
+    /// ```
+    /// # use crate::liturgy::{Condition, PreferenceKey, PreferenceValue, LiturgyPreferences};
+    /// # use calendar::{Date, Season, Weekday, BCP1979_CALENDAR};
+    /// # let prefs : [(PreferenceKey, PreferenceValue); 0] = [];
+    /// # let liturgy_prefs = LiturgyPreferences::default();
+    /// let holy_saturday = BCP1979_CALENDAR.liturgical_day(Date::from_ymd(2022, 4, 16), false);
+    /// let condition = Condition::All(vec![Condition::Weekday(Weekday::Fri), Condition::Season(Season::HolyWeek)]);
+    /// let explanation = condition.explain(&BCP1979_CALENDAR, &holy_saturday, &prefs, &liturgy_prefs);
+    /// assert!(!explanation.included);
+    /// assert!(!explanation.children[0].included);
+    /// assert!(explanation.children[1].included);
+    /// assert_eq!(explanation.to_string(), "✗ All\n  ✗ Weekday(Fri)\n  ✓ Season(HolyWeek)\n");
+    /// ```
+    pub fn explain(
+        &self,
+        calendar: &Calendar,
+        day: &LiturgicalDay,
+        prefs: &impl ClientPreferences,
+        liturgy_prefs: &LiturgyPreferences,
+    ) -> ConditionExplanation {
+        let explain_all = |conds: &[&Condition]| {
+            conds
+                .iter()
+                .map(|cond| cond.explain(calendar, day, prefs, liturgy_prefs))
+                .collect::<Vec<_>>()
+        };
+        // unlike `include`, every branch is evaluated, so that the whole tree can be shown
+        let (included, children) = match self {
+            Condition::Not(cond) => {
+                let children = explain_all(&[cond]);
+                (!children[0].included, children)
+            }
+            Condition::And(a, b) => {
+                let children = explain_all(&[a, b]);
+                (children.iter().all(|child| child.included), children)
+            }
+            Condition::Or(a, b) => {
+                let children = explain_all(&[a, b]);
+                (children.iter().any(|child| child.included), children)
+            }
+            Condition::Any(conds) => {
+                let children = explain_all(&conds.iter().collect::<Vec<_>>());
+                (
+                    children.is_empty() || children.iter().any(|child| child.included),
+                    children,
+                )
+            }
+            Condition::All(conds) => {
+                let children = explain_all(&conds.iter().collect::<Vec<_>>());
+                (children.iter().all(|child| child.included), children)
+            }
+            Condition::None(conds) => {
+                let children = explain_all(&conds.iter().collect::<Vec<_>>());
+                (!children.iter().any(|child| child.included), children)
+            }
+            _ => (
+                self.include(calendar, day, prefs, liturgy_prefs),
+                Vec::new(),
+            ),
+        };
+        ConditionExplanation {
+            condition: self.clone(),
+            included,
+            children,
+        }
+    }
+
+    /// Lists every date in the given calendar year on which the condition is met.
+    /// ```
+    /// # use crate::liturgy::{Condition, PreferenceKey, PreferenceValue, LiturgyPreferences};
+    /// # use calendar::{Date, Season, Weekday, BCP1979_CALENDAR};
+    /// # let prefs : [(PreferenceKey, PreferenceValue); 0] = [];
+    /// # let liturgy_prefs = LiturgyPreferences::default();
+    /// let condition = Condition::All(vec![Condition::Weekday(Weekday::Sun), Condition::Season(Season::Advent)]);
+    /// assert_eq!(
+    ///     condition.dates_in_year(&BCP1979_CALENDAR, 2022, false, &prefs, &liturgy_prefs),
+    ///     vec![
+    ///         Date::from_ymd(2022, 11, 27),
+    ///         Date::from_ymd(2022, 12, 4),
+    ///         Date::from_ymd(2022, 12, 11),
+    ///         Date::from_ymd(2022, 12, 18)
+    ///     ]
+    /// );
+    /// ```
+    pub fn dates_in_year(
+        &self,
+        calendar: &Calendar,
+        year: u16,
+        evening: bool,
+        prefs: &impl ClientPreferences,
+        liturgy_prefs: &LiturgyPreferences,
+    ) -> Vec<Date> {
+        calendar
+            .liturgical_days(
+                Date::from_ymd(year, 1, 1)..=Date::from_ymd(year, 12, 31),
+                evening,
+            )
+            .filter(|day| self.include(calendar, day, prefs, liturgy_prefs))
+            .map(|day| day.date)
+            .collect()
+    }
+
+    /// Normalizes the condition: nested [Condition::And]/[Condition::All] and [Condition::Or]/[Condition::Any]
+    /// trees are flattened, double negatives and duplicates are removed, and branches that can
+    /// never be met (like `Season(Lent)` and `Season(Easter)` together) or are always met
+    /// (like `Evening` or not `Evening`) are detected.
+    /// ```
+    /// # use crate::liturgy::{Condition, SimplifiedCondition};
+    /// # use calendar::{Season, Weekday};
+    /// let condition = Condition::And(
+    ///     Box::new(Condition::Not(Box::new(Condition::Not(Box::new(Condition::Weekday(Weekday::Sun)))))),
+    ///     Box::new(Condition::All(vec![Condition::Season(Season::Lent), Condition::Weekday(Weekday::Sun)]))
+    /// );
+    /// assert_eq!(
+    ///     condition.simplify(),
+    ///     SimplifiedCondition::Condition(Condition::All(vec![Condition::Weekday(Weekday::Sun), Condition::Season(Season::Lent)]))
+    /// );
+    ///
+    /// let condition = Condition::And(Box::new(Condition::Season(Season::Lent)), Box::new(Condition::Season(Season::Easter)));
+    /// assert_eq!(condition.simplify(), SimplifiedCondition::Never);
+    ///
+    /// let condition = Condition::Or(Box::new(Condition::Evening), Box::new(Condition::Not(Box::new(Condition::Evening))));
+    /// assert_eq!(condition.simplify(), SimplifiedCondition::Always);
+    /// ```
+    pub fn simplify(&self) -> SimplifiedCondition {
+        match self {
+            Condition::Not(cond) => cond.simplify().negate(),
+            Condition::And(a, b) => Self::simplify_all([a.as_ref(), b.as_ref()]),
+            Condition::All(conds) => Self::simplify_all(conds),
+            Condition::Or(a, b) => Self::simplify_any([a.as_ref(), b.as_ref()]),
+            // `include` treats an empty `Any` or `None` as always met
+            Condition::Any(conds) | Condition::None(conds) if conds.is_empty() => {
+                SimplifiedCondition::Always
+            }
+            Condition::Any(conds) => Self::simplify_any(conds),
+            Condition::None(conds) => Self::simplify_any(conds).negate(),
+            _ => SimplifiedCondition::Condition(self.clone()),
+        }
+    }
+
+    /// Whether the condition can never be met, whatever the day or preferences.
+    /// ```
+    /// # use crate::liturgy::Condition;
+    /// # use calendar::Season;
+    /// let condition = Condition::All(vec![Condition::DateGte(12, 1), Condition::DateLt(11, 30)]);
+    /// assert!(condition.is_contradiction());
+    /// // a saint’s day in Lent is in both seasons
+    /// let condition = Condition::All(vec![Condition::Season(Season::Saints), Condition::Season(Season::Lent)]);
+    /// assert!(!condition.is_contradiction());
+    /// ```
+    pub fn is_contradiction(&self) -> bool {
+        self.simplify() == SimplifiedCondition::Never
+    }
+
+    fn simplify_all<'a>(conds: impl IntoIterator<Item = &'a Condition>) -> SimplifiedCondition {
+        let mut terms: Vec<Condition> = Vec::new();
+        for cond in conds {
+            let flattened = match cond.simplify() {
+                SimplifiedCondition::Always => continue,
+                SimplifiedCondition::Never => return SimplifiedCondition::Never,
+                SimplifiedCondition::Condition(Condition::All(conds)) => conds,
+                SimplifiedCondition::Condition(cond) => vec![cond],
+            };
+            for cond in flattened {
+                if terms.iter().any(|term| term.excludes(&cond)) {
+                    return SimplifiedCondition::Never;
+                }
+                if !terms.contains(&cond) {
+                    terms.push(cond);
+                }
+            }
+        }
+        SimplifiedCondition::from_terms(terms, Condition::All)
+    }
+
+    fn simplify_any<'a>(conds: impl IntoIterator<Item = &'a Condition>) -> SimplifiedCondition {
+        let mut terms: Vec<Condition> = Vec::new();
+        for cond in conds {
+            let flattened = match cond.simplify() {
+                SimplifiedCondition::Always => return SimplifiedCondition::Always,
+                SimplifiedCondition::Never => continue,
+                SimplifiedCondition::Condition(Condition::Any(conds)) => conds,
+                SimplifiedCondition::Condition(cond) => vec![cond],
+            };
+            for cond in flattened {
+                if terms.iter().any(|term| term.is_negation_of(&cond)) {
+                    return SimplifiedCondition::Always;
+                }
+                if !terms.contains(&cond) {
+                    terms.push(cond);
+                }
+            }
+        }
+        // every branch was one that can never be met
+        if terms.is_empty() {
+            SimplifiedCondition::Never
+        } else {
+            SimplifiedCondition::from_terms(terms, Condition::Any)
+        }
+    }
+
+    fn is_negation_of(&self, other: &Condition) -> bool {
+        match (self, other) {
+            (Condition::Not(a), b) | (b, Condition::Not(a)) => a.as_ref() == b,
+            (Condition::None(conds), b) | (b, Condition::None(conds)) => {
+                matches!(conds.as_slice(), [only] if only == b)
+            }
+            _ => false,
+        }
+    }
+
+    /// Whether two conditions can never both be met on the same day
+    fn excludes(&self, other: &Condition) -> bool {
+        use Condition::*;
+
+        match (self, other) {
+            (Not(a), b) | (b, Not(a)) => b.implies(a),
+            (None(conds), b) | (b, None(conds)) => conds.iter().any(|cond| b.implies(cond)),
+            (Day(a), Day(b)) => a != b,
+            (Feast(a), Feast(b)) => a != b,
+            // a day falls within at most one octave
+            (WithinOctave(a), WithinOctave(b)) => a != b,
+            (ObservedSeason(a), ObservedSeason(b)) => a != b,
+            // a day matches at most one true season: the one observed, or else its base season
+            (Season(a), Season(b))
+            | (Season(a), ObservedSeason(b))
+            | (ObservedSeason(b), Season(a)) => a != b && a.is_true_season() && b.is_true_season(),
+            (Week(a), Week(b)) => a != b,
+            (Weekday(a), Weekday(b)) => a != b,
+            (DayOfMonth(a), DayOfMonth(b)) => a != b,
+            (Preference(key_a, value_a), Preference(key_b, value_b)) => {
+                key_a == key_b && value_a != value_b
+            }
+            (DateGt(m1, d1) | DateGte(m1, d1), DateLt(m2, d2) | DateLte(m2, d2))
+            | (DateLt(m2, d2) | DateLte(m2, d2), DateGt(m1, d1) | DateGte(m1, d1)) => {
+                let exclusive = matches!(self, DateGt(_, _) | DateLt(_, _))
+                    || matches!(other, DateGt(_, _) | DateLt(_, _));
+                (m1, d1) > (m2, d2) || ((m1, d1) == (m2, d2) && exclusive)
+            }
+            _ => false,
+        }
+    }
+
+    /// Whether this condition being met guarantees that the other is met as well
+    fn implies(&self, other: &Condition) -> bool {
+        self == other
+            || matches!(other, Condition::Any(conds) if conds.contains(self))
+            || matches!(self, Condition::All(conds) if conds.contains(other))
+    }
+}
+
+/// The result of [Condition::simplify]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SimplifiedCondition {
+    /// The condition is met on every day, with any preferences
+    Always,
+    /// The condition can never be met
+    Never,
+    Condition(Condition),
+}
+
+impl SimplifiedCondition {
+    fn negate(self) -> Self {
+        match self {
+            SimplifiedCondition::Always => SimplifiedCondition::Never,
+            SimplifiedCondition::Never => SimplifiedCondition::Always,
+            SimplifiedCondition::Condition(Condition::Not(cond)) => {
+                SimplifiedCondition::Condition(*cond)
+            }
+            SimplifiedCondition::Condition(Condition::Any(conds)) => {
+                SimplifiedCondition::Condition(Condition::None(conds))
+            }
+            SimplifiedCondition::Condition(Condition::None(conds)) => {
+                SimplifiedCondition::Condition(Condition::Any(conds))
+            }
+            SimplifiedCondition::Condition(cond) => {
+                SimplifiedCondition::Condition(Condition::Not(Box::new(cond)))
+            }
+        }
+    }
+
+    fn from_terms(mut terms: Vec<Condition>, combine: fn(Vec<Condition>) -> Condition) -> Self {
+        match terms.len() {
+            0 => SimplifiedCondition::Always,
+            1 => SimplifiedCondition::Condition(terms.remove(0)),
+            _ => SimplifiedCondition::Condition(combine(terms)),
+        }
+    }
+}
+
+/// A trace of how a [Condition] was evaluated for a particular day, returned by [Condition::explain]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ConditionExplanation {
+    pub condition: Condition,
+    /// Whether this condition was met
+    pub included: bool,
+    /// Explanations for each of the conditions this one combines, if any
+    pub children: Vec<ConditionExplanation>,
+}
+
+impl ConditionExplanation {
+    fn fmt_indented(&self, f: &mut std::fmt::Formatter<'_>, depth: usize) -> std::fmt::Result {
+        let mark = if self.included { "✓" } else { "✗" };
+        let name = match &self.condition {
+            Condition::Not(_) => "Not".to_string(),
+            Condition::And(_, _) => "And".to_string(),
+            Condition::Or(_, _) => "Or".to_string(),
+            Condition::Any(_) => "Any".to_string(),
+            Condition::All(_) => "All".to_string(),
+            Condition::None(_) => "None".to_string(),
+            condition => format!("{:?}", condition),
+        };
+        writeln!(f, "{}{} {}", "  ".repeat(depth), mark, name)?;
+        for child in &self.children {
+            child.fmt_indented(f, depth + 1)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::fmt::Display for ConditionExplanation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.fmt_indented(f, 0)
+    }
 }
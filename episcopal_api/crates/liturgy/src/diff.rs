@@ -0,0 +1,893 @@
+use std::collections::HashMap;
+use std::mem::discriminant;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{Choice, Content, Document, Liturgy, Parallel, Series};
+
+/// Two children whose text shares at least this proportion of words are treated as the same document, edited
+pub(crate) const SIMILARITY_THRESHOLD: f32 = 0.5;
+
+/// A single difference between two [Document] trees, as found by [Document::diff].
+///
+/// Paths can be passed to [Document::at_path]: `from` and `path` for a deletion refer to the old document,
+/// and `to` and `path` for an insertion to the new one.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DocumentChange {
+    /// A document that appears only in the new tree
+    Insert {
+        path: Vec<usize>,
+        document: Box<Document>,
+    },
+    /// A document that appears only in the old tree
+    Delete {
+        path: Vec<usize>,
+        document: Box<Document>,
+    },
+    /// A document that was moved relative to its siblings; any changes inside it are listed separately
+    Move { from: Vec<usize>, to: Vec<usize> },
+    /// A document whose contents were changed. For a [Liturgy], [Series], [Choice], or [Parallel],
+    /// `old` and `new` include only its own fields (label, condition, etc.) and not its children,
+    /// whose changes are listed separately.
+    Edit {
+        from: Vec<usize>,
+        to: Vec<usize>,
+        old: Box<Document>,
+        new: Box<Document>,
+    },
+}
+
+impl DocumentChange {
+    /// For an [DocumentChange::Edit], a word-by-word comparison of the text of the old and new documents
+    /// ```
+    /// # use liturgy::{Document, Text, TextChange};
+    /// let old = Document::from(Text::from("Almighty God, unto whom all hearts be open"));
+    /// let new = Document::from(Text::from("Almighty God, to you all hearts are open"));
+    /// let changes = old.diff(&new);
+    /// assert_eq!(
+    ///     changes[0].text_changes(),
+    ///     vec![
+    ///         TextChange::Same("Almighty God,".into()),
+    ///         TextChange::Removed("unto whom".into()),
+    ///         TextChange::Added("to you".into()),
+    ///         TextChange::Same("all hearts".into()),
+    ///         TextChange::Removed("be".into()),
+    ///         TextChange::Added("are".into()),
+    ///         TextChange::Same("open".into()),
+    ///     ]
+    /// );
+    /// ```
+    pub fn text_changes(&self) -> Vec<TextChange> {
+        match self {
+            DocumentChange::Edit { old, new, .. } => diff_text(&old.as_text(), &new.as_text()),
+            _ => Vec::new(),
+        }
+    }
+}
+
+/// One run of words in a comparison between two texts
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TextChange {
+    Same(String),
+    Removed(String),
+    Added(String),
+}
+
+/// The result of [Document::merge]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MergeResult {
+    pub document: Document,
+    pub conflicts: Vec<MergeConflict>,
+}
+
+/// A place where both sides of a merge changed the same document in different ways.
+/// The merged document keeps “our” version, or whichever version was changed if the other side deleted it.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MergeConflict {
+    /// Path to the document in the merged document
+    pub path: Vec<usize>,
+    pub base: Option<Document>,
+    pub ours: Option<Document>,
+    pub theirs: Option<Document>,
+}
+
+impl Document {
+    /// Compares two documents, matching up the children of any [Liturgy], [Series], [Choice], or [Parallel]
+    /// by their contents, labels, and text, and lists what was inserted, deleted, moved, or edited.
+    /// ```
+    /// # use liturgy::{Document, DocumentChange, Series, Text};
+    /// let old = Document::from(Series::from([
+    ///     Document::from(Text::from("The Lord be with you.")).label("Salutation"),
+    ///     Document::from(Text::from("Let us pray.")),
+    ///     Document::from(Text::from("Almighty God, unto whom all hearts be open")).label("Collect for Purity"),
+    /// ]));
+    /// let new = Document::from(Series::from([
+    ///     Document::from(Text::from("Almighty God, to you all hearts are open")).label("Collect for Purity"),
+    ///     Document::from(Text::from("The Lord be with you.")).label("Salutation"),
+    ///     Document::from(Text::from("Amen.")),
+    /// ]));
+    /// let changes = old.diff(&new);
+    /// assert_eq!(changes.len(), 4);
+    /// assert_eq!(changes[0], DocumentChange::Delete { path: vec![1], document: Box::new(Document::from(Text::from("Let us pray."))) });
+    /// assert_eq!(changes[1], DocumentChange::Move { from: vec![2], to: vec![0] });
+    /// assert!(matches!(&changes[2], DocumentChange::Edit { from, to, .. } if from == &vec![2] && to == &vec![0]));
+    /// assert_eq!(changes[3], DocumentChange::Insert { path: vec![2], document: Box::new(Document::from(Text::from("Amen."))) });
+    /// assert_eq!(old.at_path([1]).unwrap(), &Document::from(Text::from("Let us pray.")));
+    ///
+    /// assert!(old.diff(&old).is_empty());
+    /// ```
+    pub fn diff(&self, new: &Document) -> Vec<DocumentChange> {
+        let mut changes = Vec::new();
+        diff_documents(self, new, Vec::new(), Vec::new(), &mut changes);
+        changes
+    }
+
+    /// Combines the changes made to a common `base` document in two different revisions, `ours` and `theirs`.
+    /// ```
+    /// # use liturgy::{Document, Series, Text};
+    /// let base = Document::from(Series::from([
+    ///     Document::from(Text::from("The Lord be with you.")),
+    ///     Document::from(Text::from("Let us pray.")),
+    /// ]));
+    /// let ours = Document::from(Series::from([
+    ///     Document::from(Text::from("The Lord be with you.")),
+    ///     Document::from(Text::from("And also with you.")),
+    ///     Document::from(Text::from("Let us pray.")),
+    /// ]));
+    /// let theirs = Document::from(Series::from([
+    ///     Document::from(Text::from("The Lord be with you.")).label("Salutation"),
+    ///     Document::from(Text::from("Let us pray.")),
+    ///     Document::from(Text::from("Amen.")),
+    /// ]));
+    /// let merged = Document::merge(&base, &ours, &theirs);
+    /// assert!(merged.conflicts.is_empty());
+    /// assert_eq!(merged.document, Document::from(Series::from([
+    ///     Document::from(Text::from("The Lord be with you.")).label("Salutation"),
+    ///     Document::from(Text::from("And also with you.")),
+    ///     Document::from(Text::from("Let us pray.")),
+    ///     Document::from(Text::from("Amen.")),
+    /// ])));
+    ///
+    /// // both sides change the same document
+    /// let theirs = Document::from(Series::from([
+    ///     Document::from(Text::from("The Lord be with you.")),
+    ///     Document::from(Text::from("Let us pray to the Lord.")),
+    /// ]));
+    /// let ours = Document::from(Series::from([
+    ///     Document::from(Text::from("The Lord be with you.")),
+    ///     Document::from(Text::from("Let us all pray.")),
+    /// ]));
+    /// let merged = Document::merge(&base, &ours, &theirs);
+    /// assert_eq!(merged.conflicts.len(), 1);
+    /// assert_eq!(merged.conflicts[0].path, vec![1]);
+    /// assert_eq!(merged.document, ours);
+    /// ```
+    pub fn merge(base: &Document, ours: &Document, theirs: &Document) -> MergeResult {
+        let mut conflicts = Vec::new();
+        let document = merge_documents(base, ours, theirs, Vec::new(), &mut conflicts);
+        MergeResult {
+            document,
+            conflicts,
+        }
+    }
+}
+
+fn diff_documents(
+    old: &Document,
+    new: &Document,
+    from: Vec<usize>,
+    to: Vec<usize>,
+    changes: &mut Vec<DocumentChange>,
+) {
+    if old == new {
+        return;
+    }
+    match (children(old), children(new)) {
+        (Some(old_children), Some(new_children)) if same_kind(old, new) => {
+            let (old_shell, new_shell) = (shell(old), shell(new));
+            if old_shell != new_shell {
+                changes.push(DocumentChange::Edit {
+                    from: from.clone(),
+                    to: to.clone(),
+                    old: Box::new(old_shell),
+                    new: Box::new(new_shell),
+                });
+            }
+
+            let matches = match_children(old_children, new_children);
+            let new_to_old = matched_indices(&matches, new_children.len(), |m| (m.new, m.old));
+            let old_to_new = matched_indices(&matches, old_children.len(), |m| (m.old, m.new));
+
+            for (idx, document) in old_children.iter().enumerate() {
+                if old_to_new[idx].is_none() {
+                    changes.push(DocumentChange::Delete {
+                        path: child_path(&from, idx),
+                        document: Box::new(document.clone()),
+                    });
+                }
+            }
+            for (new_idx, document) in new_children.iter().enumerate() {
+                match new_to_old[new_idx] {
+                    None => changes.push(DocumentChange::Insert {
+                        path: child_path(&to, new_idx),
+                        document: Box::new(document.clone()),
+                    }),
+                    Some(old_idx) => {
+                        let (from, to) = (child_path(&from, old_idx), child_path(&to, new_idx));
+                        let moved = matches.iter().any(|m| m.new == new_idx && m.moved);
+                        if moved {
+                            changes.push(DocumentChange::Move {
+                                from: from.clone(),
+                                to: to.clone(),
+                            });
+                        }
+                        diff_documents(&old_children[old_idx], document, from, to, changes);
+                    }
+                }
+            }
+        }
+        _ => changes.push(DocumentChange::Edit {
+            from,
+            to,
+            old: Box::new(old.clone()),
+            new: Box::new(new.clone()),
+        }),
+    }
+}
+
+fn merge_documents(
+    base: &Document,
+    ours: &Document,
+    theirs: &Document,
+    path: Vec<usize>,
+    conflicts: &mut Vec<MergeConflict>,
+) -> Document {
+    if ours == theirs || theirs == base {
+        return ours.clone();
+    }
+    if ours == base {
+        return theirs.clone();
+    }
+    match (children(base), children(ours), children(theirs)) {
+        (Some(base_children), Some(our_children), Some(their_children))
+            if same_kind(base, ours) && same_kind(base, theirs) =>
+        {
+            let merged_shell = merge_documents(
+                &shell(base),
+                &shell(ours),
+                &shell(theirs),
+                path.clone(),
+                conflicts,
+            );
+            let merged_children = merge_children(
+                base_children,
+                our_children,
+                their_children,
+                &path,
+                conflicts,
+            );
+            with_children(&merged_shell, merged_children)
+        }
+        _ => {
+            conflicts.push(MergeConflict {
+                path,
+                base: Some(base.clone()),
+                ours: Some(ours.clone()),
+                theirs: Some(theirs.clone()),
+            });
+            ours.clone()
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Side {
+    Ours,
+    Theirs,
+}
+
+/// A child in the merged list, before it is merged
+#[derive(Clone, Copy)]
+enum Slot {
+    /// A child in the base document, by its index there
+    Base(usize),
+    /// A child added on one side, by its index there
+    Added(Side, usize),
+}
+
+fn merge_children(
+    base: &[Document],
+    ours: &[Document],
+    theirs: &[Document],
+    path: &[usize],
+    conflicts: &mut Vec<MergeConflict>,
+) -> Vec<Document> {
+    let our_matches = match_children(base, ours);
+    let their_matches = match_children(base, theirs);
+    let base_to_ours = matched_indices(&our_matches, base.len(), |m| (m.old, m.new));
+    let base_to_theirs = matched_indices(&their_matches, base.len(), |m| (m.old, m.new));
+    let ours_to_base = matched_indices(&our_matches, ours.len(), |m| (m.new, m.old));
+    let theirs_to_base = matched_indices(&their_matches, theirs.len(), |m| (m.new, m.old));
+
+    // follow the order of whichever side rearranged the children; if neither or both did, follow ours
+    let follow_theirs =
+        their_matches.iter().any(|m| m.moved) && !our_matches.iter().any(|m| m.moved);
+    let (primary_side, primary, primary_to_base, secondary_side, secondary, secondary_to_base) =
+        if follow_theirs {
+            (
+                Side::Theirs,
+                theirs,
+                &theirs_to_base,
+                Side::Ours,
+                ours,
+                &ours_to_base,
+            )
+        } else {
+            (
+                Side::Ours,
+                ours,
+                &ours_to_base,
+                Side::Theirs,
+                theirs,
+                &theirs_to_base,
+            )
+        };
+    let in_primary = |base_idx: usize| primary_to_base.contains(&Some(base_idx));
+
+    // children that appear only on the secondary side go after the child that precedes them there
+    let mut placed: Vec<(Option<usize>, Slot)> = Vec::new();
+    let mut anchor = None;
+    for (idx, base_idx) in secondary_to_base.iter().enumerate() {
+        match base_idx {
+            Some(base_idx) if in_primary(*base_idx) => anchor = Some(*base_idx),
+            // deleted on the primary side, but it may have been changed on this one
+            Some(base_idx) => placed.push((anchor, Slot::Base(*base_idx))),
+            None => {
+                let added_on_both_sides = primary
+                    .iter()
+                    .enumerate()
+                    .any(|(p_idx, doc)| primary_to_base[p_idx].is_none() && *doc == secondary[idx]);
+                if !added_on_both_sides {
+                    placed.push((anchor, Slot::Added(secondary_side, idx)));
+                }
+            }
+        }
+    }
+    let placed_after = |anchor: Option<usize>| {
+        placed
+            .iter()
+            .filter(move |(s_anchor, _)| *s_anchor == anchor)
+            .map(|(_, slot)| *slot)
+    };
+
+    let mut slots = placed_after(None).collect::<Vec<_>>();
+    for (idx, base_idx) in primary_to_base.iter().enumerate() {
+        match base_idx {
+            Some(base_idx) => {
+                slots.push(Slot::Base(*base_idx));
+                slots.extend(placed_after(Some(*base_idx)));
+            }
+            None => slots.push(Slot::Added(primary_side, idx)),
+        }
+    }
+
+    let mut merged = Vec::new();
+    for slot in slots {
+        let path = child_path(path, merged.len());
+        match slot {
+            Slot::Base(base_idx) => {
+                let base_doc = &base[base_idx];
+                match (base_to_ours[base_idx], base_to_theirs[base_idx]) {
+                    (Some(our_idx), Some(their_idx)) => merged.push(merge_documents(
+                        base_doc,
+                        &ours[our_idx],
+                        &theirs[their_idx],
+                        path,
+                        conflicts,
+                    )),
+                    // deleted on one side and unchanged on the other
+                    (Some(idx), None) if ours[idx] == *base_doc => {}
+                    (None, Some(idx)) if theirs[idx] == *base_doc => {}
+                    // deleted on one side but changed on the other
+                    (Some(idx), None) => {
+                        conflicts.push(MergeConflict {
+                            path,
+                            base: Some(base_doc.clone()),
+                            ours: Some(ours[idx].clone()),
+                            theirs: None,
+                        });
+                        merged.push(ours[idx].clone());
+                    }
+                    (None, Some(idx)) => {
+                        conflicts.push(MergeConflict {
+                            path,
+                            base: Some(base_doc.clone()),
+                            ours: None,
+                            theirs: Some(theirs[idx].clone()),
+                        });
+                        merged.push(theirs[idx].clone());
+                    }
+                    (None, None) => {}
+                }
+            }
+            Slot::Added(Side::Ours, idx) => merged.push(ours[idx].clone()),
+            Slot::Added(Side::Theirs, idx) => merged.push(theirs[idx].clone()),
+        }
+    }
+    merged
+}
+
+/// A child of the old document paired with the corresponding child of the new one
+struct ChildMatch {
+    old: usize,
+    new: usize,
+    /// Whether the child has moved relative to the others that were matched
+    moved: bool,
+}
+
+/// Pairs children of the old document with the children of the new document they correspond to.
+/// As many as possible are paired in order, preferring identical children to merely similar ones;
+/// any others that correspond are treated as having moved.
+fn match_children(old: &[Document], new: &[Document]) -> Vec<ChildMatch> {
+    let old_words = old.iter().map(word_counts).collect::<Vec<_>>();
+    let new_words = new.iter().map(word_counts).collect::<Vec<_>>();
+    let scores = old
+        .iter()
+        .enumerate()
+        .map(|(old_idx, old_doc)| {
+            new.iter()
+                .enumerate()
+                .map(|(new_idx, new_doc)| {
+                    correspondence(old_doc, new_doc, &old_words[old_idx], &new_words[new_idx])
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+
+    // longest common subsequence, weighted by how closely the children match
+    let mut best = vec![vec![0_usize; new.len() + 1]; old.len() + 1];
+    for old_idx in (0..old.len()).rev() {
+        for new_idx in (0..new.len()).rev() {
+            let skip = best[old_idx + 1][new_idx].max(best[old_idx][new_idx + 1]);
+            best[old_idx][new_idx] = match scores[old_idx][new_idx] {
+                0 => skip,
+                score => skip.max(score + best[old_idx + 1][new_idx + 1]),
+            };
+        }
+    }
+    let mut matches = Vec::new();
+    let (mut old_idx, mut new_idx) = (0, 0);
+    while old_idx < old.len() && new_idx < new.len() {
+        let score = scores[old_idx][new_idx];
+        if score > 0 && best[old_idx][new_idx] == score + best[old_idx + 1][new_idx + 1] {
+            matches.push(ChildMatch {
+                old: old_idx,
+                new: new_idx,
+                moved: false,
+            });
+            old_idx += 1;
+            new_idx += 1;
+        } else if best[old_idx][new_idx] == best[old_idx + 1][new_idx] {
+            old_idx += 1;
+        } else {
+            new_idx += 1;
+        }
+    }
+
+    // pair up the rest, best matches first
+    let mut candidates = Vec::new();
+    for (old_idx, row) in scores.iter().enumerate() {
+        for (new_idx, score) in row.iter().enumerate() {
+            if *score > 0 {
+                candidates.push((*score, old_idx, new_idx));
+            }
+        }
+    }
+    candidates.sort_by_key(|(score, _, _)| std::cmp::Reverse(*score));
+    for (_, old_idx, new_idx) in candidates {
+        if !matches.iter().any(|m| m.old == old_idx || m.new == new_idx) {
+            matches.push(ChildMatch {
+                old: old_idx,
+                new: new_idx,
+                moved: true,
+            });
+        }
+    }
+    matches
+}
+
+/// How closely two documents correspond: 2 if they are identical, 1 if they seem to be versions
+/// of the same document (with the same label or similar text), or 0 if they do not correspond
+fn correspondence(
+    a: &Document,
+    b: &Document,
+    a_words: &HashMap<String, usize>,
+    b_words: &HashMap<String, usize>,
+) -> usize {
+    if a == b {
+        2
+    } else if !same_kind(a, b) {
+        0
+    } else if (a.best_label().is_some() && a.best_label() == b.best_label())
+        || similarity(a_words, b_words) >= SIMILARITY_THRESHOLD
+    {
+        1
+    } else {
+        0
+    }
+}
+
+/// How many times each word occurs in the text of a document and all its children
+pub(crate) fn word_counts(document: &Document) -> HashMap<String, usize> {
+    let mut counts = HashMap::new();
+    for child in document.flatten() {
+        for word in child.as_text().split_whitespace() {
+            let word = word
+                .trim_matches(|c: char| !c.is_alphanumeric())
+                .to_lowercase();
+            if !word.is_empty() {
+                *counts.entry(word).or_insert(0) += 1;
+            }
+        }
+    }
+    counts
+}
+
+/// The proportion of words two texts have in common, from 0.0 (none, or either is empty) to 1.0 (the same words)
+pub(crate) fn similarity(a: &HashMap<String, usize>, b: &HashMap<String, usize>) -> f32 {
+    let total = a.values().sum::<usize>() + b.values().sum::<usize>();
+    if total == 0 {
+        return 0.0;
+    }
+    let shared: usize = a
+        .iter()
+        .map(|(word, count)| (*count).min(b.get(word).copied().unwrap_or(0)))
+        .sum();
+    (2 * shared) as f32 / total as f32
+}
+
+/// Compares two texts word by word
+fn diff_text(old: &str, new: &str) -> Vec<TextChange> {
+    let old = old.split_whitespace().collect::<Vec<_>>();
+    let new = new.split_whitespace().collect::<Vec<_>>();
+    let mut lcs = vec![vec![0_usize; new.len() + 1]; old.len() + 1];
+    for old_idx in (0..old.len()).rev() {
+        for new_idx in (0..new.len()).rev() {
+            lcs[old_idx][new_idx] = if old[old_idx] == new[new_idx] {
+                lcs[old_idx + 1][new_idx + 1] + 1
+            } else {
+                lcs[old_idx + 1][new_idx].max(lcs[old_idx][new_idx + 1])
+            };
+        }
+    }
+
+    let mut changes: Vec<TextChange> = Vec::new();
+    let mut push = |change: TextChange| {
+        let merged = match (changes.last_mut(), &change) {
+            (Some(TextChange::Same(s)), TextChange::Same(word))
+            | (Some(TextChange::Removed(s)), TextChange::Removed(word))
+            | (Some(TextChange::Added(s)), TextChange::Added(word)) => {
+                s.push(' ');
+                s.push_str(word);
+                true
+            }
+            _ => false,
+        };
+        if !merged {
+            changes.push(change);
+        }
+    };
+    let (mut old_idx, mut new_idx) = (0, 0);
+    while old_idx < old.len() || new_idx < new.len() {
+        if old_idx < old.len() && new_idx < new.len() && old[old_idx] == new[new_idx] {
+            push(TextChange::Same(old[old_idx].to_string()));
+            old_idx += 1;
+            new_idx += 1;
+        } else if new_idx == new.len()
+            || (old_idx < old.len() && lcs[old_idx + 1][new_idx] >= lcs[old_idx][new_idx + 1])
+        {
+            push(TextChange::Removed(old[old_idx].to_string()));
+            old_idx += 1;
+        } else {
+            push(TextChange::Added(new[new_idx].to_string()));
+            new_idx += 1;
+        }
+    }
+    changes
+}
+
+fn children(document: &Document) -> Option<&[Document]> {
+    match &document.content {
+        Content::Liturgy(liturgy) => Some(liturgy.body.as_slice()),
+        Content::Series(series) => Some(series.as_slice()),
+        Content::Parallel(parallel) => Some(parallel.as_slice()),
+        Content::Choice(choice) => Some(choice.options.as_slice()),
+        _ => None,
+    }
+}
+
+pub(crate) fn same_kind(a: &Document, b: &Document) -> bool {
+    discriminant(&a.content) == discriminant(&b.content)
+}
+
+/// A container document with its children removed, to compare its own fields.
+/// A [Choice] keeps its `selected` index as it is, so that a change of selection is noticed.
+fn shell(document: &Document) -> Document {
+    replace_children(document, Vec::new())
+}
+
+/// A copy of a container document with different children
+fn with_children(document: &Document, children: Vec<Document>) -> Document {
+    let mut document = replace_children(document, children);
+    // the selected option has to be one of the options the choice ends up with
+    if let Content::Choice(choice) = &mut document.content {
+        choice.selected = choice.selected.min(choice.options.len().saturating_sub(1));
+    }
+    document
+}
+
+fn replace_children(document: &Document, children: Vec<Document>) -> Document {
+    let series = |original: &Series, children: Vec<Document>| {
+        let new_series = Series::from(children);
+        if original.is_indivisible() {
+            new_series.indivisible()
+        } else {
+            new_series
+        }
+    };
+    let content = match &document.content {
+        Content::Liturgy(liturgy) => Content::Liturgy(Liturgy {
+            preferences: liturgy.preferences.clone(),
+            evening: liturgy.evening,
+            body: series(&liturgy.body, children),
+        }),
+        Content::Series(original) => Content::Series(series(original, children)),
+        Content::Parallel(_) => Content::Parallel(Parallel::from(children)),
+        Content::Choice(choice) => Content::Choice(Choice {
+            selected: choice.selected,
+            options: children,
+            rotated: choice.rotated,
+            should_rotate: choice.should_rotate,
+        }),
+        content => content.clone(),
+    };
+    Document {
+        content,
+        ..document.clone()
+    }
+}
+
+fn child_path(path: &[usize], idx: usize) -> Vec<usize> {
+    let mut path = path.to_vec();
+    path.push(idx);
+    path
+}
+
+/// For each child on one side of the matches, the index of the child it was matched with on the other side
+fn matched_indices(
+    matches: &[ChildMatch],
+    len: usize,
+    pair: impl Fn(&ChildMatch) -> (usize, usize),
+) -> Vec<Option<usize>> {
+    let mut indices = vec![None; len];
+    for (from, to) in matches.iter().map(pair) {
+        indices[from] = Some(to);
+    }
+    indices
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    fn text(s: &str) -> Document {
+        Document::from(Text::from(s))
+    }
+
+    fn choice(options: &[&str], selected: usize) -> Document {
+        let mut choice = Choice::from(options.iter().map(|s| text(s)));
+        choice.selected = selected;
+        Document::from(choice)
+    }
+
+    fn selected(document: &Document) -> usize {
+        match &document.content {
+            Content::Choice(choice) => choice.selected,
+            _ => panic!("expected a Choice"),
+        }
+    }
+
+    #[test]
+    fn diff_reports_change_of_selected_option() {
+        let old = choice(&["Venite", "Jubilate", "Christ our Passover"], 0);
+        let new = choice(&["Venite", "Jubilate", "Christ our Passover"], 2);
+        let changes = old.diff(&new);
+        assert_eq!(changes.len(), 1);
+        match &changes[0] {
+            DocumentChange::Edit { from, to, old, new } => {
+                assert!(from.is_empty() && to.is_empty());
+                assert_eq!(selected(old), 0);
+                assert_eq!(selected(new), 2);
+            }
+            change => panic!("expected an Edit, found {:?}", change),
+        }
+    }
+
+    #[test]
+    fn merge_keeps_selection_made_on_one_side() {
+        let base = choice(&["Venite", "Jubilate"], 0);
+        let ours = choice(&["Venite", "Jubilate"], 1);
+        let theirs = choice(&["Venite", "Jubilate", "Christ our Passover"], 0);
+        let merged = Document::merge(&base, &ours, &theirs);
+        assert!(merged.conflicts.is_empty());
+        assert_eq!(
+            merged.document,
+            choice(&["Venite", "Jubilate", "Christ our Passover"], 1)
+        );
+    }
+
+    #[test]
+    fn merge_clamps_selection_to_remaining_options() {
+        let base = choice(&["Venite", "Jubilate", "Christ our Passover"], 0);
+        let ours = choice(&["Venite", "Jubilate", "Christ our Passover"], 2);
+        let theirs = choice(&["Venite", "Jubilate"], 0);
+        let merged = Document::merge(&base, &ours, &theirs);
+        assert!(merged.conflicts.is_empty());
+        assert_eq!(merged.document, choice(&["Venite", "Jubilate"], 1));
+    }
+
+    #[test]
+    fn diff_and_merge_parallel_columns() {
+        let base = Document::from(Parallel::from(vec![
+            text("Our Father, which art in heaven"),
+            text("Our Father in heaven"),
+        ]));
+        let ours = Document::from(Parallel::from(vec![
+            text("Our Father, who art in heaven"),
+            text("Our Father in heaven"),
+        ]));
+        let theirs = Document::from(Parallel::from(vec![
+            text("Our Father, which art in heaven"),
+            text("Our Father in heaven, hallowed be your Name"),
+        ]));
+
+        let changes = base.diff(&theirs);
+        assert_eq!(changes.len(), 1);
+        assert!(
+            matches!(&changes[0], DocumentChange::Edit { from, to, .. } if from == &vec![1] && to == &vec![1])
+        );
+
+        let merged = Document::merge(&base, &ours, &theirs);
+        assert!(merged.conflicts.is_empty());
+        assert_eq!(
+            merged.document,
+            Document::from(Parallel::from(vec![
+                text("Our Father, who art in heaven"),
+                text("Our Father in heaven, hallowed be your Name"),
+            ]))
+        );
+    }
+
+    #[test]
+    fn diff_nested_containers() {
+        let old = Document::from(Liturgy::from(Series::from(vec![
+            text("Opening Sentence"),
+            Document::from(Series::from(vec![
+                text("The Lord be with you."),
+                text("Let us pray."),
+            ])),
+        ])));
+        let new = Document::from(Liturgy::from(Series::from(vec![
+            text("Opening Sentence"),
+            Document::from(Series::from(vec![
+                text("The Lord be with you."),
+                text("And also with you."),
+                text("Let us pray to the Lord."),
+            ])),
+        ])));
+        let changes = old.diff(&new);
+        assert_eq!(changes.len(), 2);
+        assert_eq!(
+            changes[0],
+            DocumentChange::Insert {
+                path: vec![1, 1],
+                document: Box::new(text("And also with you.")),
+            }
+        );
+        assert!(
+            matches!(&changes[1], DocumentChange::Edit { from, to, .. } if from == &vec![1, 1] && to == &vec![1, 2])
+        );
+        assert_eq!(old.at_path([1, 1]).unwrap(), &text("Let us pray."));
+    }
+
+    #[test]
+    fn merge_move_on_one_side_with_edit_on_the_other() {
+        let base = Document::from(Series::from(vec![
+            text("Almighty God, unto whom all hearts be open"),
+            text("The Lord be with you."),
+            text("Let us pray."),
+        ]));
+        // ours moves the collect to the end
+        let ours = Document::from(Series::from(vec![
+            text("The Lord be with you."),
+            text("Let us pray."),
+            text("Almighty God, unto whom all hearts be open"),
+        ]));
+        // theirs edits it in place
+        let theirs = Document::from(Series::from(vec![
+            text("Almighty God, to whom all hearts are open"),
+            text("The Lord be with you."),
+            text("Let us pray."),
+        ]));
+        let merged = Document::merge(&base, &ours, &theirs);
+        assert!(merged.conflicts.is_empty());
+        assert_eq!(
+            merged.document,
+            Document::from(Series::from(vec![
+                text("The Lord be with you."),
+                text("Let us pray."),
+                text("Almighty God, to whom all hearts are open"),
+            ]))
+        );
+    }
+
+    #[test]
+    fn merge_delete_against_edit_is_a_conflict() {
+        let base = Document::from(Series::from(vec![
+            text("The Lord be with you."),
+            text("Let us pray."),
+        ]));
+        let deleted = Document::from(Series::from(vec![text("The Lord be with you.")]));
+        let edited = Document::from(Series::from(vec![
+            text("The Lord be with you."),
+            text("Let us pray to the Lord."),
+        ]));
+
+        // we deleted it, they edited it
+        let merged = Document::merge(&base, &deleted, &edited);
+        assert_eq!(
+            merged.conflicts,
+            vec![MergeConflict {
+                path: vec![1],
+                base: Some(text("Let us pray.")),
+                ours: None,
+                theirs: Some(text("Let us pray to the Lord.")),
+            }]
+        );
+        assert_eq!(merged.document, edited);
+
+        // we edited it, they deleted it
+        let merged = Document::merge(&base, &edited, &deleted);
+        assert_eq!(
+            merged.conflicts,
+            vec![MergeConflict {
+                path: vec![1],
+                base: Some(text("Let us pray.")),
+                ours: Some(text("Let us pray to the Lord.")),
+                theirs: None,
+            }]
+        );
+        assert_eq!(merged.document, edited);
+    }
+
+    #[test]
+    fn merge_deletion_of_unchanged_child() {
+        let base = Document::from(Series::from(vec![
+            text("The Lord be with you."),
+            text("Let us pray."),
+        ]));
+        let ours = Document::from(Series::from(vec![text("The Lord be with you.")]));
+        let theirs = Document::from(Series::from(vec![
+            text("The Lord be with you.").label("Salutation"),
+            text("Let us pray."),
+        ]));
+        let merged = Document::merge(&base, &ours, &theirs);
+        assert!(merged.conflicts.is_empty());
+        assert_eq!(
+            merged.document,
+            Document::from(Series::from(vec![
+                text("The Lord be with you.").label("Salutation")
+            ]))
+        );
+    }
+}
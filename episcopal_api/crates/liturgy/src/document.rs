@@ -38,6 +38,8 @@ pub struct Document {
     pub is_compiled: bool,
     #[serde(skip_serializing_if = "Vec::is_empty", default)]
     pub tags: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub translations: Vec<Document>,
 }
 
 impl Document {
@@ -57,7 +59,8 @@ impl Document {
             content: Content::Empty,
             explainer: None,
             is_compiled: false,
-            tags: Vec::new()
+            tags: Vec::new(),
+            translations: Vec::new()
         }
     }
 
@@ -201,6 +204,7 @@ impl Document {
             Content::Choice(choice) => choice.options.iter().any(|doc| doc.has_date_condition()),
             Content::Liturgy(liturgy) => liturgy.body.iter().any(|doc| doc.has_date_condition()),
             Content::CollectOfTheDay { allow_multiple: _ } => true,
+            Content::PrefaceOfTheDay => true,
             _ => false
         };
         has_own_date_condition || has_child_date_condition
@@ -229,7 +233,8 @@ impl Document {
 				content, 
                 explainer,
 				is_compiled, 
-				tags
+				tags,
+				translations
 			} = self;
 
             let content = match content {
@@ -262,6 +267,7 @@ impl Document {
                 explainer,
 				is_compiled,
 				tags,
+				translations,
 			})
         }
     }
@@ -359,6 +365,74 @@ impl Document {
         self
     }
 
+    /// Adds a translation of this document; the translation is identified by its own `language`.
+    /// ```
+    /// # use liturgy::{Document, Text};
+    /// # use language::Language;
+    /// let doc = Document::from(Text::from("The Lord be with you."))
+    ///     .translation(Document::from(Text::from("El Señor sea con ustedes.")).language(Language::Es));
+    /// assert_eq!(doc.translations.len(), 1);
+    /// ```
+    #[must_use]
+    pub fn translation(mut self, translation: Document) -> Self {
+        self.translations.push(translation);
+        self
+    }
+
+    /// This document as it should be displayed in the given [Language](language::Language): the document
+    /// itself if it is already in that language, otherwise its translation into that language or into the first of
+    /// the language’s [fallbacks](language::Language::fallback) for which one exists. If neither the document nor any
+    /// translation matches, the document is returned as it is.
+    ///
+    /// A translation supplies the text of the document (its content, labels, and source), while its
+    /// condition, display, status, and other structural metadata are kept from the original.
+    /// The returned document carries no further translations.
+    /// ```
+    /// # use liturgy::{Document, Text};
+    /// # use language::Language;
+    /// let doc = Document::from(Text::from("The Lord be with you."))
+    ///     .label("Salutation")
+    ///     .optional()
+    ///     .translation(Document::from(Text::from("El Señor sea con ustedes.")).language(Language::Es))
+    ///     .translation(Document::from(Text::from("Le Seigneur soit avec vous.")).language(Language::Fr));
+    /// let es = doc.clone().translated(Language::Es);
+    /// assert_eq!(es.as_text(), "El Señor sea con ustedes.");
+    /// assert_eq!(es.language, Language::Es);
+    /// assert_eq!(es.label, None);
+    /// assert!(es.optional);
+    /// // Haitian Creole falls back to French
+    /// assert_eq!(doc.clone().translated(Language::Ht).as_text(), "Le Seigneur soit avec vous.");
+    /// assert_eq!(doc.translated(Language::En).as_text(), "The Lord be with you.");
+    /// ```
+    pub fn translated(mut self, language: Language) -> Self {
+        let mut translations = std::mem::take(&mut self.translations);
+        let translation = language
+            .with_fallbacks()
+            .take_while(|language| *language != self.language)
+            .find_map(|language| translations.iter().position(|t| t.language == language));
+        match translation {
+            Some(idx) => {
+                let translation = translations.swap_remove(idx);
+                Self {
+                    label: translation.label,
+                    subtitle: translation.subtitle,
+                    language: translation.language,
+                    source: translation.source.or(self.source),
+                    version: if translation.version.is_default() {
+                        self.version
+                    } else {
+                        translation.version
+                    },
+                    version_label: translation.version_label,
+                    content: translation.content,
+                    explainer: translation.explainer,
+                    ..self
+                }
+            }
+            None => self,
+        }
+    }
+
     #[must_use]
     pub fn optional(mut self) -> Self {
         self.optional = true;
@@ -608,6 +682,8 @@ pub enum Content {
     /// # Lookup Fields
     /// Inserts the Collect of the Day
     CollectOfTheDay { allow_multiple: bool },
+    /// Inserts the Proper Preface appointed for the day
+    PrefaceOfTheDay,
     /// # Content Variants
     /// A document with no contents
     Empty,
@@ -676,6 +752,7 @@ impl Content {
             Content::Parallel(docs) => docs.iter().any(|doc| doc.contains(text)),
             Content::Choice(docs) => docs.options.iter().any(|doc| doc.contains(text)),
             Content::CollectOfTheDay { allow_multiple: _ } => false,
+            Content::PrefaceOfTheDay => false,
             Content::Empty => false,
             Content::Error(_) => false,
             Content::Antiphon(antiphon) => antiphon.to_string().contains(text),
@@ -738,6 +815,7 @@ impl Content {
             Content::Parallel(docs) => docs.iter().any(|doc| doc.contains_case_insensitive(text)),
             Content::Choice(docs) => docs.options.iter().any(|doc| doc.contains_case_insensitive(text)),
             Content::CollectOfTheDay { allow_multiple: _ } => false,
+            Content::PrefaceOfTheDay => false,
             Content::Empty => false,
             Content::Error(_) => false,
             Content::Antiphon(antiphon) => antiphon.to_string().to_lowercase().contains(text),
@@ -6,6 +6,8 @@ mod biblical_reading;
 mod canticle;
 mod choice;
 mod condition;
+#[cfg(any(feature = "browser", feature = "server"))]
+mod diff;
 mod display_format;
 mod document;
 mod document_error;
@@ -39,7 +41,9 @@ pub use biblical_citation::*;
 pub use biblical_reading::*;
 pub use canticle::*;
 pub use choice::*;
-pub use condition::Condition;
+pub use condition::{Condition, ConditionExplanation, SimplifiedCondition};
+#[cfg(any(feature = "browser", feature = "server"))]
+pub use diff::*;
 pub use display_format::DisplayFormat;
 pub use document::{Content, Document};
 pub use document_error::*;
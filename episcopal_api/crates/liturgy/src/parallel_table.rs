@@ -1,7 +1,11 @@
+#[cfg(any(feature = "browser", feature = "server"))]
+use std::collections::HashMap;
 use std::fmt::Display;
 
 use serde::{Deserialize, Serialize};
 
+#[cfg(any(feature = "browser", feature = "server"))]
+use crate::diff::{same_kind, similarity, word_counts, SIMILARITY_THRESHOLD};
 use crate::{Content, Document, Reference, SlugPath};
 
 #[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
@@ -12,6 +16,8 @@ pub enum ParallelDocument {
     Document(Box<Document>),
 }
 
+/// Builds a table of parallels between several documents, one column per document, lining up the parts of each
+/// document that have been tagged with each of the `parallel_tags` in turn.
 pub fn build_parallel_table<T, U>(
     parallel_tags: T,
     docs: &[(&SlugPath, &Document)],
@@ -20,6 +26,261 @@ where
     T: IntoIterator<Item = U>,
     U: Display,
 {
+    let mut parallels = header_rows(docs);
+
+    // push contents of documents
+    for tag in parallel_tags.into_iter() {
+        let mut parallel_tagged_docs = Vec::new();
+
+        // chunk each parallel doc and add them
+        for (_, doc) in docs {
+            let children_with_this_tag_in_this_doc = doc.children_with_tag(tag.to_string());
+
+            parallel_tagged_docs.push(
+                children_with_this_tag_in_this_doc
+                    .flat_map(|child| child.clone().to_smallest_chunks())
+                    .collect::<Vec<_>>(),
+            );
+        }
+
+        // rearrange from a list of columns (by doc) to a list of rows (by chunk)
+        let max_len = parallel_tagged_docs
+            .iter()
+            .map(|column| column.len())
+            .max()
+            .unwrap_or(0);
+        for row_idx in 0..max_len {
+            let row = parallel_tagged_docs
+                .iter()
+                .map(move |column| {
+                    column
+                        .get(row_idx)
+                        .cloned()
+                        .unwrap_or_else(|| Document::from(Content::Empty))
+                })
+                .collect::<Vec<_>>();
+            parallels.push(row_to_parallels(row));
+        }
+    }
+
+    parallels
+}
+
+/// Builds a table of parallels in the same shape as [build_parallel_table], but lines up the documents
+/// automatically, by comparing the labels and text of their smallest parts, rather than by tags.
+///
+/// Tags can still be used to override the alignment: a part of a document tagged with one of `override_tags`
+/// (or contained in a document with that tag) is only lined up with parts that have the same tag.
+/// ```
+/// # use liturgy::{Content, Document, Series, SlugPath, Slug, Text, Rubric, parallel_table::{build_aligned_parallel_table, ParallelDocument}};
+/// let first = Document::from(Series::from([
+///     Document::from(Text::from("The Lord be with you.")),
+///     Document::from(Text::from("Let us pray.")),
+///     Document::from(Text::from("Almighty God, unto whom all hearts be open")),
+/// ]));
+/// let second = Document::from(Series::from([
+///     Document::from(Text::from("The Lord be with you.")),
+///     Document::from(Rubric::from("The people kneel.")),
+///     Document::from(Text::from("Almighty God, to you all hearts are open")),
+/// ]));
+/// let (first_path, second_path) = (SlugPath::from([Slug::Eucharist]), SlugPath::from([Slug::Office]));
+/// let table = build_aligned_parallel_table::<[&str; 0], &str>([], &[(&first_path, &first), (&second_path, &second)]);
+/// // after the rows of sources and links, identical texts share a cell
+/// assert_eq!(table[2], vec![(ParallelDocument::Document(Box::new(Document::from(Text::from("The Lord be with you.")))), 2)]);
+/// // parts found in only one document are paired with an empty cell
+/// assert_eq!(table[3][0].0, ParallelDocument::Document(Box::new(Document::from(Text::from("Let us pray.")))));
+/// assert_eq!(table[3][1].0, ParallelDocument::Document(Box::new(Document::from(Content::Empty))));
+/// assert_eq!(table[4][1].0, ParallelDocument::Document(Box::new(Document::from(Rubric::from("The people kneel.")))));
+/// // similar texts are lined up in the same row
+/// assert_eq!(table[5].len(), 2);
+/// assert_eq!(table.len(), 6);
+/// ```
+#[cfg(any(feature = "browser", feature = "server"))]
+pub fn build_aligned_parallel_table<T, U>(
+    override_tags: T,
+    docs: &[(&SlugPath, &Document)],
+) -> Vec<Vec<(ParallelDocument, usize)>>
+where
+    T: IntoIterator<Item = U>,
+    U: Display,
+{
+    let override_tags = override_tags
+        .into_iter()
+        .map(|tag| tag.to_string())
+        .collect::<Vec<_>>();
+    let columns = docs
+        .iter()
+        .map(|(_, doc)| {
+            tagged_chunks(doc, &override_tags, None)
+                .into_iter()
+                .map(|(tag, document)| Chunk {
+                    tag,
+                    words: word_counts(&document),
+                    document,
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+
+    // align each document in turn with the rows built from the documents before it;
+    // each row holds the index of a chunk in each column, if that column has a chunk in that row
+    let mut rows: Vec<Vec<Option<usize>>> = Vec::new();
+    for (column_idx, column) in columns.iter().enumerate() {
+        let scores = rows
+            .iter()
+            .map(|row| {
+                column
+                    .iter()
+                    .map(|chunk| {
+                        row.iter()
+                            .enumerate()
+                            .filter_map(|(other_idx, other)| {
+                                other.and_then(|other| {
+                                    chunk.alignment_score(&columns[other_idx][other])
+                                })
+                            })
+                            .fold(None, |best: Option<f32>, score| {
+                                Some(best.map_or(score, |best| best.max(score)))
+                            })
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+
+        // best total score for aligning the rows from `row_idx` and chunks from `chunk_idx` onward
+        let mut best = vec![vec![0.0_f32; column.len() + 1]; rows.len() + 1];
+        for row_idx in (0..rows.len()).rev() {
+            for chunk_idx in (0..column.len()).rev() {
+                let skip = best[row_idx + 1][chunk_idx].max(best[row_idx][chunk_idx + 1]);
+                best[row_idx][chunk_idx] = match scores[row_idx][chunk_idx] {
+                    Some(score) => skip.max(score + best[row_idx + 1][chunk_idx + 1]),
+                    None => skip,
+                };
+            }
+        }
+
+        let new_row = |chunk_idx: usize| {
+            let mut row = vec![None; columns.len()];
+            row[column_idx] = Some(chunk_idx);
+            row
+        };
+        let mut aligned = Vec::new();
+        let mut old_rows = std::mem::take(&mut rows).into_iter();
+        let (mut row_idx, mut chunk_idx) = (0, 0);
+        while row_idx < scores.len() || chunk_idx < column.len() {
+            if row_idx == scores.len() {
+                aligned.push(new_row(chunk_idx));
+                chunk_idx += 1;
+            } else if chunk_idx == column.len() {
+                aligned.extend(old_rows.next());
+                row_idx += 1;
+            } else {
+                let matched = scores[row_idx][chunk_idx].filter(|score| {
+                    best[row_idx][chunk_idx] == score + best[row_idx + 1][chunk_idx + 1]
+                });
+                if matched.is_some() {
+                    if let Some(mut row) = old_rows.next() {
+                        row[column_idx] = Some(chunk_idx);
+                        aligned.push(row);
+                    }
+                    row_idx += 1;
+                    chunk_idx += 1;
+                } else if best[row_idx][chunk_idx] == best[row_idx + 1][chunk_idx] {
+                    aligned.extend(old_rows.next());
+                    row_idx += 1;
+                } else {
+                    aligned.push(new_row(chunk_idx));
+                    chunk_idx += 1;
+                }
+            }
+        }
+        rows = aligned;
+    }
+
+    let mut parallels = header_rows(docs);
+    for row in rows {
+        parallels.push(row_to_parallels(
+            row.iter()
+                .enumerate()
+                .map(|(column_idx, chunk_idx)| match chunk_idx {
+                    Some(chunk_idx) => columns[column_idx][*chunk_idx].document.clone(),
+                    None => Document::from(Content::Empty),
+                })
+                .collect(),
+        ));
+    }
+    parallels
+}
+
+/// One of the smallest parts of a document, as lined up by [build_aligned_parallel_table]
+#[cfg(any(feature = "browser", feature = "server"))]
+struct Chunk {
+    tag: Option<String>,
+    document: Document,
+    words: HashMap<String, usize>,
+}
+
+#[cfg(any(feature = "browser", feature = "server"))]
+impl Chunk {
+    /// How well two chunks correspond, or `None` if they should not be lined up at all
+    fn alignment_score(&self, other: &Chunk) -> Option<f32> {
+        match (&self.tag, &other.tag) {
+            (Some(tag), Some(other_tag)) if tag == other_tag => {
+                Some(1.0 + similarity(&self.words, &other.words))
+            }
+            (None, None) if same_kind(&self.document, &other.document) => {
+                let same_label = self.document.best_label().is_some()
+                    && self.document.best_label() == other.document.best_label();
+                let score =
+                    similarity(&self.words, &other.words) + if same_label { 0.5 } else { 0.0 };
+                if score >= SIMILARITY_THRESHOLD {
+                    Some(score)
+                } else {
+                    None
+                }
+            }
+            _ => None,
+        }
+    }
+}
+
+/// The smallest parts of a document, each with the override tag of the part of the document it comes from, if any
+#[cfg(any(feature = "browser", feature = "server"))]
+fn tagged_chunks(
+    doc: &Document,
+    override_tags: &[String],
+    tag: Option<&String>,
+) -> Vec<(Option<String>, Document)> {
+    let tag = doc
+        .tags
+        .iter()
+        .find(|s_tag| override_tags.contains(s_tag))
+        .or(tag);
+    let children = match &doc.content {
+        Content::Liturgy(liturgy) => Some(liturgy.body.as_slice()),
+        Content::Series(series) if !series.is_indivisible() => Some(series.as_slice()),
+        _ => None,
+    };
+    match children {
+        Some(children) => children
+            .iter()
+            .flat_map(|child| tagged_chunks(child, override_tags, tag))
+            .collect(),
+        None => doc
+            .clone()
+            .to_smallest_chunks()
+            // skip blank paragraphs used for spacing
+            .filter(|chunk| {
+                !matches!(&chunk.content, Content::Empty)
+                    && !matches!(&chunk.content, Content::Text(text) if text.text.trim().is_empty())
+            })
+            .map(|chunk| (tag.cloned(), chunk))
+            .collect(),
+    }
+}
+
+/// Rows giving the source, a link to, and the explainer (if any) for each document
+fn header_rows(docs: &[(&SlugPath, &Document)]) -> Vec<Vec<(ParallelDocument, usize)>> {
     let mut parallels: Vec<Vec<(ParallelDocument, usize)>> = Vec::new();
 
     // push source links
@@ -53,77 +314,40 @@ where
         );
     }
 
-    // push contents of documents
-    for tag in parallel_tags.into_iter() {
-        let mut parallel_tagged_docs = Vec::new();
-
-        // chunk each parallel doc and add them
-        for (_, doc) in docs {
-            let children_with_this_tag_in_this_doc = doc.children_with_tag(tag.to_string());
-
-            parallel_tagged_docs.push(
-                children_with_this_tag_in_this_doc
-                    .flat_map(|child| child.clone().to_smallest_chunks())
-                    .collect::<Vec<_>>(),
-            );
-        }
-
-        // rearrange from a list of columns (by doc) to a list of rows (by chunk)
-        let mut chunked_rows = Vec::new();
-
-        let max_len = parallel_tagged_docs
-            .iter()
-            .map(|column| column.len())
-            .max()
-            .unwrap_or(0);
-        for row_idx in 0..max_len {
-            chunked_rows.push(
-                parallel_tagged_docs
-                    .iter()
-                    .map(move |column| {
-                        column
-                            .get(row_idx)
-                            .cloned()
-                            .unwrap_or_else(|| Document::from(Content::Empty))
-                    })
-                    .collect::<Vec<_>>(),
-            );
-        }
+    parallels
+}
 
-        // deduplicate/expand width of columns
-        for row in chunked_rows {
-            let mut parallels_for_this_row: Vec<(ParallelDocument, usize)> = Vec::new();
+/// Turns a row of documents, one per column, into cells, with identical neighboring documents
+/// combined into one wider cell
+fn row_to_parallels(row: Vec<Document>) -> Vec<(ParallelDocument, usize)> {
+    let mut parallels_for_this_row: Vec<(ParallelDocument, usize)> = Vec::new();
 
-            for (column_id, column) in row.iter().enumerate() {
-                let prev_child = if column_id == 0 {
-                    None
-                } else {
-                    row.get(column_id - 1)
-                };
-                if prev_child.is_none()
-                    || prev_child.unwrap().content != column.content
-                    || prev_child.unwrap().version != column.version
+    for (column_id, column) in row.iter().enumerate() {
+        let prev_child = if column_id == 0 {
+            None
+        } else {
+            row.get(column_id - 1)
+        };
+        if prev_child.is_none()
+            || prev_child.unwrap().content != column.content
+            || prev_child.unwrap().version != column.version
+        {
+            let mut width = 1;
+            for subsequent_idx in (column_id + 1)..row.len() {
+                let subsequent_doc = row.get(subsequent_idx);
+                if subsequent_doc.is_some()
+                    && subsequent_doc.unwrap().content == column.content
+                    && subsequent_doc.unwrap().version == column.version
                 {
-                    let mut width = 1;
-                    for subsequent_idx in (column_id + 1)..row.len() {
-                        let subsequent_doc = row.get(subsequent_idx);
-                        if subsequent_doc.is_some()
-                            && subsequent_doc.unwrap().content == column.content
-                            && subsequent_doc.unwrap().version == column.version
-                        {
-                            width += 1;
-                        } else {
-                            break;
-                        }
-                    }
-                    parallels_for_this_row
-                        .push((ParallelDocument::Document(Box::new(column.clone())), width));
+                    width += 1;
+                } else {
+                    break;
                 }
             }
-
-            parallels.push(parallels_for_this_row);
+            parallels_for_this_row
+                .push((ParallelDocument::Document(Box::new(column.clone())), width));
         }
     }
 
-    parallels
+    parallels_for_this_row
 }
@@ -156,6 +156,10 @@ pub enum Lectionaries {
     BCP1979ThirtyDayPsalms,
     RCLTrack1,
     RCLTrack2,
+    /// The *Common Worship* Principal Service Lectionary, with the continuous (Track 1) readings after Trinity
+    CommonWorshipTrack1,
+    /// The *Common Worship* Principal Service Lectionary, with the related (Track 2) readings after Trinity
+    CommonWorshipTrack2,
 }
 
 impl Default for Lectionaries {
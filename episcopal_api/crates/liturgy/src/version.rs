@@ -49,6 +49,12 @@ pub enum Version {
     CEB,
     /// The Reina-Valera (1909)
     RV09,
+    /// The World English Bible
+    WEB,
+    /// The Louis Segond translation (1910)
+    LSG,
+    /// A Haitian Creole translation of the Bible
+    HaitianCreole,
 }
 
 impl Version {
@@ -87,6 +93,9 @@ impl Display for Version {
             Version::KJV => "KJV",
             Version::CEB => "CEB",
             Version::RV09 => "Reina-Valera",
+            Version::WEB => "WEB",
+            Version::LSG => "Louis Segond",
+            Version::HaitianCreole => "Bib la",
             Version::Parallel => "Parallels",
         };
         write!(f, "{}", label)
@@ -131,6 +140,11 @@ impl TryFrom<&str> for Version {
             "Reina-Valera" => Ok(Self::RV09),
             "RV" => Ok(Self::RV09),
             "RV09" => Ok(Self::RV09),
+            "WEB" => Ok(Self::WEB),
+            "LSG" => Ok(Self::LSG),
+            "Louis Segond" => Ok(Self::LSG),
+            "HaitianCreole" => Ok(Self::HaitianCreole),
+            "Bib la" => Ok(Self::HaitianCreole),
             _ => Err(VersionConversionError::Invalid(value.to_string())),
         }
     }
@@ -148,6 +162,8 @@ impl From<Version> for Language {
     fn from(version: Version) -> Self {
         match version {
             Version::LibroDeOracionComun | Version::RV09 => Language::Es,
+            Version::LSG => Language::Fr,
+            Version::HaitianCreole => Language::Ht,
             _ => Language::En,
         }
     }
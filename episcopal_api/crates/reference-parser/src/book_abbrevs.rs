@@ -1,6 +1,6 @@
 use crate::Book;
 
-pub const BOOKS: [(&str, Book); 166] = [
+pub const BOOKS: [(&str, Book); 232] = [
     ("Genesis", Book::Genesis),
     ("Gen.", Book::Genesis),
     ("Exodus", Book::Exodus),
@@ -43,6 +43,7 @@ pub const BOOKS: [(&str, Book); 166] = [
     ("Esther", Book::Esther),
     ("Job", Book::Job),
     ("Psalms", Book::Psalms),
+    ("Psalm", Book::Psalms),
     ("Ps.", Book::Psalms),
     ("Proverbs", Book::Proverbs),
     ("Prov.", Book::Proverbs),
@@ -167,4 +168,70 @@ pub const BOOKS: [(&str, Book); 166] = [
     ("Judith", Book::Judith),
     ("Tob.", Book::Tobit),
     ("Tobit", Book::Tobit),
+    // Spanish names
+    ("Génesis", Book::Genesis),
+    ("Éxodo", Book::Exodus),
+    ("Levítico", Book::Leviticus),
+    ("Números", Book::Numbers),
+    ("Deuteronomio", Book::Deuteronomy),
+    ("Josué", Book::Joshua),
+    ("Jueces", Book::Judges),
+    ("Rut", Book::Ruth),
+    ("1 Reyes", Book::FirstKings),
+    ("2 Reyes", Book::SecondKings),
+    ("1 Crónicas", Book::FirstChronicles),
+    ("2 Crónicas", Book::SecondChronicles),
+    ("Nehemías", Book::Nehemiah),
+    ("Salmo", Book::Psalms),
+    ("Salmos", Book::Psalms),
+    ("Proverbios", Book::Proverbs),
+    ("Eclesiastés", Book::Ecclesiastes),
+    ("Cantares", Book::SongOfSolomon),
+    ("Isaías", Book::Isaiah),
+    ("Jeremías", Book::Jeremiah),
+    ("Lamentaciones", Book::Lamentations),
+    ("Ezequiel", Book::Ezekiel),
+    ("Oseas", Book::Hosea),
+    ("Amós", Book::Amos),
+    ("Abdías", Book::Obadiah),
+    ("Jonás", Book::Jonah),
+    ("Miqueas", Book::Micah),
+    ("Nahúm", Book::Nahum),
+    ("Habacuc", Book::Habakkuk),
+    ("Sofonías", Book::Zephaniah),
+    ("Hageo", Book::Haggai),
+    ("Zacarías", Book::Zechariah),
+    ("Malaquías", Book::Malachi),
+    ("Sabiduría", Book::Wisdom),
+    ("Eclesiástico", Book::Ecclesiasticus),
+    ("Baruc", Book::Baruch),
+    ("Tobías", Book::Tobit),
+    ("Judit", Book::Judith),
+    ("Mateo", Book::Matthew),
+    ("Marcos", Book::Mark),
+    ("Lucas", Book::Luke),
+    ("Juan", Book::John),
+    ("Hechos", Book::Acts),
+    ("Romanos", Book::Romans),
+    ("1 Corintios", Book::FirstCorinthians),
+    ("2 Corintios", Book::SecondCorinthians),
+    ("Gálatas", Book::Galatians),
+    ("Efesios", Book::Ephesians),
+    ("Filipenses", Book::Philippians),
+    ("Colosenses", Book::Colossians),
+    ("1 Tesalonicenses", Book::FirstThessalonians),
+    ("2 Tesalonicenses", Book::SecondThessalonians),
+    ("1 Timoteo", Book::FirstTimothy),
+    ("2 Timoteo", Book::SecondTimothy),
+    ("Tito", Book::Titus),
+    ("Filemón", Book::Philemon),
+    ("Hebreos", Book::Hebrews),
+    ("Santiago", Book::James),
+    ("1 Pedro", Book::FirstPeter),
+    ("2 Pedro", Book::SecondPeter),
+    ("1 Juan", Book::FirstJohn),
+    ("2 Juan", Book::SecondJohn),
+    ("3 Juan", Book::ThirdJohn),
+    ("Judas", Book::Jude),
+    ("Apocalipsis", Book::Revelation),
 ];
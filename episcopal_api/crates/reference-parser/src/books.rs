@@ -135,6 +135,22 @@ impl From<&String> for Book {
 }
 
 impl Book {
+    /// The book with exactly this name or abbreviation, ignoring case. Unlike [Book::from], which
+    /// finds the closest match for any string, this can be used to tell whether a word names a book at all.
+    /// ```
+    /// # use reference_parser::Book;
+    /// assert_eq!(Book::from_name("1 juan"), Some(Book::FirstJohn));
+    /// assert_eq!(Book::from_name("Psalm"), Some(Book::Psalms));
+    /// assert_eq!(Book::from_name("Basado"), None);
+    /// ```
+    pub fn from_name(name: &str) -> Option<Self> {
+        let name = name.trim().to_lowercase();
+        BOOKS
+            .iter()
+            .find(|(s_name, _)| s_name.to_lowercase() == name)
+            .map(|(_, book)| *book)
+    }
+
     pub fn book_short_name(&self, _language: Language) -> &'static str {
         // TODO other languages
         match self {
@@ -341,4 +357,14 @@ mod tests {
         assert_eq!(Book::from("1 Ch"), Book::FirstChronicles);
         assert_eq!(Book::from("Phil"), Book::Philippians);
     }
+
+    #[test]
+    fn spanish_names() {
+        assert_eq!(Book::from("Juan"), Book::John);
+        assert_eq!(Book::from("1 Juan"), Book::FirstJohn);
+        assert_eq!(Book::from("Salmos"), Book::Psalms);
+        assert_eq!(Book::from("Hechos"), Book::Acts);
+        assert_eq!(Book::from("Efesios"), Book::Ephesians);
+        assert_eq!(Book::from("Santiago"), Book::James);
+    }
 }
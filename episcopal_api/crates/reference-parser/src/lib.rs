@@ -74,6 +74,31 @@ impl BibleReference {
         self.ranges.iter().any(|range| range.contains(verse))
     }
 
+    /// Tests whether any verse is included in both this reference and the other.
+    /// ```
+    /// # use crate::reference_parser::BibleReference;
+    /// let reference = BibleReference::from("Luke 1:46-55");
+    /// assert!(reference.overlaps(&BibleReference::from("Luke 1:39-49")));
+    /// assert!(reference.overlaps(&BibleReference::from("Luke 1")));
+    /// assert!(!reference.overlaps(&BibleReference::from("Luke 1:57-80")));
+    /// assert!(!reference.overlaps(&BibleReference::from("John 1:46-55")));
+    /// let reference = BibleReference::from("Psalm 34:1-10, 22");
+    /// assert!(reference.overlaps(&BibleReference::from("Psalm 34:15-22")));
+    /// assert!(!reference.overlaps(&BibleReference::from("Psalm 34:11-21")));
+    /// ```
+    pub fn overlaps(&self, other: &BibleReference) -> bool {
+        self.ranges
+            .iter()
+            .filter_map(BibleReferenceRange::bounds)
+            .any(|(start, end)| {
+                other
+                    .ranges
+                    .iter()
+                    .filter_map(BibleReferenceRange::bounds)
+                    .any(|(other_start, other_end)| start <= other_end && other_start <= end)
+            })
+    }
+
     /// Returns the first verse listed in any range in the reference, if there are any
     pub fn first_verse(&self) -> Option<(Book, u16, u16)> {
         self.ranges.get(0).and_then(|range| {
@@ -1,4 +1,4 @@
-use crate::{BibleReferenceQuery, BibleVerse, BibleVersePart};
+use crate::{BibleReferenceQuery, BibleVerse, BibleVersePart, Book};
 use serde::{Deserialize, Serialize};
 
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug, Serialize, Deserialize)]
@@ -8,7 +8,29 @@ pub struct BibleReferenceRange {
     pub bracketed: bool,
 }
 
+/// (book, chapter, verse), which sort in the order in which they appear in the Bible
+pub(crate) type VerseIndex = (Book, u16, u16);
+
 impl BibleReferenceRange {
+    /// The first and last verses in the range; a range that gives
+    /// chapters but no verses runs to the end of its last chapter
+    pub(crate) fn bounds(&self) -> Option<(VerseIndex, VerseIndex)> {
+        let start_book = self.start.book?;
+        let start_chapter = self.start.chapter.unwrap_or(1);
+        let start = (start_book, start_chapter, self.start.verse.unwrap_or(1));
+
+        let end_book = self.end.and_then(|end| end.book).unwrap_or(start_book);
+        let end_chapter = self
+            .end
+            .and_then(|end| end.chapter)
+            .unwrap_or(start_chapter);
+        let end_verse = match self.end {
+            Some(end) => end.verse.unwrap_or(u16::MAX),
+            None => self.start.verse.unwrap_or(u16::MAX),
+        };
+        Some((start, (end_book, end_chapter, end_verse)))
+    }
+
     pub(crate) fn contains(&self, verse: BibleVerse) -> bool {
         if let Some(start_book) = self.start.book {
             let start_chapter = self.start.chapter.unwrap_or(1);
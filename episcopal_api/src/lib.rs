@@ -3,11 +3,14 @@ pub use bible;
 pub use calendar;
 pub use canticle_table;
 pub use docx;
+pub use epub;
+pub use export_markdown;
 pub use hymnal;
 pub use language;
 pub use ldf;
 pub use lectionary;
 pub use library;
 pub use liturgy;
+pub use pdf;
 pub use psalter;
 pub use reference_parser;
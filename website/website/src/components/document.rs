@@ -196,6 +196,7 @@ pub fn document_view(
             Content::CollectOfTheDay { allow_multiple } => {
                 collect_of_the_day(locale, *allow_multiple, doc.version)
             }
+            Content::PrefaceOfTheDay => preface_of_the_day(locale, doc.version),
             Content::DocumentLink { label, path, .. } => document_link(locale, label, path),
             Content::Empty => empty(),
             Content::Error(content) => error(content),
@@ -841,6 +842,11 @@ pub fn collect_of_the_day(locale: &str, _allow_multiple: bool, version: Version)
     (None, main)
 }
 
+pub fn preface_of_the_day(locale: &str, version: Version) -> HeaderAndMain {
+    let path = SlugPath::from([Slug::Eucharist, Slug::ProperPrefaces, Slug::Version(version)]);
+    document_link(locale, &t!("lookup.proper_preface"), &path)
+}
+
 pub fn document_class(doc: &Document) -> String {
     format!(
         "{}{}",
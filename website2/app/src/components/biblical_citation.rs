@@ -95,9 +95,13 @@ impl BiblicalCitationLoader {
             let citation = citation.clone();
             let link = link.clone();
             spawn_local(async move {
-                match fetch::<BibleReadingFromAPI>(&url, None).await {
+                match fetch::<bible::ApiReading>(&url, None).await {
                     Ok(res) => link.send(&BiblicalCitationMsg::FetchResult(
-                        res.api_data_to_biblical_reading(&citation.citation, &citation.intro),
+                        res.api_data_to_biblical_reading_with(
+                            &citation.citation,
+                            &citation.intro,
+                            strip_entities,
+                        ),
                     )),
                     Err(e) => link.send(&BiblicalCitationMsg::FetchError(e)),
                 };
@@ -105,3 +109,20 @@ impl BiblicalCitationLoader {
         })
     }
 }
+
+#[cfg(target_arch = "wasm32")]
+fn strip_entities(text: String) -> String {
+    // textarea hack — avoids additional WASM size/Rust code at the expense of slow JS interop
+    match document().create_element("textarea") {
+        Ok(textarea) => {
+            textarea.set_inner_html(&text);
+            textarea.text_content().unwrap_or(text)
+        }
+        Err(_) => text,
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn strip_entities(text: String) -> String {
+    bible::strip_entities(text)
+}
@@ -127,6 +127,60 @@ impl Component for ExportLinks {
                                 </button>
                             </form>
 
+                            // PDF: letter-size pages, or a half-letter booklet ready to be printed and folded
+                            <form class="pdf" target="_blank" method="post" action="/api/export/pdf">
+                                <input type="hidden" name="liturgy" value={&self.slug}/>
+                                <input type="hidden" name="date" value={&self.date}/>
+                                <input type="hidden" name="doc" value={&json}/>
+                                <button type="submit">
+                                    <img src="/static/icons/tabler-icon-download.svg"/>
+                                    {t!("export.pdf")}
+                                </button>
+                            </form>
+                            <form class="pdf-booklet" target="_blank" method="post" action="/api/export/pdf">
+                                <input type="hidden" name="liturgy" value={&self.slug}/>
+                                <input type="hidden" name="date" value={&self.date}/>
+                                <input type="hidden" name="doc" value={&json}/>
+                                <input type="hidden" name="booklet" value="true"/>
+                                <button type="submit">
+                                    <img src="/static/icons/tabler-icon-download.svg"/>
+                                    {t!("export.pdf_booklet")}
+                                </button>
+                            </form>
+
+                            // EPUB: downloaded as an e-book, with major headings in its table of contents
+                            <form class="epub" method="post" action="/api/export/epub">
+                                <input type="hidden" name="liturgy" value={&self.slug}/>
+                                <input type="hidden" name="date" value={&self.date}/>
+                                <input type="hidden" name="doc" value={&json}/>
+                                <button type="submit">
+                                    <img src="/static/icons/tabler-icon-download.svg"/>
+                                    {t!("export.epub")}
+                                </button>
+                            </form>
+
+                            // Markdown and plain text: rendered by the server and downloaded as a file
+                            <form class="markdown" method="post" action="/api/export/markdown">
+                                <input type="hidden" name="liturgy" value={&self.slug}/>
+                                <input type="hidden" name="date" value={&self.date}/>
+                                <input type="hidden" name="doc" value={&json}/>
+                                <input type="hidden" name="format" value="markdown"/>
+                                <button type="submit">
+                                    <img src="/static/icons/tabler-icon-download.svg"/>
+                                    {t!("export.markdown")}
+                                </button>
+                            </form>
+                            <form class="text" method="post" action="/api/export/markdown">
+                                <input type="hidden" name="liturgy" value={&self.slug}/>
+                                <input type="hidden" name="date" value={&self.date}/>
+                                <input type="hidden" name="doc" value={&json}/>
+                                <input type="hidden" name="format" value="text"/>
+                                <button type="submit">
+                                    <img src="/static/icons/tabler-icon-download.svg"/>
+                                    {t!("export.text")}
+                                </button>
+                            </form>
+
                             // Venite
                             <button class="link venite" /* on:click={
                                 let status = status.clone();
@@ -1,5 +1,5 @@
 use calendar::{
-    Date, Feast, LiturgicalDay, LiturgicalDayId, Rank, BCP1979_CALENDAR, LFF2018_CALENDAR, Weekday,
+    Color, Date, Feast, LiturgicalDay, LiturgicalDayId, Rank, BCP1979_CALENDAR, LFF2018_CALENDAR, Weekday,
 };
 use crate::Icon;
 use crate::components::Modal;
@@ -25,6 +25,8 @@ pub struct CalendarDayEntry {
     listing: Option<(String, LiturgicalDay)>,
     alternatives: Vec<(String, Feast)>,
     other_notes: Vec<(Feast, String)>,
+    color: Color,
+    color_alternatives: Vec<Color>,
 }
 
 #[derive(Params)]
@@ -72,6 +74,8 @@ impl Loader for CalendarView {
                 if current_date.year() == year && current_date.month() == month {
                     let liturgical_day = calendar.liturgical_day(current_date, false);
                     let rank = calendar.rank(&liturgical_day);
+                    let color = calendar.color(&liturgical_day);
+                    let color_alternatives = calendar.color_alternatives(&liturgical_day);
 
                     let other_notes = liturgical_day
                         .holy_days
@@ -124,6 +128,8 @@ impl Loader for CalendarView {
                         listing: marked_on_calendar,
                         alternatives,
                         other_notes,
+                        color,
+                        color_alternatives,
                     })
                 } else {
                     None
@@ -293,7 +299,7 @@ impl CalendarView {
          // calendar days
 		let days = self.days
 				.into_iter()
-				.map(|CalendarDayEntry { day, listing, alternatives, other_notes, black_letter_days, month, .. }| {
+				.map(|CalendarDayEntry { day, listing, alternatives, other_notes, black_letter_days, month, color, color_alternatives }| {
 					let listing = if let Some((day_name, liturgical_day)) = listing {
 						let transferred = if matches!(
 							liturgical_day.observed,
@@ -366,10 +372,30 @@ impl CalendarView {
                         })
                     };
 
+                    let color_name = if color_alternatives.is_empty() {
+                        t!(&format!("colors.{}", color))
+                    } else {
+                        t!(
+                            "calendar.color_with_alternatives",
+                            color = &t!(&format!("colors.{}", color)),
+                            alternatives = &color_alternatives
+                                .iter()
+                                .map(|color| t!(&format!("colors.{}", color)))
+                                .join(", ")
+                        )
+                    };
+                    let colors = std::iter::once(color)
+                        .chain(color_alternatives)
+                        .map(|color| view! {
+                            <span class={format!("liturgical-color {}", color.as_ref().to_lowercase())}></span>
+                        })
+                        .collect::<Vec<_>>();
+
 					view! {
 						<time datetime={format!("{}-{:02}-{:02}", self.year, self.month, day)} class={class}>
 							<a id={format!("{}/{}", self.month, day)}></a>
 							<div class="month-number">{day.to_string()}</div>
+							<div class="liturgical-colors" title={color_name.clone()} aria-label={color_name}>{colors}</div>
 							{listing}
                             {black_letter_days}
                             {other_notes}
@@ -1,9 +1,11 @@
 use crate::{components::Tabs, utils::encode_uri};
 use api::summary::{DocumentOrReading, EucharisticObservanceSummary, TrackedReadings};
-use calendar::{Date, Feast, LiturgicalDay, LiturgicalDayId};
+use calendar::{Date, Feast, LiturgicalDay, LiturgicalDayId, BCP1979_CALENDAR};
 use docx::DocxDocument;
 use futures::{future::join_all, Future};
+use hymnal::{suggest_hymns, HymnSuggestion, EL_HIMNARIO, HYMNAL_1982, LEVAS, WLP};
 use language::Language;
+use lectionary::{rcl_readings, RCLTrack};
 use leptos2::*;
 use library::CommonPrayer;
 use liturgy::{Content, Document, DocumentError, Heading, HeadingLevel, Parallel, Version};
@@ -17,6 +19,9 @@ use super::{
     views::*,
 };
 
+/// The number of hymn suggestions shown below the readings
+const SUGGESTED_HYMNS: usize = 10;
+
 pub struct EucharistView {
     pub locale: String,
     pub day: LiturgicalDay,
@@ -34,6 +39,7 @@ pub struct EucharistView {
     pub gospel: Vec<ReadingLoader>,
     pub vigil_readings: Vec<DocumentOrReadingLoader>,
     pub liturgy_of_the_palms: Vec<ReadingLoader>,
+    pub hymn_suggestions: Vec<HymnSuggestion>,
 }
 
 #[derive(Debug)]
@@ -138,6 +144,7 @@ impl Loader for EucharistView {
             vigil_readings,
             observance,
             collects,
+            ..
         } = observed;
 
         // Select the particular readings we need, and set up async loading
@@ -203,6 +210,27 @@ impl Loader for EucharistView {
             })
             .collect();
 
+        // Suggest hymns for whichever observance is being shown
+        let track = if using_track_two {
+            RCLTrack::Two
+        } else {
+            RCLTrack::One
+        };
+        let observed_day = LiturgicalDay {
+            observed: observance,
+            ..summary.day.clone()
+        };
+        let readings = rcl_readings(&observance, &observed_day, track).collect::<Vec<_>>();
+        let hymn_suggestions = suggest_hymns(
+            &BCP1979_CALENDAR,
+            &observed_day,
+            &readings,
+            [&*HYMNAL_1982, &*LEVAS, &*WLP, &*EL_HIMNARIO],
+        )
+        .into_iter()
+        .take(SUGGESTED_HYMNS)
+        .collect();
+
         Some(Self {
             locale: locale.to_string(),
             localized_name,
@@ -220,6 +248,7 @@ impl Loader for EucharistView {
             gospel,
             liturgy_of_the_palms,
             vigil_readings,
+            hymn_suggestions,
         })
     }
 
@@ -338,6 +367,7 @@ impl View for EucharistView {
         let has_vigil = !self.vigil_readings.is_empty();
         let psalm_after_epistle = self.first_lesson.is_empty();
         let psalm_view = self.psalm_view();
+        let hymn_suggestions_view = self.hymn_suggestions_view();
 
         let track_selection = if self.is_tracked {
             Some(view! {
@@ -453,6 +483,8 @@ impl View for EucharistView {
 
                     {async_readings_view(&self.locale, self.gospel)}
 
+                    {hymn_suggestions_view}
+
                     {no_readings_link}
                 </section>
             </div>
@@ -617,6 +649,39 @@ impl EucharistView {
         }
     }
 
+    fn hymn_suggestions_view(&self) -> Option<Node> {
+        if self.hymn_suggestions.is_empty() {
+            None
+        } else {
+            let hymns = self
+                .hymn_suggestions
+                .iter()
+                .map(|suggestion| {
+                    let hymn = &suggestion.hymn;
+                    let reasons = suggestion
+                        .reasons
+                        .iter()
+                        .map(|reason| view! { <li>{reason.to_string()}</li> })
+                        .collect::<Vec<_>>();
+                    view! {
+                        <li>
+                            <a href={format!("/{}/hymn/{:?}/{}", self.locale, hymn.source, hymn.number)}>
+                                {format!("{} {} – {}", hymn.source, hymn.number, hymn.title)}
+                            </a>
+                            <ul class="reasons">{reasons}</ul>
+                        </li>
+                    }
+                })
+                .collect::<Vec<_>>();
+            Some(view! {
+                <section class="hymn-suggestions">
+                    <h2>{t!("lectionary.suggested_hymns")}</h2>
+                    <ul>{hymns}</ul>
+                </section>
+            })
+        }
+    }
+
     fn psalm_view(&self) -> Vec<Node> {
         if self.psalm.is_empty() {
             vec![]
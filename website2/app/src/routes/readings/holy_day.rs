@@ -10,11 +10,13 @@ use calendar::{
 use docx::DocxDocument;
 use itertools::Itertools;
 use language::Language;
-use lectionary::{ReadingType, LFF2018_LECTIONARY, RCL};
+use lectionary::{observed_for_lookup, ReadingType, LFF2018_LECTIONARY, RCL};
 use leptos2::*;
 use library::{
     lff2018::collects::{LFF_COLLECTS_CONTEMPORARY, LFF_COLLECTS_TRADITIONAL},
-    CollectId,
+    rite1::collects::COLLECTS_TRADITIONAL,
+    rite2::collects::COLLECTS_CONTEMPORARY,
+    CollectData, CollectId, COLLECT_LINKS,
 };
 use liturgy::{Choice, Content, Document, Heading, HeadingLevel, Parallel, Psalm, Text, Version};
 use psalter::bcp1979::BCP1979_PSALTER;
@@ -115,8 +117,10 @@ impl Loader for HolyDayView {
             .readings
             .iter()
             .chain(LFF2018_LECTIONARY.readings.iter());
+        // Ember and Rogation Days are listed under the corresponding Various Occasions
+        let reading_id = observed_for_lookup(&LiturgicalDayId::Feast(feast));
         let readings = lectionary
-            .filter(|(id, _, _, _)| id == &LiturgicalDayId::Feast(feast))
+            .filter(|(id, _, _, _)| id == &reading_id)
             .map(|(_, _, reading_type, citation)| (*reading_type, citation.to_string()))
             .unique()
             .collect::<Vec<_>>();
@@ -126,21 +130,21 @@ impl Loader for HolyDayView {
         let epistle = filter_readings(&readings, ReadingType::SecondReading, version);
         let gospel = filter_readings(&readings, ReadingType::Gospel, version);
 
-        let collect_traditional = LFF_COLLECTS_TRADITIONAL
-            .iter()
-            .find(|(id, _)| {
-                *id == CollectId::Feast(feast) || Some(id) == eve_of.map(CollectId::Feast).as_ref()
-            })
-            .map(|(_, data)| data.document.clone())
-            .unwrap_or_else(|| Document::from(Content::Empty));
-
-        let collect_contemporary = LFF_COLLECTS_CONTEMPORARY
-            .iter()
-            .find(|(id, _)| {
-                *id == CollectId::Feast(feast) || Some(id) == eve_of.map(CollectId::Feast).as_ref()
-            })
-            .map(|(_, data)| data.document.clone())
-            .unwrap_or_else(|| Document::from(Content::Empty));
+        let collect_ids = [
+            CollectId::Feast(feast),
+            CollectId::Feast(eve_of.unwrap_or(feast)),
+            COLLECT_LINKS.linked_id(&CollectId::Feast(feast)),
+        ];
+        let collect_traditional = holy_day_collect(
+            LFF_COLLECTS_TRADITIONAL.as_slice(),
+            COLLECTS_TRADITIONAL.as_slice(),
+            &collect_ids,
+        );
+        let collect_contemporary = holy_day_collect(
+            LFF_COLLECTS_CONTEMPORARY.as_slice(),
+            COLLECTS_CONTEMPORARY.as_slice(),
+            &collect_ids,
+        );
 
         Some(HolyDayView {
             date,
@@ -214,6 +218,28 @@ impl Loader for HolyDayView {
     }
 }
 
+/// The collect for a holy day from *Lesser Feasts and Fasts*, or, for days that it does not include
+/// (like Ember and Rogation Days), the collect or collects from the Prayer Book
+fn holy_day_collect(
+    lff_collects: &[(CollectId, CollectData)],
+    bcp_collects: &[(CollectId, CollectData)],
+    ids: &[CollectId],
+) -> Document {
+    lff_collects
+        .iter()
+        .find(|(id, _)| ids.contains(id))
+        .map(|(_, data)| data.document.clone())
+        .or_else(|| {
+            Document::choice_or_document(
+                &mut bcp_collects
+                    .iter()
+                    .filter(|(id, _)| ids.contains(id))
+                    .map(|(_, data)| data.document.clone()),
+            )
+        })
+        .unwrap_or_else(|| Document::from(Content::Empty))
+}
+
 fn filter_readings(
     readings: &[(ReadingType, String)],
     reading_type: ReadingType,
@@ -1,15 +1,14 @@
 use std::pin::Pin;
 
+#[cfg(not(target_arch = "wasm32"))]
+use bible::{BibleProvider, BibleProviderError, CachedBibleProvider};
+use bible::{HttpBibleProvider, DEFAULT_BIBLE_API_URL};
 use futures::Future;
 use leptos2::*;
 use liturgy::{BiblicalReading, BiblicalReadingIntro, Version};
-use reference_parser::{BibleVerse, BibleVersePart, Book};
-use serde::{Deserialize, Serialize};
-
-use reqwest::Client;
 
 use crate::routes::document::views::biblical_reading;
-use crate::utils::{encode_uri, fetch::FetchError};
+use crate::utils::fetch::FetchError;
 
 struct Cache<K, V>(moka::sync::Cache<K, V>)
 where
@@ -40,13 +39,25 @@ where
 }
 
 lazy_static::lazy_static! {
-    static ref CLIENT: Client = Client::new();
-
     // the assumption is that we'll typically want to cache a few days of Daily Office readings,
     // and maybe a few Sundays of RCL readings, but not much more
     static ref READINGS_CACHE: Cache<(String, Version), BiblicalReading> = Cache::new();
 }
 
+#[cfg(not(target_arch = "wasm32"))]
+lazy_static::lazy_static! {
+    // readings are loaded from the Bible API given by `BIBLE_API_URL` (so it can be self-hosted),
+    // and cached on disk in `BIBLE_CACHE_DIR` so that each one only needs to be requested once
+    static ref BIBLE_PROVIDER: Box<dyn BibleProvider> = Box::new(CachedBibleProvider::new(
+        HttpBibleProvider::new(
+            std::env::var("BIBLE_API_URL").unwrap_or_else(|_| bible_api().url)
+        ),
+        std::env::var("BIBLE_CACHE_DIR")
+            .map(std::path::PathBuf::from)
+            .unwrap_or_else(|_| std::env::temp_dir().join("commonprayer-bible-cache"))
+    ));
+}
+
 pub type ReadingFuture =
     Pin<Box<dyn Future<Output = Result<BiblicalReading, FetchError>> + Send + Sync>>;
 
@@ -91,31 +102,22 @@ impl ReadingLoader {
         match READINGS_CACHE.get(&(citation.clone(), version)) {
             Some(reading) => ReadingLoader::Sync(reading),
             None => {
-                let url = reading_url(&citation, version);
-
                 let reading = Box::pin({
                     let citation = citation.clone();
                     async move {
-                        println!("\n\n(load_reading url = ), {url}");
-                        let reading = CLIENT
-                            .get(&url)
-                            .send()
-                            .await
-                            .map_err(|e| {
-                                eprintln!("\n\n(load_reading request) error \n{:#?}", e);
-                                if e.is_connect() {
-                                    FetchError::Connection
-                                } else {
-                                    FetchError::Server
-                                }
-                            })?
-                            .json::<BibleReadingFromAPI>()
-                            .await
-                            .map_err(|e| {
-                                eprintln!("\n\n(load_reading JSON) error \n{:#?}", e);
-                                FetchError::Json
-                            })
-                            .map(|reading| reading.api_data_to_biblical_reading(&citation, &intro));
+                        // providers load readings synchronously, so don't block the async runtime
+                        let reading = tokio::task::spawn_blocking({
+                            let citation = citation.clone();
+                            move || BIBLE_PROVIDER.get_reading(version, &citation)
+                        })
+                        .await
+                        .map_err(|_| FetchError::Server)?
+                        .map_err(|e| match e {
+                            BibleProviderError::Http(_) => FetchError::Connection,
+                            BibleProviderError::Json(_) => FetchError::Json,
+                            _ => FetchError::Server,
+                        })
+                        .map(|reading| BiblicalReading { intro, ..reading });
                         if let Ok(reading) = &reading {
                             READINGS_CACHE.insert((citation, version), reading.clone());
                         }
@@ -216,135 +218,10 @@ fn reading_loaded_script(path: &[usize], reading: &BiblicalReading) -> Node {
     }
 }
 
-#[derive(Deserialize, Clone, Default, Debug, PartialEq, Serialize)]
-pub struct BibleReadingFromAPI {
-    pub citation: String,
-    pub label: String,
-    pub version: Version,
-    pub value: Vec<serde_json::value::Value>,
-}
-
-#[derive(Debug)]
-enum ReadingContent {
-    Verse(BibleVerse, String),
-    ParagraphBreak,
-}
-
-impl BibleReadingFromAPI {
-    pub fn api_data_to_biblical_reading(
-        &self,
-        citation: &str,
-        intro: &Option<BiblicalReadingIntro>,
-    ) -> BiblicalReading {
-        let mut text = Vec::new();
-        let parts = self
-            .value
-            .iter()
-            .filter_map(|line| {
-                let book = line.get("book");
-                let chapter = line.get("chapter");
-                let verse = line.get("verse");
-                let text = line.get("text");
-                let ldf_type = line.get("type");
-                match (ldf_type, book, chapter, verse, text) {
-                    (Some(value), ..) => {
-                        if value == &serde_json::Value::String("heading".to_string()) {
-                            Some(ReadingContent::ParagraphBreak)
-                        } else {
-                            None
-                        }
-                    }
-                    (_, Some(book), Some(chapter), Some(verse), Some(text)) => {
-                        let text = text.as_str().unwrap_or_default().to_string();
-                        let book = Book::from(book.as_str().unwrap_or_default());
-                        let chapter = chapter
-                            .as_str()
-                            .unwrap_or_default()
-                            .parse()
-                            .unwrap_or_default();
-                        let verse = verse
-                            .as_str()
-                            .unwrap_or_default()
-                            .parse()
-                            .unwrap_or_default();
-
-                        Some(ReadingContent::Verse(
-                            BibleVerse {
-                                book,
-                                chapter,
-                                verse,
-                                verse_part: BibleVersePart::All,
-                            },
-                            strip_entities(text),
-                        ))
-                    }
-                    _ => None,
-                }
-            })
-            .collect::<Vec<_>>();
-        for (idx, piece) in parts.iter().enumerate() {
-            let peeked = parts.get(idx + 1);
-            let this_verse = match (piece, peeked) {
-                (ReadingContent::Verse(verse, text), None) => {
-                    Some((verse.to_owned(), text.to_owned()))
-                }
-                (ReadingContent::Verse(verse, text), Some(ReadingContent::Verse(..))) => {
-                    Some((verse.to_owned(), text.to_owned()))
-                }
-                (ReadingContent::Verse(verse, text), Some(ReadingContent::ParagraphBreak)) => {
-                    Some((verse.to_owned(), text.to_owned() + "\n\n"))
-                }
-                (ReadingContent::ParagraphBreak, _) => None,
-            };
-            if let Some(data) = this_verse {
-                text.push(data);
-            }
-        }
-
-        BiblicalReading {
-            citation: citation.to_string(),
-            intro: intro.clone(),
-            text,
-        }
-    }
+fn bible_api() -> HttpBibleProvider {
+    HttpBibleProvider::new(option_env!("BIBLE_API_URL").unwrap_or(DEFAULT_BIBLE_API_URL))
 }
 
 pub fn reading_url(citation: &str, version: Version) -> String {
-    format!(
-        "https://us-central1-venite-2.cloudfunctions.net/bible?citation={}&version={}",
-        encode_uri(citation),
-        version
-    )
-}
-
-#[cfg(target_arch = "wasm32")]
-fn strip_entities(text: String) -> String {
-    // textarea hack — avoids additional WASM size/Rust code at the expense of slow JS interop
-    match document().create_element("textarea") {
-        Ok(textarea) => {
-            textarea.set_inner_html(&text);
-            textarea.text_content().unwrap_or(text)
-        }
-        Err(_) => text,
-    }
-}
-
-#[cfg(not(target_arch = "wasm32"))]
-fn strip_entities(text: String) -> String {
-    // HTML handles certain entities in a way that's not actually Unicode,
-    // so we need to fix them manually — we can't actually just use a normal entity replacement
-    // see https://stackoverflow.com/questions/7031633/146-is-getting-converted-as-u0092-by-nokogiri-in-ruby-on-rails
-    text.replace("&#141;", "‘")
-        .replace("&#142;", "’")
-        .replace("&#143;", "“")
-        .replace("&#144;", "”")
-        .replace("&#146;", "’")
-        .replace("&#147;", "“")
-        .replace("&#148;", "”")
-        .replace("&#149;", "‘")
-        .replace("&#150;", "’")
-        .replace("&#151;", "—")
-        .replace("&#152;", "”")
-        .replace("&#153;", "–")
-        .replace("&#154;", "—")
+    bible_api().reading_url(citation, version)
 }
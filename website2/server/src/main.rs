@@ -2,14 +2,17 @@
 #![feature(const_fn_trait_bound)]
 #![allow(unused_parens)]
 
-use std::{collections::HashSet, convert::Infallible, fs::File};
+use std::{convert::Infallible, fs::File};
 
 use actix_cors::Cors;
 use actix_files::{Files, NamedFile};
 use actix_web::{
     dev::Service,
     error, get,
-    http::StatusCode,
+    http::{
+        header::{Charset, ContentDisposition, DispositionParam, DispositionType, ExtendedValue},
+        StatusCode,
+    },
     middleware, post,
     web::{self, Query},
     App, HttpRequest, HttpResponse, HttpServer, ResponseError, Result,
@@ -17,11 +20,15 @@ use actix_web::{
 use app::{api::bing::BingSearchResult, routes::router};
 use episcopal_api::{
     api::summary::DailySummary,
-    calendar::Date,
-    hymnal::{HymnMetadata, HymnNumber, Hymnal, Hymnals, EL_HIMNARIO, HYMNAL_1982, LEVAS, WLP},
+    calendar::{Calendar, Date, BCP1979_CALENDAR},
+    hymnal::{
+        suggest_hymns, AlternativeTune, HymnMetadata, HymnNumber, HymnSuggestion, Hymnal, Hymnals,
+        EL_HIMNARIO, HYMNAL_1982, HYMN_SEARCH_INDEX, LEVAS, METRICAL_INDEX, WLP,
+    },
     language::Language,
-    library::{CommonPrayer, Library},
-    liturgy::{Document, Slug, SlugPath},
+    lectionary::{common_worship_readings, rcl_readings, RCLTrack},
+    library::{CommonPrayer, Library, ServicePlan},
+    liturgy::{Document, Lectionaries, Slug, SlugPath},
 };
 use futures::StreamExt;
 use lazy_static::lazy_static;
@@ -88,11 +95,20 @@ async fn main() -> std::io::Result<()> {
                 .app_data(web::Data::new(pool.clone()))
                 .service(health_check)
                 //.service(daily_summary)
+                .service(service_plan_api)
                 .service(export_docx)
+                .service(export_markdown)
+                .service(export_pdf)
+                .service(export_epub)
+                .service(export_psalter_epub)
+                .service(export_contents_epub)
+                .service(calendar_ics)
                 //.service(canticle_list_api)
                 //.service(hymnal_api)
-                //.service(hymnal_search_api)
+                .service(hymnal_search_api)
                 //.service(hymnal_search_api_with_metadata)
+                .service(hymn_suggestions_api)
+                .service(alternative_tunes_api)
                 .service(video_search_api)
                 //.service(hymnal_word_cloud)
                 .service(Files::new("/client", &format!("{}/client", *PROJECT_ROOT)))
@@ -272,6 +288,28 @@ async fn daily_summary(params: web::Path<(String, String)>) -> Result<web::Json<
     Ok(web::Json(summary))
 }
 
+// iCalendar Export API
+const MAX_CALENDAR_EXPORT_DAYS: i64 = 366 * 5;
+
+#[get("/api/calendar/{calendar}/{locale}/{start}/{end}.ics")]
+async fn calendar_ics(
+    params: web::Path<(String, String, String, String)>,
+) -> Result<HttpResponse> {
+    let (calendar, locale, start, end) = params.into_inner();
+    let calendar = Calendar::from(calendar.as_str());
+    let language = Language::from_locale(&locale);
+    let start = Date::parse_from_str(&start, "%Y-%m-%d").map_err(DateError)?;
+    let end = Date::parse_from_str(&end, "%Y-%m-%d").map_err(DateError)?;
+    // limit the size of a single request to a few years
+    if (end - start).num_days() > MAX_CALENDAR_EXPORT_DAYS {
+        return Err(error::ErrorBadRequest("date range is too long"));
+    }
+    let ics = calendar.to_ical(start, end, language);
+    Ok(HttpResponse::Ok()
+        .content_type("text/calendar; charset=utf-8")
+        .body(ics))
+}
+
 // Canticle List API
 #[get("/api/canticles.json")]
 async fn canticle_list_api() -> Result<web::Json<Vec<Document>>> {
@@ -297,56 +335,87 @@ struct HymnalSearchParams {
     hymnal: Option<Hymnals>,
 }
 
+/// Hymns matching the search, from most to least relevant
+fn hymnal_search(params: &HymnalSearchParams) -> impl Iterator<Item = HymnMetadata> + '_ {
+    HYMN_SEARCH_INDEX
+        .search(&params.q)
+        .into_iter()
+        .filter(|hymn| {
+            params
+                .hymnal
+                .map(|hymnal| hymn.source == hymnal)
+                .unwrap_or(true)
+        })
+}
+
 #[get("/api/hymnal/search")]
 async fn hymnal_search_api(
     params: Query<HymnalSearchParams>,
-) -> web::Json<HashSet<(Hymnals, HymnNumber)>> {
-    let search = &params.q;
-    let matches = if let Some(hymnal) = &params.hymnal {
-        let hymnal = Hymnal::from(*hymnal);
-        hymnal
-            .search(search)
-            .map(|hymn| (hymnal.id, hymn.number))
-            .collect()
-    } else {
-        HYMNAL_1982
-            .search(search)
-            .map(|hymn| (Hymnals::Hymnal1982, hymn.number))
-            .chain(
-                LEVAS
-                    .search(search)
-                    .map(|hymn| (Hymnals::LEVAS, hymn.number)),
-            )
-            .chain(WLP.search(search).map(|hymn| (Hymnals::WLP, hymn.number)))
-            .chain(
-                EL_HIMNARIO
-                    .search(search)
-                    .map(|hymn| (Hymnals::ElHimnario, hymn.number)),
-            )
-            .collect()
-    };
-
-    web::Json(matches)
+) -> web::Json<Vec<(Hymnals, HymnNumber)>> {
+    web::Json(
+        hymnal_search(&params)
+            .map(|hymn| (hymn.source, hymn.number))
+            .collect(),
+    )
 }
 
 #[get("/api/hymnal/search_with_metadata")]
 async fn hymnal_search_api_with_metadata(
     params: Query<HymnalSearchParams>,
 ) -> web::Json<Vec<HymnMetadata>> {
-    let search = &params.q;
-    let matches = if let Some(hymnal) = &params.hymnal {
-        let hymnal = Hymnal::from(*hymnal);
-        hymnal.search(search).collect()
-    } else {
-        HYMNAL_1982
-            .search(search)
-            .chain(LEVAS.search(search))
-            .chain(WLP.search(search))
-            .chain(EL_HIMNARIO.search(search))
-            .collect()
-    };
+    web::Json(hymnal_search(&params).collect())
+}
+
+// Alternative Tunes API
+#[get("/api/hymnal/{hymnal}/{number}/tunes.json")]
+async fn alternative_tunes_api(
+    path: web::Path<(Hymnals, HymnNumber)>,
+) -> web::Json<Vec<AlternativeTune>> {
+    let (hymnal, number) = path.into_inner();
+    web::Json(METRICAL_INDEX.alternative_tunes(hymnal, number))
+}
 
-    web::Json(matches)
+// Hymn Suggestion API
+#[derive(Deserialize, Debug)]
+struct HymnSuggestionParams {
+    hymnal: Option<Hymnals>,
+    /// The calendar used to find the day, as in the iCalendar export (`bcp1979`, `lff2018`, or `cw`); defaults to the 1979 BCP
+    calendar: Option<String>,
+    /// The lectionary from which the day's readings are drawn; defaults to Track One of the Revised Common Lectionary
+    lectionary: Option<Lectionaries>,
+}
+
+#[get("/api/hymnal/suggestions/{date}.json")]
+async fn hymn_suggestions_api(
+    date: web::Path<String>,
+    params: Query<HymnSuggestionParams>,
+) -> Result<web::Json<Vec<HymnSuggestion>>> {
+    let date = Date::parse_from_str(&date, "%Y-%m-%d").map_err(DateError)?;
+    let calendar = params
+        .calendar
+        .as_deref()
+        .map(Calendar::from)
+        .unwrap_or(BCP1979_CALENDAR);
+    let day = calendar.liturgical_day(date, false);
+    let readings: Vec<_> = match params.lectionary.unwrap_or(Lectionaries::RCLTrack1) {
+        Lectionaries::RCLTrack1 => rcl_readings(&day.observed, &day, RCLTrack::One).collect(),
+        Lectionaries::RCLTrack2 => rcl_readings(&day.observed, &day, RCLTrack::Two).collect(),
+        Lectionaries::CommonWorshipTrack1 => {
+            common_worship_readings(&day.observed, &day, RCLTrack::One).collect()
+        }
+        Lectionaries::CommonWorshipTrack2 => {
+            common_worship_readings(&day.observed, &day, RCLTrack::Two).collect()
+        }
+        lectionary => CommonPrayer::lectionary(lectionary)
+            .readings_by_day(&day.observed, &day)
+            .collect(),
+    };
+    let hymnals = [&*HYMNAL_1982, &*LEVAS, &*WLP, &*EL_HIMNARIO]
+        .into_iter()
+        .filter(|hymnal| params.hymnal.map(|id| id == hymnal.id).unwrap_or(true));
+    Ok(web::Json(suggest_hymns(
+        &calendar, &day, &readings, hymnals,
+    )))
 }
 
 // Hymn Video API
@@ -387,6 +456,29 @@ struct DocxExportFormData {
     doc: String,
 }
 
+// Service Plan API
+#[derive(Debug)]
+pub struct ServicePlanError(episcopal_api::library::ServicePlanError);
+
+impl std::fmt::Display for ServicePlanError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl ResponseError for ServicePlanError {
+    fn status_code(&self) -> StatusCode {
+        StatusCode::BAD_REQUEST
+    }
+}
+
+/// Compiles a [ServicePlan] into a single document, which can be passed on to the export APIs
+#[post("/api/plan.json")]
+async fn service_plan_api(plan: web::Json<ServicePlan>) -> Result<web::Json<Document>> {
+    let doc = plan.compile::<CommonPrayer>().map_err(ServicePlanError)?;
+    Ok(web::Json(doc))
+}
+
 // Document Export APIs
 #[post("/api/export/docx")]
 async fn export_docx(data: web::Form<DocxExportFormData>) -> Result<NamedFile> {
@@ -408,3 +500,190 @@ async fn export_docx(data: web::Form<DocxExportFormData>) -> Result<NamedFile> {
         .map_err(|e| error::InternalError::new(e.to_string(), StatusCode::INTERNAL_SERVER_ERROR))?;
     Ok(NamedFile::open(path)?)
 }
+
+#[derive(Deserialize)]
+struct MarkdownExportFormData {
+    liturgy: String,
+    date: String,
+    doc: String,
+    /// `"text"` for plain text; otherwise Markdown
+    #[serde(default)]
+    format: String,
+}
+
+#[post("/api/export/markdown")]
+async fn export_markdown(data: web::Form<MarkdownExportFormData>) -> Result<HttpResponse> {
+    let data = data.into_inner();
+    let doc: Document = serde_json::from_str(&data.doc)?;
+
+    let (body, extension, content_type) = if data.format == "text" {
+        (
+            episcopal_api::export_markdown::PlainTextDocument::from(doc).to_string(),
+            "txt",
+            "text/plain; charset=utf-8",
+        )
+    } else {
+        (
+            episcopal_api::export_markdown::MarkdownDocument::from(doc).to_string(),
+            "md",
+            "text/markdown; charset=utf-8",
+        )
+    };
+
+    let file_name = export_file_name(&data.liturgy, &data.date, extension);
+
+    Ok(HttpResponse::Ok()
+        .content_type(content_type)
+        .insert_header(content_disposition(DispositionType::Attachment, &file_name))
+        .body(body))
+}
+
+/// The name of an exported file, like `eucharist-2022-05-31.pdf`, from the liturgy's slug path and the date
+fn export_file_name(liturgy: &str, date: &str, extension: &str) -> String {
+    let slug = liturgy.replace('/', "-");
+    if !date.is_empty() {
+        format!("{}-{}.{}", slug, date, extension)
+    } else {
+        format!("{}.{}", slug, extension)
+    }
+}
+
+/// A `Content-Disposition` header for an exported file. The name is given both in full, encoded as
+/// RFC 6266 describes (`filename*`), and reduced to safe ASCII characters for clients that don't support that.
+fn content_disposition(disposition: DispositionType, file_name: &str) -> ContentDisposition {
+    let ascii_file_name = file_name
+        .chars()
+        .map(|ch| {
+            if ch.is_ascii_alphanumeric() || matches!(ch, '-' | '_' | '.') {
+                ch
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    ContentDisposition {
+        disposition,
+        parameters: vec![
+            DispositionParam::Filename(ascii_file_name),
+            DispositionParam::FilenameExt(ExtendedValue {
+                charset: Charset::Ext("UTF-8".to_string()),
+                language_tag: None,
+                value: file_name.as_bytes().to_vec(),
+            }),
+        ],
+    }
+}
+
+#[derive(Deserialize)]
+struct PdfExportFormData {
+    liturgy: String,
+    date: String,
+    doc: String,
+    /// e.g., `letter`, `half-letter`, or `a4`; defaults to US Letter
+    #[serde(default)]
+    page_size: String,
+    /// If present, prints half-size pages two to a sheet so they can be folded into a booklet
+    #[serde(default)]
+    booklet: Option<String>,
+}
+
+#[post("/api/export/pdf")]
+async fn export_pdf(data: web::Form<PdfExportFormData>) -> Result<HttpResponse> {
+    use episcopal_api::pdf::{PageSize, PdfDocument, PdfOptions};
+
+    let data = data.into_inner();
+    let doc: Document = serde_json::from_str(&data.doc)?;
+
+    let options = if data.booklet.is_some() {
+        PdfOptions::booklet()
+    } else {
+        PdfOptions::default().page_size(PageSize::from(data.page_size.as_str()))
+    };
+
+    let mut pdf = Vec::new();
+    PdfDocument::with_options(options)
+        .add_content(&doc)
+        .write(&mut pdf)
+        .map_err(|e| error::InternalError::new(e.to_string(), StatusCode::INTERNAL_SERVER_ERROR))?;
+
+    let file_name = export_file_name(&data.liturgy, &data.date, "pdf");
+
+    Ok(HttpResponse::Ok()
+        .content_type("application/pdf")
+        .insert_header(content_disposition(DispositionType::Inline, &file_name))
+        .body(pdf))
+}
+
+#[derive(Deserialize)]
+struct EpubExportFormData {
+    liturgy: String,
+    date: String,
+    doc: String,
+}
+
+#[post("/api/export/epub")]
+async fn export_epub(data: web::Form<EpubExportFormData>) -> Result<HttpResponse> {
+    let data = data.into_inner();
+    let doc: Document = serde_json::from_str(&data.doc)?;
+
+    let title = doc.best_label().unwrap_or_else(|| data.liturgy.clone());
+    let file_name = export_file_name(&data.liturgy, &data.date, "epub");
+
+    epub_response(
+        episcopal_api::epub::Epub::new(title).add_document(&doc),
+        &file_name,
+    )
+}
+
+#[derive(Deserialize)]
+struct PsalterEpubParams {
+    /// `60` to divide the Psalter into a sixty-day cycle; otherwise the 30-day cycle
+    #[serde(default)]
+    cycle: Option<u8>,
+}
+
+#[get("/api/export/psalter.epub")]
+async fn export_psalter_epub(params: Query<PsalterEpubParams>) -> Result<HttpResponse> {
+    use episcopal_api::epub::{Epub, PsalterCycle};
+    use episcopal_api::psalter::bcp1979::BCP1979_PSALTER;
+
+    let cycle = if params.cycle == Some(60) {
+        PsalterCycle::SixtyDay
+    } else {
+        PsalterCycle::ThirtyDay
+    };
+    epub_response(
+        Epub::new("The Psalter").add_psalter("The Psalter", &BCP1979_PSALTER, cycle),
+        "psalter.epub",
+    )
+}
+
+/// Every document under a path in the table of contents, like `office/canticles`, as one book
+#[get("/api/export/contents/{path:.*}.epub")]
+async fn export_contents_epub(path: web::Path<String>) -> Result<HttpResponse> {
+    let path = path.into_inner();
+    let slug_path = path
+        .parse::<SlugPath>()
+        .map_err(|_| error::ErrorNotFound(format!("{} not found", path)))?;
+    let contents = CommonPrayer::contents()
+        .contents_at_path(&slug_path)
+        .ok_or_else(|| error::ErrorNotFound(format!("{} not found", path)))?;
+
+    let title = contents.label().unwrap_or_else(|| path.clone());
+    let file_name = export_file_name(&path, "", "epub");
+    epub_response(
+        episcopal_api::epub::Epub::new(title).add_contents(&contents),
+        &file_name,
+    )
+}
+
+fn epub_response(book: episcopal_api::epub::Epub, file_name: &str) -> Result<HttpResponse> {
+    let mut epub = std::io::Cursor::new(Vec::new());
+    book.write(&mut epub)
+        .map_err(|e| error::InternalError::new(e.to_string(), StatusCode::INTERNAL_SERVER_ERROR))?;
+
+    Ok(HttpResponse::Ok()
+        .content_type("application/epub+zip")
+        .insert_header(content_disposition(DispositionType::Attachment, file_name))
+        .body(epub.into_inner()))
+}